@@ -211,7 +211,8 @@ impl SoundGenerator for SteamGenerator {
 }
 
 /// Explosion sound: low-frequency sawtooth oscillator with exponential decay.
-/// One-shot: ramps gain to peak then decays over ~0.5s.
+/// One-shot: ramps gain to peak then decays over 0.3-1.5s; the stronger the
+/// blast, the longer and deeper the boom (80 Hz down to 40 Hz).
 #[derive(Default)]
 pub struct ExplosionGenerator {
     osc: Option<OscillatorNode>,
@@ -234,7 +235,11 @@ impl SoundGenerator for ExplosionGenerator {
             return;
         }
         let result: Result<(), JsValue> = (|| {
-            let (osc, gain_node) = bridge.create_oscillator(60.0, OscillatorType::Sawtooth, 0.0)?;
+            let intensity = intensity.clamp(0.0, 1.0);
+            let pitch = 80.0 - 40.0 * intensity;
+            let decay = 0.3 + 1.2 * f64::from(intensity);
+            let (osc, gain_node) =
+                bridge.create_oscillator(pitch, OscillatorType::Sawtooth, 0.0)?;
 
             let now = bridge.current_time();
             // Exponential decay envelope
@@ -244,10 +249,10 @@ impl SoundGenerator for ExplosionGenerator {
                 .linear_ramp_to_value_at_time(intensity * 0.5, now + 0.02)?;
             gain_node
                 .gain()
-                .exponential_ramp_to_value_at_time(0.001, now + 0.5)?;
+                .exponential_ramp_to_value_at_time(0.001, now + decay)?;
 
             osc.start()?;
-            osc.stop_with_when(now + 0.6)?;
+            osc.stop_with_when(now + decay + 0.1)?;
 
             self.osc = Some(osc);
             self.gain = Some(gain_node);
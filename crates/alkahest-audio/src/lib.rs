@@ -7,7 +7,7 @@ pub mod generators;
 #[cfg(target_arch = "wasm32")]
 pub mod mixer;
 
-pub use scanner::{AudioCategory, AudioSource};
+pub use scanner::{explosion_intensity, AudioCategory, AudioSource};
 
 /// Top-level audio facade. Owns the scanner (platform-independent) and
 /// the Web Audio mixer (WASM-only). When disabled, update is a no-op
@@ -104,6 +104,20 @@ impl AudioSystem {
         self.scanner.register_event(position, category, intensity);
     }
 
+    /// Register an explosion with the given blast yield (from the activity
+    /// flags). Louder, longer and deeper the bigger the yield. No-op when
+    /// disabled or when nothing went off.
+    pub fn register_explosion(&mut self, position: glam::Vec3, blast_yield: u32) {
+        if blast_yield == 0 {
+            return;
+        }
+        self.register_event(
+            position,
+            scanner::AudioCategory::Explosion,
+            scanner::explosion_intensity(blast_yield),
+        );
+    }
+
     /// Update the audio system. When disabled: immediate return (zero CPU cost).
     /// When enabled: scanner produces sources, mixer spatializes them.
     pub fn update(
@@ -143,6 +157,7 @@ mod tests {
 
         // Register event while disabled — no panic
         sys.register_event(glam::Vec3::ZERO, AudioCategory::Fire, 1.0);
+        sys.register_explosion(glam::Vec3::ZERO, 240);
 
         // Update while disabled — no panic, immediate return
         sys.update(0.016, glam::Vec3::ZERO, glam::Vec3::Z, 8);
@@ -31,6 +31,17 @@ impl AudioCategory {
     }
 }
 
+/// Blast yield (summed strength of the fronts set off in a chunk in one tick)
+/// that plays an explosion at full intensity.
+const EXPLOSION_FULL_YIELD: u32 = 4096;
+
+/// Explosion intensity (0.0-1.0) for a chunk's blast yield. Logarithmic, so a
+/// lone gas pocket is still audible and a large charge does not clip.
+pub fn explosion_intensity(blast_yield: u32) -> f32 {
+    let full = (EXPLOSION_FULL_YIELD as f32).ln_1p();
+    ((blast_yield as f32).ln_1p() / full).clamp(0.0, 1.0)
+}
+
 /// A positioned audio source with intensity.
 #[derive(Debug, Clone)]
 pub struct AudioSource {
@@ -215,6 +226,17 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_explosion_intensity_scales_with_yield() {
+        assert_eq!(explosion_intensity(0), 0.0);
+        let pocket = explosion_intensity(60);
+        let charge = explosion_intensity(1200);
+        assert!(pocket > 0.3, "a gas pocket should be audible, got {pocket}");
+        assert!(pocket < charge && charge < 1.0);
+        assert_eq!(explosion_intensity(EXPLOSION_FULL_YIELD), 1.0);
+        assert_eq!(explosion_intensity(u32::MAX), 1.0);
+    }
+
     #[test]
     fn test_material_id_mapping() {
         assert_eq!(
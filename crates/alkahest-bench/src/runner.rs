@@ -239,16 +239,19 @@ impl BenchmarkRunner {
         ])
         .expect("failed to parse material RON data");
 
-        let rules = alkahest_rules::loader::load_all_rules(&[
-            combustion_ron,
-            structural_ron,
-            phase_change_ron,
-            dissolution_ron,
-            displacement_ron,
-            biological_ron,
-            thermal_ron,
-            synthesis_ron,
-        ])
+        let rules = alkahest_rules::loader::load_all_rules(
+            &[
+                combustion_ron,
+                structural_ron,
+                phase_change_ron,
+                dissolution_ron,
+                displacement_ron,
+                biological_ron,
+                thermal_ron,
+                synthesis_ron,
+            ],
+            &materials,
+        )
         .expect("failed to parse rules RON data");
 
         let mut materials = materials;
@@ -266,6 +269,8 @@ impl BenchmarkRunner {
                 mod_manifest_ron,
                 &[mod_materials_ron],
                 &[mod_rules_ron],
+                &materials,
+                &[],
             )
             .expect("failed to load example mod");
 
@@ -279,13 +284,13 @@ impl BenchmarkRunner {
             }
 
             let mut remap = alkahest_rules::migration::IdRemap::new(materials.max_id());
-            let warnings = alkahest_rules::loader::merge_mod(
+            let report = alkahest_rules::loader::merge_mod(
                 &mut materials,
                 &mut rules,
                 &mod_result,
                 &mut remap,
             );
-            for w in &warnings {
+            for w in report.warnings() {
                 log::warn!("{w}");
             }
         }
@@ -297,10 +302,17 @@ impl BenchmarkRunner {
             panic!("Material validation failed with {} errors", errors.len());
         }
         if let Err(errors) = alkahest_rules::validator::validate_rules(&rules, &materials) {
-            for e in &errors {
+            let (warnings, fatal): (Vec<_>, Vec<_>) =
+                errors.into_iter().partition(|e| e.is_warning());
+            for e in &warnings {
+                log::warn!("Rule validation warning: {e}");
+            }
+            for e in &fatal {
                 log::error!("Rule validation error: {e}");
             }
-            panic!("Rule validation failed with {} errors", errors.len());
+            if !fatal.is_empty() {
+                panic!("Rule validation failed with {} errors", fatal.len());
+            }
         }
 
         let gpu_data = alkahest_rules::compiler::compile(device, &materials, &rules);
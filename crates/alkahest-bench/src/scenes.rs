@@ -2,8 +2,8 @@ use alkahest_core::constants::{
     AMBIENT_TEMP_QUANTIZED, CHUNK_SIZE, VOXELS_PER_CHUNK, WORLD_CHUNKS_X, WORLD_CHUNKS_Y,
     WORLD_CHUNKS_Z,
 };
-use alkahest_core::math::pack_voxel;
 use alkahest_core::types::MaterialId;
+use alkahest_core::voxel::Voxel;
 
 /// Configuration for a single benchmark scene.
 pub struct SceneConfig {
@@ -85,8 +85,10 @@ pub fn generate_bench_chunk(chunk_index: u32, fill_count: u32) -> Vec<[u32; 2]>
         };
 
         let flags = 0x01u8; // active flag set
-        let voxel = pack_voxel(MaterialId(mat_id), temp, 0, 0, 0, 0, flags);
-        *slot = [voxel.low, voxel.high];
+        *slot = Voxel::new(MaterialId(mat_id))
+            .with_temperature(temp)
+            .with_flags(flags)
+            .into();
     }
 
     data
@@ -0,0 +1,239 @@
+//! Loading material and rule data from disk.
+//!
+//! The web app embeds its RON files at compile time; the CLI reads them at
+//! runtime so designers can iterate on data without rebuilding.
+
+use std::path::{Path, PathBuf};
+
+use alkahest_core::material::MaterialTable;
+use alkahest_core::rule::RuleSet;
+use alkahest_rules::loader::{self, LoadedMod};
+use alkahest_rules::migration::IdRemap;
+use alkahest_rules::report::MergeReport;
+use alkahest_rules::{resolver, validator};
+
+use crate::error::CliError;
+
+/// Validated material table and rule set, ready for compilation.
+pub struct RuleData {
+    pub materials: MaterialTable,
+    pub rules: RuleSet,
+    /// What each mod merge changed, in load order.
+    pub merge_reports: Vec<MergeReport>,
+    /// Non-fatal messages from rule validation.
+    pub warnings: Vec<String>,
+}
+
+/// Load base data from `data_dir` (expects `materials/` and `rules/`), merge
+/// each mod directory in dependency order, and validate the result.
+pub fn load_rule_data(data_dir: &Path, mod_dirs: &[PathBuf]) -> Result<RuleData, CliError> {
+    let mut materials = MaterialTable {
+        materials: Vec::new(),
+    };
+    for (path, source) in read_ron_dir(&data_dir.join("materials"))? {
+        let table = loader::load_materials_from_str(&source)
+            .map_err(|source| CliError::Load { path, source })?;
+        materials.materials.extend(table.materials);
+    }
+
+    let mut rules = RuleSet { rules: Vec::new() };
+    for (path, source) in read_ron_dir(&data_dir.join("rules"))? {
+        let set = loader::load_rules_from_str(&source, &materials)
+            .map_err(|source| CliError::Load { path, source })?;
+        rules.rules.extend(set.rules);
+    }
+
+    // Read every manifest up front so the load order can follow dependencies.
+    let mut manifests = Vec::with_capacity(mod_dirs.len());
+    let mut parsed = Vec::with_capacity(mod_dirs.len());
+    for mod_dir in mod_dirs {
+        let manifest = read_file(&mod_dir.join("mod.ron"))?;
+        parsed.push(
+            loader::load_mod_manifest(&manifest).map_err(|source| CliError::Load {
+                path: mod_dir.clone(),
+                source,
+            })?,
+        );
+        manifests.push(manifest);
+    }
+    let order = resolver::resolve_load_order(&parsed)
+        .map_err(|errors| CliError::Validation(errors.iter().map(|e| e.to_string()).collect()))?;
+
+    let mut merge_reports = Vec::with_capacity(order.len());
+    let mut loaded = Vec::with_capacity(order.len());
+    for i in order {
+        let mod_dir = &mod_dirs[i];
+        let material_sources = read_ron_dir(&mod_dir.join("materials"))?;
+        let rule_sources = read_ron_dir(&mod_dir.join("rules"))?;
+        let material_strs: Vec<&str> = material_sources.iter().map(|(_, s)| s.as_str()).collect();
+        let rule_strs: Vec<&str> = rule_sources.iter().map(|(_, s)| s.as_str()).collect();
+
+        let mod_result = loader::load_mod(
+            &manifests[i],
+            &material_strs,
+            &rule_strs,
+            &materials,
+            &loaded,
+        )
+        .map_err(|source| CliError::Load {
+            path: mod_dir.clone(),
+            source,
+        })?;
+        validator::validate_mod_materials(&mod_result.materials).map_err(errors_to_strings)?;
+
+        let mut remap = IdRemap::new(materials.max_id());
+        merge_reports.push(loader::merge_mod(
+            &mut materials,
+            &mut rules,
+            &mod_result,
+            &mut remap,
+        ));
+        loaded.push(LoadedMod::new(&mod_result, &remap));
+    }
+
+    validator::validate_materials(&materials).map_err(errors_to_strings)?;
+    let mut warnings = Vec::new();
+    if let Err(errors) = validator::validate_rules(&rules, &materials) {
+        // Cycle and starvation checks are heuristics the shipped data trips
+        // (reversible pairs, rules split across files), so report those
+        // without failing.
+        let (heuristic, fatal): (Vec<_>, Vec<_>) = errors.into_iter().partition(|e| e.is_warning());
+        if !fatal.is_empty() {
+            return Err(errors_to_strings(fatal));
+        }
+        warnings.extend(heuristic.iter().map(|e| e.to_string()));
+    }
+    for expansion in validator::report_pattern_expansions(&rules, &materials) {
+        log::info!(
+            "Pattern rule '{}' expanded to {} rules ({} pairs already covered)",
+            expansion.rule,
+            expansion.generated,
+            expansion.shadowed
+        );
+    }
+
+    Ok(RuleData {
+        materials,
+        rules,
+        merge_reports,
+        warnings,
+    })
+}
+
+/// Read every `*.ron` file in a directory, sorted by file name.
+/// Files starting with `_` (schema documentation) are skipped.
+/// A missing directory yields no files.
+fn read_ron_dir(dir: &Path) -> Result<Vec<(PathBuf, String)>, CliError> {
+    if !dir.is_dir() {
+        return Ok(Vec::new());
+    }
+    let entries = std::fs::read_dir(dir).map_err(|source| CliError::Io {
+        path: dir.to_path_buf(),
+        source,
+    })?;
+
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|e| e.ok().map(|e| e.path()))
+        .filter(|p| {
+            p.extension().is_some_and(|ext| ext == "ron")
+                && !p
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .is_some_and(|n| n.starts_with('_'))
+        })
+        .collect();
+    paths.sort();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let source = read_file(&path)?;
+            Ok((path, source))
+        })
+        .collect()
+}
+
+fn read_file(path: &Path) -> Result<String, CliError> {
+    std::fs::read_to_string(path).map_err(|source| CliError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+fn errors_to_strings(errors: Vec<validator::ValidationError>) -> CliError {
+    CliError::Validation(errors.iter().map(|e| e.to_string()).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn repo_data_dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("../../data")
+    }
+
+    #[test]
+    fn test_load_repo_data() {
+        let data = load_rule_data(&repo_data_dir(), &[]).expect("repo data should load");
+        assert!(data.materials.get(1).is_some_and(|m| m.name == "Stone"));
+        assert!(!data.rules.is_empty());
+    }
+
+    #[test]
+    fn test_load_with_example_mod() {
+        let base = load_rule_data(&repo_data_dir(), &[]).unwrap();
+        let modded = load_rule_data(
+            &repo_data_dir(),
+            &[repo_data_dir().join("mods/example-mod")],
+        )
+        .expect("example mod should merge");
+        assert!(modded.materials.len() > base.materials.len());
+        assert!(base.merge_reports.is_empty());
+        let report = &modded.merge_reports[0];
+        assert_eq!(report.mod_name, "Crystal Pack");
+        assert_eq!(
+            report.remapped_ids.len(),
+            modded.materials.len() - base.materials.len()
+        );
+    }
+
+    #[test]
+    fn test_dependent_mod_loads_after_dependency() {
+        let dir = std::env::temp_dir().join(format!("alkahest-dep-mod-{}", std::process::id()));
+        std::fs::create_dir_all(dir.join("rules")).unwrap();
+        std::fs::write(
+            dir.join("mod.ron"),
+            r#"(name: "Quartz Tools", version: "0.1.0", author: "Tester", description: "",
+                load_order_hint: 0, depends_on: [(name: "Crystal Pack", version: "^1.0")])"#,
+        )
+        .unwrap();
+        std::fs::write(
+            dir.join("rules/tools.ron"),
+            r#"[(name: "Quench", input_a: "Crystal Pack:Quartz", input_b: "Water",
+                 output_a: "Crystal Pack:Quartz", output_b: "Steam", probability: 0.1)]"#,
+        )
+        .unwrap();
+
+        // Listed first with a lower hint, but must still load second
+        let result = load_rule_data(
+            &repo_data_dir(),
+            &[dir.clone(), repo_data_dir().join("mods/example-mod")],
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+        let data = result.expect("dependent mod should load");
+        let quench = data
+            .rules
+            .rules
+            .iter()
+            .find(|r| r.name == "Quench")
+            .unwrap();
+        let quartz = data.materials.get(quench.input_a).unwrap();
+        assert_eq!(quartz.name, "Quartz");
+    }
+
+    #[test]
+    fn test_missing_mod_manifest_is_io_error() {
+        let result = load_rule_data(&repo_data_dir(), &[repo_data_dir().join("no-such-mod")]);
+        assert!(matches!(result, Err(CliError::Io { .. })));
+    }
+}
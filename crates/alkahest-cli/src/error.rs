@@ -0,0 +1,37 @@
+use std::path::PathBuf;
+
+use alkahest_persist::PersistError;
+use alkahest_rules::loader::LoadError;
+
+/// Errors surfaced by the headless command-line tools.
+#[derive(Debug, thiserror::Error)]
+pub enum CliError {
+    /// Bad command-line arguments; the caller prints the usage text.
+    #[error("{0}")]
+    Usage(String),
+
+    #[error("{path}: {source}")]
+    Io {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+
+    #[error("{path}: {source}")]
+    Load { path: PathBuf, source: LoadError },
+
+    #[error("validation failed:\n  {}", .0.join("\n  "))]
+    Validation(Vec<String>),
+
+    #[error("failed to read save: {0}")]
+    Persist(#[from] PersistError),
+
+    #[error("world needs {needed} chunk slots, limit is {limit}")]
+    TooManyChunks { needed: usize, limit: u32 },
+
+    #[error("chunk {coord} has {actual} bytes of voxel data, expected {expected}")]
+    InvalidChunkData {
+        coord: glam::IVec3,
+        expected: usize,
+        actual: usize,
+    },
+}
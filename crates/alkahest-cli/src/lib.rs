@@ -0,0 +1,5 @@
+pub mod data;
+pub mod error;
+pub mod mod_check;
+pub mod session;
+pub mod stats;
@@ -0,0 +1,352 @@
+use std::path::PathBuf;
+use std::process;
+use std::time::Instant;
+
+use alkahest_cli::data;
+use alkahest_cli::error::CliError;
+use alkahest_cli::mod_check::{self, ModCheck};
+use alkahest_cli::session::{self, Session};
+use alkahest_cli::stats;
+use alkahest_rules::compiler::compile_tables;
+use glam::IVec3;
+
+/// Options for the `run` subcommand.
+struct RunOptions {
+    load_path: Option<PathBuf>,
+    seed: u32,
+    size: IVec3,
+    ticks: u64,
+    output_path: Option<PathBuf>,
+    data_dir: PathBuf,
+    mod_dirs: Vec<PathBuf>,
+    json: bool,
+}
+
+/// Options for the `mod check` subcommand.
+struct ModCheckOptions {
+    data_dir: PathBuf,
+    mod_dirs: Vec<PathBuf>,
+    json: bool,
+}
+
+fn main() {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("info")).init();
+
+    let args: Vec<String> = std::env::args().collect();
+
+    let result = match args.get(1).map(String::as_str) {
+        Some("run") => parse_run_args(&args[2..]).and_then(run),
+        Some("mod") if args.get(2).map(String::as_str) == Some("check") => {
+            parse_mod_check_args(&args[3..]).and_then(mod_check)
+        }
+        Some("--help") | Some("-h") | None => {
+            print_usage();
+            process::exit(0);
+        }
+        Some(other) => {
+            eprintln!("Unknown command: {}", other);
+            print_usage();
+            process::exit(1);
+        }
+    };
+
+    if let Err(e) = result {
+        eprintln!("error: {}", e);
+        if matches!(e, CliError::Usage(_)) {
+            eprintln!();
+            print_usage();
+        }
+        process::exit(1);
+    }
+}
+
+fn print_usage() {
+    eprintln!("Usage: alkahest-cli <COMMAND> [OPTIONS]");
+    eprintln!();
+    eprintln!("Commands:");
+    eprintln!("  run          Simulate a world headlessly and print material populations");
+    eprintln!("  mod check    Merge mods and report overridden rules and shadowed materials");
+    eprintln!();
+    eprintln!("Options for run:");
+    eprintln!("  --load <path>      Load an .alka save instead of generating terrain");
+    eprintln!("  --seed <n>         Terrain seed for generated worlds (default: 42)");
+    eprintln!("  --size <x,y,z>     Generated world size in chunks (default: 8,4,8)");
+    eprintln!("  --ticks <n>        Ticks to simulate (default: 100)");
+    eprintln!("  --output <path>    Write the resulting world as an .alka save");
+    eprintln!("  --data <dir>       Material and rule data directory (default: data)");
+    eprintln!(
+        "  --mod <dir>        Merge a mod directory (repeatable, applied in dependency order)"
+    );
+    eprintln!("  --json             Print statistics as JSON instead of a table");
+    eprintln!();
+    eprintln!("Options for mod check:");
+    eprintln!("  --data <dir>       Material and rule data directory (default: data)");
+    eprintln!("  --mod <dir>        Mod directory to check (repeatable)");
+    eprintln!("  --json             Print the report as JSON");
+}
+
+fn parse_run_args(args: &[String]) -> Result<RunOptions, CliError> {
+    let mut options = RunOptions {
+        load_path: None,
+        seed: 42,
+        size: session::full_world_size(),
+        ticks: 100,
+        output_path: None,
+        data_dir: PathBuf::from("data"),
+        mod_dirs: Vec::new(),
+        json: false,
+    };
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--load" => {
+                i += 1;
+                options.load_path = Some(PathBuf::from(expect_value(args, i, "--load")?));
+            }
+            "--seed" => {
+                i += 1;
+                options.seed = parse_value(args, i, "--seed")?;
+            }
+            "--size" => {
+                i += 1;
+                let value = expect_value(args, i, "--size")?;
+                options.size = parse_size(value).ok_or_else(|| invalid_value("--size", value))?;
+            }
+            "--ticks" => {
+                i += 1;
+                options.ticks = parse_value(args, i, "--ticks")?;
+            }
+            "--output" => {
+                i += 1;
+                options.output_path = Some(PathBuf::from(expect_value(args, i, "--output")?));
+            }
+            "--data" => {
+                i += 1;
+                options.data_dir = PathBuf::from(expect_value(args, i, "--data")?);
+            }
+            "--mod" => {
+                i += 1;
+                options
+                    .mod_dirs
+                    .push(PathBuf::from(expect_value(args, i, "--mod")?));
+            }
+            "--json" => options.json = true,
+            "--help" | "-h" => {
+                print_usage();
+                process::exit(0);
+            }
+            other => {
+                return Err(CliError::Usage(format!("unknown argument: {}", other)));
+            }
+        }
+        i += 1;
+    }
+
+    Ok(options)
+}
+
+fn parse_mod_check_args(args: &[String]) -> Result<ModCheckOptions, CliError> {
+    let mut options = ModCheckOptions {
+        data_dir: PathBuf::from("data"),
+        mod_dirs: Vec::new(),
+        json: false,
+    };
+
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--data" => {
+                i += 1;
+                options.data_dir = PathBuf::from(expect_value(args, i, "--data")?);
+            }
+            "--mod" => {
+                i += 1;
+                options
+                    .mod_dirs
+                    .push(PathBuf::from(expect_value(args, i, "--mod")?));
+            }
+            "--json" => options.json = true,
+            "--help" | "-h" => {
+                print_usage();
+                process::exit(0);
+            }
+            other => {
+                return Err(CliError::Usage(format!("unknown argument: {}", other)));
+            }
+        }
+        i += 1;
+    }
+
+    Ok(options)
+}
+
+fn expect_value<'a>(args: &'a [String], i: usize, flag: &str) -> Result<&'a str, CliError> {
+    args.get(i)
+        .map(String::as_str)
+        .ok_or_else(|| CliError::Usage(format!("missing value for {}", flag)))
+}
+
+/// Parse the value of `flag`, which follows it at `args[i]`.
+fn parse_value<T: std::str::FromStr>(args: &[String], i: usize, flag: &str) -> Result<T, CliError> {
+    let value = expect_value(args, i, flag)?;
+    value.parse().map_err(|_| invalid_value(flag, value))
+}
+
+fn invalid_value(flag: &str, value: &str) -> CliError {
+    CliError::Usage(format!("invalid {} value: {:?}", flag, value))
+}
+
+/// Parse `x,y,z` into a chunk-space size. Sizes whose chunk count does not
+/// fit in an `i32` are rejected here rather than overflowing later.
+fn parse_size(value: &str) -> Option<IVec3> {
+    let parts: Vec<i32> = value
+        .split(',')
+        .map(|p| p.trim().parse().ok())
+        .collect::<Option<_>>()?;
+    match parts.as_slice() {
+        &[x, y, z] if x > 0 && y > 0 && z > 0 => {
+            x.checked_mul(y)?.checked_mul(z)?;
+            Some(IVec3::new(x, y, z))
+        }
+        _ => None,
+    }
+}
+
+fn run(options: RunOptions) -> Result<(), CliError> {
+    let rule_data = data::load_rule_data(&options.data_dir, &options.mod_dirs)?;
+    for report in &rule_data.merge_reports {
+        for warning in report.warnings() {
+            log::warn!("{}", warning);
+        }
+    }
+    for warning in &rule_data.warnings {
+        log::warn!("{}", warning);
+    }
+    let tables = compile_tables(&rule_data.materials, &rule_data.rules);
+    log::info!(
+        "Loaded {} materials, {} rules",
+        rule_data.materials.len(),
+        rule_data.rules.len()
+    );
+
+    let mut session = match &options.load_path {
+        Some(path) => {
+            let bytes = std::fs::read(path).map_err(|source| CliError::Io {
+                path: path.clone(),
+                source,
+            })?;
+            let session = Session::from_save(tables, &bytes)?;
+            log::info!(
+                "Loaded {} chunks from {} at tick {}",
+                session.chunk_count(),
+                path.display(),
+                session.tick_count()
+            );
+            session
+        }
+        None => {
+            let session = Session::generate(tables, options.seed, options.size)?;
+            log::info!(
+                "Generated {} chunks with seed {}",
+                session.chunk_count(),
+                options.seed
+            );
+            session
+        }
+    };
+    for warning in session.warnings() {
+        log::warn!("{}", warning);
+    }
+
+    let start = Instant::now();
+    session.run(options.ticks);
+    let (loaded, active, static_count) = session.chunk_counts();
+    log::info!(
+        "Simulated {} ticks in {:.2}s ({} loaded, {} active, {} static chunks)",
+        options.ticks,
+        start.elapsed().as_secs_f64(),
+        loaded,
+        active,
+        static_count
+    );
+
+    let population = stats::collect(
+        session.chunks().map(|(_, data)| data),
+        &rule_data.materials,
+        session.tick_count(),
+    );
+    if options.json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&population).expect("stats serialize to JSON")
+        );
+    } else {
+        print!("{}", stats::format_table(&population));
+    }
+
+    if let Some(path) = &options.output_path {
+        std::fs::write(path, session.to_save()).map_err(|source| CliError::Io {
+            path: path.clone(),
+            source,
+        })?;
+        log::info!("Saved world to {}", path.display());
+    }
+
+    Ok(())
+}
+
+fn mod_check(options: ModCheckOptions) -> Result<(), CliError> {
+    let rule_data = data::load_rule_data(&options.data_dir, &options.mod_dirs)?;
+    let check = ModCheck::new(&rule_data);
+    if options.json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&check).expect("mod check serializes to JSON")
+        );
+    } else {
+        print!("{}", mod_check::format_report(&check));
+    }
+    log::info!(
+        "Checked {} mods: {} conflicts",
+        check.mods.len(),
+        check.conflict_count()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(list: &[&str]) -> Vec<String> {
+        list.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn test_bad_run_values_are_usage_errors() {
+        for bad in [
+            &["--seed", "abc"][..],
+            &["--size", "1,2"],
+            &["--size", "100000,100000,100000"],
+            &["--ticks", "-5"],
+            &["--ticks"],
+            &["--bogus"],
+        ] {
+            let result = parse_run_args(&args(bad));
+            assert!(
+                matches!(result, Err(CliError::Usage(_))),
+                "{bad:?} should be a usage error"
+            );
+        }
+    }
+
+    #[test]
+    fn test_run_values_parse() {
+        let options =
+            parse_run_args(&args(&["--seed", "7", "--size", "2,1,3", "--ticks", "10"])).unwrap();
+        assert_eq!(options.seed, 7);
+        assert_eq!(options.size, IVec3::new(2, 1, 3));
+        assert_eq!(options.ticks, 10);
+    }
+}
@@ -0,0 +1,116 @@
+//! Conflict report for `mod check`.
+
+use alkahest_rules::report::MergeReport;
+use serde::Serialize;
+
+use crate::data::RuleData;
+
+/// Merge reports for every mod, plus rule validation warnings on the
+/// merged result.
+#[derive(Debug, Clone, Serialize)]
+pub struct ModCheck {
+    pub mods: Vec<MergeReport>,
+    pub warnings: Vec<String>,
+}
+
+impl ModCheck {
+    pub fn new(data: &RuleData) -> Self {
+        Self {
+            mods: data.merge_reports.clone(),
+            warnings: data.warnings.clone(),
+        }
+    }
+
+    /// Total conflicts across all mods.
+    pub fn conflict_count(&self) -> usize {
+        self.mods.iter().map(MergeReport::conflict_count).sum()
+    }
+}
+
+/// Format a mod check as plain text, one section per mod in load order.
+pub fn format_report(check: &ModCheck) -> String {
+    let mut out = String::new();
+    for report in &check.mods {
+        out.push_str(&format!(
+            "Mod '{}': {} conflicts\n",
+            report.mod_name,
+            report.conflict_count()
+        ));
+        if !report.overridden_rules.is_empty() {
+            out.push_str("  Overridden rules:\n");
+            for o in &report.overridden_rules {
+                out.push_str(&format!("    '{}' -> '{}'\n", o.before.name, o.after.name));
+                for change in o.changes() {
+                    out.push_str(&format!(
+                        "      {}: {} -> {}\n",
+                        change.field, change.before, change.after
+                    ));
+                }
+            }
+        }
+        if !report.unreachable_rules.is_empty() {
+            out.push_str("  Unreachable rules:\n");
+            for u in &report.unreachable_rules {
+                let mut reasons = Vec::new();
+                if !u.blocked_by.is_empty() {
+                    reasons.push(format!("covered by {}", u.blocked_by.join(", ")));
+                }
+                if !u.shadowed_materials.is_empty() {
+                    reasons.push(format!("shadowed {}", u.shadowed_materials.join(", ")));
+                }
+                out.push_str(&format!("    '{}' ({})\n", u.name, reasons.join("; ")));
+            }
+        }
+        if !report.shadowed_materials.is_empty() {
+            out.push_str("  Shadowed materials:\n");
+            for s in &report.shadowed_materials {
+                out.push_str(&format!(
+                    "    {:<24} {:>5} shadows {:>5}\n",
+                    s.name, s.mod_id, s.existing_id
+                ));
+            }
+        }
+        if !report.remapped_ids.is_empty() {
+            out.push_str("  Remapped IDs:\n");
+            for r in &report.remapped_ids {
+                out.push_str(&format!(
+                    "    {:<24} {:>5} -> {:>5}\n",
+                    r.name, r.external, r.internal
+                ));
+            }
+        }
+    }
+    if !check.warnings.is_empty() {
+        out.push_str("Validation warnings:\n");
+        for warning in &check.warnings {
+            out.push_str(&format!("  {}\n", warning));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alkahest_rules::report::RemappedId;
+
+    #[test]
+    fn test_format_lists_sections_with_entries() {
+        let mut report = MergeReport::new("Crystal Pack");
+        report.remapped_ids.push(RemappedId {
+            name: "Quartz".into(),
+            external: 10001,
+            internal: 562,
+        });
+        let check = ModCheck {
+            mods: vec![report],
+            warnings: vec!["Rule 'x' is starved".into()],
+        };
+        let text = format_report(&check);
+        assert!(text.starts_with("Mod 'Crystal Pack': 0 conflicts\n"));
+        assert!(text.contains("  Remapped IDs:\n    Quartz"));
+        assert!(text.contains("10001 ->   562"));
+        assert!(!text.contains("Overridden rules"));
+        assert!(text.ends_with("Validation warnings:\n  Rule 'x' is starved\n"));
+    }
+}
@@ -0,0 +1,310 @@
+//! A headless world: chunk bookkeeping plus the CPU reference simulator.
+//!
+//! Chunk lifecycle follows the web app: the dispatch list is rebuilt every
+//! tick from `ChunkMap`, and activity flags drive the Active/Static state
+//! machine so settled regions stop costing simulation time.
+
+use alkahest_core::constants::{
+    BYTES_PER_CHUNK, CHUNK_SIZE, MAX_CHUNK_SLOTS, VOXELS_PER_CHUNK, WORLD_CHUNKS_X, WORLD_CHUNKS_Y,
+    WORLD_CHUNKS_Z,
+};
+use alkahest_core::types::ChunkCoord;
+use alkahest_core::voxel::Voxel;
+use alkahest_persist::{CameraState, ChunkSnapshot};
+use alkahest_rules::compiler::CompiledTables;
+use alkahest_sim::cpu::CpuSimulator;
+use alkahest_world::chunk_map::ChunkMap;
+use alkahest_world::dispatch::build_dispatch_list;
+use alkahest_world::state_machine::process_activity_flags;
+use alkahest_world::terrain::TerrainGenerator;
+use glam::IVec3;
+
+use crate::error::CliError;
+
+/// Camera written to saves created from generated terrain.
+/// Matches the web app's default orbit camera.
+pub fn default_camera() -> CameraState {
+    let half = CHUNK_SIZE as f32 / 2.0;
+    CameraState {
+        mode: 0,
+        yaw: std::f32::consts::FRAC_PI_4,
+        pitch: -0.4,
+        target: [half, half, half],
+        distance: 60.0,
+    }
+}
+
+/// The full world grid, as generated by the web app.
+pub fn full_world_size() -> IVec3 {
+    IVec3::new(
+        WORLD_CHUNKS_X as i32,
+        WORLD_CHUNKS_Y as i32,
+        WORLD_CHUNKS_Z as i32,
+    )
+}
+
+/// A loaded world being simulated on the CPU.
+pub struct Session {
+    chunk_map: ChunkMap,
+    sim: CpuSimulator,
+    /// Loaded chunk coordinates in load order (saves preserve this order).
+    coords: Vec<ChunkCoord>,
+    world_seed: u32,
+    camera: CameraState,
+    warnings: Vec<String>,
+}
+
+impl Session {
+    /// Generate terrain for every chunk in `0..size` with the given seed.
+    pub fn generate(tables: CompiledTables, seed: u32, size: IVec3) -> Result<Self, CliError> {
+        let chunk_count = [size.x, size.y, size.z]
+            .iter()
+            .fold(1usize, |n, &axis| n.saturating_mul(axis.max(0) as usize));
+        check_capacity(chunk_count)?;
+        let terrain = TerrainGenerator::new(seed as u64);
+        let mut chunks = Vec::new();
+        for cx in 0..size.x {
+            for cy in 0..size.y {
+                for cz in 0..size.z {
+                    let coord = IVec3::new(cx, cy, cz);
+                    chunks.push((coord, terrain.generate_chunk(coord), Vec::new()));
+                }
+            }
+        }
+        Self::from_chunks(tables, chunks, seed, default_camera(), Vec::new())
+    }
+
+    /// Restore a world from `.alka` save bytes.
+    /// Compatibility warnings (e.g. a rule hash mismatch) are kept in
+    /// [`Session::warnings`] rather than failing the load.
+    pub fn from_save(tables: CompiledTables, bytes: &[u8]) -> Result<Self, CliError> {
+        let save = alkahest_persist::load(bytes, tables.rule_hash)?;
+        check_capacity(save.chunks.len())?;
+        let mut chunks = Vec::with_capacity(save.chunks.len());
+        for ChunkSnapshot {
+            coord,
+            voxel_data: bytes,
+            aux_data,
+        } in save.chunks
+        {
+            if bytes.len() != BYTES_PER_CHUNK as usize {
+                return Err(CliError::InvalidChunkData {
+                    coord,
+                    expected: BYTES_PER_CHUNK as usize,
+                    actual: bytes.len(),
+                });
+            }
+            let voxels = bytes
+                .chunks_exact(8)
+                .map(|b| {
+                    [
+                        u32::from_le_bytes([b[0], b[1], b[2], b[3]]),
+                        u32::from_le_bytes([b[4], b[5], b[6], b[7]]),
+                    ]
+                })
+                .collect();
+            chunks.push((coord, voxels, aux_data));
+        }
+        let mut session = Self::from_chunks(
+            tables,
+            chunks,
+            save.header.world_seed,
+            save.camera,
+            save.warnings,
+        )?;
+        session.sim.set_tick_count(save.header.tick_count);
+        Ok(session)
+    }
+
+    /// `chunks` holds each chunk's voxels and aux words; empty aux words
+    /// mean every channel starts at zero.
+    fn from_chunks(
+        tables: CompiledTables,
+        chunks: Vec<(ChunkCoord, Vec<[u32; 2]>, Vec<u32>)>,
+        world_seed: u32,
+        camera: CameraState,
+        warnings: Vec<String>,
+    ) -> Result<Self, CliError> {
+        let slot_count = chunks.len() as u32;
+        let mut chunk_map = ChunkMap::with_capacity(slot_count);
+        let mut sim = CpuSimulator::new(tables, slot_count);
+        let mut coords = Vec::with_capacity(chunks.len());
+
+        for (coord, data, aux) in chunks {
+            let slot = chunk_map
+                .load_chunk(coord)
+                .expect("chunk map sized to hold every chunk");
+            sim.upload_chunk_data_both(slot, &data);
+            if !aux.is_empty() {
+                sim.upload_chunk_aux_both(slot, &aux);
+            }
+
+            let has_non_air = data.iter().any(|&v| Voxel::from(v).material_id().0 != 0);
+            if let Some(chunk) = chunk_map.get_mut(&coord) {
+                chunk.has_non_air = has_non_air;
+                // Air is skipped by every pass, so empty chunks start asleep
+                // and are woken by neighbor activity like any other chunk.
+                if !has_non_air {
+                    chunk.sleep();
+                }
+            }
+            coords.push(coord);
+        }
+
+        Ok(Self {
+            chunk_map,
+            sim,
+            coords,
+            world_seed,
+            camera,
+            warnings,
+        })
+    }
+
+    /// Advance the simulation by one tick.
+    /// Like the GPU pipeline, a tick with no active chunks is a no-op.
+    pub fn tick(&mut self) {
+        let dispatch = build_dispatch_list(&self.chunk_map);
+        if dispatch.is_empty() {
+            return;
+        }
+        let slots: Vec<u32> = dispatch.entries.iter().map(|e| e.pool_slot).collect();
+        let coords: Vec<ChunkCoord> = dispatch.entries.iter().map(|e| e.coord).collect();
+        self.sim
+            .upload_chunk_descriptors(&dispatch.build_descriptor_data());
+        self.sim.upload_chunk_coords(&coords);
+        self.sim.solve_structures(slots.len() as u32);
+        self.sim.tick(slots.len() as u32, &slots);
+        let flags = self.sim.activity_flags().to_vec();
+        process_activity_flags(&mut self.chunk_map, &flags);
+    }
+
+    /// Advance the simulation by `ticks` ticks.
+    pub fn run(&mut self, ticks: u64) {
+        for _ in 0..ticks {
+            self.tick();
+        }
+    }
+
+    /// Serialize the current world state to `.alka` save bytes.
+    pub fn to_save(&self) -> Vec<u8> {
+        let snapshots: Vec<ChunkSnapshot> = self
+            .coords
+            .iter()
+            .map(|&coord| {
+                let slot = self.slot(coord);
+                ChunkSnapshot {
+                    coord,
+                    voxel_data: bytemuck::cast_slice(self.sim.chunk_data(slot)).to_vec(),
+                    aux_data: self.sim.chunk_aux(slot).to_vec(),
+                }
+            })
+            .collect();
+        alkahest_persist::save(
+            &snapshots,
+            self.sim.rule_hash(),
+            self.sim.tick_count(),
+            self.world_seed,
+            self.camera,
+        )
+    }
+
+    /// Iterate loaded chunks in load order with their current voxel data.
+    pub fn chunks(&self) -> impl Iterator<Item = (ChunkCoord, &[[u32; 2]])> {
+        self.coords
+            .iter()
+            .map(|&coord| (coord, self.sim.chunk_data(self.slot(coord))))
+    }
+
+    /// Number of loaded chunks.
+    pub fn chunk_count(&self) -> usize {
+        self.coords.len()
+    }
+
+    /// Total voxels across all loaded chunks.
+    pub fn voxel_count(&self) -> u64 {
+        self.coords.len() as u64 * VOXELS_PER_CHUNK as u64
+    }
+
+    /// Ticks simulated since the world was created.
+    pub fn tick_count(&self) -> u64 {
+        self.sim.tick_count()
+    }
+
+    /// (total_loaded, active, static) chunk counts.
+    pub fn chunk_counts(&self) -> (u32, u32, u32) {
+        self.chunk_map.chunk_counts()
+    }
+
+    /// Warnings raised while loading the world.
+    pub fn warnings(&self) -> &[String] {
+        &self.warnings
+    }
+
+    fn slot(&self, coord: ChunkCoord) -> u32 {
+        self.chunk_map
+            .get(&coord)
+            .and_then(|c| c.pool_slot)
+            .expect("loaded chunk has a pool slot")
+    }
+}
+
+/// Worlds are capped at the same slot budget as the GPU chunk pool.
+fn check_capacity(chunk_count: usize) -> Result<(), CliError> {
+    if chunk_count > MAX_CHUNK_SLOTS as usize {
+        return Err(CliError::TooManyChunks {
+            needed: chunk_count,
+            limit: MAX_CHUNK_SLOTS,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::load_rule_data;
+    use alkahest_rules::compiler::compile_tables;
+    use std::path::Path;
+
+    fn tables() -> CompiledTables {
+        let data_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("../../data");
+        let data = load_rule_data(&data_dir, &[]).unwrap();
+        compile_tables(&data.materials, &data.rules)
+    }
+
+    #[test]
+    fn test_generate_matches_terrain_generator() {
+        let session = Session::generate(tables(), 7, IVec3::new(1, 1, 1)).unwrap();
+        let expected = TerrainGenerator::new(7).generate_chunk(IVec3::ZERO);
+        let (coord, data) = session.chunks().next().unwrap();
+        assert_eq!(coord, IVec3::ZERO);
+        assert!(data == expected.as_slice());
+    }
+
+    #[test]
+    fn test_save_roundtrip_preserves_state() {
+        let mut session = Session::generate(tables(), 42, IVec3::new(1, 2, 1)).unwrap();
+        session.run(3);
+        let bytes = session.to_save();
+
+        let restored = Session::from_save(tables(), &bytes).unwrap();
+        assert!(restored.warnings().is_empty());
+        assert_eq!(restored.tick_count(), 3);
+        assert_eq!(restored.chunk_count(), 2);
+        for ((ca, a), (cb, b)) in session.chunks().zip(restored.chunks()) {
+            assert_eq!(ca, cb);
+            assert!(a == b);
+            let aux_a = session.sim.chunk_aux(session.slot(ca));
+            let aux_b = restored.sim.chunk_aux(restored.slot(cb));
+            assert!(aux_a == aux_b);
+        }
+    }
+
+    #[test]
+    fn test_too_many_chunks_rejected() {
+        let size = IVec3::new(17, 1, 16);
+        let result = Session::generate(tables(), 0, size);
+        assert!(matches!(result, Err(CliError::TooManyChunks { .. })));
+    }
+}
@@ -0,0 +1,137 @@
+//! Per-material population statistics.
+
+use std::collections::BTreeMap;
+
+use alkahest_core::material::MaterialTable;
+use alkahest_core::voxel::Voxel;
+use serde::Serialize;
+
+/// Population of a single material across the world.
+#[derive(Debug, Clone, Serialize)]
+pub struct MaterialPopulation {
+    pub material_id: u16,
+    pub name: String,
+    pub count: u64,
+    /// Fraction of all scanned voxels, 0.0..=1.0.
+    pub fraction: f64,
+    /// Mean quantized temperature (12-bit units).
+    pub mean_temp: f64,
+}
+
+/// Population statistics for a world snapshot.
+#[derive(Debug, Clone, Serialize)]
+pub struct PopulationStats {
+    pub tick: u64,
+    pub total_voxels: u64,
+    /// Materials present in the world, most populous first.
+    pub materials: Vec<MaterialPopulation>,
+}
+
+/// Count every voxel in `chunks` by material.
+/// Material IDs missing from the table are reported as `<unknown>`.
+pub fn collect<'a>(
+    chunks: impl IntoIterator<Item = &'a [[u32; 2]]>,
+    materials: &MaterialTable,
+    tick: u64,
+) -> PopulationStats {
+    // material_id -> (count, temperature sum)
+    let mut tally: BTreeMap<u16, (u64, u64)> = BTreeMap::new();
+    let mut total_voxels = 0u64;
+
+    for chunk in chunks {
+        for voxel in chunk {
+            let voxel = Voxel::from(*voxel);
+            let entry = tally.entry(voxel.material_id().0).or_default();
+            entry.0 += 1;
+            entry.1 += voxel.temperature() as u64;
+            total_voxels += 1;
+        }
+    }
+
+    let mut populations: Vec<MaterialPopulation> = tally
+        .into_iter()
+        .map(|(material_id, (count, temp_sum))| MaterialPopulation {
+            material_id,
+            name: materials
+                .get(material_id)
+                .map_or_else(|| "<unknown>".to_string(), |m| m.name.clone()),
+            count,
+            fraction: count as f64 / total_voxels as f64,
+            mean_temp: temp_sum as f64 / count as f64,
+        })
+        .collect();
+    populations.sort_by(|a, b| {
+        b.count
+            .cmp(&a.count)
+            .then(a.material_id.cmp(&b.material_id))
+    });
+
+    PopulationStats {
+        tick,
+        total_voxels,
+        materials: populations,
+    }
+}
+
+/// Format population statistics as a plain-text table.
+pub fn format_table(stats: &PopulationStats) -> String {
+    let mut out = String::new();
+    out.push_str(&format!(
+        "Tick {} — {} voxels\n\n",
+        stats.tick, stats.total_voxels
+    ));
+    out.push_str(&format!(
+        "{:>5}  {:<24} {:>12} {:>9} {:>10}\n",
+        "ID", "Material", "Count", "Share", "Mean temp"
+    ));
+    for m in &stats.materials {
+        out.push_str(&format!(
+            "{:>5}  {:<24} {:>12} {:>8.3}% {:>10.1}\n",
+            m.material_id,
+            m.name,
+            m.count,
+            m.fraction * 100.0,
+            m.mean_temp
+        ));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alkahest_core::material::MaterialTable;
+    use alkahest_core::types::MaterialId;
+    use alkahest_rules::loader::load_materials_from_str;
+
+    fn table() -> MaterialTable {
+        load_materials_from_str(include_str!("../../../data/materials/naturals.ron")).unwrap()
+    }
+
+    #[test]
+    fn test_collect_counts_and_sorts() {
+        let a = vec![[0u32, 0u32]; 6];
+        let mut b = vec![[0u32, 0u32]; 4];
+        // Two sand voxels at temperatures 100 and 300
+        b[0] = Voxel::new(MaterialId(2)).with_temperature(100).into();
+        b[1] = Voxel::new(MaterialId(2)).with_temperature(300).into();
+
+        let stats = collect([a.as_slice(), b.as_slice()], &table(), 5);
+        assert_eq!(stats.tick, 5);
+        assert_eq!(stats.total_voxels, 10);
+        assert_eq!(stats.materials.len(), 2);
+        assert_eq!(stats.materials[0].name, "Air");
+        assert_eq!(stats.materials[0].count, 8);
+        assert_eq!(stats.materials[1].name, "Sand");
+        assert_eq!(stats.materials[1].count, 2);
+        assert!((stats.materials[1].mean_temp - 200.0).abs() < 1e-9);
+        assert!((stats.materials[1].fraction - 0.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_unknown_material_is_labelled() {
+        let chunk = vec![[9999u32, 0u32]];
+        let stats = collect([chunk.as_slice()], &table(), 0);
+        assert_eq!(stats.materials[0].name, "<unknown>");
+    }
+}
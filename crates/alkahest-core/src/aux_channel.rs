@@ -0,0 +1,352 @@
+//! Registry of auxiliary per-voxel data channels, and the single definition
+//! of how they are packed.
+//!
+//! The 8-byte voxel is full, so per-voxel state that does not fit in it
+//! (electrical charge first) lives in a separate pair of double-buffered aux
+//! pools. Every voxel owns [`WORDS_PER_VOXEL`] u32 words there, at
+//! `pool_voxel_index * WORDS_PER_VOXEL`, and each registered [`AuxChannel`]
+//! is a u8 or u16 bit range of one of those words. A voxel's words are only
+//! ever written by the thread that owns the voxel, so packing several
+//! channels into one word needs no atomics.
+//!
+//! Adding a channel means adding it to [`CHANNELS`]; the GPU pools, the CPU
+//! simulator, save files and the pick buffer all follow the table. The WGSL
+//! accessors in `shaders/common/aux_channels.wgsl` are generated from it and
+//! a test fails if the checked-in file drifts from [`wgsl_prelude`].
+
+use std::fmt::Write;
+
+use crate::constants::VOXELS_PER_CHUNK;
+
+/// Storage width of an auxiliary channel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuxFormat {
+    U8,
+    U16,
+}
+
+impl AuxFormat {
+    pub const fn bits(self) -> u32 {
+        match self {
+            AuxFormat::U8 => 8,
+            AuxFormat::U16 => 16,
+        }
+    }
+
+    /// Bytes per voxel when the channel is serialized on its own.
+    pub const fn bytes(self) -> usize {
+        self.bits() as usize / 8
+    }
+
+    /// The format with the given bit width, if any.
+    pub fn from_bits(bits: u32) -> Option<Self> {
+        match bits {
+            8 => Some(AuxFormat::U8),
+            16 => Some(AuxFormat::U16),
+            _ => None,
+        }
+    }
+}
+
+/// One named auxiliary channel: a bit range of one aux word.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuxChannel {
+    /// Stable name. Save files match channels by it, and the WGSL accessors
+    /// are named after it.
+    pub name: &'static str,
+    pub format: AuxFormat,
+    /// Which of the voxel's aux words holds the channel.
+    pub word: u32,
+    /// First bit within that word. A multiple of the channel's width.
+    pub offset: u32,
+}
+
+/// Electrical charge (0..=`CHARGE_MAX`), propagated by the electrical pass
+/// and read by charge-gated reactions.
+pub const CHARGE: AuxChannel = AuxChannel::new("charge", AuxFormat::U8, 0, 0);
+
+/// Oxygen used up in the gas of a voxel's cell (0 = fresh air, `max()` =
+/// none left), drawn down by oxygen-consuming reactions and spread by the
+/// oxygen pass. Stored as a deficit so that new chunks start out breathable.
+pub const OXYGEN_DEFICIT: AuxChannel = AuxChannel::new("oxygen_deficit", AuxFormat::U8, 0, 8);
+
+/// Strength of the last blast front to reach the voxel (0..=255). While the
+/// front is on the voxel this is what it carries onward; afterwards it fades
+/// by `BLAST_DECAY` per tick and keeps weaker fronts (including the one's own
+/// echo) from passing again.
+pub const BLAST: AuxChannel = AuxChannel::new("blast", AuxFormat::U8, 0, 16);
+
+/// Whether a blast front is on the voxel this tick: one of the
+/// `BLAST_FRONT_*` states in `constants`.
+pub const BLAST_FRONT: AuxChannel = AuxChannel::new("blast_front", AuxFormat::U8, 0, 24);
+
+/// Material the liquid in the voxel's cell carries as sediment (0 = none),
+/// picked up and dropped by the erosion pass. Unlike the other channels it
+/// travels with its voxel: the movement pass carries it along.
+pub const SEDIMENT: AuxChannel = AuxChannel::new("sediment", AuxFormat::U16, 1, 0);
+
+/// The part of a liquid's pressure that is hydrostatic head (0 for anything
+/// else), which the pressure pass sets on top of the pressure the voxel
+/// accumulates. It travels with its voxel like [`SEDIMENT`].
+pub const HEAD: AuxChannel = AuxChannel::new("head", AuxFormat::U8, 1, 16);
+
+/// Weight of the liquid from the voxel's cell up to the top of its column
+/// (0 if the cell holds no liquid), capped at `MAX_PRESSURE`. Worked out
+/// once per column at the start of the pressure pass, so neither pressure
+/// nor movement has to walk the column per voxel.
+pub const COLUMN: AuxChannel = AuxChannel::new("column", AuxFormat::U8, 1, 24);
+
+/// Every registered channel. Indices into this table are the channel
+/// numbers used by the pick buffer and `aux_channel_word`/`unpack_aux_channel`
+/// in WGSL.
+pub const CHANNELS: [AuxChannel; 7] = [
+    CHARGE,
+    OXYGEN_DEFICIT,
+    BLAST,
+    BLAST_FRONT,
+    SEDIMENT,
+    HEAD,
+    COLUMN,
+];
+
+/// Aux words per voxel. Large enough to hold every channel in [`CHANNELS`].
+pub const WORDS_PER_VOXEL: u32 = 2;
+
+/// Aux words per chunk.
+pub const WORDS_PER_CHUNK: usize = VOXELS_PER_CHUNK as usize * WORDS_PER_VOXEL as usize;
+
+impl AuxChannel {
+    const fn new(name: &'static str, format: AuxFormat, word: u32, offset: u32) -> Self {
+        Self {
+            name,
+            format,
+            word,
+            offset,
+        }
+    }
+
+    /// Largest value the channel can hold.
+    pub const fn max(&self) -> u32 {
+        ((1u64 << self.format.bits()) - 1) as u32
+    }
+
+    /// The channel's value in aux word `word`.
+    pub const fn get(&self, word: u32) -> u32 {
+        (word >> self.offset) & self.max()
+    }
+
+    /// `word` with the channel replaced by the low bits of `value`.
+    pub const fn set(&self, word: u32, value: u32) -> u32 {
+        (word & !(self.max() << self.offset)) | ((value & self.max()) << self.offset)
+    }
+
+    /// Index of this channel's word for voxel `voxel` in a run of aux words.
+    pub const fn word_index(&self, voxel: usize) -> usize {
+        voxel * WORDS_PER_VOXEL as usize + self.word as usize
+    }
+
+    /// The channel's value for voxel `voxel` of `words`.
+    pub fn read(&self, words: &[u32], voxel: usize) -> u32 {
+        self.get(words[self.word_index(voxel)])
+    }
+
+    /// Store `value` (low bits kept) for voxel `voxel` of `words`, leaving
+    /// other channels alone.
+    pub fn write(&self, words: &mut [u32], voxel: usize, value: u32) {
+        let i = self.word_index(voxel);
+        words[i] = self.set(words[i], value);
+    }
+}
+
+/// Look up a registered channel by name.
+pub fn find(name: &str) -> Option<AuxChannel> {
+    CHANNELS.into_iter().find(|c| c.name == name)
+}
+
+/// WGSL source for the aux channel accessors, generated from [`CHANNELS`].
+/// This is the content of `shaders/common/aux_channels.wgsl`.
+///
+/// Per channel it emits `AUX_<NAME>_WORD`/`_OFFSET`/`_BITS`/`_MAX` constants, an
+/// `unpack_aux_<name>` getter and a `with_aux_<name>` setter over a single
+/// aux word, plus `aux_index` and channel-number dispatch for code that walks
+/// every channel (the pick buffer).
+pub fn wgsl_prelude() -> String {
+    let mut out = String::new();
+    out.push_str(
+        "// aux_channels.wgsl — Auxiliary per-voxel channel layout and accessors.\n\
+         // GENERATED from alkahest-core/src/aux_channel.rs; do not edit by hand. After\n\
+         // changing the channels, run `ALKAHEST_BLESS=1 cargo test -p alkahest-core`.\n\
+         // Each voxel owns AUX_WORDS_PER_VOXEL u32 words in the aux pools, starting at\n\
+         // its voxel pool index * AUX_WORDS_PER_VOXEL.\n\
+         //\n\
+         // Channels:\n",
+    );
+    for channel in CHANNELS {
+        let last = channel.offset + channel.format.bits() - 1;
+        let bits = format!("[{}:{last}]", channel.offset);
+        let _ = writeln!(
+            out,
+            "//   word {} {bits:<7}  {} (u{})",
+            channel.word,
+            channel.name,
+            channel.format.bits()
+        );
+    }
+    out.push('\n');
+
+    let _ = writeln!(out, "const AUX_WORDS_PER_VOXEL: u32 = {WORDS_PER_VOXEL}u;");
+    let _ = writeln!(out, "const AUX_CHANNEL_COUNT: u32 = {}u;", CHANNELS.len());
+    for channel in CHANNELS {
+        let upper = channel.name.to_uppercase();
+        let _ = writeln!(out, "const AUX_{upper}_WORD: u32 = {}u;", channel.word);
+        let _ = writeln!(out, "const AUX_{upper}_OFFSET: u32 = {}u;", channel.offset);
+        let _ = writeln!(
+            out,
+            "const AUX_{upper}_BITS: u32 = {}u;",
+            channel.format.bits()
+        );
+        let _ = writeln!(out, "const AUX_{upper}_MAX: u32 = {}u;", channel.max());
+    }
+
+    out.push_str(
+        "\n/// Index of aux word `word` of the voxel at voxel pool index `pool_index`.\n\
+         fn aux_index(pool_index: u32, word: u32) -> u32 {\n    \
+             return pool_index * AUX_WORDS_PER_VOXEL + word;\n\
+         }\n",
+    );
+
+    for channel in CHANNELS {
+        let mask = channel.max();
+        let mut read = "w".to_string();
+        if channel.offset > 0 {
+            read = format!("({read} >> {}u)", channel.offset);
+        }
+        let _ = writeln!(
+            out,
+            "\nfn unpack_aux_{}(w: u32) -> u32 {{\n    return {read} & {mask:#X}u;\n}}",
+            channel.name
+        );
+        let mut bits = format!("(value & {mask:#X}u)");
+        if channel.offset > 0 {
+            bits = format!("({bits} << {}u)", channel.offset);
+        }
+        let _ = writeln!(
+            out,
+            "\nfn with_aux_{}(w: u32, value: u32) -> u32 {{\n    \
+                 return (w & {:#X}u) | {bits};\n}}",
+            channel.name,
+            !(mask << channel.offset)
+        );
+    }
+
+    out.push_str(
+        "\n/// Aux word holding channel number `channel`.\n\
+         fn aux_channel_word(channel: u32) -> u32 {\n    \
+             switch channel {\n",
+    );
+    for (i, channel) in CHANNELS.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "        case {i}u: {{ return AUX_{}_WORD; }}",
+            channel.name.to_uppercase()
+        );
+    }
+    out.push_str("        default: { return 0u; }\n    }\n}\n");
+
+    out.push_str(
+        "\n/// Value of channel number `channel` in its aux word `w`.\n\
+         fn unpack_aux_channel(w: u32, channel: u32) -> u32 {\n    \
+             switch channel {\n",
+    );
+    for (i, channel) in CHANNELS.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "        case {i}u: {{ return unpack_aux_{}(w); }}",
+            channel.name
+        );
+    }
+    out.push_str("        default: { return 0u; }\n    }\n}\n");
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_channels_fit_without_overlap() {
+        for (i, a) in CHANNELS.iter().enumerate() {
+            let bits = a.format.bits();
+            assert!(a.word < WORDS_PER_VOXEL, "{} is past the last word", a.name);
+            assert_eq!(a.offset % bits, 0, "{} is not aligned", a.name);
+            assert!(a.offset + bits <= 32, "{} straddles a word", a.name);
+            for b in &CHANNELS[i + 1..] {
+                assert_ne!(a.name, b.name, "duplicate channel name");
+                let overlaps = a.word == b.word
+                    && a.offset < b.offset + b.format.bits()
+                    && b.offset < a.offset + bits;
+                assert!(!overlaps, "{} overlaps {}", a.name, b.name);
+            }
+            assert!(
+                a.name.bytes().all(|c| c.is_ascii_lowercase() || c == b'_'),
+                "{} is not a valid WGSL identifier suffix",
+                a.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_set_keeps_other_bits_and_truncates() {
+        let wide = AuxChannel::new("wide", AuxFormat::U16, 0, 16);
+        let word = wide.set(0x1234_00FF, 0xABCDE);
+        assert_eq!(word, 0xBCDE_00FF);
+        assert_eq!(wide.get(word), 0xBCDE);
+        assert_eq!(CHARGE.get(word), 0xFF);
+        assert_eq!(CHARGE.max(), 255);
+        assert_eq!(wide.max(), 65_535);
+    }
+
+    #[test]
+    fn test_read_write_by_voxel() {
+        let mut words = vec![0u32; 4 * WORDS_PER_VOXEL as usize];
+        CHARGE.write(&mut words, 2, 200);
+        assert_eq!(CHARGE.read(&words, 2), 200);
+        assert_eq!(CHARGE.read(&words, 1), 0);
+        OXYGEN_DEFICIT.write(&mut words, 2, 17);
+        assert_eq!(CHARGE.read(&words, 2), 200);
+        assert_eq!(OXYGEN_DEFICIT.read(&words, 2), 17);
+        assert_eq!(find("charge"), Some(CHARGE));
+        BLAST_FRONT.write(&mut words, 2, 2);
+        assert_eq!(OXYGEN_DEFICIT.read(&words, 2), 17);
+        assert_eq!(BLAST_FRONT.read(&words, 2), 2);
+        assert_eq!(BLAST.read(&words, 2), 0);
+        SEDIMENT.write(&mut words, 2, 570);
+        assert_eq!(SEDIMENT.read(&words, 2), 570);
+        assert_eq!(BLAST_FRONT.read(&words, 2), 2);
+        HEAD.write(&mut words, 2, 9);
+        COLUMN.write(&mut words, 2, 63);
+        assert_eq!(SEDIMENT.read(&words, 2), 570);
+        assert_eq!(HEAD.read(&words, 2), 9);
+        assert_eq!(find("moisture"), None);
+    }
+
+    #[test]
+    fn test_wgsl_prelude_matches_channels() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../shaders/common/aux_channels.wgsl"
+        );
+        let generated = wgsl_prelude();
+        let on_disk = std::fs::read_to_string(path).unwrap_or_default();
+        if on_disk != generated {
+            if std::env::var_os("ALKAHEST_BLESS").is_some() {
+                std::fs::write(path, &generated).expect("write aux_channels.wgsl");
+            } else {
+                panic!(
+                    "shaders/common/aux_channels.wgsl does not match the channels in \
+                     alkahest-core/src/aux_channel.rs (rerun with ALKAHEST_BLESS=1 to regenerate)"
+                );
+            }
+        }
+    }
+}
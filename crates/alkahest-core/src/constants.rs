@@ -2,6 +2,8 @@
 //! These values are used by both Rust and WGSL. The build script
 //! will inject them into shader preambles in later milestones.
 
+use crate::math::TempCurve;
+
 /// Side length of a chunk in voxels.
 pub const CHUNK_SIZE: u32 = 32;
 
@@ -23,8 +25,14 @@ pub const TEMP_QUANT_BITS: u32 = 12;
 /// Maximum quantized temperature value (2^12 - 1).
 pub const TEMP_QUANT_MAX_VALUE: u16 = 4095;
 
-/// Ambient temperature as a quantized integer: round(293.0 / 8000.0 * 4095.0) = 150.
-pub const AMBIENT_TEMP_QUANTIZED: u16 = 150;
+/// Quantization curve for voxel temperatures. Swap for
+/// [`crate::math::AMBIENT_DETAIL_CURVE`] (or another `Piecewise` curve) for
+/// finer steps around ambient; saves record the curve they were written with.
+pub const TEMP_CURVE: TempCurve = TempCurve::Linear;
+
+/// Ambient temperature as a quantized integer on [`TEMP_CURVE`]
+/// (150 on the linear curve).
+pub const AMBIENT_TEMP_QUANTIZED: u16 = TEMP_CURVE.to_quantized(AMBIENT_TEMP_K);
 
 /// Total voxels per chunk (32^3).
 pub const VOXELS_PER_CHUNK: u32 = CHUNK_SIZE * CHUNK_SIZE * CHUNK_SIZE;
@@ -39,12 +47,41 @@ pub const MAX_RULE_MATERIALS: u32 = 1024;
 /// Sentinel value in the rule lookup buffer: no rule exists for this pair.
 pub const NO_RULE: u32 = 0xFFFFFFFF;
 
+/// Low bits of a rule lookup word holding the pair's candidate rule count.
+/// The remaining high bits hold the index of the pair's first rule entry.
+pub const RULE_COUNT_BITS: u32 = 4;
+
+/// Maximum number of candidate rules per ordered material pair.
+pub const MAX_RULES_PER_PAIR: u32 = (1 << RULE_COUNT_BITS) - 1;
+
+/// Sentinel value in rule data: the rule has no catalyst.
+pub const NO_CATALYST: u32 = 0xFFFF;
+
 /// Sentinel value in rule data: material unchanged by this rule.
 pub const MATERIAL_UNCHANGED: u32 = 0xFFFF;
 
-/// Thermal diffusion rate per tick. CFL constraint: DIFFUSION_RATE * max_conductivity * 26 < 1.0.
+/// vec4<f32> slots per material in the GPU material properties buffer.
+pub const MATERIAL_PROPS_STRIDE: u32 = 7;
+
+/// Thermal diffusion rate per tick. CFL constraint, for every material:
+/// DIFFUSION_RATE * (conductivity + max_conductivity) / 2 * 26 / heat_capacity < 1.0.
 pub const DIFFUSION_RATE: f32 = 0.03;
 
+/// Radiative exchange rate per tick, in linear temperature steps per unit of
+/// (T / 1000 K)^4 difference at a distance of one voxel. Scaled by the pair's
+/// smaller radiative_emissivity and divided by the squared distance.
+pub const RADIATION_RATE: f32 = 2.0;
+
+/// Farthest a voxel exchanges radiant heat, in steps along each of the 26
+/// neighbor directions. Must not exceed CHUNK_SIZE (rays read at most one
+/// chunk over).
+pub const RADIATION_RADIUS: u32 = 8;
+
+/// Cap on one voxel pair's radiative exchange per tick, as a fraction of
+/// their linear temperature gap. T^4 grows steeply enough that, uncapped,
+/// voxels near 8000 K would overshoot each other and oscillate.
+pub const RADIATION_MAX_GAP_FRACTION: f32 = 1.0 / 64.0;
+
 /// Per-tick temperature drain toward ambient (quantized units).
 pub const ENTROPY_DRAIN_RATE: u32 = 1;
 
@@ -94,8 +131,31 @@ pub const MAX_PRESSURE: u32 = 63;
 /// Pressure gain per tick for enclosed gas/liquid above ambient temperature.
 pub const THERMAL_PRESSURE_FACTOR: u32 = 1;
 
-/// Maximum BFS radius for structural collapse detection (CPU-side).
-pub const STRUCTURAL_BFS_RADIUS: u32 = 32;
+/// Ticks between the pressure unit a liquid loses on the head it takes from
+/// the liquid beside it. Without the loss, head left behind by a column that
+/// has since drained would keep itself up between neighbors at one level;
+/// with it, such head fades one unit per period.
+pub const HYDROSTATIC_DECAY_PERIOD: u32 = 8;
+
+/// Head, in voxel weights, that liquid must hold beyond the weight above it
+/// to be pushed up, or to hold up the liquid over a hole it is refilling.
+/// Two rather than one, so the tick the pressure field lags behind movement
+/// does not make a settling column bounce.
+pub const HYDROSTATIC_PUSH_HEAD: u32 = 2;
+
+/// Weight of a voxel per unit of material density, in the units structural
+/// strengths are given in: a density-1000 voxel weighs 1.0.
+pub const STRUCTURAL_WEIGHT_PER_DENSITY: f32 = 0.001;
+
+/// Compressive strength per point of structural_integrity, for materials
+/// that do not set compressive_strength. Stone (50) carries 2000, a column
+/// several times the world's height.
+pub const STRUCTURAL_COMPRESSION_PER_INTEGRITY: f32 = 40.0;
+
+/// Tensile strength per point of structural_integrity, for materials that
+/// do not set tensile_strength. Stone (50) holds 50, an overhang of about
+/// ten voxels of its own weight.
+pub const STRUCTURAL_TENSION_PER_INTEGRITY: f32 = 1.0;
 
 // ── M10: Rendering Polish Constants ─────────────────────────────────
 
@@ -131,6 +191,125 @@ pub const CHARGE_DECAY_RATE: u32 = 2;
 /// Joule heating factor: temp_increase = charge^2 * resistance * JOULE_HEATING_FACTOR.
 pub const JOULE_HEATING_FACTOR: f32 = 0.01;
 
+// ── Oxygen ───────────────────────────────────────────────────────────
+
+/// Fraction of the oxygen deficit difference exchanged per tick between two
+/// face-adjacent gas cells.
+pub const OXYGEN_DIFFUSION_RATE: f32 = 0.1;
+
+/// Exchange rate between vertically adjacent gas cells when the lower one is
+/// more than CONVECTION_THRESHOLD hotter: hot gas rising out of a vent draws
+/// fresh air in behind it. Two draft faces plus four diffusing ones must stay
+/// at or below 1.0 (2 * 0.25 + 4 * 0.1).
+pub const OXYGEN_DRAFT_RATE: f32 = 0.25;
+
+// ── Ballistic movement ───────────────────────────────────────────────
+
+/// Velocity field units per voxel per tick: a voxel with velocity 32 on an
+/// axis covers one voxel a tick along it.
+pub const VELOCITY_PER_CELL: u32 = 32;
+
+/// Ballistic steps per tick. Each axis steps at most once per substep, so
+/// the fastest voxel (velocity 127) covers just under this many voxels a tick
+/// per axis. VELOCITY_PER_CELL * BALLISTIC_SUBSTEPS must exceed 127.
+pub const BALLISTIC_SUBSTEPS: u32 = 4;
+
+/// Speed (largest axis, velocity field units) from which a non-gas voxel
+/// moves ballistically instead of through the gravity and flow sub-passes.
+/// Above the convection nudge (1) so heated fluids are not thrown.
+pub const BALLISTIC_MIN_SPEED: u32 = 8;
+
+/// Downward velocity a ballistic voxel gains per tick.
+pub const BALLISTIC_GRAVITY: u32 = 4;
+
+/// Fraction of the closing speed two voxels keep, apart, after an impact.
+pub const BALLISTIC_RESTITUTION: f32 = 0.25;
+
+/// Fraction of its velocity across the impact axis a voxel loses on impact.
+pub const BALLISTIC_FRICTION: f32 = 0.25;
+
+// ── Granular movement ────────────────────────────────────────────────
+
+/// Farthest a powder slides sideways toward a drop, in voxels. Sets the
+/// shallowest repose angle the grid tells apart: a slope of one voxel of
+/// drop per this many across, about 14°.
+pub const REPOSE_MAX_RUN: u32 = 4;
+
+/// Longest overhang, in voxels, a powder of cohesion 1.0 stands out over
+/// nothing. Cohesion scales it down, rounding to the nearest voxel.
+pub const COHESION_MAX_OVERHANG: u32 = 4;
+
+// ── Erosion and sediment ─────────────────────────────────────────────
+
+/// Velocity a liquid gains along an axis each time it moves along it. A
+/// liquid that keeps moving the same way speeds up to FLOW_SPEED_MAX.
+pub const FLOW_ACCEL: u32 = 2;
+
+/// Velocity a liquid loses per tick on each axis, so flow that stops moving
+/// slows to rest.
+pub const FLOW_DRAG: u32 = 1;
+
+/// Fastest a liquid flows, in velocity field units. Below
+/// BALLISTIC_MIN_SPEED, so flowing liquid never flies.
+pub const FLOW_SPEED_MAX: u32 = BALLISTIC_MIN_SPEED - 1;
+
+/// Slowest flow (largest axis) that picks up sediment.
+pub const EROSION_MIN_FLOW: u32 = 3;
+
+/// Flow below which a liquid drops the sediment it carries.
+pub const SEDIMENT_SETTLE_FLOW: u32 = 2;
+
+// ── Blast ────────────────────────────────────────────────────────────
+
+/// Blast resistance per point of structural_integrity, for materials that do
+/// not set blast_resistance. Glass (8) gives way to a front of 32, Stone (50)
+/// only to one of 200.
+pub const BLAST_RESISTANCE_PER_INTEGRITY: f32 = 4.0;
+
+/// Fraction of a blast front's strength that reaches the next voxel. The
+/// carried strength is rounded down, so a front always weakens by at least
+/// one per step and cannot flow back into the voxel it came from.
+pub const BLAST_FALLOFF: f32 = 0.85;
+
+/// Per-tick fade of the blast strength a voxel remembers once the front has
+/// passed. Must stay at 1: any faster and a weak front's echo could outrun
+/// the fade and bounce back.
+pub const BLAST_DECAY: u32 = 1;
+
+/// Blast strength left over after breaking a voxel per unit of fragment
+/// speed (velocity field units). Glass right next to a gunpowder charge flies
+/// off at about a voxel per tick.
+pub const BLAST_FRAGMENT_SPEED_DIVISOR: u32 = 2;
+
+/// `blast_front` states: no front on the voxel.
+pub const BLAST_FRONT_NONE: u32 = 0;
+
+/// `blast_front` states: the front reached the voxel this tick and moves on
+/// to its neighbors next tick.
+pub const BLAST_FRONT_LIVE: u32 = 1;
+
+/// `blast_front` states: a reaction set off a blast at the voxel this tick.
+/// Propagates like a live front; the activity pass adds its strength to the
+/// chunk's blast yield.
+pub const BLAST_FRONT_SOURCE: u32 = 2;
+
+/// `blast_front` states: set by the reactions pass on a voxel whose reaction
+/// detonated, and turned into `BLAST_FRONT_SOURCE` by the blast pass later in
+/// the same tick. Never survives a tick, so a source left over from the last
+/// tick is never mistaken for a new one.
+pub const BLAST_FRONT_SEEDED: u32 = 3;
+
+/// Chunk activity flag bit: the chunk changed this tick.
+pub const ACTIVITY_DIRTY: u32 = 1;
+
+/// Chunk activity flag bits from here up: sum of the strengths of the blasts
+/// set off in the chunk this tick (the explosion's yield), saturating at
+/// [`ACTIVITY_BLAST_MAX`].
+pub const ACTIVITY_BLAST_SHIFT: u32 = 8;
+
+/// Largest yield the activity flag bits above [`ACTIVITY_BLAST_SHIFT`] hold.
+pub const ACTIVITY_BLAST_MAX: u32 = u32::MAX >> ACTIVITY_BLAST_SHIFT;
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1,3 +1,4 @@
+pub mod aux_channel;
 pub mod constants;
 pub mod direction;
 pub mod error;
@@ -6,3 +7,4 @@ pub mod math;
 pub mod mod_manifest;
 pub mod rule;
 pub mod types;
+pub mod voxel;
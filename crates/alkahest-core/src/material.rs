@@ -1,5 +1,11 @@
 use serde::{Deserialize, Serialize};
 
+use crate::constants::{
+    BLAST_RESISTANCE_PER_INTEGRITY, CHUNK_SIZE, REPOSE_MAX_RUN,
+    STRUCTURAL_COMPRESSION_PER_INTEGRITY, STRUCTURAL_TENSION_PER_INTEGRITY,
+};
+use crate::types::{Kelvin, QuantizedTemp};
+
 /// Physical phase of a material, controlling movement behavior.
 /// Stored as u32 in GPU buffers to match shader constants.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -37,35 +43,93 @@ pub struct MaterialDef {
     /// Flammability (0.0 = fireproof, 1.0 = highly flammable).
     #[serde(default)]
     pub flammability: f32,
-    /// Ignition temperature in Kelvin. Only relevant if flammability > 0.
+    /// Ignition temperature. Only relevant if flammability > 0.
     #[serde(default)]
-    pub ignition_temp: f32,
+    pub ignition_temp: Kelvin,
     /// Per-tick temperature decrement for self-decay (quantized units).
     #[serde(default)]
     pub decay_rate: u32,
-    /// Temperature threshold below which material transforms to decay_product (quantized).
+    /// Temperature threshold below which material transforms to decay_product.
     #[serde(default)]
-    pub decay_threshold: u32,
+    pub decay_threshold: QuantizedTemp,
     /// Material ID to transform into when temperature drops below decay_threshold.
     #[serde(default)]
     pub decay_product: u16,
     /// Viscosity for lateral liquid movement (0.0 = free flow, 1.0 = no flow).
     #[serde(default)]
     pub viscosity: f32,
+    /// Steepest slope a pile of this powder stands at, in degrees from the
+    /// horizontal (0.0–90.0). Piles come out in whole-voxel steps: 45 slides
+    /// off any diagonal drop, 60 only off a drop two voxels deep, 90 never;
+    /// 30 also creeps sideways toward a drop two voxels away.
+    #[serde(default = "default_repose_angle")]
+    pub repose_angle: f32,
+    /// How well the grains hold together (0.0–1.0). A cohesive powder with
+    /// nothing under it stays up while a run of the same powder beside it
+    /// reaches something resting, at most cohesion * COHESION_MAX_OVERHANG
+    /// voxels away. Takes the overhang down when its support goes.
+    #[serde(default)]
+    pub cohesion: f32,
+    /// How readily flowing liquid wears this material away (0.0–1.0): the
+    /// chance per tick that liquid flowing past at full speed picks a voxel
+    /// of it up. Slower flow takes proportionally less. Carried voxels settle
+    /// out again where the flow slows.
+    #[serde(default)]
+    pub erodibility: f32,
     /// Thermal conductivity (0.0 = insulator, 1.0 = perfect conductor).
     #[serde(default)]
     pub thermal_conductivity: f32,
-    /// Temperature in Kelvin at which this material undergoes upward phase change.
-    /// 0.0 = no phase change.
+    /// Volumetric heat capacity relative to the default of 1.0. Heat flowing
+    /// into a voxel changes its temperature by flow / heat_capacity, so iron
+    /// (higher) warms slower than steam (lower).
+    #[serde(default = "default_heat_capacity")]
+    pub heat_capacity: f32,
+    /// How strongly the material exchanges radiant heat with surfaces it can
+    /// see across air or gas (0.0–1.0). A pair of voxels exchanges heat at
+    /// the larger of their two emissivities, so a cold wall with 0.0 is still
+    /// warmed by lava with 0.95. 0.0 on both sides = no radiation.
     #[serde(default)]
-    pub phase_change_temp: f32,
+    pub radiative_emissivity: f32,
+    /// Temperature at which this material undergoes upward phase change.
+    /// 0 K = no phase change.
+    #[serde(default)]
+    pub phase_change_temp: Kelvin,
     /// Material ID to transform into when temperature exceeds phase_change_temp.
     #[serde(default)]
     pub phase_change_product: u16,
+    /// Energy absorbed by the upward phase change, as the temperature rise in
+    /// Kelvin it would otherwise cause in this material. While absorbing it
+    /// the voxel stays at phase_change_temp. 0 = changes immediately.
+    #[serde(default)]
+    pub latent_heat: f32,
+    /// Temperature below which this material undergoes downward phase change
+    /// (condensing or freezing). Kept below the product's phase_change_temp
+    /// so the pair has hysteresis. 0 K = no downward phase change.
+    #[serde(default)]
+    pub freeze_temp: Kelvin,
+    /// Material ID to transform into when temperature drops below freeze_temp.
+    #[serde(default)]
+    pub freeze_product: u16,
     /// Structural integrity (0.0–63.0). Pressure exceeding this causes rupture.
     /// 0.0 = no structural role (powders, gases). Higher = stronger containment.
     #[serde(default)]
     pub structural_integrity: f32,
+    /// Load a solid voxel can carry on top of it before it crushes, in voxel
+    /// weights (see `STRUCTURAL_WEIGHT_PER_DENSITY`). None = derive from
+    /// structural_integrity.
+    #[serde(default)]
+    pub compressive_strength: Option<f32>,
+    /// Load a solid voxel can hold sideways or hanging before it snaps, in
+    /// voxel weights. Beams, bridges and overhangs fail on this. None =
+    /// derive from structural_integrity.
+    #[serde(default)]
+    pub tensile_strength: Option<f32>,
+    /// Blast front strength (0–255) a voxel withstands. A stronger front
+    /// breaks it loose as a fragment and goes on weakened by this much; a
+    /// weaker one stops here, shadowing whatever is behind. None = derive
+    /// from structural_integrity.
+    #[serde(default)]
+    pub blast_resistance: Option<f32>,
     /// Opacity (0.0–1.0). None = derive from phase (Gas=0.3, Liquid=0.7, Solid/Powder=1.0).
     #[serde(default)]
     pub opacity: Option<f32>,
@@ -86,6 +150,42 @@ pub struct MaterialDef {
     pub charge_emission: u8,
 }
 
+fn default_heat_capacity() -> f32 {
+    1.0
+}
+
+fn default_repose_angle() -> f32 {
+    45.0
+}
+
+impl MaterialDef {
+    /// Compressive strength, derived from structural_integrity if unset.
+    pub fn compression_limit(&self) -> f32 {
+        self.compressive_strength
+            .unwrap_or(self.structural_integrity * STRUCTURAL_COMPRESSION_PER_INTEGRITY)
+    }
+
+    /// Tensile strength, derived from structural_integrity if unset.
+    pub fn tension_limit(&self) -> f32 {
+        self.tensile_strength
+            .unwrap_or(self.structural_integrity * STRUCTURAL_TENSION_PER_INTEGRITY)
+    }
+
+    /// Blast resistance, derived from structural_integrity if unset.
+    pub fn blast_limit(&self) -> f32 {
+        self.blast_resistance
+            .unwrap_or(self.structural_integrity * BLAST_RESISTANCE_PER_INTEGRITY)
+    }
+
+    /// Drop per voxel across of the steepest slope the powder stands at,
+    /// tan(repose_angle), kept between 1 / REPOSE_MAX_RUN and CHUNK_SIZE. No
+    /// drop within a chunk is CHUNK_SIZE deep, so a 90° pile never slides.
+    pub fn repose_slope(&self) -> f32 {
+        let slope = f64::from(self.repose_angle).to_radians().tan() as f32;
+        slope.clamp(1.0 / REPOSE_MAX_RUN as f32, CHUNK_SIZE as f32)
+    }
+}
+
 /// Collection of material definitions indexed by ID.
 #[derive(Debug, Clone, Default)]
 pub struct MaterialTable {
@@ -137,15 +237,26 @@ mod tests {
                 color: (0.76, 0.70, 0.50),
                 emission: 0.0,
                 flammability: 0.0,
-                ignition_temp: 0.0,
+                ignition_temp: Kelvin(0.0),
                 decay_rate: 0,
-                decay_threshold: 0,
+                decay_threshold: QuantizedTemp(0),
                 decay_product: 0,
                 viscosity: 0.0,
+                repose_angle: 45.0,
+                cohesion: 0.0,
+                erodibility: 0.0,
                 thermal_conductivity: 0.0,
-                phase_change_temp: 0.0,
+                heat_capacity: 1.0,
+                radiative_emissivity: 0.0,
+                phase_change_temp: Kelvin(0.0),
                 phase_change_product: 0,
+                latent_heat: 0.0,
+                freeze_temp: Kelvin(0.0),
+                freeze_product: 0,
                 structural_integrity: 0.0,
+                compressive_strength: None,
+                tensile_strength: None,
+                blast_resistance: None,
                 opacity: None,
                 absorption_rate: 0.0,
                 electrical_conductivity: 0.0,
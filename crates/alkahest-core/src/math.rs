@@ -1,20 +1,221 @@
-use crate::constants::{CHUNK_SIZE, TEMP_QUANT_MAX_K, TEMP_QUANT_MAX_VALUE};
-use crate::types::{ChunkCoord, MaterialId, VoxelData, WorldCoord};
+use crate::constants::{CHUNK_SIZE, TEMP_CURVE, TEMP_QUANT_MAX_K, TEMP_QUANT_MAX_VALUE};
+use crate::types::{ChunkCoord, WorldCoord};
 use glam::IVec3;
 
-/// Convert a temperature in Kelvin to a 12-bit quantized integer.
-/// Clamps to [0, TEMP_QUANT_MAX_VALUE].
+/// Mapping between 12-bit quantized temperatures and Kelvin.
+///
+/// The active curve is [`TEMP_CURVE`]. Every curve is monotonic, so
+/// thresholds compare directly in quantized space. Temperature changes are
+/// applied on the linear scale (see [`TempCurve::linear_value`]) through
+/// [`step_temperature`], which the thermal and reaction shaders mirror using
+/// [`TempCurve::lut`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TempCurve {
+    /// Evenly spaced steps of `TEMP_QUANT_MAX_K / TEMP_QUANT_MAX_VALUE` (~1.95 K).
+    Linear,
+    /// Straight segments between `(quantized, kelvin)` knots. Knots start at
+    /// `(0, 0.0)`, end at `(TEMP_QUANT_MAX_VALUE, TEMP_QUANT_MAX_K)` and
+    /// increase strictly in both coordinates.
+    Piecewise(&'static [(u16, f32)]),
+}
+
+/// Piecewise curve spending half the range on 250–400 K (~0.1 K per step),
+/// where water freezes and boils, with coarser steps above 2000 K.
+pub const AMBIENT_DETAIL_CURVE: TempCurve = TempCurve::Piecewise(&[
+    (0, 0.0),
+    (640, 250.0),
+    (2176, 400.0),
+    (3072, 2000.0),
+    (TEMP_QUANT_MAX_VALUE, TEMP_QUANT_MAX_K),
+]);
+
+impl TempCurve {
+    /// Curves a save file may have been written with, for converting
+    /// temperatures on load.
+    pub const KNOWN: [TempCurve; 2] = [TempCurve::Linear, AMBIENT_DETAIL_CURVE];
+
+    /// Convert a 12-bit quantized integer to Kelvin.
+    pub const fn to_kelvin(self, quantized: u16) -> f32 {
+        let q = if quantized > TEMP_QUANT_MAX_VALUE {
+            TEMP_QUANT_MAX_VALUE
+        } else {
+            quantized
+        };
+        match self {
+            TempCurve::Linear => (q as f32 / TEMP_QUANT_MAX_VALUE as f32) * TEMP_QUANT_MAX_K,
+            TempCurve::Piecewise(knots) => {
+                let mut i = 1;
+                while i < knots.len() - 1 && knots[i].0 < q {
+                    i += 1;
+                }
+                let (q0, k0) = knots[i - 1];
+                let (q1, k1) = knots[i];
+                k0 + (k1 - k0) * ((q - q0) as f32 / (q1 - q0) as f32)
+            }
+        }
+    }
+
+    /// Convert Kelvin to the nearest 12-bit quantized integer.
+    /// Clamps to [0, TEMP_QUANT_MAX_VALUE].
+    pub const fn to_quantized(self, kelvin: f32) -> u16 {
+        let k = if kelvin > TEMP_QUANT_MAX_K {
+            TEMP_QUANT_MAX_K
+        } else if kelvin > 0.0 {
+            kelvin
+        } else {
+            0.0
+        };
+        let quantized = match self {
+            TempCurve::Linear => (k / TEMP_QUANT_MAX_K * TEMP_QUANT_MAX_VALUE as f32).round(),
+            TempCurve::Piecewise(knots) => {
+                let mut i = 1;
+                while i < knots.len() - 1 && knots[i].1 < k {
+                    i += 1;
+                }
+                let (q0, k0) = knots[i - 1];
+                let (q1, k1) = knots[i];
+                (q0 as f32 + (k - k0) / (k1 - k0) * (q1 - q0) as f32).round()
+            }
+        } as u16;
+        if quantized > TEMP_QUANT_MAX_VALUE {
+            TEMP_QUANT_MAX_VALUE
+        } else {
+            quantized
+        }
+    }
+
+    /// Temperature of step `quantized` on the linear scale: Kelvin in units
+    /// of one `Linear` step. Rule `temp_delta`s and thermal diffusion are
+    /// expressed on this scale. Exact integers under `Linear`.
+    pub fn linear_value(self, quantized: u16) -> f32 {
+        match self {
+            TempCurve::Linear => quantized.min(TEMP_QUANT_MAX_VALUE) as f32,
+            TempCurve::Piecewise(_) => {
+                self.to_kelvin(quantized) * (TEMP_QUANT_MAX_VALUE as f32 / TEMP_QUANT_MAX_K)
+            }
+        }
+    }
+
+    /// [`TempCurve::linear_value`] for every quantized value, as uploaded to
+    /// the GPU after the material properties.
+    pub fn lut(self) -> Vec<f32> {
+        (0..=TEMP_QUANT_MAX_VALUE)
+            .map(|q| self.linear_value(q))
+            .collect()
+    }
+
+    /// Stable identifier recorded in save files. 0 for `Linear`, otherwise
+    /// an FNV-1a hash of the knots.
+    pub fn id(self) -> u32 {
+        match self {
+            TempCurve::Linear => 0,
+            TempCurve::Piecewise(knots) => {
+                let mut hash: u32 = 0x811c_9dc5;
+                for &(q, k) in knots {
+                    for byte in q.to_le_bytes().into_iter().chain(k.to_bits().to_le_bytes()) {
+                        hash = (hash ^ byte as u32).wrapping_mul(0x0100_0193);
+                    }
+                }
+                hash.max(1)
+            }
+        }
+    }
+
+    /// Look up a curve by [`TempCurve::id`] among [`TempCurve::KNOWN`].
+    pub fn from_id(id: u32) -> Option<TempCurve> {
+        Self::KNOWN.into_iter().find(|curve| curve.id() == id)
+    }
+
+    /// Re-express a quantized value written under `from` on this curve.
+    pub fn requantize(self, from: TempCurve, quantized: u16) -> u16 {
+        self.to_quantized(from.to_kelvin(quantized))
+    }
+
+    /// WGSL source of `fn temp_to_kelvin(quantized: u32) -> f32`, the
+    /// [`TempCurve::to_kelvin`] of this curve, for shaders that display
+    /// temperatures rather than step them.
+    pub fn wgsl_to_kelvin(self) -> String {
+        let linear = [(0, 0.0), (TEMP_QUANT_MAX_VALUE, TEMP_QUANT_MAX_K)];
+        let knots: &[(u16, f32)] = match self {
+            TempCurve::Linear => &linear,
+            TempCurve::Piecewise(knots) => knots,
+        };
+        let mut segments = String::new();
+        for pair in knots.windows(2) {
+            let ((q0, k0), (q1, k1)) = (pair[0], pair[1]);
+            segments.push_str(&format!(
+                "    if q <= {q1}u {{\n        return {k0:?} + f32(q - {q0}u) * {:?};\n    }}\n",
+                (k1 - k0) / (q1 - q0) as f32
+            ));
+        }
+        format!(
+            "fn temp_to_kelvin(quantized: u32) -> f32 {{\n    \
+             let q = min(quantized, {TEMP_QUANT_MAX_VALUE}u);\n\
+             {segments}    return {TEMP_QUANT_MAX_K:?};\n}}\n"
+        )
+    }
+}
+
+/// Convert a temperature in Kelvin to a 12-bit quantized integer on
+/// [`TEMP_CURVE`]. Clamps to [0, TEMP_QUANT_MAX_VALUE].
 pub fn temp_to_quantized(kelvin: f32) -> u16 {
-    let clamped = kelvin.clamp(0.0, TEMP_QUANT_MAX_K);
-    let ratio = clamped / TEMP_QUANT_MAX_K;
-    let quantized = (ratio * TEMP_QUANT_MAX_VALUE as f32).round() as u16;
-    quantized.min(TEMP_QUANT_MAX_VALUE)
+    TEMP_CURVE.to_quantized(kelvin)
 }
 
-/// Convert a 12-bit quantized integer back to temperature in Kelvin.
+/// Convert a 12-bit quantized integer on [`TEMP_CURVE`] back to Kelvin.
 pub fn temp_from_quantized(quantized: u16) -> f32 {
-    let clamped = quantized.min(TEMP_QUANT_MAX_VALUE);
-    (clamped as f32 / TEMP_QUANT_MAX_VALUE as f32) * TEMP_QUANT_MAX_K
+    TEMP_CURVE.to_kelvin(quantized)
+}
+
+/// Convert a temperature change in Kelvin to linear-scale steps, rounding
+/// to the nearest step. Independent of the active curve.
+pub fn temp_delta_to_steps(kelvin: f32) -> i32 {
+    let steps = (kelvin.abs().min(TEMP_QUANT_MAX_K) / TEMP_QUANT_MAX_K
+        * TEMP_QUANT_MAX_VALUE as f32)
+        .round() as i32;
+    if kelvin < 0.0 {
+        -steps
+    } else {
+        steps
+    }
+}
+
+/// Move `quantized` by `delta` on the linear scale, given a curve's
+/// [`TempCurve::lut`].
+///
+/// Returns the step furthest from `quantized` in the direction of `delta`
+/// that does not overshoot it, so a change smaller than the local step size
+/// leaves the temperature unchanged. Under `Linear` this is
+/// `quantized + trunc(delta)` clamped to the 12-bit range.
+/// `step_temperature` in `shaders/common/temperature.wgsl` is the same search.
+pub fn step_temperature(lut: &[f32], quantized: u16, delta: f32) -> u16 {
+    let q = quantized.min(TEMP_QUANT_MAX_VALUE) as usize;
+    let base = lut[q];
+    if delta >= 0.0 {
+        // Largest step in [q, max] within `delta` of q
+        let (mut lo, mut hi) = (q, TEMP_QUANT_MAX_VALUE as usize);
+        while lo < hi {
+            let mid = (lo + hi).div_ceil(2);
+            if lut[mid] - base <= delta {
+                lo = mid;
+            } else {
+                hi = mid - 1;
+            }
+        }
+        lo as u16
+    } else {
+        // Smallest step in [0, q] within `delta` of q
+        let (mut lo, mut hi) = (0, q);
+        while lo < hi {
+            let mid = (lo + hi) / 2;
+            if lut[mid] - base >= delta {
+                hi = mid;
+            } else {
+                lo = mid + 1;
+            }
+        }
+        lo as u16
+    }
 }
 
 /// Convert a world-space voxel coordinate to its containing chunk coordinate.
@@ -47,70 +248,12 @@ pub fn chunk_local_to_world(chunk: ChunkCoord, local: IVec3) -> WorldCoord {
     )
 }
 
-/// Pack voxel fields into the two-u32 representation.
-///
-/// velocity_x straddles the u32 boundary: low 4 bits in `low[28:31]`,
-/// high 4 bits in `high[0:3]`.
-pub fn pack_voxel(
-    material_id: MaterialId,
-    temperature: u16,
-    vel_x: i8,
-    vel_y: i8,
-    vel_z: i8,
-    pressure: u8,
-    flags: u8,
-) -> VoxelData {
-    let mat = material_id.0 as u32;
-    let temp = (temperature.min(TEMP_QUANT_MAX_VALUE)) as u32;
-    let vx = vel_x as u8 as u32; // reinterpret i8 as u8 for bit packing
-
-    // low word: material[0:15] | temperature[16:27] | vel_x_low[28:31]
-    let low = mat | (temp << 16) | ((vx & 0x0F) << 28);
-
-    let vy = vel_y as u8 as u32;
-    let vz = vel_z as u8 as u32;
-    let pr = (pressure & 0x3F) as u32; // 6 bits
-    let fl = (flags & 0x3F) as u32; // 6 bits
-
-    // high word: vel_x_high[0:3] | vel_y[4:11] | vel_z[12:19] | pressure[20:25] | flags[26:31]
-    let high = ((vx >> 4) & 0x0F) | (vy << 4) | (vz << 12) | (pr << 20) | (fl << 26);
-
-    VoxelData { low, high }
-}
-
-/// Unpack voxel fields from the two-u32 representation.
-///
-/// Returns (material_id, temperature, vel_x, vel_y, vel_z, pressure, flags).
-pub fn unpack_voxel(voxel: VoxelData) -> (MaterialId, u16, i8, i8, i8, u8, u8) {
-    let material_id = MaterialId((voxel.low & 0xFFFF) as u16);
-    let temperature = ((voxel.low >> 16) & 0x0FFF) as u16;
-
-    // vel_x straddles boundary: low 4 bits from low[28:31], high 4 bits from high[0:3]
-    let vx_low = (voxel.low >> 28) & 0x0F;
-    let vx_high = voxel.high & 0x0F;
-    let vx_u8 = (vx_low | (vx_high << 4)) as u8;
-    let vel_x = vx_u8 as i8; // reinterpret u8 as i8
-
-    let vel_y = ((voxel.high >> 4) & 0xFF) as u8 as i8;
-    let vel_z = ((voxel.high >> 12) & 0xFF) as u8 as i8;
-    let pressure = ((voxel.high >> 20) & 0x3F) as u8;
-    let flags = ((voxel.high >> 26) & 0x3F) as u8;
-
-    (
-        material_id,
-        temperature,
-        vel_x,
-        vel_y,
-        vel_z,
-        pressure,
-        flags,
-    )
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::constants::AMBIENT_TEMP_K;
+    use crate::types::{MaterialId, VoxelData};
+    use crate::voxel::Voxel;
 
     #[test]
     fn test_temp_roundtrip_ambient() {
@@ -132,6 +275,63 @@ mod tests {
         assert_eq!(temp_from_quantized(TEMP_QUANT_MAX_VALUE), TEMP_QUANT_MAX_K);
     }
 
+    #[test]
+    fn test_linear_step_matches_integer_add() {
+        let lut = TempCurve::Linear.lut();
+        for (q, delta) in [
+            (150, 12.9),
+            (150, -12.9),
+            (4090, 100.0),
+            (5, -40.0),
+            (300, 0.4),
+        ] {
+            let expected = (q as i32 + delta as i32).clamp(0, TEMP_QUANT_MAX_VALUE as i32);
+            assert_eq!(step_temperature(&lut, q, delta) as i32, expected);
+        }
+        assert_eq!(step_temperature(&lut, 150, f32::NAN), 150);
+    }
+
+    #[test]
+    fn test_piecewise_curve_roundtrip_and_steps() {
+        let curve = AMBIENT_DETAIL_CURVE;
+        assert_eq!(curve.to_kelvin(0), 0.0);
+        assert_eq!(curve.to_kelvin(TEMP_QUANT_MAX_VALUE), TEMP_QUANT_MAX_K);
+        for q in (0..=TEMP_QUANT_MAX_VALUE).step_by(7) {
+            assert_eq!(curve.to_quantized(curve.to_kelvin(q)), q);
+        }
+        // ~0.1 K per step around ambient instead of ~2 K
+        let ambient = curve.to_quantized(AMBIENT_TEMP_K);
+        assert!((curve.to_kelvin(ambient + 1) - curve.to_kelvin(ambient)) < 0.2);
+
+        // A 10 K rise moves the same distance in Kelvin on either curve
+        let lut = curve.lut();
+        let steps = temp_delta_to_steps(10.0) as f32;
+        let warmed = step_temperature(&lut, ambient, steps);
+        let rise = curve.to_kelvin(warmed) - curve.to_kelvin(ambient);
+        assert!((rise - 10.0).abs() < 0.5, "rise {rise}");
+        assert_eq!(step_temperature(&lut, warmed, -steps), ambient);
+
+        assert_eq!(TempCurve::from_id(curve.id()), Some(curve));
+        assert_eq!(
+            TempCurve::from_id(TempCurve::Linear.id()),
+            Some(TempCurve::Linear)
+        );
+    }
+
+    #[test]
+    fn test_wgsl_to_kelvin_has_one_segment_per_knot_pair() {
+        let linear = TempCurve::Linear.wgsl_to_kelvin();
+        assert_eq!(linear.matches("if q <=").count(), 1);
+        assert!(linear.contains(&format!(
+            "return 0.0 + f32(q - 0u) * {:?};",
+            TEMP_QUANT_MAX_K / TEMP_QUANT_MAX_VALUE as f32
+        )));
+
+        let piecewise = AMBIENT_DETAIL_CURVE.wgsl_to_kelvin();
+        assert_eq!(piecewise.matches("if q <=").count(), 4);
+        assert!(piecewise.contains("if q <= 2176u {\n        return 250.0 + f32(q - 640u)"));
+    }
+
     #[test]
     fn test_world_to_chunk_positive() {
         assert_eq!(world_to_chunk(IVec3::new(0, 0, 0)), IVec3::ZERO);
@@ -170,58 +370,60 @@ mod tests {
 
     #[test]
     fn test_pack_unpack_zeros() {
-        let voxel = pack_voxel(MaterialId(0), 0, 0, 0, 0, 0, 0);
-        assert_eq!(voxel, VoxelData { low: 0, high: 0 });
-        let (mat, temp, vx, vy, vz, pr, fl) = unpack_voxel(voxel);
-        assert_eq!(mat, MaterialId(0));
-        assert_eq!(temp, 0);
-        assert_eq!(vx, 0);
-        assert_eq!(vy, 0);
-        assert_eq!(vz, 0);
-        assert_eq!(pr, 0);
-        assert_eq!(fl, 0);
+        let data = VoxelData::from(Voxel::new(MaterialId(0)));
+        assert_eq!(data, VoxelData { low: 0, high: 0 });
+        let voxel = Voxel::from(data);
+        assert_eq!(voxel.material_id(), MaterialId(0));
+        assert_eq!(voxel.temperature(), 0);
+        assert_eq!(voxel.velocity(), [0, 0, 0]);
+        assert_eq!(voxel.pressure(), 0);
+        assert_eq!(voxel.flags(), 0);
     }
 
     #[test]
     fn test_pack_unpack_max_values() {
-        let voxel = pack_voxel(
-            MaterialId(65535),
-            TEMP_QUANT_MAX_VALUE,
-            127,
-            127,
-            127,
-            63,
-            63,
+        let data = VoxelData::from(
+            Voxel::new(MaterialId(65535))
+                .with_temperature(TEMP_QUANT_MAX_VALUE)
+                .with_velocity([127, 127, 127])
+                .with_pressure(63)
+                .with_flags(63),
         );
-        let (mat, temp, vx, vy, vz, pr, fl) = unpack_voxel(voxel);
-        assert_eq!(mat, MaterialId(65535));
-        assert_eq!(temp, TEMP_QUANT_MAX_VALUE);
-        assert_eq!(vx, 127);
-        assert_eq!(vy, 127);
-        assert_eq!(vz, 127);
-        assert_eq!(pr, 63);
-        assert_eq!(fl, 63);
+        let voxel = Voxel::from(data);
+        assert_eq!(voxel.material_id(), MaterialId(65535));
+        assert_eq!(voxel.temperature(), TEMP_QUANT_MAX_VALUE);
+        assert_eq!(voxel.velocity(), [127, 127, 127]);
+        assert_eq!(voxel.pressure(), 63);
+        assert_eq!(voxel.flags(), 63);
     }
 
     #[test]
     fn test_pack_unpack_negative_velocities() {
-        let voxel = pack_voxel(MaterialId(1), 150, -128, -1, -50, 10, 5);
-        let (mat, temp, vx, vy, vz, pr, fl) = unpack_voxel(voxel);
-        assert_eq!(mat, MaterialId(1));
-        assert_eq!(temp, 150);
-        assert_eq!(vx, -128);
-        assert_eq!(vy, -1);
-        assert_eq!(vz, -50);
-        assert_eq!(pr, 10);
-        assert_eq!(fl, 5);
+        let data = VoxelData::from(
+            Voxel::new(MaterialId(1))
+                .with_temperature(150)
+                .with_velocity([-128, -1, -50])
+                .with_pressure(10)
+                .with_flags(5),
+        );
+        let voxel = Voxel::from(data);
+        assert_eq!(voxel.material_id(), MaterialId(1));
+        assert_eq!(voxel.temperature(), 150);
+        assert_eq!(voxel.velocity(), [-128, -1, -50]);
+        assert_eq!(voxel.pressure(), 10);
+        assert_eq!(voxel.flags(), 5);
     }
 
     #[test]
     fn test_pack_unpack_vel_x_boundary_cases() {
         // vel_x straddles the u32 boundary — test edge cases
         for val in [-128i8, -1, 0, 1, 15, 16, 127] {
-            let voxel = pack_voxel(MaterialId(42), 100, val, 0, 0, 0, 0);
-            let (_, _, vx, _, _, _, _) = unpack_voxel(voxel);
+            let data = VoxelData::from(
+                Voxel::new(MaterialId(42))
+                    .with_temperature(100)
+                    .with_velocity([val, 0, 0]),
+            );
+            let [vx, _, _] = Voxel::from(data).velocity();
             assert_eq!(vx, val, "vel_x roundtrip failed for {val}");
         }
     }
@@ -229,28 +431,23 @@ mod tests {
     #[test]
     fn test_pack_unpack_typical_voxel() {
         // Sand at ambient temp, falling at 1 voxel/tick
-        let voxel = pack_voxel(
-            MaterialId(2), // sand
-            150,           // ambient temp quantized
-            0,
-            -1, // falling down
-            0,
-            0,
-            0b000001, // active flag
+        let data = VoxelData::from(
+            Voxel::new(MaterialId(2)) // sand
+                .with_temperature(150) // ambient temp quantized
+                .with_velocity([0, -1, 0]) // falling down
+                .with_flags(0b000001), // active flag
         );
-        let (mat, temp, vx, vy, vz, pr, fl) = unpack_voxel(voxel);
-        assert_eq!(mat, MaterialId(2));
-        assert_eq!(temp, 150);
-        assert_eq!(vx, 0);
-        assert_eq!(vy, -1);
-        assert_eq!(vz, 0);
-        assert_eq!(pr, 0);
-        assert_eq!(fl, 1);
+        let voxel = Voxel::from(data);
+        assert_eq!(voxel.material_id(), MaterialId(2));
+        assert_eq!(voxel.temperature(), 150);
+        assert_eq!(voxel.velocity(), [0, -1, 0]);
+        assert_eq!(voxel.pressure(), 0);
+        assert_eq!(voxel.flags(), 1);
     }
 
     #[test]
     fn test_voxel_data_size() {
-        assert_eq!(std::mem::size_of::<super::VoxelData>(), 8);
+        assert_eq!(std::mem::size_of::<VoxelData>(), 8);
     }
 
     #[test]
@@ -286,30 +483,36 @@ mod tests {
 
     #[test]
     fn test_pack_unpack_all_flag_combinations() {
+        let roundtrip = |flags: u8| {
+            let data = VoxelData::from(
+                Voxel::new(MaterialId(1))
+                    .with_temperature(100)
+                    .with_flags(flags),
+            );
+            Voxel::from(data).flags()
+        };
         // Test each flag bit individually
         for bit in 0..6u8 {
             let flags = 1u8 << bit;
-            let voxel = pack_voxel(MaterialId(1), 100, 0, 0, 0, 0, flags);
-            let (_, _, _, _, _, _, fl) = unpack_voxel(voxel);
-            assert_eq!(fl, flags, "single flag bit {bit} failed");
+            assert_eq!(roundtrip(flags), flags, "single flag bit {bit} failed");
         }
         // Test all flags combined
         let all_flags = 0x3F; // all 6 bits set
-        let voxel = pack_voxel(MaterialId(1), 100, 0, 0, 0, 0, all_flags);
-        let (_, _, _, _, _, _, fl) = unpack_voxel(voxel);
-        assert_eq!(fl, all_flags, "all flags combined failed");
+        assert_eq!(roundtrip(all_flags), all_flags, "all flags combined failed");
 
         // Test no flags
-        let voxel = pack_voxel(MaterialId(1), 100, 0, 0, 0, 0, 0);
-        let (_, _, _, _, _, _, fl) = unpack_voxel(voxel);
-        assert_eq!(fl, 0, "zero flags failed");
+        assert_eq!(roundtrip(0), 0, "zero flags failed");
     }
 
     #[test]
     fn test_pack_unpack_pressure_boundary() {
         for &pressure in &[0u8, 1, 32, 63] {
-            let voxel = pack_voxel(MaterialId(1), 100, 0, 0, 0, pressure, 0);
-            let (_, _, _, _, _, pr, _) = unpack_voxel(voxel);
+            let data = VoxelData::from(
+                Voxel::new(MaterialId(1))
+                    .with_temperature(100)
+                    .with_pressure(pressure),
+            );
+            let pr = Voxel::from(data).pressure();
             assert_eq!(pr, pressure, "pressure {pressure} roundtrip failed");
         }
     }
@@ -13,4 +13,24 @@ pub struct ModManifest {
     pub description: String,
     /// Lower values load first. Base game is implicitly 0.
     pub load_order_hint: u32,
+    /// Mods that must be loaded before this one.
+    #[serde(default)]
+    pub depends_on: Vec<ModDependency>,
+    /// Mods that cannot be loaded alongside this one.
+    #[serde(default)]
+    pub conflicts_with: Vec<ModDependency>,
+}
+
+/// A reference to another mod by name, constrained to a version range.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModDependency {
+    /// The other mod's `name`.
+    pub name: String,
+    /// Semver version requirement (e.g. "^1.2", ">=1.0, <2.0"). Defaults to any version.
+    #[serde(default = "any_version")]
+    pub version: String,
+}
+
+fn any_version() -> String {
+    "*".to_string()
 }
@@ -1,10 +1,36 @@
 use serde::{Deserialize, Serialize};
 
+use crate::material::Phase;
+use crate::types::QuantizedTemp;
+
+/// Placeholder for an input that is matched by a pattern instead of an ID.
+pub const UNSET_MATERIAL: u16 = u16::MAX;
+
+fn unset_material() -> u16 {
+    UNSET_MATERIAL
+}
+
+/// A material selector used in place of a concrete rule input.
+///
+/// The compiler expands a rule with a pattern into one concrete rule per
+/// matching material, so the GPU lookup table only ever sees plain pairs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum MaterialPattern {
+    /// Any material with flammability strictly above the threshold.
+    FlammabilityAbove(f32),
+    /// Any material whose ID falls in the named category range
+    /// (e.g. `"Organics"`; case-insensitive).
+    Category(String),
+    /// Any material in the given phase.
+    Phase(Phase),
+}
+
 /// A single pairwise interaction rule loaded from RON data.
 ///
 /// When voxel A (input_a) is adjacent to voxel B (input_b):
 /// - A becomes output_a, B becomes output_b
-/// - Subject to probability check and temperature range
+/// - Subject to probability check, temperature/charge/pressure windows and
+///   an optional catalyst
 ///
 /// The compiler stores bidirectional GPU entries: one from A's perspective,
 /// one from B's perspective, so each thread only writes its own voxel.
@@ -12,9 +38,11 @@ use serde::{Deserialize, Serialize};
 pub struct InteractionRule {
     /// Human-readable name for debug display.
     pub name: String,
-    /// Material ID of the first input.
+    /// Material ID of the first input. May be omitted when `pattern_a` is set.
+    #[serde(default = "unset_material")]
     pub input_a: u16,
-    /// Material ID of the second input.
+    /// Material ID of the second input. May be omitted when `pattern_b` is set.
+    #[serde(default = "unset_material")]
     pub input_b: u16,
     /// Material ID that input_a transforms into.
     pub output_a: u16,
@@ -22,15 +50,16 @@ pub struct InteractionRule {
     pub output_b: u16,
     /// Probability of the reaction occurring per tick (0.0–1.0).
     pub probability: f32,
-    /// Temperature change applied to the reacting voxel (quantized integer delta).
+    /// Temperature change applied to the reacting voxel, in linear-scale
+    /// quantized steps (~1.95 K each, whatever the quantization curve).
     #[serde(default)]
     pub temp_delta: i32,
-    /// Minimum temperature (quantized) for the reaction to occur. 0 = no minimum.
+    /// Minimum temperature for the reaction to occur. 0 = no minimum.
     #[serde(default)]
-    pub min_temp: u32,
-    /// Maximum temperature (quantized) for the reaction to occur. 0 = no maximum.
+    pub min_temp: QuantizedTemp,
+    /// Maximum temperature for the reaction to occur. 0 = no maximum.
     #[serde(default)]
-    pub max_temp: u32,
+    pub max_temp: QuantizedTemp,
     /// Pressure delta applied when this reaction fires (signed).
     /// Positive = pressure increase (explosions), negative = pressure decrease.
     #[serde(default)]
@@ -41,6 +70,62 @@ pub struct InteractionRule {
     /// Maximum charge for the reaction to occur. 0 = no maximum.
     #[serde(default)]
     pub max_charge: u32,
+    /// Minimum pressure (6-bit, 0–63) for the reaction to occur. 0 = no minimum.
+    #[serde(default)]
+    pub min_pressure: u32,
+    /// Maximum pressure (6-bit, 0–63) for the reaction to occur. 0 = no maximum.
+    #[serde(default)]
+    pub max_pressure: u32,
+    /// Material that must be face-adjacent to the reacting voxel for the
+    /// reaction to occur. The catalyst itself is never consumed.
+    #[serde(default)]
+    pub catalyst: Option<u16>,
+    /// Oxygen the reaction burns each time it fires, out of the 255 a cell
+    /// of fresh air holds. 0 = needs no air. When set, the reaction only
+    /// fires while there is at least this much oxygen in reach: in the
+    /// reacting voxel's own cell if it is a gas, else in a face-adjacent gas
+    /// cell. Sealed fires smother.
+    #[serde(default)]
+    pub oxygen: u32,
+    /// Strength (1–255) of the blast front set off at each reacting voxel.
+    /// 0 = not an explosion. The front spreads outward, breaking voxels
+    /// whose blast_resistance it exceeds and launching them as fragments.
+    #[serde(default)]
+    pub blast: u32,
+    /// Pattern matched in place of `input_a`; `input_a` is ignored when set.
+    #[serde(default)]
+    pub pattern_a: Option<MaterialPattern>,
+    /// Pattern matched in place of `input_b`; `input_b` is ignored when set.
+    #[serde(default)]
+    pub pattern_b: Option<MaterialPattern>,
+}
+
+impl InteractionRule {
+    /// Whether either input is a pattern that must be expanded by the compiler.
+    pub fn is_pattern(&self) -> bool {
+        self.pattern_a.is_some() || self.pattern_b.is_some()
+    }
+
+    /// Whether this rule and `other` have temperature, charge and pressure
+    /// windows that can all hold for the same voxel.
+    pub fn conditions_overlap(&self, other: &InteractionRule) -> bool {
+        fn window(min: u32, max: u32) -> (u32, u32) {
+            (min, if max == 0 { u32::MAX } else { max })
+        }
+        fn overlap(a: (u32, u32), b: (u32, u32)) -> bool {
+            a.0 <= b.1 && b.0 <= a.1
+        }
+        overlap(
+            window(self.min_temp.0.into(), self.max_temp.0.into()),
+            window(other.min_temp.0.into(), other.max_temp.0.into()),
+        ) && overlap(
+            window(self.min_charge, self.max_charge),
+            window(other.min_charge, other.max_charge),
+        ) && overlap(
+            window(self.min_pressure, self.max_pressure),
+            window(other.min_pressure, other.max_pressure),
+        )
+    }
 }
 
 /// Collection of interaction rules.
@@ -76,14 +161,65 @@ mod tests {
                 output_b: 9,
                 probability: 0.8,
                 temp_delta: 200,
-                min_temp: 0,
-                max_temp: 0,
+                min_temp: QuantizedTemp(0),
+                max_temp: QuantizedTemp(0),
                 pressure_delta: 0,
                 min_charge: 0,
                 max_charge: 0,
+                min_pressure: 0,
+                max_pressure: 0,
+                catalyst: None,
+                oxygen: 0,
+                blast: 0,
+                pattern_a: None,
+                pattern_b: None,
             }],
         };
         assert_eq!(set.len(), 1);
         assert!(!set.is_empty());
     }
+
+    #[test]
+    fn test_conditions_overlap() {
+        let rule = |min_temp, max_temp, min_charge| InteractionRule {
+            name: "r".into(),
+            input_a: 3,
+            input_b: 11,
+            output_a: 7,
+            output_b: 1,
+            probability: 0.5,
+            temp_delta: 0,
+            min_temp: QuantizedTemp(min_temp),
+            max_temp: QuantizedTemp(max_temp),
+            pressure_delta: 0,
+            min_charge,
+            max_charge: 0,
+            min_pressure: 0,
+            max_pressure: 0,
+            catalyst: None,
+            oxygen: 0,
+            blast: 0,
+            pattern_a: None,
+            pattern_b: None,
+        };
+        let cool = rule(0, 1000, 0);
+        let hot = rule(1001, 0, 0);
+        let any = rule(0, 0, 0);
+        assert!(!cool.conditions_overlap(&hot));
+        assert!(any.conditions_overlap(&hot));
+        assert!(any.conditions_overlap(&cool));
+        // Overlapping temperatures, but only one needs charge: both can hold
+        assert!(rule(0, 0, 50).conditions_overlap(&any));
+        // Same temperatures, disjoint pressure windows
+        let low = InteractionRule {
+            max_pressure: 20,
+            ..any.clone()
+        };
+        let high = InteractionRule {
+            min_pressure: 40,
+            ..any.clone()
+        };
+        assert!(!low.conditions_overlap(&high));
+        assert!(any.conditions_overlap(&high));
+    }
 }
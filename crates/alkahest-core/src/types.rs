@@ -1,9 +1,58 @@
 use glam::IVec3;
+use serde::{Deserialize, Serialize};
+
+use crate::math::{temp_from_quantized, temp_to_quantized};
 
 /// Newtype for material identifiers. 0 = air/empty.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub struct MaterialId(pub u16);
 
+/// Absolute temperature in Kelvin.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Kelvin(pub f32);
+
+impl Kelvin {
+    pub fn from_celsius(celsius: f32) -> Self {
+        Self(celsius + 273.15)
+    }
+
+    pub fn to_celsius(self) -> f32 {
+        self.0 - 273.15
+    }
+
+    /// Quantize, clamping to the 12-bit range.
+    pub fn quantize(self) -> QuantizedTemp {
+        QuantizedTemp(temp_to_quantized(self.0))
+    }
+}
+
+/// Temperature in the 12-bit quantized units stored in voxels
+/// (0 = 0 K, 4095 = `TEMP_QUANT_MAX_K`).
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct QuantizedTemp(pub u16);
+
+impl QuantizedTemp {
+    pub fn to_kelvin(self) -> Kelvin {
+        Kelvin(temp_from_quantized(self.0))
+    }
+}
+
+impl From<Kelvin> for QuantizedTemp {
+    fn from(kelvin: Kelvin) -> Self {
+        kelvin.quantize()
+    }
+}
+
+impl From<QuantizedTemp> for Kelvin {
+    fn from(quantized: QuantizedTemp) -> Self {
+        quantized.to_kelvin()
+    }
+}
+
 /// Chunk coordinate in chunk-space (each unit = CHUNK_SIZE voxels).
 pub type ChunkCoord = IVec3;
 
@@ -12,21 +61,29 @@ pub type WorldCoord = IVec3;
 
 /// Packed voxel data: 8 bytes stored as two u32 values.
 ///
-/// Bit layout (matching architecture.md spec):
-///   low  [0:15]   material_id (u16)
-///   low  [16:27]  temperature (12-bit quantized)
-///   low  [28:31]  velocity_x low 4 bits
-///   high [0:3]    velocity_x high 4 bits
-///   high [4:11]   velocity_y (i8)
-///   high [12:19]  velocity_z (i8)
-///   high [20:25]  pressure (6-bit)
-///   high [26:31]  flags (6-bit)
-///
-/// velocity_x straddles the u32 boundary intentionally — this matches
-/// the spec in architecture.md and the WGSL shader vec2<u32> access.
+/// The bit layout is defined once in [`crate::voxel`]; read and write fields
+/// through [`crate::voxel::Voxel`].
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 #[repr(C)]
 pub struct VoxelData {
     pub low: u32,
     pub high: u32,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::{AMBIENT_TEMP_K, AMBIENT_TEMP_QUANTIZED};
+
+    #[test]
+    fn test_temperature_conversions() {
+        assert_eq!(
+            Kelvin(AMBIENT_TEMP_K).quantize(),
+            QuantizedTemp(AMBIENT_TEMP_QUANTIZED)
+        );
+        assert_eq!(Kelvin::from_celsius(0.0), Kelvin(273.15));
+        assert!((Kelvin(373.15).to_celsius() - 100.0).abs() < 1e-4);
+        let q = QuantizedTemp::from(Kelvin(1000.0));
+        assert!((Kelvin::from(q).0 - 1000.0).abs() < 1.0);
+    }
+}
@@ -0,0 +1,435 @@
+//! Typed view of the packed 8-byte voxel, and the single definition of its
+//! bit layout.
+//!
+//! The layout is a list of [`VoxelField`]s over the voxel as one 64-bit
+//! value, low word first. The Rust accessors and the WGSL prelude in
+//! `shaders/common/types.wgsl` are both derived from it; a test fails if the
+//! checked-in shader file drifts from [`wgsl_prelude`].
+
+use std::fmt::Write;
+
+use crate::constants::TEMP_QUANT_MAX_VALUE;
+use crate::types::{MaterialId, VoxelData};
+
+/// One named bit range of the packed voxel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VoxelField {
+    pub name: &'static str,
+    /// First bit, counting from bit 0 of the low word. Bits 32..63 are the
+    /// high word.
+    pub offset: u32,
+    pub bits: u32,
+    /// Stored as two's complement and sign-extended on read.
+    pub signed: bool,
+}
+
+pub const MATERIAL_ID: VoxelField = VoxelField::unsigned("material_id", 0, 16);
+/// 12-bit quantized temperature.
+pub const TEMPERATURE: VoxelField = VoxelField::unsigned("temperature", 16, 12);
+/// Straddles the word boundary: low 4 bits in `low[28:31]`, high 4 bits in
+/// `high[0:3]`.
+pub const VEL_X: VoxelField = VoxelField::signed("vel_x", 28, 8);
+pub const VEL_Y: VoxelField = VoxelField::signed("vel_y", 36, 8);
+pub const VEL_Z: VoxelField = VoxelField::signed("vel_z", 44, 8);
+pub const PRESSURE: VoxelField = VoxelField::unsigned("pressure", 52, 6);
+pub const FLAGS: VoxelField = VoxelField::unsigned("flags", 58, 6);
+
+/// Every field, in packing order. This is also the parameter order of the
+/// WGSL `pack_voxel`.
+pub const FIELDS: [VoxelField; 7] = [
+    MATERIAL_ID,
+    TEMPERATURE,
+    VEL_X,
+    VEL_Y,
+    VEL_Z,
+    PRESSURE,
+    FLAGS,
+];
+
+impl VoxelField {
+    const fn unsigned(name: &'static str, offset: u32, bits: u32) -> Self {
+        Self {
+            name,
+            offset,
+            bits,
+            signed: false,
+        }
+    }
+
+    const fn signed(name: &'static str, offset: u32, bits: u32) -> Self {
+        Self {
+            name,
+            offset,
+            bits,
+            signed: true,
+        }
+    }
+
+    /// Mask of the field's width, not shifted into place.
+    pub const fn mask(&self) -> u64 {
+        (1u64 << self.bits) - 1
+    }
+
+    /// Raw (not sign-extended) field bits of a packed voxel.
+    pub const fn get(&self, packed: u64) -> u64 {
+        (packed >> self.offset) & self.mask()
+    }
+
+    /// `packed` with the field replaced by the low bits of `value`.
+    pub const fn set(&self, packed: u64, value: u64) -> u64 {
+        (packed & !(self.mask() << self.offset)) | ((value & self.mask()) << self.offset)
+    }
+}
+
+/// A voxel as its two packed words, with named accessors.
+///
+/// Converts freely to and from the `[u32; 2]` chunk data the CPU simulator
+/// and GPU readback use, and from [`VoxelData`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Voxel([u32; 2]);
+
+impl Voxel {
+    /// A voxel of `material` with every other field zero. Chain the `with_*`
+    /// methods to fill in the rest.
+    pub fn new(material: MaterialId) -> Self {
+        Self::default().with_material_id(material)
+    }
+
+    pub const fn from_words(words: [u32; 2]) -> Self {
+        Self(words)
+    }
+
+    pub const fn words(self) -> [u32; 2] {
+        self.0
+    }
+
+    const fn packed(self) -> u64 {
+        self.0[0] as u64 | ((self.0[1] as u64) << 32)
+    }
+
+    fn get(self, field: VoxelField) -> u64 {
+        field.get(self.packed())
+    }
+
+    fn set(&mut self, field: VoxelField, value: u64) {
+        let packed = field.set(self.packed(), value);
+        self.0 = [packed as u32, (packed >> 32) as u32];
+    }
+
+    pub fn material_id(self) -> MaterialId {
+        MaterialId(self.get(MATERIAL_ID) as u16)
+    }
+
+    pub fn temperature(self) -> u16 {
+        self.get(TEMPERATURE) as u16
+    }
+
+    pub fn velocity(self) -> [i8; 3] {
+        [
+            self.get(VEL_X) as u8 as i8,
+            self.get(VEL_Y) as u8 as i8,
+            self.get(VEL_Z) as u8 as i8,
+        ]
+    }
+
+    pub fn pressure(self) -> u8 {
+        self.get(PRESSURE) as u8
+    }
+
+    pub fn flags(self) -> u8 {
+        self.get(FLAGS) as u8
+    }
+
+    pub fn set_material_id(&mut self, material: MaterialId) {
+        self.set(MATERIAL_ID, material.0 as u64);
+    }
+
+    /// Clamps to [`TEMP_QUANT_MAX_VALUE`].
+    pub fn set_temperature(&mut self, temperature: u16) {
+        self.set(TEMPERATURE, temperature.min(TEMP_QUANT_MAX_VALUE) as u64);
+    }
+
+    pub fn set_velocity(&mut self, velocity: [i8; 3]) {
+        self.set(VEL_X, velocity[0] as u8 as u64);
+        self.set(VEL_Y, velocity[1] as u8 as u64);
+        self.set(VEL_Z, velocity[2] as u8 as u64);
+    }
+
+    /// Keeps the low 6 bits.
+    pub fn set_pressure(&mut self, pressure: u8) {
+        self.set(PRESSURE, pressure as u64);
+    }
+
+    /// Keeps the low 6 bits.
+    pub fn set_flags(&mut self, flags: u8) {
+        self.set(FLAGS, flags as u64);
+    }
+
+    pub fn with_material_id(mut self, material: MaterialId) -> Self {
+        self.set_material_id(material);
+        self
+    }
+
+    pub fn with_temperature(mut self, temperature: u16) -> Self {
+        self.set_temperature(temperature);
+        self
+    }
+
+    pub fn with_velocity(mut self, velocity: [i8; 3]) -> Self {
+        self.set_velocity(velocity);
+        self
+    }
+
+    pub fn with_pressure(mut self, pressure: u8) -> Self {
+        self.set_pressure(pressure);
+        self
+    }
+
+    pub fn with_flags(mut self, flags: u8) -> Self {
+        self.set_flags(flags);
+        self
+    }
+}
+
+impl From<[u32; 2]> for Voxel {
+    fn from(words: [u32; 2]) -> Self {
+        Self(words)
+    }
+}
+
+impl From<Voxel> for [u32; 2] {
+    fn from(voxel: Voxel) -> Self {
+        voxel.0
+    }
+}
+
+impl From<VoxelData> for Voxel {
+    fn from(data: VoxelData) -> Self {
+        Self([data.low, data.high])
+    }
+}
+
+impl From<Voxel> for VoxelData {
+    fn from(voxel: Voxel) -> Self {
+        VoxelData {
+            low: voxel.0[0],
+            high: voxel.0[1],
+        }
+    }
+}
+
+/// WGSL source for the voxel accessors, generated from [`FIELDS`]. This is
+/// the content of `shaders/common/types.wgsl`.
+///
+/// Per field it emits `VOXEL_<NAME>_OFFSET`/`_BITS` constants, an
+/// `unpack_<name>` getter and a `with_<name>` setter. Signed fields read and
+/// write `i32`, unsigned ones `u32`; setters keep only the field's low bits.
+pub fn wgsl_prelude() -> String {
+    let mut out = String::new();
+    out.push_str(
+        "// types.wgsl — Voxel layout constants and accessors.\n\
+         // GENERATED from alkahest-core/src/voxel.rs; do not edit by hand. After\n\
+         // changing the layout, run `ALKAHEST_BLESS=1 cargo test -p alkahest-core`.\n\
+         // VoxelData is packed as vec2<u32>: .x = low word, .y = high word.\n\
+         //\n\
+         // Bit layout:\n",
+    );
+    for field in FIELDS {
+        let ranges = word_ranges(field);
+        let mut read = 0;
+        for &(word, first, last) in &ranges {
+            let width = last - first + 1;
+            let label = if ranges.len() > 1 {
+                format!("{} bits {}-{}", field.name, read, read + width - 1)
+            } else if field.signed {
+                format!("{} (signed {}-bit)", field.name, field.bits)
+            } else {
+                format!("{} ({}-bit)", field.name, field.bits)
+            };
+            let bits = format!("[{first}:{last}]");
+            let _ = writeln!(out, "//   {:<4} {bits:<7}  {label}", ["low", "high"][word]);
+            read += width;
+        }
+    }
+    out.push('\n');
+
+    for field in FIELDS {
+        let upper = field.name.to_uppercase();
+        let _ = writeln!(out, "const VOXEL_{upper}_OFFSET: u32 = {}u;", field.offset);
+        let _ = writeln!(out, "const VOXEL_{upper}_BITS: u32 = {}u;", field.bits);
+    }
+
+    for field in FIELDS {
+        let ty = if field.signed { "i32" } else { "u32" };
+        let _ = writeln!(out, "\nfn unpack_{}(v: vec2<u32>) -> {ty} {{", field.name);
+        let ranges = word_ranges(field);
+        let parts: Vec<String> = ranges
+            .iter()
+            .scan(0, |read, &(word, first, last)| {
+                let width = last - first + 1;
+                let mut part = format!("v.{}", ["x", "y"][word]);
+                if first > 0 {
+                    part = format!("({part} >> {first}u)");
+                }
+                if first + width < 32 {
+                    part = format!("{part} & {:#X}u", (1u64 << width) - 1);
+                    if ranges.len() > 1 {
+                        part = format!("({part})");
+                    }
+                }
+                if *read > 0 {
+                    part = format!("({part} << {read}u)");
+                }
+                *read += width;
+                Some(part)
+            })
+            .collect();
+        let raw = parts.join(" | ");
+        if field.signed {
+            let _ = writeln!(out, "    let raw = {raw};");
+            let _ = writeln!(out, "    return i32(raw << {0}u) >> {0}u;", 32 - field.bits);
+        } else {
+            let _ = writeln!(out, "    return {raw};");
+        }
+        out.push_str("}\n");
+
+        let _ = writeln!(
+            out,
+            "\nfn with_{}(v: vec2<u32>, value: {ty}) -> vec2<u32> {{",
+            field.name
+        );
+        let value = if field.signed { "u32(value)" } else { "value" };
+        let _ = writeln!(out, "    let bits = {value} & {:#X}u;", field.mask());
+        out.push_str("    var packed = v;\n");
+        let mut written = 0;
+        for (word, first, last) in word_ranges(field) {
+            let width = last - first + 1;
+            let component = ["x", "y"][word];
+            let mask = ((1u64 << width) - 1) << first;
+            let mut part = "bits".to_string();
+            if written > 0 {
+                part = format!("({part} >> {written}u)");
+            }
+            if first > 0 {
+                part = format!("({part} << {first}u)");
+            }
+            let _ = writeln!(
+                out,
+                "    packed.{component} = (packed.{component} & {:#X}u) | {part};",
+                !mask as u32
+            );
+            written += width;
+        }
+        out.push_str("    return packed;\n}\n");
+    }
+
+    let params: Vec<String> = FIELDS
+        .iter()
+        .map(|f| format!("{}: {}", f.name, if f.signed { "i32" } else { "u32" }))
+        .collect();
+    let _ = writeln!(
+        out,
+        "\n/// Pack all voxel fields into vec2<u32>. Values are truncated to their\n\
+         /// field widths.\n\
+         fn pack_voxel(\n    {},\n) -> vec2<u32> {{",
+        params.join(",\n    ")
+    );
+    out.push_str("    var v = vec2<u32>(0u, 0u);\n");
+    for field in FIELDS {
+        let _ = writeln!(out, "    v = with_{0}(v, {0});", field.name);
+    }
+    out.push_str("    return v;\n}\n");
+
+    out.push_str(
+        "\n/// Repack a voxel with new material and temperature, preserving velocity/pressure/flags.\n\
+         fn repack_material_temp(old: vec2<u32>, new_mat: u32, new_temp: u32) -> vec2<u32> {\n    \
+             return with_temperature(with_material_id(old, new_mat), new_temp);\n\
+         }\n",
+    );
+    out
+}
+
+/// The (word, first bit, last bit) ranges a field occupies, low word first.
+fn word_ranges(field: VoxelField) -> Vec<(usize, u32, u32)> {
+    let end = field.offset + field.bits;
+    let mut ranges = Vec::with_capacity(2);
+    if field.offset < 32 {
+        ranges.push((0, field.offset, end.min(32) - 1));
+    }
+    if end > 32 {
+        ranges.push((1, field.offset.max(32) - 32, end - 33));
+    }
+    ranges
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fields_tile_both_words() {
+        let mut next = 0;
+        for field in FIELDS {
+            assert_eq!(field.offset, next, "gap or overlap before {}", field.name);
+            next += field.bits;
+        }
+        assert_eq!(next, 64);
+    }
+
+    #[test]
+    fn test_builder_and_getters_roundtrip() {
+        let voxel = Voxel::new(MaterialId(2))
+            .with_temperature(150)
+            .with_velocity([-128, -1, 127])
+            .with_pressure(63)
+            .with_flags(0b100001);
+        assert_eq!(voxel.material_id(), MaterialId(2));
+        assert_eq!(voxel.temperature(), 150);
+        assert_eq!(voxel.velocity(), [-128, -1, 127]);
+        assert_eq!(voxel.pressure(), 63);
+        assert_eq!(voxel.flags(), 0b100001);
+    }
+
+    #[test]
+    fn test_setters_leave_other_fields_alone() {
+        let mut voxel = Voxel::from_words([u32::MAX, u32::MAX]);
+        voxel.set_material_id(MaterialId(7));
+        voxel.set_velocity([0, 0, 0]);
+        assert_eq!(voxel.material_id(), MaterialId(7));
+        assert_eq!(voxel.temperature(), 0xFFF);
+        assert_eq!(voxel.pressure(), 63);
+        assert_eq!(voxel.flags(), 63);
+        assert_eq!(voxel.words(), [0x0FFF_0007, 0xFFF0_0000]);
+    }
+
+    #[test]
+    fn test_setters_truncate_to_field_width() {
+        let voxel = Voxel::default()
+            .with_temperature(u16::MAX)
+            .with_pressure(0xFF)
+            .with_flags(0xFF);
+        assert_eq!(voxel.temperature(), TEMP_QUANT_MAX_VALUE);
+        assert_eq!(voxel.pressure(), 63);
+        assert_eq!(voxel.flags(), 63);
+    }
+
+    #[test]
+    fn test_wgsl_prelude_matches_layout() {
+        let path = concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/../../shaders/common/types.wgsl"
+        );
+        let generated = wgsl_prelude();
+        let on_disk = std::fs::read_to_string(path).unwrap_or_default();
+        if on_disk != generated {
+            if std::env::var_os("ALKAHEST_BLESS").is_some() {
+                std::fs::write(path, &generated).expect("write types.wgsl");
+            } else {
+                panic!(
+                    "shaders/common/types.wgsl does not match the voxel layout in \
+                     alkahest-core/src/voxel.rs (rerun with ALKAHEST_BLESS=1 to regenerate)"
+                );
+            }
+        }
+    }
+}
@@ -1,10 +1,14 @@
+use alkahest_core::constants::TEMP_CURVE;
+use alkahest_core::math::TempCurve;
+
 use crate::error::PersistError;
-use crate::format::{SaveHeader, FORMAT_VERSION, MAGIC};
+use crate::format::{SaveHeader, FORMAT_VERSION, MAGIC, MIN_FORMAT_VERSION};
 
 /// Validate a save file header and return any compatibility warnings.
 ///
 /// Returns Ok(warnings) on success, Err on fatal errors.
-/// A rule hash mismatch produces a warning, not an error.
+/// A rule hash mismatch or a temperature curve other than `TEMP_CURVE`
+/// produces a warning, not an error; an unknown curve is an error.
 pub fn validate_header(
     header: &SaveHeader,
     current_rule_hash: u64,
@@ -15,7 +19,7 @@ pub fn validate_header(
     }
 
     // Check version
-    if header.version != FORMAT_VERSION {
+    if !(MIN_FORMAT_VERSION..=FORMAT_VERSION).contains(&header.version) {
         return Err(PersistError::UnsupportedVersion(header.version));
     }
 
@@ -31,9 +35,28 @@ pub fn validate_header(
         ));
     }
 
+    // Curve mismatch = warning (temperatures are converted on load)
+    if saved_temp_curve(header)? != TEMP_CURVE {
+        warnings.push(format!(
+            "Save uses temperature curve {:08x} (current: {:08x}). \
+             Temperatures were converted and may have lost precision.",
+            header.temp_curve,
+            TEMP_CURVE.id()
+        ));
+    }
+
     Ok(warnings)
 }
 
+/// The curve a save's voxel temperatures were quantized with.
+pub fn saved_temp_curve(header: &SaveHeader) -> Result<TempCurve, PersistError> {
+    // Version 1 predates curves; its temp_curve field was zero padding
+    if header.version < 2 {
+        return Ok(TempCurve::Linear);
+    }
+    TempCurve::from_id(header.temp_curve).ok_or(PersistError::UnknownTempCurve(header.temp_curve))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -43,7 +66,7 @@ mod tests {
         SaveHeader {
             magic: MAGIC,
             version: FORMAT_VERSION,
-            _pad0: 0,
+            aux_channel_count: 0,
             rule_hash,
             tick_count: 0,
             chunk_count: 0,
@@ -55,7 +78,7 @@ mod tests {
                 target: [0.0; 3],
                 distance: 0.0,
             },
-            _pad1: 0,
+            temp_curve: TEMP_CURVE.id(),
         }
     }
 
@@ -89,4 +112,20 @@ mod tests {
         let result = validate_header(&header, 42);
         assert!(matches!(result, Err(PersistError::UnsupportedVersion(99))));
     }
+
+    #[test]
+    fn test_temp_curve_from_header() {
+        let mut header = test_header(42);
+        header.version = 1;
+        header.temp_curve = 0;
+        assert_eq!(saved_temp_curve(&header).unwrap(), TempCurve::Linear);
+
+        header.version = FORMAT_VERSION;
+        header.temp_curve = 0xDEAD_BEEF;
+        let result = validate_header(&header, 42);
+        assert!(matches!(
+            result,
+            Err(PersistError::UnknownTempCurve(0xDEAD_BEEF))
+        ));
+    }
 }
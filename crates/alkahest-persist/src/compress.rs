@@ -1,19 +1,31 @@
+use alkahest_core::voxel::Voxel;
+
 use crate::error::PersistError;
 use crate::format::{CHUNK_DATA_SIZE, FILL_FLAG};
 
 /// Compress a 256KB chunk using LZ4.
 pub fn compress_chunk(data: &[u8]) -> Vec<u8> {
+    compress_block(data)
+}
+
+/// Compress an arbitrary block using LZ4, with its size prepended.
+pub fn compress_block(data: &[u8]) -> Vec<u8> {
     lz4_flex::compress_prepend_size(data)
 }
 
 /// Decompress an LZ4-compressed chunk, validating the output size.
 pub fn decompress_chunk(compressed: &[u8]) -> Result<Vec<u8>, PersistError> {
+    decompress_block(compressed, CHUNK_DATA_SIZE)
+}
+
+/// Decompress an LZ4-compressed block that must expand to `expected` bytes.
+pub fn decompress_block(compressed: &[u8], expected: usize) -> Result<Vec<u8>, PersistError> {
     let decompressed = lz4_flex::decompress_size_prepended(compressed)
         .map_err(|e| PersistError::DecompressError(e.to_string()))?;
 
-    if decompressed.len() != CHUNK_DATA_SIZE {
+    if decompressed.len() != expected {
         return Err(PersistError::InvalidChunkSize {
-            expected: CHUNK_DATA_SIZE,
+            expected,
             actual: decompressed.len(),
         });
     }
@@ -36,10 +48,9 @@ pub fn detect_fill(data: &[u8]) -> Option<u16> {
         }
     }
 
-    // Extract material_id from bits [0:15] of the low u32
     let low = u32::from_le_bytes([data[0], data[1], data[2], data[3]]);
-    let material_id = (low & 0xFFFF) as u16;
-    Some(material_id)
+    let high = u32::from_le_bytes([data[4], data[5], data[6], data[7]]);
+    Some(Voxel::from([low, high]).material_id().0)
 }
 
 /// Encode a fill marker: 4 bytes = (material_id: u16, FILL_FLAG: u16).
@@ -126,13 +137,9 @@ mod tests {
 
         // Check that all voxels have material_id 42
         for i in (0..CHUNK_DATA_SIZE).step_by(8) {
-            let low = u32::from_le_bytes([
-                expanded[i],
-                expanded[i + 1],
-                expanded[i + 2],
-                expanded[i + 3],
-            ]);
-            assert_eq!((low & 0xFFFF) as u16, material_id);
+            let word = |at: usize| u32::from_le_bytes(expanded[at..at + 4].try_into().unwrap());
+            let voxel = Voxel::from([word(i), word(i + 4)]);
+            assert_eq!(voxel.material_id().0, material_id);
         }
     }
 
@@ -21,4 +21,10 @@ pub enum PersistError {
 
     #[error("invalid fill chunk data (expected 4 bytes)")]
     InvalidFillChunk,
+
+    #[error("unknown temperature curve {0:#010x}")]
+    UnknownTempCurve(u32),
+
+    #[error("aux channel {name:?} has unsupported width of {bits} bits")]
+    InvalidAuxChannel { name: String, bits: u8 },
 }
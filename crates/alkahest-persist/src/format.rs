@@ -4,7 +4,16 @@ use alkahest_core::constants::BYTES_PER_CHUNK;
 pub const MAGIC: [u8; 4] = *b"ALKA";
 
 /// Current save format version.
-pub const FORMAT_VERSION: u16 = 1;
+///
+/// Version 3 adds auxiliary channel data: a channel table and an aux block
+/// table after the chunk table. Version 2 records the temperature
+/// quantization curve in the header. Version 1 files (where that field was
+/// padding) are still accepted and read as linear; version 1 and 2 files
+/// load with every aux channel zero.
+pub const FORMAT_VERSION: u16 = 3;
+
+/// Oldest save format version that can still be loaded.
+pub const MIN_FORMAT_VERSION: u16 = 1;
 
 /// Size of the file header in bytes.
 pub const HEADER_SIZE: usize = 64;
@@ -12,6 +21,17 @@ pub const HEADER_SIZE: usize = 64;
 /// Size of each chunk table entry in bytes.
 pub const CHUNK_TABLE_ENTRY_SIZE: usize = 18;
 
+/// Bytes reserved for a channel name in the aux channel table (NUL-padded).
+pub const AUX_NAME_SIZE: usize = 16;
+
+/// Size of each aux channel table entry: name + bit width (u8).
+pub const AUX_CHANNEL_ENTRY_SIZE: usize = AUX_NAME_SIZE + 1;
+
+/// Size of each aux block table entry: offset (u64) + size (u32).
+/// Entries are chunk-major, one per chunk per saved channel; size 0 means
+/// the channel is zero throughout the chunk.
+pub const AUX_BLOCK_ENTRY_SIZE: usize = 12;
+
 /// Marker flag indicating a single-material fill chunk (stored in 4 bytes).
 pub const FILL_FLAG: u16 = 0xFFFF;
 
@@ -38,13 +58,16 @@ pub struct CameraState {
 pub struct SaveHeader {
     pub magic: [u8; 4],
     pub version: u16,
-    pub _pad0: u16,
+    /// Number of entries in the aux channel table. Padding (zero) before
+    /// version 3.
+    pub aux_channel_count: u16,
     pub rule_hash: u64,
     pub tick_count: u64,
     pub chunk_count: u32,
     pub world_seed: u32,
     pub camera: CameraState,
-    pub _pad1: u32,
+    /// `TempCurve::id` of the curve voxel temperatures were quantized with.
+    pub temp_curve: u32,
 }
 
 #[cfg(test)]
@@ -1,18 +1,24 @@
-use alkahest_core::types::ChunkCoord;
+use alkahest_core::aux_channel::{self, AuxChannel, AuxFormat};
+use alkahest_core::constants::{TEMP_CURVE, VOXELS_PER_CHUNK};
+use alkahest_core::math::TempCurve;
+use alkahest_core::voxel::Voxel;
 use glam::IVec3;
 
 use crate::compat;
 use crate::compress;
 use crate::error::PersistError;
 use crate::format::*;
+use crate::save::ChunkSnapshot;
 
 /// Parsed save file data ready for world reconstruction.
 pub struct SaveData {
     pub header: SaveHeader,
     pub camera: CameraState,
-    /// Chunks as (coordinate, decompressed 256KB voxel data).
-    pub chunks: Vec<(ChunkCoord, Vec<u8>)>,
+    /// Chunks with decompressed 256KB voxel data, and aux channel data
+    /// repacked into the current `aux_channel` layout.
+    pub chunks: Vec<ChunkSnapshot>,
     /// Compatibility warnings (e.g., rule hash mismatch).
+    /// Voxel temperatures are already on `TEMP_CURVE`.
     pub warnings: Vec<String>,
 }
 
@@ -25,13 +31,21 @@ pub fn load(bytes: &[u8], current_rule_hash: u64) -> Result<SaveData, PersistErr
 
     // Parse header
     let header: &SaveHeader = bytemuck::from_bytes(&bytes[..HEADER_SIZE]);
-    let warnings = compat::validate_header(header, current_rule_hash)?;
+    let mut warnings = compat::validate_header(header, current_rule_hash)?;
+    let saved_curve = compat::saved_temp_curve(header)?;
 
     let chunk_count = header.chunk_count as usize;
+    let aux_channel_count = if header.version >= 3 {
+        header.aux_channel_count as usize
+    } else {
+        0
+    };
     let camera = header.camera;
 
-    // Validate file has enough room for chunk table
-    let table_end = HEADER_SIZE + chunk_count * CHUNK_TABLE_ENTRY_SIZE;
+    // Validate file has enough room for the tables
+    let chunk_table_end = HEADER_SIZE + chunk_count * CHUNK_TABLE_ENTRY_SIZE;
+    let channel_table_end = chunk_table_end + aux_channel_count * AUX_CHANNEL_ENTRY_SIZE;
+    let table_end = channel_table_end + chunk_count * aux_channel_count * AUX_BLOCK_ENTRY_SIZE;
     if bytes.len() < table_end {
         return Err(PersistError::TruncatedFile {
             expected: table_end,
@@ -39,6 +53,10 @@ pub fn load(bytes: &[u8], current_rule_hash: u64) -> Result<SaveData, PersistErr
         });
     }
 
+    // Match saved aux channels to registered ones by name
+    let saved_channels =
+        parse_channel_table(&bytes[chunk_table_end..channel_table_end], &mut warnings)?;
+
     // Parse chunk table and decompress each chunk
     let mut chunks = Vec::with_capacity(chunk_count);
     for i in 0..chunk_count {
@@ -48,8 +66,7 @@ pub fn load(bytes: &[u8], current_rule_hash: u64) -> Result<SaveData, PersistErr
         let cx = i16::from_le_bytes([entry[0], entry[1]]);
         let cy = i16::from_le_bytes([entry[2], entry[3]]);
         let cz = i16::from_le_bytes([entry[4], entry[5]]);
-        let offset = u64::from_le_bytes(entry[6..14].try_into().expect("8-byte slice")) as usize;
-        let size = u32::from_le_bytes(entry[14..18].try_into().expect("4-byte slice")) as usize;
+        let (offset, size) = block_range(&entry[6..]);
 
         let coord = IVec3::new(cx as i32, cy as i32, cz as i32);
 
@@ -64,13 +81,54 @@ pub fn load(bytes: &[u8], current_rule_hash: u64) -> Result<SaveData, PersistErr
         let block = &bytes[offset..offset + size];
 
         // Decompress or expand fill
-        let voxel_data = if compress::is_fill(block) {
+        let mut voxel_data = if compress::is_fill(block) {
             compress::expand_fill(block)?
         } else {
             compress::decompress_chunk(block)?
         };
+        if saved_curve != TEMP_CURVE {
+            requantize_temperatures(&mut voxel_data, saved_curve);
+        }
+
+        // Aux blocks for this chunk, one per saved channel
+        let mut aux_data = Vec::new();
+        for (c, saved) in saved_channels.iter().enumerate() {
+            let entry_start =
+                channel_table_end + (i * aux_channel_count + c) * AUX_BLOCK_ENTRY_SIZE;
+            let (offset, size) = block_range(&bytes[entry_start..]);
+            let Some((channel, format)) = *saved else {
+                continue;
+            };
+            if size == 0 {
+                continue;
+            }
+            if offset + size > bytes.len() {
+                return Err(PersistError::TruncatedFile {
+                    expected: offset + size,
+                    actual: bytes.len(),
+                });
+            }
+            let width = format.bytes();
+            let values = compress::decompress_block(
+                &bytes[offset..offset + size],
+                VOXELS_PER_CHUNK as usize * width,
+            )?;
+            if aux_data.is_empty() {
+                aux_data = vec![0u32; aux_channel::WORDS_PER_CHUNK];
+            }
+            for (voxel, value) in values.chunks_exact(width).enumerate() {
+                let mut le = [0u8; 4];
+                le[..width].copy_from_slice(value);
+                let value = u32::from_le_bytes(le).min(channel.max());
+                channel.write(&mut aux_data, voxel, value);
+            }
+        }
 
-        chunks.push((coord, voxel_data));
+        chunks.push(ChunkSnapshot {
+            coord,
+            voxel_data,
+            aux_data,
+        });
     }
 
     Ok(SaveData {
@@ -81,6 +139,60 @@ pub fn load(bytes: &[u8], current_rule_hash: u64) -> Result<SaveData, PersistErr
     })
 }
 
+/// Decode an (offset: u64, size: u32) pair at the start of `entry`.
+fn block_range(entry: &[u8]) -> (usize, usize) {
+    let offset = u64::from_le_bytes(entry[0..8].try_into().expect("8-byte slice")) as usize;
+    let size = u32::from_le_bytes(entry[8..12].try_into().expect("4-byte slice")) as usize;
+    (offset, size)
+}
+
+/// Resolve each saved aux channel to the registered channel of the same
+/// name, with the width it was saved at. Channels that are no longer
+/// registered resolve to `None` and their data is dropped with a warning;
+/// values wider than the registered channel are clamped on load.
+fn parse_channel_table(
+    table: &[u8],
+    warnings: &mut Vec<String>,
+) -> Result<Vec<Option<(AuxChannel, AuxFormat)>>, PersistError> {
+    table
+        .chunks_exact(AUX_CHANNEL_ENTRY_SIZE)
+        .map(|entry| {
+            let name_bytes = &entry[..AUX_NAME_SIZE];
+            let len = name_bytes
+                .iter()
+                .position(|&b| b == 0)
+                .unwrap_or(AUX_NAME_SIZE);
+            let name = String::from_utf8_lossy(&name_bytes[..len]).into_owned();
+            let bits = entry[AUX_NAME_SIZE];
+            let format = AuxFormat::from_bits(bits as u32).ok_or_else(|| {
+                PersistError::InvalidAuxChannel {
+                    name: name.clone(),
+                    bits,
+                }
+            })?;
+            let channel = aux_channel::find(&name);
+            if channel.is_none() {
+                warnings.push(format!(
+                    "Save contains aux channel \"{name}\", which is no longer registered. \
+                     Its data was dropped."
+                ));
+            }
+            Ok(channel.map(|c| (c, format)))
+        })
+        .collect()
+}
+
+/// Convert every voxel temperature in a chunk from `from` to `TEMP_CURVE`.
+fn requantize_temperatures(voxel_data: &mut [u8], from: TempCurve) {
+    for bytes in voxel_data.chunks_exact_mut(8) {
+        let words: [u32; 2] = bytemuck::pod_read_unaligned(bytes);
+        let voxel = Voxel::from(words);
+        let temperature = TEMP_CURVE.requantize(from, voxel.temperature());
+        let words = voxel.with_temperature(temperature).words();
+        bytes.copy_from_slice(bytemuck::bytes_of(&words));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -108,10 +220,12 @@ mod tests {
             ChunkSnapshot {
                 coord: IVec3::new(0, 0, 0),
                 voxel_data: voxel_data.clone(),
+                aux_data: Vec::new(),
             },
             ChunkSnapshot {
                 coord: IVec3::new(1, 2, 3),
                 voxel_data: vec![0u8; CHUNK_DATA_SIZE], // all air
+                aux_data: Vec::new(),
             },
         ];
 
@@ -125,12 +239,12 @@ mod tests {
         assert!(loaded.warnings.is_empty());
 
         // First chunk: verify data matches
-        assert_eq!(loaded.chunks[0].0, IVec3::new(0, 0, 0));
-        assert_eq!(loaded.chunks[0].1, voxel_data);
+        assert_eq!(loaded.chunks[0].coord, IVec3::new(0, 0, 0));
+        assert_eq!(loaded.chunks[0].voxel_data, voxel_data);
 
         // Second chunk: was fill-optimized, should still decompress correctly
-        assert_eq!(loaded.chunks[1].0, IVec3::new(1, 2, 3));
-        assert_eq!(loaded.chunks[1].1, vec![0u8; CHUNK_DATA_SIZE]);
+        assert_eq!(loaded.chunks[1].coord, IVec3::new(1, 2, 3));
+        assert_eq!(loaded.chunks[1].voxel_data, vec![0u8; CHUNK_DATA_SIZE]);
     }
 
     #[test]
@@ -146,21 +260,89 @@ mod tests {
         let chunks = vec![ChunkSnapshot {
             coord: IVec3::new(0, 0, 0),
             voxel_data: vec![0u8; CHUNK_DATA_SIZE],
+            aux_data: Vec::new(),
         }];
 
         let camera = default_camera();
         let saved = save::save(&chunks, 0, 0, 0, camera);
 
-        // Fill-optimized: header(64) + table(18) + fill(4) = 86 bytes
+        // Fill-optimized: header(64) + tables + fill(4); all-zero aux channels
+        // take only their table entries
+        let channels = aux_channel::CHANNELS.len();
         assert_eq!(
             saved.len(),
-            HEADER_SIZE + CHUNK_TABLE_ENTRY_SIZE + 4,
+            HEADER_SIZE
+                + CHUNK_TABLE_ENTRY_SIZE
+                + channels * (AUX_CHANNEL_ENTRY_SIZE + AUX_BLOCK_ENTRY_SIZE)
+                + 4,
             "fill-optimized single air chunk should be very small"
         );
 
         let loaded = load(&saved, 0).expect("load should succeed");
         assert_eq!(loaded.chunks.len(), 1);
-        assert_eq!(loaded.chunks[0].1, vec![0u8; CHUNK_DATA_SIZE]);
+        assert_eq!(loaded.chunks[0].voxel_data, vec![0u8; CHUNK_DATA_SIZE]);
+    }
+
+    #[test]
+    fn test_aux_channels_roundtrip() {
+        let mut aux_data = vec![0u32; aux_channel::WORDS_PER_CHUNK];
+        aux_channel::CHARGE.write(&mut aux_data, 0, 255);
+        aux_channel::CHARGE.write(&mut aux_data, 1234, 17);
+        let chunks = vec![
+            ChunkSnapshot {
+                coord: IVec3::ZERO,
+                voxel_data: vec![0u8; CHUNK_DATA_SIZE],
+                aux_data: aux_data.clone(),
+            },
+            ChunkSnapshot {
+                coord: IVec3::X,
+                voxel_data: vec![0u8; CHUNK_DATA_SIZE],
+                aux_data: vec![0u32; aux_channel::WORDS_PER_CHUNK],
+            },
+        ];
+
+        let saved = save::save(&chunks, 0, 0, 0, default_camera());
+        let loaded = load(&saved, 0).expect("load should succeed");
+        assert_eq!(loaded.chunks[0].aux_data, aux_data);
+        assert!(
+            loaded.chunks[1].aux_data.is_empty(),
+            "all-zero channels load as no aux data"
+        );
+    }
+
+    #[test]
+    fn test_unregistered_aux_channel_dropped_with_warning() {
+        let mut aux_data = vec![0u32; aux_channel::WORDS_PER_CHUNK];
+        aux_channel::CHARGE.write(&mut aux_data, 5, 99);
+        let chunks = vec![ChunkSnapshot {
+            coord: IVec3::ZERO,
+            voxel_data: vec![0u8; CHUNK_DATA_SIZE],
+            aux_data,
+        }];
+        let mut saved = save::save(&chunks, 0, 0, 0, default_camera());
+        // Rename the first channel table entry
+        let name_start = HEADER_SIZE + CHUNK_TABLE_ENTRY_SIZE;
+        saved[name_start..name_start + AUX_NAME_SIZE].fill(0);
+        saved[name_start..name_start + 8].copy_from_slice(b"retired!");
+
+        let loaded = load(&saved, 0).expect("should load with warning");
+        assert_eq!(loaded.warnings.len(), 1);
+        assert!(loaded.warnings[0].contains("retired!"));
+        assert!(loaded.chunks[0].aux_data.is_empty());
+    }
+
+    #[test]
+    fn test_version_2_loads_without_aux_channels() {
+        let mut saved = save::save(&[], 0, 0, 0, default_camera());
+        saved[4..6].copy_from_slice(&2u16.to_le_bytes());
+        // Version 2 had padding where the channel count now is
+        saved[6..8].copy_from_slice(&0u16.to_le_bytes());
+        let channels = aux_channel::CHANNELS.len();
+        saved.truncate(saved.len() - channels * AUX_CHANNEL_ENTRY_SIZE);
+
+        let loaded = load(&saved, 0).expect("version 2 should load");
+        assert!(loaded.warnings.is_empty());
+        assert!(loaded.chunks.is_empty());
     }
 
     #[test]
@@ -191,6 +373,38 @@ mod tests {
         assert!(loaded.warnings[0].contains("Rule set has changed"));
     }
 
+    #[test]
+    fn test_temperatures_converted_from_other_curve() {
+        let other = TempCurve::KNOWN
+            .into_iter()
+            .find(|&curve| curve != TEMP_CURVE)
+            .unwrap();
+        let mut voxel_data = vec![0u8; CHUNK_DATA_SIZE];
+        let temps = [0u16, 150, 1000, 4095];
+        for (i, &temp) in temps.iter().enumerate() {
+            let words = Voxel::from([1, 0]).with_temperature(temp).words();
+            voxel_data[i * 8..i * 8 + 8].copy_from_slice(bytemuck::bytes_of(&words));
+        }
+        let chunks = vec![ChunkSnapshot {
+            coord: IVec3::ZERO,
+            voxel_data,
+            aux_data: Vec::new(),
+        }];
+        let mut saved = save::save(&chunks, 0, 0, 0, default_camera());
+        saved[60..64].copy_from_slice(&other.id().to_le_bytes());
+
+        let loaded = load(&saved, 0).expect("should load with warning");
+        assert_eq!(loaded.warnings.len(), 1);
+        assert!(loaded.warnings[0].contains("temperature curve"));
+        for (i, &temp) in temps.iter().enumerate() {
+            let words: [u32; 2] =
+                bytemuck::pod_read_unaligned(&loaded.chunks[0].voxel_data[i * 8..i * 8 + 8]);
+            let voxel = Voxel::from(words);
+            assert_eq!(voxel.material_id().0, 1);
+            assert_eq!(voxel.temperature(), TEMP_CURVE.requantize(other, temp));
+        }
+    }
+
     #[test]
     fn test_file_too_small_rejected() {
         let result = load(&[0u8; 10], 0);
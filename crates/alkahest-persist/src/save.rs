@@ -1,17 +1,24 @@
+use alkahest_core::aux_channel::{self, AuxChannel};
+use alkahest_core::constants::{TEMP_CURVE, VOXELS_PER_CHUNK};
 use alkahest_core::types::ChunkCoord;
 
 use crate::compress;
 use crate::format::*;
 
-/// A snapshot of one chunk's voxel data for serialization.
+/// A snapshot of one chunk's voxel and aux channel data for serialization.
 pub struct ChunkSnapshot {
     pub coord: ChunkCoord,
     pub voxel_data: Vec<u8>,
+    /// Packed aux channel words in the current `aux_channel` layout
+    /// (`WORDS_PER_CHUNK` of them), or empty when every channel is zero.
+    pub aux_data: Vec<u32>,
 }
 
 /// Serialize chunks into the Alkahest save binary format.
 ///
-/// Layout: header (64B) + chunk table (18B × N) + compressed data blocks.
+/// Layout: header (64B) + chunk table (18B × N) + aux channel table
+/// (17B × C) + aux block table (12B × N × C) + compressed voxel blocks +
+/// compressed aux blocks. Every registered aux channel is saved.
 pub fn save(
     chunks: &[ChunkSnapshot],
     rule_hash: u64,
@@ -20,6 +27,7 @@ pub fn save(
     camera: CameraState,
 ) -> Vec<u8> {
     let chunk_count = chunks.len() as u32;
+    let channels = aux_channel::CHANNELS;
 
     // Compress each chunk (detect fill first, then LZ4)
     let mut compressed_blocks: Vec<Vec<u8>> = Vec::with_capacity(chunks.len());
@@ -31,25 +39,41 @@ pub fn save(
         }
     }
 
-    // Compute chunk table size and data offsets
-    let table_size = chunks.len() * CHUNK_TABLE_ENTRY_SIZE;
+    // Split each chunk's aux words per channel; all-zero channels are omitted
+    let aux_blocks: Vec<Vec<u8>> = chunks
+        .iter()
+        .flat_map(|chunk| {
+            channels
+                .iter()
+                .map(|&channel| encode_aux_channel(&chunk.aux_data, channel))
+        })
+        .collect();
+
+    // Compute table sizes and data offsets
+    let table_size = chunks.len() * CHUNK_TABLE_ENTRY_SIZE
+        + channels.len() * AUX_CHANNEL_ENTRY_SIZE
+        + aux_blocks.len() * AUX_BLOCK_ENTRY_SIZE;
     let data_start = HEADER_SIZE + table_size;
 
     // Build header
     let header = SaveHeader {
         magic: MAGIC,
         version: FORMAT_VERSION,
-        _pad0: 0,
+        aux_channel_count: channels.len() as u16,
         rule_hash,
         tick_count,
         chunk_count,
         world_seed,
         camera,
-        _pad1: 0,
+        temp_curve: TEMP_CURVE.id(),
     };
 
     // Calculate total file size
-    let total_data_size: usize = compressed_blocks.iter().map(|b| b.len()).sum();
+    let total_data_size: usize = compressed_blocks
+        .iter()
+        .chain(&aux_blocks)
+        .map(|b| b.len())
+        .sum();
     let total_size = data_start + total_data_size;
     let mut output = Vec::with_capacity(total_size);
 
@@ -69,14 +93,48 @@ pub fn save(
         current_offset += block_size as u64;
     }
 
+    // Write aux channel table: name (NUL-padded) + bit width
+    for channel in &channels {
+        let mut name = [0u8; AUX_NAME_SIZE];
+        name[..channel.name.len()].copy_from_slice(channel.name.as_bytes());
+        output.extend_from_slice(&name);
+        output.push(channel.format.bits() as u8);
+    }
+
+    // Write aux block table (data follows the voxel blocks)
+    for block in &aux_blocks {
+        let offset = if block.is_empty() { 0 } else { current_offset };
+        output.extend_from_slice(&offset.to_le_bytes());
+        output.extend_from_slice(&(block.len() as u32).to_le_bytes());
+        current_offset += block.len() as u64;
+    }
+
     // Write compressed data blocks
-    for block in &compressed_blocks {
+    for block in compressed_blocks.iter().chain(&aux_blocks) {
         output.extend_from_slice(block);
     }
 
     output
 }
 
+/// One channel of a chunk as LZ4-compressed little-endian values of the
+/// channel's width, or empty if the channel is zero throughout.
+fn encode_aux_channel(aux_data: &[u32], channel: AuxChannel) -> Vec<u8> {
+    if aux_data.is_empty() {
+        return Vec::new();
+    }
+    let values = (0..VOXELS_PER_CHUNK as usize).map(|i| channel.read(aux_data, i));
+    if values.clone().all(|v| v == 0) {
+        return Vec::new();
+    }
+    let width = channel.format.bytes();
+    let mut bytes = Vec::with_capacity(VOXELS_PER_CHUNK as usize * width);
+    for value in values {
+        bytes.extend_from_slice(&value.to_le_bytes()[..width]);
+    }
+    compress::compress_block(&bytes)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -87,6 +145,7 @@ mod tests {
         let chunks = vec![ChunkSnapshot {
             coord: IVec3::new(1, 2, 3),
             voxel_data: vec![0u8; CHUNK_DATA_SIZE],
+            aux_data: Vec::new(),
         }];
 
         let camera = CameraState {
@@ -132,5 +191,6 @@ mod tests {
         assert_eq!(header.chunk_count, 0);
         assert_eq!(header.world_seed, 7);
         assert_eq!(header.camera.mode, 1);
+        assert_eq!(header.temp_curve, TEMP_CURVE.id());
     }
 }
@@ -6,6 +6,7 @@ use crate::save::{self, ChunkSnapshot};
 /// Export a subregion of the world defined by a bounding box of chunk coordinates.
 ///
 /// Filters chunks to only those within [bbox_min, bbox_max] inclusive, then delegates to save.
+/// Aux channel data travels with each chunk.
 pub fn export_subregion(
     all_chunks: &[ChunkSnapshot],
     bbox_min: ChunkCoord,
@@ -28,6 +29,7 @@ pub fn export_subregion(
         .map(|c| ChunkSnapshot {
             coord: c.coord,
             voxel_data: c.voxel_data.clone(),
+            aux_data: c.aux_data.clone(),
         })
         .collect();
 
@@ -57,14 +59,17 @@ mod tests {
             ChunkSnapshot {
                 coord: IVec3::new(0, 0, 0),
                 voxel_data: vec![0u8; CHUNK_DATA_SIZE],
+                aux_data: Vec::new(),
             },
             ChunkSnapshot {
                 coord: IVec3::new(1, 0, 0),
                 voxel_data: vec![0u8; CHUNK_DATA_SIZE],
+                aux_data: Vec::new(),
             },
             ChunkSnapshot {
                 coord: IVec3::new(5, 5, 5),
                 voxel_data: vec![0u8; CHUNK_DATA_SIZE],
+                aux_data: Vec::new(),
             },
         ];
 
@@ -82,7 +87,7 @@ mod tests {
         // Only chunks (0,0,0) and (1,0,0) are in the bbox; (5,5,5) is excluded
         assert_eq!(loaded.chunks.len(), 2);
 
-        let coords: Vec<_> = loaded.chunks.iter().map(|(c, _)| *c).collect();
+        let coords: Vec<_> = loaded.chunks.iter().map(|c| c.coord).collect();
         assert!(coords.contains(&IVec3::new(0, 0, 0)));
         assert!(coords.contains(&IVec3::new(1, 0, 0)));
         assert!(!coords.contains(&IVec3::new(5, 5, 5)));
@@ -93,6 +98,7 @@ mod tests {
         let chunks = vec![ChunkSnapshot {
             coord: IVec3::new(2, 1, 3),
             voxel_data: vec![0u8; CHUNK_DATA_SIZE],
+            aux_data: Vec::new(),
         }];
 
         let saved = export_subregion(
@@ -1,10 +1,16 @@
 //! GPU pick buffer for voxel hover info via async readback.
-//! Stores 8 u32 values written by the ray march shader for the cursor pixel:
+//! Stores u32 values written by the ray march shader for the cursor pixel:
 //!   [0] world_x, [1] world_y, [2] world_z, [3] material_id,
-//!   [4] temperature, [5] pressure, [6] velocity_packed, [7] flags
+//!   [4] temperature, [5] pressure, [6] velocity_packed, [7] flags,
+//!   [8..] one value per registered aux channel, in `aux_channel::CHANNELS` order
 
-/// Size of the pick buffer in bytes (8 × u32 = 32 bytes).
-pub const PICK_BUFFER_SIZE: u64 = 8 * 4;
+use alkahest_core::aux_channel::CHANNELS;
+
+/// Number of u32 values in the pick buffer.
+const PICK_VALUES: usize = 8 + CHANNELS.len();
+
+/// Size of the pick buffer in bytes (4 bytes per value).
+pub const PICK_BUFFER_SIZE: u64 = PICK_VALUES as u64 * 4;
 
 /// Decoded pick result from GPU readback.
 #[derive(Debug, Clone, Default)]
@@ -20,6 +26,8 @@ pub struct PickResult {
     pub vel_y: i32,
     pub vel_z: i32,
     pub flags: u32,
+    /// Aux channel values, indexed like `aux_channel::CHANNELS`.
+    pub aux: Vec<u32>,
 }
 
 /// GPU pick buffer + staging buffer for async readback.
@@ -90,7 +98,7 @@ impl PickBuffer {
             Ok(Ok(())) => {
                 let data = slice.get_mapped_range();
                 let values: &[u32] = bytemuck::cast_slice(&data);
-                let result = if values.len() >= 8 && values[3] != 0 {
+                let result = if values.len() >= PICK_VALUES && values[3] != 0 {
                     // Decode velocity_packed: 3 i8 values packed as u32
                     let vp = values[6];
                     let vx_u8 = vp & 0xFF;
@@ -111,6 +119,7 @@ impl PickBuffer {
                         vel_y: vy,
                         vel_z: vz,
                         flags: values[7],
+                        aux: values[8..PICK_VALUES].to_vec(),
                     }
                 } else {
                     PickResult::default()
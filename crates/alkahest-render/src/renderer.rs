@@ -1,3 +1,4 @@
+use alkahest_core::aux_channel;
 use alkahest_core::constants::*;
 use wgpu::util::DeviceExt;
 
@@ -84,6 +85,7 @@ pub struct Renderer {
     pub light_manager: LightManager,
     // Scene
     voxel_pool_buffer: wgpu::Buffer,
+    aux_pool_buffer: wgpu::Buffer,
     material_color_buffer: wgpu::Buffer,
     chunk_map_buffer: wgpu::Buffer,
     octree_buffer: wgpu::Buffer,
@@ -107,7 +109,8 @@ impl Renderer {
              const WORLD_CHUNKS_X: u32 = {}u;\nconst WORLD_CHUNKS_Y: u32 = {}u;\n\
              const WORLD_CHUNKS_Z: u32 = {}u;\n\
              const SENTINEL_NEIGHBOR: u32 = {}u;\n\
-             const CHUNK_DESC_STRIDE: u32 = {}u;\n",
+             const CHUNK_DESC_STRIDE: u32 = {}u;\n\
+             const TEMP_QUANT_MAX_K: f32 = {:.6};\n{}",
             CHUNK_SIZE,
             VOXELS_PER_CHUNK,
             WORLD_CHUNKS_X,
@@ -115,6 +118,8 @@ impl Renderer {
             WORLD_CHUNKS_Z,
             SENTINEL_NEIGHBOR,
             CHUNK_DESC_STRIDE,
+            TEMP_QUANT_MAX_K,
+            TEMP_CURVE.wgsl_to_kelvin(),
         );
 
         let types_wgsl = include_str!("../../../shaders/common/types.wgsl");
@@ -124,10 +129,12 @@ impl Renderer {
         let debug_lines_wgsl = include_str!("../../../shaders/render/debug_lines.wgsl");
 
         let sky_wgsl = include_str!("../../../shaders/render/sky.wgsl");
+        let aux_channels_wgsl = include_str!("../../../shaders/common/aux_channels.wgsl");
 
-        // Compose ray march shader: constants + types + coords + sky + ray_march
+        // Compose ray march shader: constants + types + coords + aux channels + sky + ray_march
         let ray_march_source = format!(
-            "{constants_preamble}\n{types_wgsl}\n{coords_wgsl}\n{sky_wgsl}\n{ray_march_wgsl}"
+            "{constants_preamble}\n{types_wgsl}\n{coords_wgsl}\n{aux_channels_wgsl}\n\
+             {sky_wgsl}\n{ray_march_wgsl}"
         );
 
         // -- Create shader modules --
@@ -170,6 +177,14 @@ impl Renderer {
             mapped_at_creation: false,
         });
 
+        // -- Aux pool buffer placeholder (replaced by the sim pipeline's aux read pool) --
+        let aux_pool_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("aux-pool-placeholder"),
+            size: aux_channel::WORDS_PER_CHUNK as u64 * 4,
+            usage: wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
         // -- Chunk map buffer: WORLD_CHUNKS_X * WORLD_CHUNKS_Y * WORLD_CHUNKS_Z u32 entries --
         // Each entry is a pool_slot_byte_offset (0xFFFFFFFF for unloaded).
         let chunk_map_size = (WORLD_CHUNKS_X * WORLD_CHUNKS_Y * WORLD_CHUNKS_Z * 4) as u64;
@@ -309,6 +324,17 @@ impl Renderer {
                     },
                     count: None,
                 },
+                // binding 6: aux_pool (storage, read) — only read for the pick
+                wgpu::BindGroupLayoutEntry {
+                    binding: 6,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage { read_only: true },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                },
             ],
         });
 
@@ -338,6 +364,7 @@ impl Renderer {
             device,
             &scene_bgl,
             &voxel_pool_buffer,
+            &aux_pool_buffer,
             &material_color_buffer,
             &render_texture_view,
             &chunk_map_buffer,
@@ -560,6 +587,7 @@ impl Renderer {
             light_config_buffer,
             light_manager,
             voxel_pool_buffer,
+            aux_pool_buffer,
             material_color_buffer,
             chunk_map_buffer,
             octree_buffer,
@@ -578,6 +606,7 @@ impl Renderer {
             device,
             &self.scene_bgl,
             &self.voxel_pool_buffer,
+            &self.aux_pool_buffer,
             &self.material_color_buffer,
             &self.render_texture_view,
             &self.chunk_map_buffer,
@@ -715,13 +744,20 @@ impl Renderer {
         }
     }
 
-    /// Rebind the scene bind group to use an external voxel pool buffer (from the sim pipeline).
-    /// Called each frame (or when the pool buffer changes) to point at the sim's read pool.
-    pub fn update_voxel_pool(&mut self, device: &wgpu::Device, voxel_pool_buffer: &wgpu::Buffer) {
+    /// Rebind the scene bind group to use external voxel and aux pool buffers (from the sim
+    /// pipeline). Called each frame (or when the pool buffers change) to point at the sim's
+    /// read pools.
+    pub fn update_voxel_pool(
+        &mut self,
+        device: &wgpu::Device,
+        voxel_pool_buffer: &wgpu::Buffer,
+        aux_pool_buffer: &wgpu::Buffer,
+    ) {
         self.scene_bind_group = Self::create_scene_bind_group(
             device,
             &self.scene_bgl,
             voxel_pool_buffer,
+            aux_pool_buffer,
             &self.material_color_buffer,
             &self.render_texture_view,
             &self.chunk_map_buffer,
@@ -756,6 +792,7 @@ impl Renderer {
             device,
             &self.scene_bgl,
             &self.voxel_pool_buffer,
+            &self.aux_pool_buffer,
             &self.material_color_buffer,
             &self.render_texture_view,
             &self.chunk_map_buffer,
@@ -794,6 +831,7 @@ impl Renderer {
         device: &wgpu::Device,
         layout: &wgpu::BindGroupLayout,
         voxel_pool_buffer: &wgpu::Buffer,
+        aux_pool_buffer: &wgpu::Buffer,
         material_color_buffer: &wgpu::Buffer,
         texture_view: &wgpu::TextureView,
         chunk_map_buffer: &wgpu::Buffer,
@@ -828,6 +866,10 @@ impl Renderer {
                     binding: 5,
                     resource: pick_buffer.as_entire_binding(),
                 },
+                wgpu::BindGroupEntry {
+                    binding: 6,
+                    resource: aux_pool_buffer.as_entire_binding(),
+                },
             ],
         })
     }
@@ -20,17 +20,20 @@ fn main() {
     ])
     .expect("Failed to load materials");
 
-    let rules = load_all_rules(&[
-        include_str!("../../../data/rules/combustion.ron"),
-        include_str!("../../../data/rules/structural.ron"),
-        include_str!("../../../data/rules/phase_change.ron"),
-        include_str!("../../../data/rules/dissolution.ron"),
-        include_str!("../../../data/rules/displacement.ron"),
-        include_str!("../../../data/rules/biological.ron"),
-        include_str!("../../../data/rules/thermal.ron"),
-        include_str!("../../../data/rules/synthesis.ron"),
-        include_str!("../../../data/rules/electrical.ron"),
-    ])
+    let rules = load_all_rules(
+        &[
+            include_str!("../../../data/rules/combustion.ron"),
+            include_str!("../../../data/rules/structural.ron"),
+            include_str!("../../../data/rules/phase_change.ron"),
+            include_str!("../../../data/rules/dissolution.ron"),
+            include_str!("../../../data/rules/displacement.ron"),
+            include_str!("../../../data/rules/biological.ron"),
+            include_str!("../../../data/rules/thermal.ron"),
+            include_str!("../../../data/rules/synthesis.ron"),
+            include_str!("../../../data/rules/electrical.ron"),
+        ],
+        &table,
+    )
     .expect("Failed to load rules");
 
     // Build JSON data for materials
@@ -59,14 +62,16 @@ fn main() {
             rules_json.push(',');
         }
         rules_json.push_str(&format!(
-            r#"{{"name":"{}","ia":{},"ib":{},"oa":{},"ob":{},"prob":{:.2},"td":{},"mt":{},"xt":{},"pd":{}}}"#,
+            r#"{{"name":"{}","ia":{},"ib":{},"oa":{},"ob":{},"prob":{:.2},"td":{},"mt":{},"xt":{},"pd":{},"ox":{},"cat":{}}}"#,
             rule.name.replace('"', r#"\""#),
             rule.input_a, rule.input_b,
             rule.output_a, rule.output_b,
             rule.probability,
             rule.temp_delta,
-            rule.min_temp, rule.max_temp,
+            rule.min_temp.0, rule.max_temp.0,
             rule.pressure_delta,
+            rule.oxygen,
+            rule.catalyst.map_or("null".to_string(), |c| c.to_string()),
         ));
     }
     rules_json.push(']');
@@ -245,7 +250,9 @@ function showTip(el,key){{
     if(r.prob<1)html+=` (p=${{r.prob}})`;
     if(r.td)html+=` td=${{r.td}}`;
     if(r.pd)html+=` pd=${{r.pd}}`;
+    if(r.ox)html+=` O2=${{r.ox}}`;
     if(r.mt||r.xt)html+=` [${{r.mt}}-${{r.xt||'inf'}}K]`;
+    if(r.cat!==null){{const c=matById[r.cat];html+=` via ${{c?c.name:'?'}}`;}}
     html+='<br>';
   }});
   tip.innerHTML=html;
@@ -27,17 +27,20 @@ mod tests {
         ])
         .expect("all materials should load");
 
-        let rules = load_all_rules(&[
-            include_str!("../../../data/rules/combustion.ron"),
-            include_str!("../../../data/rules/structural.ron"),
-            include_str!("../../../data/rules/phase_change.ron"),
-            include_str!("../../../data/rules/dissolution.ron"),
-            include_str!("../../../data/rules/displacement.ron"),
-            include_str!("../../../data/rules/biological.ron"),
-            include_str!("../../../data/rules/thermal.ron"),
-            include_str!("../../../data/rules/synthesis.ron"),
-            include_str!("../../../data/rules/electrical.ron"),
-        ])
+        let rules = load_all_rules(
+            &[
+                include_str!("../../../data/rules/combustion.ron"),
+                include_str!("../../../data/rules/structural.ron"),
+                include_str!("../../../data/rules/phase_change.ron"),
+                include_str!("../../../data/rules/dissolution.ron"),
+                include_str!("../../../data/rules/displacement.ron"),
+                include_str!("../../../data/rules/biological.ron"),
+                include_str!("../../../data/rules/thermal.ron"),
+                include_str!("../../../data/rules/synthesis.ron"),
+                include_str!("../../../data/rules/electrical.ron"),
+            ],
+            &table,
+        )
         .expect("all rules should load");
 
         (table, rules)
@@ -99,6 +102,38 @@ mod tests {
         );
     }
 
+    /// Verify catalyst rules never consume their catalyst.
+    /// A catalyst that is also one of the inputs must come out unchanged, and
+    /// the shipped data must include at least one catalyzed rule.
+    #[test]
+    fn test_catalysts_not_consumed() {
+        let (_, rules) = load_all();
+
+        let mut catalyzed = 0;
+        let mut violations = Vec::new();
+        for rule in &rules.rules {
+            let Some(catalyst) = rule.catalyst else {
+                continue;
+            };
+            catalyzed += 1;
+            let consumed = (rule.input_a == catalyst && rule.output_a != catalyst)
+                || (rule.input_b == catalyst && rule.output_b != catalyst);
+            if consumed {
+                violations.push(format!(
+                    "Rule '{}': catalyst {} is consumed",
+                    rule.name, catalyst
+                ));
+            }
+        }
+
+        assert!(catalyzed > 0, "expected at least one catalyst rule");
+        assert!(
+            violations.is_empty(),
+            "Catalyst violations:\n{}",
+            violations.join("\n")
+        );
+    }
+
     /// Verify no exothermic reaction chain can exceed 8000K without consuming fuel.
     /// Each rule with temp_delta > 0 must transform at least one material,
     /// and the cumulative temp_delta of any single rule cannot exceed 8000.
@@ -215,6 +250,60 @@ mod tests {
         );
     }
 
+    /// Verify Fire needs air to burn anything flammable, and that every
+    /// oxygen-consuming rule burns something up. A rule that draws oxygen
+    /// without transforming either input would keep a sealed chamber spent
+    /// with nothing to show for it.
+    #[test]
+    fn test_combustion_consumes_oxygen() {
+        let (table, rules) = load_all();
+        let combustion = load_all_rules(
+            &[include_str!("../../../data/rules/combustion.ron")],
+            &table,
+        )
+        .expect("combustion rules should load");
+
+        // Fuels that burn without air by design:
+        // - Gunpowder (12) carries its own oxidizer
+        // - Vinegar (122) boils off rather than burning
+        let airless_fuels: HashSet<u16> = [12, 122].into_iter().collect();
+        let fire_id: u16 = 5;
+        let burns = |fuel: u16, becomes: u16| {
+            fuel != becomes
+                && !airless_fuels.contains(&fuel)
+                && table.get(fuel).is_some_and(|m| m.flammability > 0.0)
+        };
+
+        // Other files' Fire rules are heat effects (charring, melting)
+        let airless: Vec<&str> = combustion
+            .rules
+            .iter()
+            .filter(|r| {
+                (r.input_a == fire_id && burns(r.input_b, r.output_b))
+                    || (r.input_b == fire_id && burns(r.input_a, r.output_a))
+            })
+            .filter(|r| r.oxygen == 0)
+            .map(|r| r.name.as_str())
+            .collect();
+        assert!(
+            airless.is_empty(),
+            "Fire burns fuel without oxygen:\n{}",
+            airless.join("\n")
+        );
+
+        let idle: Vec<&str> = rules
+            .rules
+            .iter()
+            .filter(|r| r.oxygen > 0 && r.output_a == r.input_a && r.output_b == r.input_b)
+            .map(|r| r.name.as_str())
+            .collect();
+        assert!(
+            idle.is_empty(),
+            "Oxygen-consuming rules that transform nothing:\n{}",
+            idle.join("\n")
+        );
+    }
+
     /// Detect multi-step oscillation cycles: A→B→C→A where all rules have
     /// overlapping temperature ranges. Extends the validator's pairwise check.
     #[test]
@@ -225,8 +314,8 @@ mod tests {
         // Edge: (input_pair) -> (output_pair) with temp range
         struct Edge {
             output: (u16, u16),
-            min_temp: u32,
-            max_temp: u32,
+            min_temp: u16,
+            max_temp: u16,
         }
 
         let mut graph: HashMap<(u16, u16), Vec<Edge>> = HashMap::new();
@@ -245,15 +334,15 @@ mod tests {
                 rule.output_a.min(rule.output_b),
                 rule.output_a.max(rule.output_b),
             );
-            let max_temp = if rule.max_temp == 0 {
-                u32::MAX
+            let max_temp = if rule.max_temp.0 == 0 {
+                u16::MAX
             } else {
-                rule.max_temp
+                rule.max_temp.0
             };
 
             graph.entry(key_a).or_default().push(Edge {
                 output: out_a,
-                min_temp: rule.min_temp,
+                min_temp: rule.min_temp.0,
                 max_temp,
             });
         }
@@ -504,8 +593,14 @@ mod tests {
         let mod_rules_ron =
             include_str!("../../../data/mods/example-mod/rules/crystal_interactions.ron");
 
-        let mod_result = load_mod(mod_manifest_ron, &[mod_materials_ron], &[mod_rules_ron])
-            .expect("example mod should load");
+        let mod_result = load_mod(
+            mod_manifest_ron,
+            &[mod_materials_ron],
+            &[mod_rules_ron],
+            &table,
+            &[],
+        )
+        .expect("example mod should load");
 
         // Validate mod IDs are in range before remapping
         validator::validate_mod_materials(&mod_result.materials)
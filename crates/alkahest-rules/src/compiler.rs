@@ -1,11 +1,17 @@
+use std::collections::{BTreeMap, HashSet};
 use std::hash::{Hash, Hasher};
-
-use alkahest_core::constants::NO_RULE;
-use alkahest_core::material::MaterialTable;
-use alkahest_core::math::temp_to_quantized;
-use alkahest_core::rule::RuleSet;
+use std::ops::Range;
+
+use alkahest_core::constants::{
+    MATERIAL_PROPS_STRIDE, MAX_RULES_PER_PAIR, NO_CATALYST, NO_RULE, RULE_COUNT_BITS, TEMP_CURVE,
+    TEMP_QUANT_MAX_K, TEMP_QUANT_MAX_VALUE,
+};
+use alkahest_core::material::{MaterialDef, MaterialTable};
+use alkahest_core::rule::{InteractionRule, MaterialPattern, RuleSet};
 use wgpu::util::DeviceExt;
 
+use crate::defaults::get_category;
+
 /// Color + rendering data extracted from material definitions for the renderer.
 /// 32 bytes per entry, matching the GPU MaterialColor struct.
 #[repr(C)]
@@ -21,9 +27,11 @@ pub struct CompiledMaterialColor {
 
 /// Compiled GPU rule data ready for upload. Created once at init (C-PERF-2).
 pub struct GpuRuleData {
-    /// Material properties buffer: 64 bytes (4x vec4<f32>) per material.
+    /// Material properties buffer: 80 bytes (5x vec4<f32>) per material,
+    /// followed by the temperature LUT (see [`CompiledTables::temp_lut`]).
     pub material_props_buffer: wgpu::Buffer,
-    /// Flat 2D lookup: `rule_lookup[a * material_count + b]` = rule index or NO_RULE.
+    /// Flat 2D lookup: `rule_lookup[a * material_count + b]` = packed candidate
+    /// range (see [`rule_range`]) or NO_RULE.
     pub rule_lookup_buffer: wgpu::Buffer,
     /// Rule data buffer: 32 bytes (2x vec4<u32>) per packed rule entry.
     pub rule_data_buffer: wgpu::Buffer,
@@ -35,54 +43,147 @@ pub struct GpuRuleData {
     pub material_colors: Vec<CompiledMaterialColor>,
     /// Deterministic hash of the rule set for save/load compatibility checking.
     pub rule_hash: u64,
+    /// See [`CompiledTables::radiates`].
+    pub radiates: bool,
 }
 
-/// GPU material property layout: 4x vec4<f32> = 64 bytes per material.
+/// GPU material property layout: MATERIAL_PROPS_STRIDE (7) x vec4<f32> = 112 bytes per material.
 ///
 /// ```text
 /// vec4<f32>[0]: density, phase, flammability, ignition_temp_quantized
 /// vec4<f32>[1]: decay_rate, decay_threshold, decay_product_id, viscosity
 /// vec4<f32>[2]: thermal_conductivity, phase_change_temp_quantized, phase_change_product_id, structural_integrity
 /// vec4<f32>[3]: electrical_conductivity, electrical_resistance, activation_threshold, charge_emission
+/// vec4<f32>[4]: inv_heat_capacity, latent_heat_steps, freeze_temp_quantized, freeze_product_id
+/// vec4<f32>[5]: radiative_emissivity, blast_resistance, repose_slope, cohesion
+/// vec4<f32>[6]: erodibility, compressive_strength, tensile_strength, (reserved)
 /// ```
 #[repr(C)]
-#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
-struct GpuMaterialProps {
-    density: f32,
-    phase: f32,
-    flammability: f32,
-    ignition_temp_quantized: f32,
-    decay_rate: f32,
-    decay_threshold: f32,
-    decay_product_id: f32,
-    viscosity: f32,
-    thermal_conductivity: f32,
-    phase_change_temp_quantized: f32,
-    phase_change_product_id: f32,
-    structural_integrity: f32,
-    electrical_conductivity: f32,
-    electrical_resistance: f32,
-    activation_threshold: f32,
-    charge_emission: f32,
+#[derive(Clone, Copy, Debug, Default, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct GpuMaterialProps {
+    pub density: f32,
+    pub phase: f32,
+    pub flammability: f32,
+    pub ignition_temp_quantized: f32,
+    pub decay_rate: f32,
+    pub decay_threshold: f32,
+    pub decay_product_id: f32,
+    pub viscosity: f32,
+    pub thermal_conductivity: f32,
+    pub phase_change_temp_quantized: f32,
+    pub phase_change_product_id: f32,
+    pub structural_integrity: f32,
+    pub electrical_conductivity: f32,
+    pub electrical_resistance: f32,
+    pub activation_threshold: f32,
+    pub charge_emission: f32,
+    /// 1 / heat_capacity; 0 for IDs with no material, which never change.
+    pub inv_heat_capacity: f32,
+    /// Latent heat in linear-scale temperature steps.
+    pub latent_heat_steps: f32,
+    pub freeze_temp_quantized: f32,
+    pub freeze_product_id: f32,
+    pub radiative_emissivity: f32,
+    pub blast_resistance: f32,
+    /// tan(repose_angle), clamped (see `MaterialDef::repose_slope`).
+    pub repose_slope: f32,
+    pub cohesion: f32,
+    pub erodibility: f32,
+    /// See `MaterialDef::compression_limit`; read by the CPU structural solver.
+    pub compressive_strength: f32,
+    /// See `MaterialDef::tension_limit`; read by the CPU structural solver.
+    pub tensile_strength: f32,
+    pub _reserved: [f32; 1],
 }
 
+const _: () = assert!(
+    std::mem::size_of::<GpuMaterialProps>() == MATERIAL_PROPS_STRIDE as usize * 16,
+    "GpuMaterialProps must match MATERIAL_PROPS_STRIDE"
+);
+
 /// GPU rule data layout: 2x vec4<u32> = 32 bytes per rule entry.
 ///
 /// ```text
-/// vec4<u32>[0]: input_a_becomes, pressure_delta (bitcast i32), min_charge, probability_u32
-/// vec4<u32>[1]: temp_delta_i32, max_charge, min_temp, max_temp
+/// vec4<u32>[0]: input_a_becomes | catalyst << 16, pressure_delta (bitcast i32), min_charge | oxygen << 16, probability_u32
+/// vec4<u32>[1]: temp_delta_i32, max_charge | blast << 16, min_temp | min_pressure << 16, max_temp | max_pressure << 16
 /// ```
 #[repr(C)]
-#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
-struct GpuRuleEntry {
-    input_a_becomes: u32,
-    pressure_delta: i32,
-    min_charge: u32,
-    probability_u32: u32,
-    temp_delta: i32,
-    max_charge: u32,
-    min_temp: u32,
-    max_temp: u32,
+#[derive(Clone, Copy, Debug, Default, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct GpuRuleEntry {
+    pub input_a_becomes: u16,
+    /// Material required in the reacting voxel's 6-neighborhood, or NO_CATALYST.
+    pub catalyst: u16,
+    pub pressure_delta: i32,
+    pub min_charge: u16,
+    /// Oxygen burned per firing; the rule needs that much in reach (0 = none).
+    pub oxygen: u16,
+    pub probability_u32: u32,
+    pub temp_delta: i32,
+    pub max_charge: u16,
+    /// Strength of the blast front set off at the reacting voxel (0 = none).
+    pub blast: u16,
+    pub min_temp: u16,
+    pub min_pressure: u16,
+    pub max_temp: u16,
+    pub max_pressure: u16,
+}
+
+/// CPU-side compiled tables, identical to the contents of the GPU buffers.
+///
+/// `compile()` uploads these verbatim; the CPU reference simulator in
+/// alkahest-sim reads them directly so both paths share one encoding.
+pub struct CompiledTables {
+    /// Per-material properties, indexed by material ID.
+    pub material_props: Vec<GpuMaterialProps>,
+    /// Flat 2D lookup: `rule_lookup[a * material_count + b]` = packed candidate
+    /// range (see [`rule_range`]) or NO_RULE.
+    pub rule_lookup: Vec<u32>,
+    /// Packed rule entries (each bidirectional rule creates 2).
+    pub rule_entries: Vec<GpuRuleEntry>,
+    /// Number of materials (max ID + 1).
+    pub material_count: u32,
+    /// Material colors extracted from material definitions for the renderer.
+    pub material_colors: Vec<CompiledMaterialColor>,
+    /// Deterministic hash of the rule set for save/load compatibility checking.
+    pub rule_hash: u64,
+    /// Linear-scale value of every quantized temperature on `TEMP_CURVE`
+    /// (see `TempCurve::lut`). Uploaded after the material properties, so
+    /// entry `q` is `materials[material_count * MATERIAL_PROPS_STRIDE + q / 4][q % 4]`
+    /// in WGSL;
+    /// the reaction pass has no storage binding to spare for it.
+    pub temp_lut: Vec<f32>,
+}
+
+impl CompiledTables {
+    /// Whether any material has a nonzero radiative emissivity. Without one
+    /// the thermal pass skips radiation entirely.
+    pub fn radiates(&self) -> bool {
+        self.material_props
+            .iter()
+            .any(|props| props.radiative_emissivity > 0.0)
+    }
+}
+
+/// Decode a rule lookup word into the range of the pair's candidate entries.
+///
+/// A word packs `(first_entry << RULE_COUNT_BITS) | count`; candidates for
+/// one ordered pair are contiguous in `rule_entries`, in rule order.
+/// `NO_RULE` decodes to an empty range.
+pub fn rule_range(word: u32) -> Range<usize> {
+    if word == NO_RULE {
+        return 0..0;
+    }
+    let first = (word >> RULE_COUNT_BITS) as usize;
+    let count = (word & MAX_RULES_PER_PAIR) as usize;
+    first..first + count
+}
+
+/// Narrow a pressure window bound, minimum charge or oxygen cost to the 16
+/// bits it gets in the GPU entry. Pressures (6-bit), charges and oxygen
+/// (8-bit) always fit; anything larger saturates, which keeps its meaning as
+/// an unreachable minimum or an unbounded maximum.
+fn window_bound(value: u32) -> u16 {
+    value.min(u16::MAX as u32) as u16
 }
 
 /// Compute a deterministic hash from material definitions and interaction rules.
@@ -101,14 +202,25 @@ pub fn compute_rule_hash(materials: &MaterialTable, rules: &RuleSet) -> u64 {
         (mat.phase as u8).hash(&mut hasher);
         mat.density.to_bits().hash(&mut hasher);
         mat.flammability.to_bits().hash(&mut hasher);
-        mat.ignition_temp.to_bits().hash(&mut hasher);
+        mat.ignition_temp.0.to_bits().hash(&mut hasher);
         mat.decay_rate.hash(&mut hasher);
-        mat.decay_threshold.hash(&mut hasher);
+        u32::from(mat.decay_threshold.0).hash(&mut hasher);
         mat.decay_product.hash(&mut hasher);
         mat.viscosity.to_bits().hash(&mut hasher);
         mat.thermal_conductivity.to_bits().hash(&mut hasher);
-        mat.phase_change_temp.to_bits().hash(&mut hasher);
+        mat.heat_capacity.to_bits().hash(&mut hasher);
+        mat.radiative_emissivity.to_bits().hash(&mut hasher);
+        mat.blast_limit().to_bits().hash(&mut hasher);
+        mat.compression_limit().to_bits().hash(&mut hasher);
+        mat.tension_limit().to_bits().hash(&mut hasher);
+        mat.repose_slope().to_bits().hash(&mut hasher);
+        mat.cohesion.to_bits().hash(&mut hasher);
+        mat.erodibility.to_bits().hash(&mut hasher);
+        mat.phase_change_temp.0.to_bits().hash(&mut hasher);
         mat.phase_change_product.hash(&mut hasher);
+        mat.latent_heat.to_bits().hash(&mut hasher);
+        mat.freeze_temp.0.to_bits().hash(&mut hasher);
+        mat.freeze_product.hash(&mut hasher);
         mat.structural_integrity.to_bits().hash(&mut hasher);
         mat.electrical_conductivity.to_bits().hash(&mut hasher);
         mat.electrical_resistance.to_bits().hash(&mut hasher);
@@ -127,42 +239,117 @@ pub fn compute_rule_hash(materials: &MaterialTable, rules: &RuleSet) -> u64 {
         rule.output_b.hash(&mut hasher);
         rule.probability.to_bits().hash(&mut hasher);
         rule.temp_delta.hash(&mut hasher);
-        rule.min_temp.hash(&mut hasher);
-        rule.max_temp.hash(&mut hasher);
+        u32::from(rule.min_temp.0).hash(&mut hasher);
+        u32::from(rule.max_temp.0).hash(&mut hasher);
         rule.pressure_delta.hash(&mut hasher);
         rule.min_charge.hash(&mut hasher);
         rule.max_charge.hash(&mut hasher);
+        rule.min_pressure.hash(&mut hasher);
+        rule.max_pressure.hash(&mut hasher);
+        rule.catalyst.hash(&mut hasher);
+        rule.oxygen.hash(&mut hasher);
+        rule.blast.hash(&mut hasher);
     }
 
     hasher.finish()
 }
 
-/// Compile material and rule data into GPU buffers.
-pub fn compile(device: &wgpu::Device, materials: &MaterialTable, rules: &RuleSet) -> GpuRuleData {
-    let material_count = (materials.max_id() as u32) + 1;
+/// Number of concrete rules a pattern rule expanded into.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PatternExpansion {
+    /// Name of the pattern rule.
+    pub rule: String,
+    /// Concrete rules generated.
+    pub generated: usize,
+    /// Matching pairs skipped because an explicit rule or an earlier
+    /// pattern rule already covers them.
+    pub shadowed: usize,
+}
 
-    // Build material properties buffer
-    let mut props = vec![
-        GpuMaterialProps {
-            density: 0.0,
-            phase: 0.0,
-            flammability: 0.0,
-            ignition_temp_quantized: 0.0,
-            decay_rate: 0.0,
-            decay_threshold: 0.0,
-            decay_product_id: 0.0,
-            viscosity: 0.0,
-            thermal_conductivity: 0.0,
-            phase_change_temp_quantized: 0.0,
-            phase_change_product_id: 0.0,
-            structural_integrity: 0.0,
-            electrical_conductivity: 0.0,
-            electrical_resistance: 0.0,
-            activation_threshold: 0.0,
-            charge_emission: 0.0,
+/// Whether a material matches a rule input pattern.
+pub fn pattern_matches(pattern: &MaterialPattern, mat: &MaterialDef) -> bool {
+    match pattern {
+        MaterialPattern::FlammabilityAbove(threshold) => mat.flammability > *threshold,
+        MaterialPattern::Category(category) => get_category(mat.id).eq_ignore_ascii_case(category),
+        MaterialPattern::Phase(phase) => mat.phase == *phase,
+    }
+}
+
+/// Expand pattern rules into concrete per-pair rules.
+///
+/// Explicit rules are kept first and in order. Each pattern rule then
+/// contributes one rule per matching (a, b) pair not already covered by an
+/// explicit rule or an earlier pattern, so explicit pairs always take
+/// precedence regardless of file order. Patterns never match Air (id 0): a
+/// `Phase(Gas)` or `Category("Naturals")` pattern would otherwise react with
+/// every empty neighbor. A rule that means Air names it as an explicit input.
+pub fn expand_patterns(
+    materials: &MaterialTable,
+    rules: &RuleSet,
+) -> (RuleSet, Vec<PatternExpansion>) {
+    let (patterns, explicit): (Vec<_>, Vec<_>) = rules.rules.iter().partition(|r| r.is_pattern());
+
+    let mut covered: HashSet<(u16, u16)> = explicit
+        .iter()
+        .map(|r| (r.input_a.min(r.input_b), r.input_a.max(r.input_b)))
+        .collect();
+    let mut expanded: Vec<InteractionRule> = explicit.into_iter().cloned().collect();
+    let mut expansions = Vec::with_capacity(patterns.len());
+
+    let candidates = |pattern: &Option<MaterialPattern>, id: u16| -> Vec<u16> {
+        match pattern {
+            Some(pattern) => {
+                let mut ids: Vec<u16> = materials
+                    .materials
+                    .iter()
+                    .filter(|m| m.id != 0 && pattern_matches(pattern, m))
+                    .map(|m| m.id)
+                    .collect();
+                ids.sort_unstable();
+                ids
+            }
+            None => vec![id],
+        }
+    };
+
+    for rule in patterns {
+        let mut expansion = PatternExpansion {
+            rule: rule.name.clone(),
+            generated: 0,
+            shadowed: 0,
         };
-        material_count as usize
-    ];
+        let ids_b = candidates(&rule.pattern_b, rule.input_b);
+        for a in candidates(&rule.pattern_a, rule.input_a) {
+            for &b in &ids_b {
+                if !covered.insert((a.min(b), a.max(b))) {
+                    expansion.shadowed += 1;
+                    continue;
+                }
+                expanded.push(InteractionRule {
+                    input_a: a,
+                    input_b: b,
+                    pattern_a: None,
+                    pattern_b: None,
+                    ..rule.clone()
+                });
+                expansion.generated += 1;
+            }
+        }
+        expansions.push(expansion);
+    }
+
+    (RuleSet { rules: expanded }, expansions)
+}
+
+/// Build the material property, rule lookup, and rule data tables without a GPU device.
+///
+/// Pattern rules are expanded first (see [`expand_patterns`]).
+pub fn compile_tables(materials: &MaterialTable, rules: &RuleSet) -> CompiledTables {
+    let (expanded, _) = expand_patterns(materials, rules);
+    let material_count = (materials.max_id() as u32) + 1;
+
+    // Build material properties table
+    let mut props = vec![GpuMaterialProps::default(); material_count as usize];
 
     for mat in &materials.materials {
         let idx = mat.id as usize;
@@ -171,35 +358,42 @@ pub fn compile(device: &wgpu::Device, materials: &MaterialTable, rules: &RuleSet
                 density: mat.density,
                 phase: mat.phase.as_f32(),
                 flammability: mat.flammability,
-                ignition_temp_quantized: temp_to_quantized(mat.ignition_temp) as f32,
+                ignition_temp_quantized: mat.ignition_temp.quantize().0 as f32,
                 decay_rate: mat.decay_rate as f32,
-                decay_threshold: mat.decay_threshold as f32,
+                decay_threshold: mat.decay_threshold.0 as f32,
                 decay_product_id: mat.decay_product as f32,
                 viscosity: mat.viscosity,
                 thermal_conductivity: mat.thermal_conductivity,
-                phase_change_temp_quantized: temp_to_quantized(mat.phase_change_temp) as f32,
+                phase_change_temp_quantized: mat.phase_change_temp.quantize().0 as f32,
                 phase_change_product_id: mat.phase_change_product as f32,
                 structural_integrity: mat.structural_integrity,
                 electrical_conductivity: mat.electrical_conductivity,
                 electrical_resistance: mat.electrical_resistance,
                 activation_threshold: mat.activation_threshold as f32,
                 charge_emission: mat.charge_emission as f32,
+                inv_heat_capacity: 1.0 / mat.heat_capacity,
+                latent_heat_steps: mat.latent_heat * TEMP_QUANT_MAX_VALUE as f32 / TEMP_QUANT_MAX_K,
+                freeze_temp_quantized: mat.freeze_temp.quantize().0 as f32,
+                freeze_product_id: mat.freeze_product as f32,
+                radiative_emissivity: mat.radiative_emissivity,
+                blast_resistance: mat.blast_limit(),
+                repose_slope: mat.repose_slope(),
+                cohesion: mat.cohesion,
+                erodibility: mat.erodibility,
+                compressive_strength: mat.compression_limit(),
+                tensile_strength: mat.tension_limit(),
+                _reserved: [0.0; 1],
             };
         }
     }
 
-    let material_props_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-        label: Some("material-props-compiled"),
-        contents: bytemuck::cast_slice(&props),
-        usage: wgpu::BufferUsages::STORAGE,
-    });
-
-    // Build rule lookup and rule data buffers
-    // Each bidirectional rule creates 2 GPU entries
-    let mut rule_entries: Vec<GpuRuleEntry> = Vec::new();
-    let mut lookup = vec![NO_RULE; (material_count * material_count) as usize];
+    // Build rule lookup and rule data tables.
+    // Each bidirectional rule creates 2 GPU entries, one per ordered pair.
+    // Entries are grouped by ordered pair so each pair's candidates are
+    // contiguous, keeping rule order within the pair.
+    let mut candidates: BTreeMap<(u32, u32), Vec<GpuRuleEntry>> = BTreeMap::new();
 
-    for rule in &rules.rules {
+    for rule in &expanded.rules {
         let a = rule.input_a as u32;
         let b = rule.input_b as u32;
 
@@ -209,72 +403,63 @@ pub fn compile(device: &wgpu::Device, materials: &MaterialTable, rules: &RuleSet
 
         // Convert probability to u32 (0.0-1.0 -> 0-u32::MAX)
         let probability_u32 = (rule.probability.clamp(0.0, 1.0) * u32::MAX as f32) as u32;
+        let catalyst = rule.catalyst.unwrap_or(NO_CATALYST as u16);
 
         // Entry from A's perspective: A sees neighbor B
         // A becomes output_a
-        let entry_a_idx = rule_entries.len() as u32;
-        rule_entries.push(GpuRuleEntry {
-            input_a_becomes: rule.output_a as u32,
+        candidates.entry((a, b)).or_default().push(GpuRuleEntry {
+            input_a_becomes: rule.output_a,
+            catalyst,
             pressure_delta: rule.pressure_delta,
-            min_charge: rule.min_charge,
+            min_charge: window_bound(rule.min_charge),
+            oxygen: window_bound(rule.oxygen),
             probability_u32,
             temp_delta: rule.temp_delta,
-            max_charge: rule.max_charge,
-            min_temp: rule.min_temp,
-            max_temp: rule.max_temp,
+            max_charge: window_bound(rule.max_charge),
+            blast: window_bound(rule.blast),
+            min_temp: rule.min_temp.0,
+            min_pressure: window_bound(rule.min_pressure),
+            max_temp: rule.max_temp.0,
+            max_pressure: window_bound(rule.max_pressure),
         });
 
         // Entry from B's perspective: B sees neighbor A
-        // B becomes output_b
-        let entry_b_idx = rule_entries.len() as u32;
-        rule_entries.push(GpuRuleEntry {
-            input_a_becomes: rule.output_b as u32,
-            pressure_delta: rule.pressure_delta,
-            min_charge: rule.min_charge,
-            probability_u32,
-            temp_delta: rule.temp_delta,
-            max_charge: rule.max_charge,
-            min_temp: rule.min_temp,
-            max_temp: rule.max_temp,
-        });
-
-        // Store in lookup table (first rule wins if duplicate)
-        let idx_ab = (a * material_count + b) as usize;
-        let idx_ba = (b * material_count + a) as usize;
-        if lookup[idx_ab] == NO_RULE {
-            lookup[idx_ab] = entry_a_idx;
-        }
-        if lookup[idx_ba] == NO_RULE {
-            lookup[idx_ba] = entry_b_idx;
+        // B becomes output_b. A self-pair rule only applies as input_a.
+        if a != b {
+            candidates.entry((b, a)).or_default().push(GpuRuleEntry {
+                input_a_becomes: rule.output_b,
+                catalyst,
+                pressure_delta: rule.pressure_delta,
+                min_charge: window_bound(rule.min_charge),
+                oxygen: window_bound(rule.oxygen),
+                probability_u32,
+                temp_delta: rule.temp_delta,
+                max_charge: window_bound(rule.max_charge),
+                blast: window_bound(rule.blast),
+                min_temp: rule.min_temp.0,
+                min_pressure: window_bound(rule.min_pressure),
+                max_temp: rule.max_temp.0,
+                max_pressure: window_bound(rule.max_pressure),
+            });
         }
     }
 
+    // Lay out each pair's candidates contiguously and pack the lookup word.
+    // Candidates beyond MAX_RULES_PER_PAIR are dropped (the validator reports them).
+    let mut rule_entries: Vec<GpuRuleEntry> = Vec::new();
+    let mut lookup = vec![NO_RULE; (material_count * material_count) as usize];
+    for ((a, b), entries) in candidates {
+        let count = entries.len().min(MAX_RULES_PER_PAIR as usize);
+        lookup[(a * material_count + b) as usize] =
+            ((rule_entries.len() as u32) << RULE_COUNT_BITS) | count as u32;
+        rule_entries.extend_from_slice(&entries[..count]);
+    }
+
     // Ensure at least one rule entry exists (GPU buffer can't be empty)
     if rule_entries.is_empty() {
-        rule_entries.push(GpuRuleEntry {
-            input_a_becomes: 0,
-            pressure_delta: 0,
-            min_charge: 0,
-            probability_u32: 0,
-            temp_delta: 0,
-            max_charge: 0,
-            min_temp: 0,
-            max_temp: 0,
-        });
+        rule_entries.push(GpuRuleEntry::default());
     }
 
-    let rule_lookup_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-        label: Some("rule-lookup"),
-        contents: bytemuck::cast_slice(&lookup),
-        usage: wgpu::BufferUsages::STORAGE,
-    });
-
-    let rule_data_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
-        label: Some("rule-data"),
-        contents: bytemuck::cast_slice(&rule_entries),
-        usage: wgpu::BufferUsages::STORAGE,
-    });
-
     // Build material colors for the renderer (32 bytes per entry)
     let mut material_colors = vec![
         CompiledMaterialColor {
@@ -309,16 +494,51 @@ pub fn compile(device: &wgpu::Device, materials: &MaterialTable, rules: &RuleSet
         }
     }
 
-    let rule_hash = compute_rule_hash(materials, rules);
+    CompiledTables {
+        material_props: props,
+        rule_lookup: lookup,
+        rule_entries,
+        material_count,
+        material_colors,
+        rule_hash: compute_rule_hash(materials, &expanded),
+        temp_lut: TEMP_CURVE.lut(),
+    }
+}
+
+/// Compile material and rule data into GPU buffers.
+pub fn compile(device: &wgpu::Device, materials: &MaterialTable, rules: &RuleSet) -> GpuRuleData {
+    let tables = compile_tables(materials, rules);
+    let radiates = tables.radiates();
+
+    let mut material_props_bytes: Vec<u8> = bytemuck::cast_slice(&tables.material_props).to_vec();
+    material_props_bytes.extend_from_slice(bytemuck::cast_slice(&tables.temp_lut));
+    let material_props_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some("material-props-compiled"),
+        contents: &material_props_bytes,
+        usage: wgpu::BufferUsages::STORAGE,
+    });
+
+    let rule_lookup_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some("rule-lookup"),
+        contents: bytemuck::cast_slice(&tables.rule_lookup),
+        usage: wgpu::BufferUsages::STORAGE,
+    });
+
+    let rule_data_buffer = device.create_buffer_init(&wgpu::util::BufferInitDescriptor {
+        label: Some("rule-data"),
+        contents: bytemuck::cast_slice(&tables.rule_entries),
+        usage: wgpu::BufferUsages::STORAGE,
+    });
 
     GpuRuleData {
         material_props_buffer,
         rule_lookup_buffer,
         rule_data_buffer,
-        material_count,
-        rule_count: rule_entries.len() as u32,
-        material_colors,
-        rule_hash,
+        material_count: tables.material_count,
+        rule_count: tables.rule_entries.len() as u32,
+        material_colors: tables.material_colors,
+        rule_hash: tables.rule_hash,
+        radiates,
     }
 }
 
@@ -326,7 +546,8 @@ pub fn compile(device: &wgpu::Device, materials: &MaterialTable, rules: &RuleSet
 mod tests {
     use super::*;
     use alkahest_core::material::{MaterialDef, MaterialTable, Phase};
-    use alkahest_core::rule::{InteractionRule, RuleSet};
+    use alkahest_core::rule::UNSET_MATERIAL;
+    use alkahest_core::types::{Kelvin, QuantizedTemp};
 
     fn test_materials() -> MaterialTable {
         MaterialTable {
@@ -339,15 +560,26 @@ mod tests {
                     color: (0.0, 0.0, 0.0),
                     emission: 0.0,
                     flammability: 0.0,
-                    ignition_temp: 0.0,
+                    ignition_temp: Kelvin(0.0),
                     decay_rate: 0,
-                    decay_threshold: 0,
+                    decay_threshold: QuantizedTemp(0),
                     decay_product: 0,
                     viscosity: 0.0,
+                    repose_angle: 45.0,
+                    cohesion: 0.0,
+                    erodibility: 0.0,
                     thermal_conductivity: 0.0,
-                    phase_change_temp: 0.0,
+                    heat_capacity: 1.0,
+                    radiative_emissivity: 0.0,
+                    phase_change_temp: Kelvin(0.0),
                     phase_change_product: 0,
+                    latent_heat: 0.0,
+                    freeze_temp: Kelvin(0.0),
+                    freeze_product: 0,
                     structural_integrity: 0.0,
+                    compressive_strength: None,
+                    tensile_strength: None,
+                    blast_resistance: None,
                     opacity: None,
                     absorption_rate: 0.0,
                     electrical_conductivity: 0.0,
@@ -363,15 +595,26 @@ mod tests {
                     color: (0.5, 0.5, 0.5),
                     emission: 0.0,
                     flammability: 0.0,
-                    ignition_temp: 0.0,
+                    ignition_temp: Kelvin(0.0),
                     decay_rate: 0,
-                    decay_threshold: 0,
+                    decay_threshold: QuantizedTemp(0),
                     decay_product: 0,
                     viscosity: 0.0,
+                    repose_angle: 45.0,
+                    cohesion: 0.0,
+                    erodibility: 0.0,
                     thermal_conductivity: 0.5,
-                    phase_change_temp: 0.0,
+                    heat_capacity: 1.0,
+                    radiative_emissivity: 0.0,
+                    phase_change_temp: Kelvin(0.0),
                     phase_change_product: 0,
+                    latent_heat: 0.0,
+                    freeze_temp: Kelvin(0.0),
+                    freeze_product: 0,
                     structural_integrity: 63.0,
+                    compressive_strength: None,
+                    tensile_strength: None,
+                    blast_resistance: None,
                     opacity: None,
                     absorption_rate: 0.0,
                     electrical_conductivity: 0.0,
@@ -393,15 +636,189 @@ mod tests {
                 output_b: 1,
                 probability: 0.5,
                 temp_delta: 0,
-                min_temp: 0,
-                max_temp: 0,
+                min_temp: QuantizedTemp(0),
+                max_temp: QuantizedTemp(0),
                 pressure_delta: 0,
                 min_charge: 0,
                 max_charge: 0,
+                min_pressure: 0,
+                max_pressure: 0,
+                catalyst: None,
+                oxygen: 0,
+                blast: 0,
+                pattern_a: None,
+                pattern_b: None,
             }],
         }
     }
 
+    /// Air, Stone, plus a flammable liquid (Oil, organics) and a flammable
+    /// solid (Wood, organics) and a non-flammable liquid (Water, naturals).
+    fn pattern_materials() -> MaterialTable {
+        let mut table = test_materials();
+        let base = table.materials[1].clone();
+        for (id, name, phase, flammability) in [
+            (20, "Water", Phase::Liquid, 0.0),
+            (90, "Oil", Phase::Liquid, 0.9),
+            (91, "Wood", Phase::Solid, 0.6),
+        ] {
+            table.materials.push(MaterialDef {
+                id,
+                name: name.into(),
+                phase,
+                flammability,
+                ..base.clone()
+            });
+        }
+        table
+    }
+
+    fn pattern_rule(name: &str, pattern_b: MaterialPattern) -> InteractionRule {
+        InteractionRule {
+            name: name.into(),
+            input_b: UNSET_MATERIAL,
+            pattern_b: Some(pattern_b),
+            ..test_rules().rules[0].clone()
+        }
+    }
+
+    fn pairs(rules: &RuleSet) -> Vec<(u16, u16)> {
+        rules.rules.iter().map(|r| (r.input_a, r.input_b)).collect()
+    }
+
+    #[test]
+    fn test_expand_phase_pattern() {
+        let rules = RuleSet {
+            rules: vec![pattern_rule(
+                "any liquid",
+                MaterialPattern::Phase(Phase::Liquid),
+            )],
+        };
+        let (expanded, report) = expand_patterns(&pattern_materials(), &rules);
+        assert_eq!(pairs(&expanded), vec![(0, 20), (0, 90)]);
+        assert!(expanded.rules.iter().all(|r| !r.is_pattern()));
+        assert_eq!(report[0].generated, 2);
+        assert_eq!(report[0].shadowed, 0);
+    }
+
+    #[test]
+    fn test_gas_pattern_skips_air() {
+        let mut materials = pattern_materials();
+        let steam = MaterialDef {
+            id: 7,
+            name: "Steam".into(),
+            phase: Phase::Gas,
+            ..materials.materials[1].clone()
+        };
+        materials.materials.push(steam);
+        let rules = RuleSet {
+            rules: vec![InteractionRule {
+                input_a: 20,
+                ..pattern_rule("water and any gas", MaterialPattern::Phase(Phase::Gas))
+            }],
+        };
+        let (expanded, report) = expand_patterns(&materials, &rules);
+        assert_eq!(pairs(&expanded), vec![(20, 7)]);
+        assert_eq!(report[0].generated, 1);
+
+        // Air is still reachable as an explicit input
+        let explicit_air = RuleSet {
+            rules: vec![InteractionRule {
+                input_a: 0,
+                ..pattern_rule("air and any liquid", MaterialPattern::Phase(Phase::Liquid))
+            }],
+        };
+        assert_eq!(
+            pairs(&expand_patterns(&materials, &explicit_air).0),
+            vec![(0, 20), (0, 90)]
+        );
+    }
+
+    #[test]
+    fn test_expand_category_and_flammability_patterns() {
+        let materials = pattern_materials();
+        let category = RuleSet {
+            rules: vec![pattern_rule(
+                "organics",
+                MaterialPattern::Category("organics".into()),
+            )],
+        };
+        assert_eq!(
+            pairs(&expand_patterns(&materials, &category).0),
+            vec![(0, 90), (0, 91)]
+        );
+
+        let flammable = RuleSet {
+            rules: vec![pattern_rule(
+                "flammable",
+                MaterialPattern::FlammabilityAbove(0.7),
+            )],
+        };
+        assert_eq!(
+            pairs(&expand_patterns(&materials, &flammable).0),
+            vec![(0, 90)]
+        );
+    }
+
+    #[test]
+    fn test_explicit_pair_beats_pattern() {
+        let mut explicit = test_rules().rules[0].clone();
+        explicit.input_b = 90;
+        explicit.output_b = 1;
+        // The pattern comes first in file order but must not win.
+        let rules = RuleSet {
+            rules: vec![
+                pattern_rule("any liquid", MaterialPattern::Phase(Phase::Liquid)),
+                explicit,
+            ],
+        };
+        let materials = pattern_materials();
+        let (expanded, report) = expand_patterns(&materials, &rules);
+        assert_eq!(pairs(&expanded), vec![(0, 90), (0, 20)]);
+        assert_eq!(report[0].generated, 1);
+        assert_eq!(report[0].shadowed, 1);
+
+        let tables = compile_tables(&materials, &rules);
+        let range = rule_range(tables.rule_lookup[90 * tables.material_count as usize]);
+        assert_eq!(range.len(), 1);
+        assert_eq!(tables.rule_entries[range.start].input_a_becomes, 1);
+    }
+
+    #[test]
+    fn test_pair_candidates_packed_in_rule_order() {
+        let first = test_rules().rules[0].clone();
+        let second = InteractionRule {
+            name: "second".into(),
+            output_a: 1,
+            min_temp: QuantizedTemp(500),
+            ..first.clone()
+        };
+        let rules = RuleSet {
+            rules: vec![first, second],
+        };
+        let tables = compile_tables(&test_materials(), &rules);
+        let n = tables.material_count as usize;
+
+        let ab = rule_range(tables.rule_lookup[1]);
+        assert_eq!(ab.len(), 2);
+        let outputs: Vec<u16> = tables.rule_entries[ab]
+            .iter()
+            .map(|e| e.input_a_becomes)
+            .collect();
+        assert_eq!(outputs, vec![0, 1]);
+
+        // B's perspective gets its own contiguous run, also in rule order.
+        let ba = rule_range(tables.rule_lookup[n]);
+        assert_eq!(ba.len(), 2);
+        let windows: Vec<(u16, u16)> = tables.rule_entries[ba]
+            .iter()
+            .map(|e| (e.input_a_becomes, e.min_temp))
+            .collect();
+        assert_eq!(windows[0].0, 1);
+        assert!(windows[0].1 < windows[1].1);
+        assert!(rule_range(NO_RULE).is_empty());
+    }
+
     #[test]
     fn test_rule_hash_deterministic() {
         let materials = test_materials();
@@ -456,4 +873,64 @@ mod tests {
             "changing a rule field should produce a different hash"
         );
     }
+
+    #[test]
+    fn test_rule_hash_differs_on_pressure_window() {
+        let materials = test_materials();
+        let mut rules = test_rules();
+        rules.rules[0].min_pressure = 20;
+        assert_ne!(
+            compute_rule_hash(&materials, &test_rules()),
+            compute_rule_hash(&materials, &rules)
+        );
+    }
+
+    #[test]
+    fn test_radiates_only_with_an_emissive_material() {
+        let mut materials = test_materials();
+        assert!(!compile_tables(&materials, &test_rules()).radiates());
+        materials.materials[1].radiative_emissivity = 0.5;
+        assert!(compile_tables(&materials, &test_rules()).radiates());
+    }
+
+    #[test]
+    fn test_pressure_window_packed_with_temp() {
+        let mut rules = test_rules();
+        rules.rules[0].min_temp = QuantizedTemp(300);
+        rules.rules[0].max_temp = QuantizedTemp(900);
+        rules.rules[0].min_pressure = 20;
+        rules.rules[0].max_pressure = 45;
+        let tables = compile_tables(&test_materials(), &rules);
+        let words: &[u32] = bytemuck::cast_slice(&tables.rule_entries[..1]);
+        assert_eq!(words[6], 300 | (20 << 16));
+        assert_eq!(words[7], 900 | (45 << 16));
+    }
+
+    #[test]
+    fn test_catalyst_packed_with_output() {
+        let mut rules = test_rules();
+        rules.rules[0].catalyst = Some(1);
+        let catalyzed = compile_tables(&test_materials(), &rules);
+        let words: &[u32] = bytemuck::cast_slice(&catalyzed.rule_entries[..1]);
+        assert_eq!(words[0], 1 << 16);
+
+        let plain = compile_tables(&test_materials(), &test_rules());
+        let words: &[u32] = bytemuck::cast_slice(&plain.rule_entries[..1]);
+        assert_eq!(words[0], NO_CATALYST << 16);
+        assert_ne!(catalyzed.rule_hash, plain.rule_hash);
+    }
+
+    #[test]
+    fn test_blast_packed_with_max_charge() {
+        let mut rules = test_rules();
+        rules.rules[0].max_charge = 40;
+        rules.rules[0].blast = 180;
+        let tables = compile_tables(&test_materials(), &rules);
+        let words: &[u32] = bytemuck::cast_slice(&tables.rule_entries[..1]);
+        assert_eq!(words[5], 40 | (180 << 16));
+        assert_ne!(
+            tables.rule_hash,
+            compute_rule_hash(&test_materials(), &test_rules())
+        );
+    }
 }
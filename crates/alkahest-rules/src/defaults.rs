@@ -44,6 +44,11 @@ pub const EXOTIC_EXT_END: u16 = 549;
 pub const ELECTRICAL_START: u16 = 550;
 pub const ELECTRICAL_END: u16 = 569;
 
+// ── Naturals, second expansion ────────────────────────────────────
+
+pub const NATURALS_EXT2_START: u16 = 570;
+pub const NATURALS_EXT2_END: u16 = 599;
+
 /// First valid material ID for mod-defined materials.
 pub const MOD_ID_START: u16 = 10000;
 
@@ -52,7 +57,9 @@ pub fn get_category(id: u16) -> &'static str {
     match id {
         0 => "Air",
         1..=15 => "Legacy",
-        NATURALS_START..=NATURALS_END | NATURALS_EXT_START..=NATURALS_EXT_END => "Naturals",
+        NATURALS_START..=NATURALS_END
+        | NATURALS_EXT_START..=NATURALS_EXT_END
+        | NATURALS_EXT2_START..=NATURALS_EXT2_END => "Naturals",
         METALS_START..=METALS_END | METALS_EXT_START..=METALS_EXT_END => "Metals",
         ORGANICS_START..=ORGANICS_END | ORGANICS_EXT_START..=ORGANICS_EXT_END => "Organics",
         ENERGY_START..=ENERGY_END | ENERGY_EXT_START..=ENERGY_EXT_END => "Energy",
@@ -61,7 +68,7 @@ pub fn get_category(id: u16) -> &'static str {
         }
         EXOTIC_START..=EXOTIC_END | EXOTIC_EXT_START..=EXOTIC_EXT_END => "Exotic",
         ELECTRICAL_START..=ELECTRICAL_END => "Electrical",
-        570..=9999 => "Reserved",
+        600..=9999 => "Reserved",
         MOD_ID_START.. => "Mod",
     }
 }
@@ -103,8 +110,11 @@ mod tests {
         // Electrical
         assert_eq!(get_category(550), "Electrical");
         assert_eq!(get_category(569), "Electrical");
+        // Second Naturals expansion
+        assert_eq!(get_category(570), "Naturals");
+        assert_eq!(get_category(599), "Naturals");
         // Reserved and Mod
-        assert_eq!(get_category(570), "Reserved");
+        assert_eq!(get_category(600), "Reserved");
         assert_eq!(get_category(9999), "Reserved");
         assert_eq!(get_category(10000), "Mod");
         assert_eq!(get_category(10001), "Mod");
@@ -3,6 +3,11 @@ pub mod compiler;
 pub mod defaults;
 pub mod loader;
 pub mod migration;
+mod names;
+pub mod report;
+pub mod resolver;
+mod units;
 pub mod validator;
 
-pub use compiler::{CompiledMaterialColor, GpuRuleData};
+pub use compiler::{CompiledMaterialColor, CompiledTables, GpuRuleData};
+pub use units::resolve_quantized_temperatures;
@@ -3,7 +3,10 @@ use alkahest_core::mod_manifest::ModManifest;
 use alkahest_core::rule::{InteractionRule, RuleSet};
 use thiserror::Error;
 
-use crate::migration::{remap_material_table, remap_rule_set, IdRemap};
+use crate::migration::{migrate_decay_cooling, remap_material_table, remap_rule_set, IdRemap};
+use crate::names::{resolve_material_names, MaterialNames};
+use crate::report::{newly_unreachable, MergeReport, RemappedId, RuleOverride, ShadowedMaterial};
+use crate::units::resolve_temperature_units;
 
 #[derive(Debug, Error)]
 pub enum LoadError {
@@ -13,43 +16,82 @@ pub enum LoadError {
     RuleParseError(String),
     #[error("Failed to parse mod manifest RON: {0}")]
     ManifestParseError(String),
+    #[error("line {line}: unknown material name \"{name}\"")]
+    UnknownMaterialName { name: String, line: usize },
+    #[error("line {line}: \"{mod_name}\" is not a dependency of this mod")]
+    UnknownModReference { mod_name: String, line: usize },
+    #[error("line {line}: material name \"{name}\" is ambiguous (matches IDs {ids:?})")]
+    AmbiguousMaterialName {
+        name: String,
+        line: usize,
+        ids: Vec<u16>,
+    },
+    #[error("line {line}: invalid temperature {value} for {field}: {reason}")]
+    InvalidTemperature {
+        field: String,
+        value: String,
+        line: usize,
+        reason: &'static str,
+    },
 }
 
 /// Parse a single materials RON string into a MaterialTable.
+///
+/// Temperature fields accept unit-tagged values (`ignition_temp: 300C`).
+/// Decay entries that model cooling are migrated to `freeze_temp` (see
+/// [`migrate_decay_cooling`]).
 pub fn load_materials_from_str(ron_str: &str) -> Result<MaterialTable, LoadError> {
+    let resolved = resolve_temperature_units(ron_str)?;
     let options = ron::Options::default();
     let materials: Vec<MaterialDef> = options
-        .from_str(ron_str)
+        .from_str(&resolved)
         .map_err(|e| LoadError::MaterialParseError(e.to_string()))?;
-    Ok(MaterialTable { materials })
+    let mut table = MaterialTable { materials };
+    migrate_decay_cooling(&mut table);
+    Ok(table)
 }
 
 /// Parse a single rules RON string into a RuleSet.
-pub fn load_rules_from_str(ron_str: &str) -> Result<RuleSet, LoadError> {
+///
+/// Material fields accept either a numeric ID or a material name
+/// (`input_a: "Fire"`), resolved against `materials`. Temperature fields
+/// accept unit-tagged values (`min_temp: 500K`).
+pub fn load_rules_from_str(ron_str: &str, materials: &MaterialTable) -> Result<RuleSet, LoadError> {
+    parse_rules(ron_str, &MaterialNames::new(&[materials]))
+}
+
+fn parse_rules(ron_str: &str, names: &MaterialNames) -> Result<RuleSet, LoadError> {
+    let resolved = resolve_temperature_units(&resolve_material_names(ron_str, names)?)?;
     let options = ron::Options::default();
     let rules: Vec<InteractionRule> = options
-        .from_str(ron_str)
+        .from_str(&resolved)
         .map_err(|e| LoadError::RuleParseError(e.to_string()))?;
     Ok(RuleSet { rules })
 }
 
 /// Load and merge multiple material sources into a single MaterialTable.
+/// Decay-based cooling is migrated again on the merged table, for pairs
+/// split across sources.
 pub fn load_all_materials(sources: &[&str]) -> Result<MaterialTable, LoadError> {
     let mut all_materials = Vec::new();
     for source in sources {
         let table = load_materials_from_str(source)?;
         all_materials.extend(table.materials);
     }
-    Ok(MaterialTable {
+    let mut table = MaterialTable {
         materials: all_materials,
-    })
+    };
+    migrate_decay_cooling(&mut table);
+    Ok(table)
 }
 
 /// Load and merge multiple rule sources into a single RuleSet.
-pub fn load_all_rules(sources: &[&str]) -> Result<RuleSet, LoadError> {
+/// Material names are resolved against `materials`.
+pub fn load_all_rules(sources: &[&str], materials: &MaterialTable) -> Result<RuleSet, LoadError> {
+    let names = MaterialNames::new(&[materials]);
     let mut all_rules = Vec::new();
     for source in sources {
-        let set = load_rules_from_str(source)?;
+        let set = parse_rules(source, &names)?;
         all_rules.extend(set.rules);
     }
     Ok(RuleSet { rules: all_rules })
@@ -72,15 +114,57 @@ pub struct ModLoadResult {
     pub warnings: Vec<String>,
 }
 
+/// A mod that has already been merged, kept so later mods that depend on it
+/// can refer to its materials by qualified name.
+#[derive(Debug, Clone)]
+pub struct LoadedMod {
+    pub name: String,
+    /// The mod's materials with their internal (post-remap) IDs.
+    pub materials: MaterialTable,
+}
+
+impl LoadedMod {
+    /// Record `mod_result` after it has been merged with `remap`.
+    pub fn new(mod_result: &ModLoadResult, remap: &IdRemap) -> Self {
+        let mut materials = mod_result.materials.clone();
+        for mat in &mut materials.materials {
+            mat.id = remap.get_internal(mat.id).unwrap_or(mat.id);
+        }
+        Self {
+            name: mod_result.manifest.name.clone(),
+            materials,
+        }
+    }
+}
+
 /// Load a complete mod pack from its manifest and data sources.
+///
+/// Material names in mod rules resolve against the mod's own materials
+/// first, then `base_materials`, so a mod material shadows a base material
+/// with the same name. Resolved mod IDs are pre-remap and are shifted by
+/// `merge_mod` like numeric ones.
+///
+/// Materials of a mod listed in the manifest's `depends_on` can also be
+/// named as `"Mod Name:Material Name"`, looked up in `loaded`.
 pub fn load_mod(
     manifest_str: &str,
     material_sources: &[&str],
     rule_sources: &[&str],
+    base_materials: &MaterialTable,
+    loaded: &[LoadedMod],
 ) -> Result<ModLoadResult, LoadError> {
     let manifest = load_mod_manifest(manifest_str)?;
     let materials = load_all_materials(material_sources)?;
-    let rules = load_all_rules(rule_sources)?;
+    let mut names = MaterialNames::new(&[&materials, base_materials]);
+    for dep in &manifest.depends_on {
+        if let Some(l) = loaded.iter().find(|l| l.name == dep.name) {
+            names = names.with_mod(&l.name, &l.materials);
+        }
+    }
+    let mut rules = RuleSet::default();
+    for source in rule_sources {
+        rules.rules.extend(parse_rules(source, &names)?.rules);
+    }
     Ok(ModLoadResult {
         manifest,
         materials,
@@ -91,14 +175,15 @@ pub fn load_mod(
 
 /// Merge a loaded mod into the base material table and rule set.
 /// Remaps mod IDs to contiguous internal IDs via the provided IdRemap.
-/// Returns a list of conflict warning strings (e.g. rule overrides).
+/// Returns a report of everything the merge overrode, shadowed or remapped.
 pub fn merge_mod(
     base_materials: &mut MaterialTable,
     base_rules: &mut RuleSet,
     mod_result: &ModLoadResult,
     remap: &mut IdRemap,
-) -> Vec<String> {
-    let mut warnings = Vec::new();
+) -> MergeReport {
+    let mut report = MergeReport::new(&mod_result.manifest.name);
+    let rules_before = base_rules.clone();
 
     // Clone mod data so we can remap in place
     let mut mod_materials = mod_result.materials.clone();
@@ -110,33 +195,102 @@ pub fn merge_mod(
     // Remap mod rule IDs (base IDs pass through unchanged)
     remap_rule_set(&mut mod_rules, remap);
 
+    for (original, remapped) in mod_result
+        .materials
+        .materials
+        .iter()
+        .zip(&mod_materials.materials)
+    {
+        if original.id != remapped.id {
+            report.remapped_ids.push(RemappedId {
+                name: remapped.name.clone(),
+                external: original.id,
+                internal: remapped.id,
+            });
+        }
+        if let Some(existing) = base_materials
+            .materials
+            .iter()
+            .find(|m| m.name == remapped.name)
+        {
+            report.shadowed_materials.push(ShadowedMaterial {
+                name: remapped.name.clone(),
+                existing_id: existing.id,
+                mod_id: remapped.id,
+            });
+        }
+    }
+
     // Append mod materials to base
     base_materials.materials.extend(mod_materials.materials);
 
-    // Merge rules: check for conflicts (same input_a, input_b pair)
+    // Merge rules. A mod rule replaces every base rule for the same pair
+    // whose conditions overlap its own, taking the place of the first;
+    // otherwise it becomes another candidate for the pair. Pattern rules
+    // never replace anything here; explicit pairs already take precedence
+    // over them when the compiler expands patterns. `origin` tracks which
+    // base rule each merged rule still is, if any.
+    let mut origin: Vec<Option<usize>> = (0..base_rules.rules.len()).map(Some).collect();
     for mod_rule in mod_rules.rules {
-        let existing_idx = base_rules.rules.iter().position(|r| {
-            (r.input_a == mod_rule.input_a && r.input_b == mod_rule.input_b)
-                || (r.input_a == mod_rule.input_b && r.input_b == mod_rule.input_a)
-        });
-
-        if let Some(idx) = existing_idx {
-            warnings.push(format!(
-                "Mod '{}': rule '{}' overrides base rule '{}'",
-                mod_result.manifest.name, mod_rule.name, base_rules.rules[idx].name
-            ));
-            base_rules.rules[idx] = mod_rule;
-        } else {
+        let overlapping: Vec<usize> = (0..base_rules.rules.len())
+            .filter(|&i| {
+                let r = &base_rules.rules[i];
+                origin[i].is_some()
+                    && !r.is_pattern()
+                    && !mod_rule.is_pattern()
+                    && ((r.input_a == mod_rule.input_a && r.input_b == mod_rule.input_b)
+                        || (r.input_a == mod_rule.input_b && r.input_b == mod_rule.input_a))
+                    && r.conditions_overlap(&mod_rule)
+            })
+            .collect();
+
+        let Some((&first, rest)) = overlapping.split_first() else {
             base_rules.rules.push(mod_rule);
+            origin.push(None);
+            continue;
+        };
+        for &idx in &overlapping {
+            report.overridden_rules.push(RuleOverride {
+                before: base_rules.rules[idx].clone(),
+                after: mod_rule.clone(),
+            });
         }
+        for &idx in rest.iter().rev() {
+            base_rules.rules.remove(idx);
+            origin.remove(idx);
+        }
+        base_rules.rules[first] = mod_rule;
+        origin[first] = None;
     }
 
-    warnings
+    report.unreachable_rules = newly_unreachable(
+        &rules_before,
+        base_rules,
+        &origin,
+        &report.shadowed_materials,
+    );
+    report
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::report::{FieldChange, UnreachableRule};
+    use alkahest_core::types::{Kelvin, QuantizedTemp};
+
+    fn base_materials() -> MaterialTable {
+        load_all_materials(&[
+            include_str!("../../../data/materials/naturals.ron"),
+            include_str!("../../../data/materials/organics.ron"),
+            include_str!("../../../data/materials/energy.ron"),
+            include_str!("../../../data/materials/explosives.ron"),
+            include_str!("../../../data/materials/metals.ron"),
+            include_str!("../../../data/materials/synthetics.ron"),
+            include_str!("../../../data/materials/exotic.ron"),
+            include_str!("../../../data/materials/electrical.ron"),
+        ])
+        .expect("base materials should load")
+    }
 
     #[test]
     fn test_load_single_material() {
@@ -168,11 +322,119 @@ mod tests {
                 temp_delta: 200,
             ),
         ]"#;
-        let set = load_rules_from_str(ron).expect("should parse");
+        let set = load_rules_from_str(ron, &MaterialTable::default()).expect("should parse");
         assert_eq!(set.len(), 1);
         assert_eq!(set.rules[0].name, "Fire+Wood");
     }
 
+    #[test]
+    fn test_load_pressure_gated_rule() {
+        let ron = r#"[
+            (name: "Graphite to Diamond", input_a: 1, input_b: 1, output_a: 2, output_b: 2,
+             probability: 0.01, min_pressure: 50),
+            (name: "Plain", input_a: 5, input_b: 8, output_a: 5, output_b: 9, probability: 0.8),
+        ]"#;
+        let set = load_rules_from_str(ron, &MaterialTable::default()).expect("should parse");
+        assert_eq!(
+            (set.rules[0].min_pressure, set.rules[0].max_pressure),
+            (50, 0)
+        );
+        assert_eq!(
+            (set.rules[1].min_pressure, set.rules[1].max_pressure),
+            (0, 0)
+        );
+    }
+
+    #[test]
+    fn test_load_catalyst_rule() {
+        let ron = r#"[
+            (name: "Catalyzed", input_a: "Fire", input_b: "Wood", output_a: "Fire",
+             output_b: "Ash", probability: 0.5, catalyst: Some("Sand")),
+            (name: "Plain", input_a: 5, input_b: 8, output_a: 5, output_b: 9, probability: 0.5),
+        ]"#;
+        let set = load_rules_from_str(ron, &base_materials()).expect("should parse");
+        assert_eq!(set.rules[0].catalyst, Some(2));
+        assert_eq!(set.rules[1].catalyst, None);
+    }
+
+    #[test]
+    fn test_load_rule_with_material_names() {
+        let ron = r#"[
+            (
+                name: "Fire+Wood",
+                input_a: "Fire",
+                input_b: "Wood",
+                output_a: "Fire",
+                output_b: "Ash",
+                probability: 0.8,
+            ),
+        ]"#;
+        let set = load_rules_from_str(ron, &base_materials()).expect("should parse");
+        let rule = &set.rules[0];
+        assert_eq!(
+            (rule.input_a, rule.input_b, rule.output_a, rule.output_b),
+            (5, 8, 5, 9)
+        );
+    }
+
+    #[test]
+    fn test_load_pattern_rule() {
+        let ron = r#"[
+            (
+                name: "Acid dissolves organics",
+                input_a: "Acid",
+                pattern_b: Some(Category("Organics")),
+                output_a: "Acid",
+                output_b: "Air",
+                probability: 0.05,
+            ),
+        ]"#;
+        let set = load_rules_from_str(ron, &base_materials()).expect("should parse");
+        let rule = &set.rules[0];
+        assert_eq!(rule.input_b, alkahest_core::rule::UNSET_MATERIAL);
+        assert_eq!(
+            rule.pattern_b,
+            Some(alkahest_core::rule::MaterialPattern::Category(
+                "Organics".into()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_load_unit_tagged_temperatures() {
+        let materials = load_materials_from_str(
+            r#"[(id: 1, name: "Wax", phase: Solid, density: 900.0, color: (1.0, 1.0, 0.9),
+                 ignition_temp: 200C, decay_threshold: 350K, phase_change_temp: q(165))]"#,
+        )
+        .expect("should parse");
+        let wax = &materials.materials[0];
+        assert_eq!(wax.ignition_temp, Kelvin::from_celsius(200.0));
+        assert_eq!(wax.decay_threshold, Kelvin(350.0).quantize());
+        assert_eq!(wax.phase_change_temp, QuantizedTemp(165).to_kelvin());
+
+        let ron = r#"[
+            (name: "Melt", input_a: 1, input_b: 1, output_a: 2, output_b: 2, probability: 0.5,
+             temp_delta: -20K, min_temp: 500K, max_temp: 227C),
+        ]"#;
+        let set = load_rules_from_str(ron, &MaterialTable::default()).expect("should parse");
+        let rule = &set.rules[0];
+        assert_eq!(rule.temp_delta, -(Kelvin(20.0).quantize().0 as i32));
+        assert_eq!(rule.min_temp, Kelvin(500.0).quantize());
+        assert_eq!(rule.max_temp, Kelvin::from_celsius(227.0).quantize());
+    }
+
+    #[test]
+    fn test_unknown_material_name_rejected() {
+        let ron = "[\n    (\n        name: \"typo\",\n        input_a: \"Fier\",\n    ),\n]";
+        match load_rules_from_str(ron, &base_materials()) {
+            Err(LoadError::UnknownMaterialName { name, line }) => {
+                assert_eq!(name, "Fier");
+                assert_eq!(line, 4);
+            }
+            other => panic!("expected UnknownMaterialName, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_malformed_ron_rejected() {
         let ron = r#"[this is not valid RON {"#;
@@ -224,16 +486,19 @@ mod tests {
         let biological = include_str!("../../../data/rules/biological.ron");
         let thermal = include_str!("../../../data/rules/thermal.ron");
         let synthesis = include_str!("../../../data/rules/synthesis.ron");
-        let set = load_all_rules(&[
-            combustion,
-            structural,
-            phase_change,
-            dissolution,
-            displacement,
-            biological,
-            thermal,
-            synthesis,
-        ])
+        let set = load_all_rules(
+            &[
+                combustion,
+                structural,
+                phase_change,
+                dissolution,
+                displacement,
+                biological,
+                thermal,
+                synthesis,
+            ],
+            &base_materials(),
+        )
         .expect("should load");
         assert!(
             set.len() >= 500,
@@ -270,27 +535,33 @@ mod tests {
     fn test_gunpowder_rule_loads() {
         // Verify gunpowder combustion rules parse with pressure_delta
         let structural = include_str!("../../../data/rules/structural.ron");
-        let set = load_rules_from_str(structural).expect("should parse structural.ron");
+        let set = load_rules_from_str(structural, &base_materials())
+            .expect("should parse structural.ron");
         assert!(
             set.len() >= 2,
             "expected at least 2 structural rules, got {}",
             set.len()
         );
 
+        let combustion = include_str!("../../../data/rules/combustion.ron");
+        let set = load_rules_from_str(combustion, &base_materials())
+            .expect("should parse combustion.ron");
         let fire_rule = set
             .rules
             .iter()
-            .find(|r| r.name == "Gunpowder+Fire explosion")
-            .expect("Gunpowder+Fire explosion rule missing");
+            .find(|r| r.name == "Fire+Gunpowder detonation")
+            .expect("Fire+Gunpowder detonation rule missing");
         assert_eq!(fire_rule.pressure_delta, 60);
         assert_eq!(fire_rule.temp_delta, 500);
+        assert_eq!(fire_rule.blast, 120);
 
         let lava_rule = set
             .rules
             .iter()
-            .find(|r| r.name == "Gunpowder+Lava explosion")
-            .expect("Gunpowder+Lava explosion rule missing");
+            .find(|r| r.name == "Lava+Gunpowder detonation")
+            .expect("Lava+Gunpowder detonation rule missing");
         assert_eq!(lava_rule.pressure_delta, 55);
+        assert_eq!(lava_rule.blast, 110);
     }
 
     #[test]
@@ -327,8 +598,14 @@ mod tests {
         let rules_ron =
             include_str!("../../../data/mods/example-mod/rules/crystal_interactions.ron");
 
-        let result = load_mod(manifest_ron, &[materials_ron], &[rules_ron])
-            .expect("should load example mod");
+        let result = load_mod(
+            manifest_ron,
+            &[materials_ron],
+            &[rules_ron],
+            &base_materials(),
+            &[],
+        )
+        .expect("should load example mod");
         assert_eq!(result.manifest.name, "Crystal Pack");
         assert!(
             result.materials.len() >= 20,
@@ -361,7 +638,8 @@ mod tests {
     #[test]
     fn test_load_electrical_rules() {
         let electrical = include_str!("../../../data/rules/electrical.ron");
-        let set = load_rules_from_str(electrical).expect("should parse electrical.ron rules");
+        let set = load_rules_from_str(electrical, &base_materials())
+            .expect("should parse electrical.ron rules");
         assert!(
             set.len() >= 10,
             "expected at least 10 electrical rules, got {}",
@@ -376,6 +654,33 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_shipped_rules_have_no_starved_candidates() {
+        // Same order the CLI loads data/rules/*.ron in
+        let set = load_all_rules(
+            &[
+                include_str!("../../../data/rules/biological.ron"),
+                include_str!("../../../data/rules/combustion.ron"),
+                include_str!("../../../data/rules/displacement.ron"),
+                include_str!("../../../data/rules/dissolution.ron"),
+                include_str!("../../../data/rules/electrical.ron"),
+                include_str!("../../../data/rules/phase_change.ron"),
+                include_str!("../../../data/rules/structural.ron"),
+                include_str!("../../../data/rules/synthesis.ron"),
+                include_str!("../../../data/rules/thermal.ron"),
+            ],
+            &base_materials(),
+        )
+        .expect("should load");
+        let starved: Vec<_> = crate::validator::validate_rules(&set, &base_materials())
+            .err()
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|e| matches!(e, crate::validator::ValidationError::StarvedRule { .. }))
+            .collect();
+        assert!(starved.is_empty(), "starved rules: {starved:?}");
+    }
+
     #[test]
     fn test_empty_sources_returns_empty() {
         let table = load_all_materials(&[]).expect("empty sources should succeed");
@@ -383,7 +688,7 @@ mod tests {
     }
 
     #[test]
-    fn test_merge_mod_conflict_reports_warnings() {
+    fn test_merge_mod_conflict_reports_override() {
         // Create base with a rule
         let mut base_materials = MaterialTable {
             materials: vec![
@@ -395,15 +700,26 @@ mod tests {
                     color: (0.0, 0.0, 0.0),
                     emission: 0.0,
                     flammability: 0.0,
-                    ignition_temp: 0.0,
+                    ignition_temp: Kelvin(0.0),
                     decay_rate: 0,
-                    decay_threshold: 0,
+                    decay_threshold: QuantizedTemp(0),
                     decay_product: 0,
                     viscosity: 0.0,
+                    repose_angle: 45.0,
+                    cohesion: 0.0,
+                    erodibility: 0.0,
                     thermal_conductivity: 0.0,
-                    phase_change_temp: 0.0,
+                    heat_capacity: 1.0,
+                    radiative_emissivity: 0.0,
+                    phase_change_temp: Kelvin(0.0),
                     phase_change_product: 0,
+                    latent_heat: 0.0,
+                    freeze_temp: Kelvin(0.0),
+                    freeze_product: 0,
                     structural_integrity: 0.0,
+                    compressive_strength: None,
+                    tensile_strength: None,
+                    blast_resistance: None,
                     opacity: None,
                     absorption_rate: 0.0,
                     electrical_conductivity: 0.0,
@@ -419,15 +735,26 @@ mod tests {
                     color: (0.5, 0.5, 0.55),
                     emission: 0.0,
                     flammability: 0.0,
-                    ignition_temp: 0.0,
+                    ignition_temp: Kelvin(0.0),
                     decay_rate: 0,
-                    decay_threshold: 0,
+                    decay_threshold: QuantizedTemp(0),
                     decay_product: 0,
                     viscosity: 0.0,
+                    repose_angle: 45.0,
+                    cohesion: 0.0,
+                    erodibility: 0.0,
                     thermal_conductivity: 0.5,
-                    phase_change_temp: 0.0,
+                    heat_capacity: 1.0,
+                    radiative_emissivity: 0.0,
+                    phase_change_temp: Kelvin(0.0),
                     phase_change_product: 0,
+                    latent_heat: 0.0,
+                    freeze_temp: Kelvin(0.0),
+                    freeze_product: 0,
                     structural_integrity: 63.0,
+                    compressive_strength: None,
+                    tensile_strength: None,
+                    blast_resistance: None,
                     opacity: None,
                     absorption_rate: 0.0,
                     electrical_conductivity: 0.0,
@@ -446,11 +773,18 @@ mod tests {
                 output_b: 1,
                 probability: 0.5,
                 temp_delta: 0,
-                min_temp: 0,
-                max_temp: 0,
+                min_temp: QuantizedTemp(0),
+                max_temp: QuantizedTemp(0),
                 pressure_delta: 0,
                 min_charge: 0,
                 max_charge: 0,
+                min_pressure: 0,
+                max_pressure: 0,
+                catalyst: None,
+                oxygen: 0,
+                blast: 0,
+                pattern_a: None,
+                pattern_b: None,
             }],
         };
 
@@ -470,29 +804,216 @@ mod tests {
             (name: "ModRule", input_a: 0, input_b: 1, output_a: 0, output_b: 1, probability: 0.9),
         ]"#;
 
-        let mod_result =
-            load_mod(manifest_ron, &[materials_ron], &[rules_ron]).expect("should load mod");
+        let mod_result = load_mod(
+            manifest_ron,
+            &[materials_ron],
+            &[rules_ron],
+            &base_materials,
+            &[],
+        )
+        .expect("should load mod");
 
         let base_max_id = base_materials.max_id();
         let mut remap = IdRemap::new(base_max_id);
-        let warnings = merge_mod(
+        let report = merge_mod(
             &mut base_materials,
             &mut base_rules,
             &mod_result,
             &mut remap,
         );
 
-        assert!(
-            !warnings.is_empty(),
-            "expected warnings about rule override"
+        assert_eq!(report.mod_name, "Override Mod");
+        assert_eq!(report.overridden_rules.len(), 1);
+        let overridden = &report.overridden_rules[0];
+        assert_eq!(overridden.before.name, "BaseRule");
+        assert_eq!(overridden.after.name, "ModRule");
+        assert_eq!(
+            overridden.changes(),
+            vec![FieldChange {
+                field: "probability",
+                before: "0.5".into(),
+                after: "0.9".into(),
+            }]
         );
+        let warnings = report.warnings();
         assert!(
-            warnings[0].contains("overrides"),
-            "warning should mention override: {}",
+            warnings[0].contains("overrides base rule 'BaseRule' (probability 0.5 -> 0.9)"),
+            "warning should describe the override: {}",
             warnings[0]
         );
     }
 
+    #[test]
+    fn test_merge_mod_disjoint_conditions_add_candidate() {
+        let mut materials = base_materials();
+        let mut rules = load_rules_from_str(
+            r#"[(name: "Cool", input_a: 3, input_b: 11, output_a: 7, output_b: 21, probability: 0.5, max_temp: 500)]"#,
+            &materials,
+        )
+        .unwrap();
+        let manifest_ron = r#"(
+            name: "Hot Mod",
+            version: "1.0.0",
+            author: "Tester",
+            description: "Adds a hot outcome",
+            load_order_hint: 100,
+        )"#;
+        let rules_ron = r#"[
+            (name: "Hot", input_a: "Lava", input_b: "Water", output_a: "Stone", output_b: "Steam", probability: 0.5, min_temp: 501),
+        ]"#;
+        let mod_result = load_mod(manifest_ron, &[], &[rules_ron], &materials, &[]).unwrap();
+
+        let mut remap = IdRemap::new(materials.max_id());
+        let report = merge_mod(&mut materials, &mut rules, &mod_result, &mut remap);
+        assert_eq!(
+            report.conflict_count(),
+            0,
+            "unexpected conflicts: {:?}",
+            report
+        );
+        let names: Vec<&str> = rules.rules.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["Cool", "Hot"]);
+    }
+
+    #[test]
+    fn test_merge_mod_reports_shadowed_and_unreachable() {
+        let mut materials = base_materials();
+        let mut rules = load_rules_from_str(
+            r#"[
+                (name: "Quench", input_a: "Lava", input_b: "Water", output_a: "Stone", output_b: "Steam", probability: 0.5),
+                (name: "Boil", input_a: "Lava", input_b: "Water", output_a: "Lava", output_b: "Steam", probability: 0.5),
+                (name: "Condense", input_a: "Steam", input_b: "Ice", output_a: "Water", output_b: "Ice", probability: 0.5),
+            ]"#,
+            &materials,
+        )
+        .unwrap();
+        let manifest_ron = r#"(name: "Sure Quench", version: "1.0.0", author: "Tester", description: "", load_order_hint: 100)"#;
+        let materials_ron =
+            r#"[(id: 10001, name: "Steam", phase: Gas, density: 0.5, color: (0.9, 0.9, 0.9))]"#;
+        let rules_ron = r#"[
+            (name: "Always quench", input_a: "Lava", input_b: "Water", output_a: "Stone", output_b: "Steam", probability: 1.0),
+            (name: "Sometimes boil", input_a: "Lava", input_b: "Water", output_a: "Lava", output_b: "Steam", probability: 0.5),
+        ]"#;
+        let mod_result = load_mod(
+            manifest_ron,
+            &[materials_ron],
+            &[rules_ron],
+            &materials,
+            &[],
+        )
+        .unwrap();
+        let base_steam = materials
+            .materials
+            .iter()
+            .find(|m| m.name == "Steam")
+            .unwrap()
+            .id;
+
+        let mut remap = IdRemap::new(materials.max_id());
+        let report = merge_mod(&mut materials, &mut rules, &mod_result, &mut remap);
+        let mod_steam = remap.get_internal(10001).unwrap();
+
+        assert_eq!(
+            report.shadowed_materials,
+            vec![ShadowedMaterial {
+                name: "Steam".into(),
+                existing_id: base_steam,
+                mod_id: mod_steam,
+            }]
+        );
+        // Both overlapping base rules give way to the mod's; the mod's own
+        // later candidate is only another candidate
+        let overridden: Vec<&str> = report
+            .overridden_rules
+            .iter()
+            .map(|o| o.before.name.as_str())
+            .collect();
+        assert_eq!(overridden, vec!["Quench", "Boil"]);
+        let names: Vec<&str> = rules.rules.iter().map(|r| r.name.as_str()).collect();
+        assert_eq!(names, vec!["Always quench", "Condense", "Sometimes boil"]);
+        assert_eq!(
+            report.unreachable_rules,
+            vec![
+                UnreachableRule {
+                    name: "Condense".into(),
+                    blocked_by: vec![],
+                    shadowed_materials: vec!["Steam".into()],
+                },
+                UnreachableRule {
+                    name: "Sometimes boil".into(),
+                    blocked_by: vec!["Always quench".into()],
+                    shadowed_materials: vec![],
+                },
+            ]
+        );
+        assert_eq!(report.conflict_count(), 5);
+    }
+
+    #[test]
+    fn test_qualified_name_resolves_to_dependency() {
+        let mut materials = base_materials();
+        let mut rules = RuleSet::default();
+        let gem_ron =
+            r#"[(id: 10001, name: "Gem", phase: Solid, density: 3000.0, color: (0.2, 0.9, 0.4))]"#;
+
+        let gems = load_mod(
+            r#"(name: "Gem Pack", version: "1.2.0", author: "A", description: "", load_order_hint: 100)"#,
+            &[gem_ron],
+            &[],
+            &materials,
+            &[],
+        )
+        .unwrap();
+        let mut remap = IdRemap::new(materials.max_id());
+        merge_mod(&mut materials, &mut rules, &gems, &mut remap);
+        let loaded = vec![LoadedMod::new(&gems, &remap)];
+        let pack_gem = loaded[0].materials.materials[0].id;
+
+        let manifest_ron = r#"(
+            name: "Gem Tools",
+            version: "1.0.0",
+            author: "B",
+            description: "",
+            load_order_hint: 100,
+            depends_on: [(name: "Gem Pack", version: "^1.0")],
+        )"#;
+        // "Gem" alone is this mod's own material; the qualified name reaches
+        // the dependency's
+        let rules_ron = r#"[
+            (name: "Cut", input_a: "Gem Pack:Gem", input_b: "Gem", output_a: "Gem", output_b: "Gem", probability: 0.5),
+        ]"#;
+        let tools = load_mod(manifest_ron, &[gem_ron], &[rules_ron], &materials, &loaded).unwrap();
+        assert_eq!(tools.manifest.depends_on[0].name, "Gem Pack");
+
+        let mut remap = IdRemap::new(materials.max_id());
+        merge_mod(&mut materials, &mut rules, &tools, &mut remap);
+        let tools_gem = remap.get_internal(10001).unwrap();
+        assert_ne!(pack_gem, tools_gem);
+        assert_eq!(rules.rules[0].input_a, pack_gem);
+        assert_eq!(rules.rules[0].input_b, tools_gem);
+    }
+
+    #[test]
+    fn test_qualified_name_requires_dependency() {
+        let materials = base_materials();
+        let loaded = vec![LoadedMod {
+            name: "Gem Pack".into(),
+            materials: load_materials_from_str(
+                r#"[(id: 200, name: "Gem", phase: Solid, density: 3000.0, color: (0.2, 0.9, 0.4))]"#,
+            )
+            .unwrap(),
+        }];
+        let manifest_ron = r#"(name: "Loner", version: "1.0.0", author: "B", description: "", load_order_hint: 100)"#;
+        let rules_ron = "[\n(name: \"Cut\", input_a: \"Gem Pack:Gem\", input_b: 1, output_a: 1, output_b: 1, probability: 0.5)]";
+        match load_mod(manifest_ron, &[], &[rules_ron], &materials, &loaded) {
+            Err(LoadError::UnknownModReference { mod_name, line }) => {
+                assert_eq!(mod_name, "Gem Pack");
+                assert_eq!(line, 2);
+            }
+            other => panic!("expected UnknownModReference, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_merge_mod_into_base() {
         // Minimal base
@@ -506,15 +1027,26 @@ mod tests {
                     color: (0.0, 0.0, 0.0),
                     emission: 0.0,
                     flammability: 0.0,
-                    ignition_temp: 0.0,
+                    ignition_temp: Kelvin(0.0),
                     decay_rate: 0,
-                    decay_threshold: 0,
+                    decay_threshold: QuantizedTemp(0),
                     decay_product: 0,
                     viscosity: 0.0,
+                    repose_angle: 45.0,
+                    cohesion: 0.0,
+                    erodibility: 0.0,
                     thermal_conductivity: 0.0,
-                    phase_change_temp: 0.0,
+                    heat_capacity: 1.0,
+                    radiative_emissivity: 0.0,
+                    phase_change_temp: Kelvin(0.0),
                     phase_change_product: 0,
+                    latent_heat: 0.0,
+                    freeze_temp: Kelvin(0.0),
+                    freeze_product: 0,
                     structural_integrity: 0.0,
+                    compressive_strength: None,
+                    tensile_strength: None,
+                    blast_resistance: None,
                     opacity: None,
                     absorption_rate: 0.0,
                     electrical_conductivity: 0.0,
@@ -530,15 +1062,26 @@ mod tests {
                     color: (0.5, 0.5, 0.55),
                     emission: 0.0,
                     flammability: 0.0,
-                    ignition_temp: 0.0,
+                    ignition_temp: Kelvin(0.0),
                     decay_rate: 0,
-                    decay_threshold: 0,
+                    decay_threshold: QuantizedTemp(0),
                     decay_product: 0,
                     viscosity: 0.0,
+                    repose_angle: 45.0,
+                    cohesion: 0.0,
+                    erodibility: 0.0,
                     thermal_conductivity: 0.5,
-                    phase_change_temp: 0.0,
+                    heat_capacity: 1.0,
+                    radiative_emissivity: 0.0,
+                    phase_change_temp: Kelvin(0.0),
                     phase_change_product: 0,
+                    latent_heat: 0.0,
+                    freeze_temp: Kelvin(0.0),
+                    freeze_product: 0,
                     structural_integrity: 63.0,
+                    compressive_strength: None,
+                    tensile_strength: None,
+                    blast_resistance: None,
                     opacity: None,
                     absorption_rate: 0.0,
                     electrical_conductivity: 0.0,
@@ -564,19 +1107,38 @@ mod tests {
             (name: "TestCrystal+Stone", input_a: 10001, input_b: 1, output_a: 10001, output_b: 1, probability: 0.5),
         ]"#;
 
-        let mod_result =
-            load_mod(manifest_ron, &[materials_ron], &[rules_ron]).expect("should load test mod");
+        let mod_result = load_mod(
+            manifest_ron,
+            &[materials_ron],
+            &[rules_ron],
+            &base_materials,
+            &[],
+        )
+        .expect("should load test mod");
 
         let base_max_id = base_materials.max_id();
         let mut remap = IdRemap::new(base_max_id);
-        let warnings = merge_mod(
+        let report = merge_mod(
             &mut base_materials,
             &mut base_rules,
             &mod_result,
             &mut remap,
         );
 
-        assert!(warnings.is_empty(), "unexpected warnings: {:?}", warnings);
+        assert_eq!(
+            report.conflict_count(),
+            0,
+            "unexpected conflicts: {:?}",
+            report
+        );
+        assert_eq!(
+            report.remapped_ids,
+            vec![RemappedId {
+                name: "TestCrystal".into(),
+                external: 10001,
+                internal: 2,
+            }]
+        );
         // Base (2) + mod (1) = 3 materials
         assert_eq!(base_materials.len(), 3);
         // The mod material should be remapped to base_max_id + 1 = 2
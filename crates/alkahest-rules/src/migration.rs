@@ -1,5 +1,6 @@
 use alkahest_core::material::MaterialTable;
 use alkahest_core::rule::RuleSet;
+use alkahest_core::types::QuantizedTemp;
 use std::collections::HashMap;
 
 /// Maps external mod IDs (10000+) to contiguous internal IDs starting after
@@ -59,7 +60,7 @@ impl IdRemap {
 }
 
 /// Remap all material IDs in a table, including cross-references
-/// (decay_product, phase_change_product).
+/// (decay_product, phase_change_product, freeze_product).
 pub fn remap_material_table(table: &mut MaterialTable, remap: &mut IdRemap) {
     for mat in &mut table.materials {
         let new_id = remap.remap(mat.id);
@@ -73,9 +74,52 @@ pub fn remap_material_table(table: &mut MaterialTable, remap: &mut IdRemap) {
         if let Some(internal) = remap.get_internal(mat.phase_change_product) {
             mat.phase_change_product = internal;
         }
+        if let Some(internal) = remap.get_internal(mat.freeze_product) {
+            mat.freeze_product = internal;
+        }
     }
 }
 
+/// Rewrite decay entries that model cooling into downward phase changes.
+///
+/// Before `freeze_temp` existed, condensing and solidifying were written as
+/// self-decay: Steam decayed into Water below a threshold, and Water boiled
+/// back into Steam. A decay entry is treated as cooling when its product's
+/// upward phase change leads straight back to the material. Its threshold
+/// and product move to `freeze_temp`/`freeze_product` and the decay fields
+/// are cleared, so the material no longer cools itself every tick; it
+/// changes once its surroundings have brought it below the threshold.
+///
+/// Returns the IDs of the migrated materials.
+pub fn migrate_decay_cooling(table: &mut MaterialTable) -> Vec<u16> {
+    let cooling: Vec<usize> = table
+        .materials
+        .iter()
+        .enumerate()
+        .filter(|(_, mat)| {
+            mat.decay_product != mat.id
+                && mat.freeze_product == 0
+                && mat.freeze_temp.0 == 0.0
+                && table
+                    .get(mat.decay_product)
+                    .is_some_and(|product| product.phase_change_product == mat.id)
+        })
+        .map(|(i, _)| i)
+        .collect();
+
+    let mut migrated = Vec::with_capacity(cooling.len());
+    for i in cooling {
+        let mat = &mut table.materials[i];
+        mat.freeze_temp = mat.decay_threshold.to_kelvin();
+        mat.freeze_product = mat.decay_product;
+        mat.decay_rate = 0;
+        mat.decay_threshold = QuantizedTemp(0);
+        mat.decay_product = 0;
+        migrated.push(mat.id);
+    }
+    migrated
+}
+
 /// Remap material IDs in all rules. IDs not in the remap table are left unchanged
 /// (base game IDs pass through).
 pub fn remap_rule_set(rules: &mut RuleSet, remap: &IdRemap) {
@@ -92,6 +136,9 @@ pub fn remap_rule_set(rules: &mut RuleSet, remap: &IdRemap) {
         if let Some(id) = remap.get_internal(rule.output_b) {
             rule.output_b = id;
         }
+        if let Some(id) = rule.catalyst.and_then(|c| remap.get_internal(c)) {
+            rule.catalyst = Some(id);
+        }
     }
 }
 
@@ -100,6 +147,7 @@ mod tests {
     use super::*;
     use alkahest_core::material::{MaterialDef, Phase};
     use alkahest_core::rule::InteractionRule;
+    use alkahest_core::types::{Kelvin, QuantizedTemp};
 
     fn make_mod_material(id: u16, name: &str) -> MaterialDef {
         MaterialDef {
@@ -110,15 +158,26 @@ mod tests {
             color: (0.5, 0.5, 0.5),
             emission: 0.0,
             flammability: 0.0,
-            ignition_temp: 0.0,
+            ignition_temp: Kelvin(0.0),
             decay_rate: 0,
-            decay_threshold: 0,
+            decay_threshold: QuantizedTemp(0),
             decay_product: 0,
             viscosity: 0.0,
+            repose_angle: 45.0,
+            cohesion: 0.0,
+            erodibility: 0.0,
             thermal_conductivity: 0.3,
-            phase_change_temp: 0.0,
+            heat_capacity: 1.0,
+            radiative_emissivity: 0.0,
+            phase_change_temp: Kelvin(0.0),
             phase_change_product: 0,
+            latent_heat: 0.0,
+            freeze_temp: Kelvin(0.0),
+            freeze_product: 0,
             structural_integrity: 30.0,
+            compressive_strength: None,
+            tensile_strength: None,
+            blast_resistance: None,
             opacity: None,
             absorption_rate: 0.0,
             electrical_conductivity: 0.0,
@@ -165,11 +224,18 @@ mod tests {
                 output_b: 0, // base ID, should pass through
                 probability: 1.0,
                 temp_delta: 0,
-                min_temp: 0,
-                max_temp: 0,
+                min_temp: QuantizedTemp(0),
+                max_temp: QuantizedTemp(0),
                 pressure_delta: 0,
                 min_charge: 0,
                 max_charge: 0,
+                min_pressure: 0,
+                max_pressure: 0,
+                catalyst: Some(10002),
+                oxygen: 0,
+                blast: 0,
+                pattern_a: None,
+                pattern_b: None,
             }],
         };
 
@@ -178,6 +244,7 @@ mod tests {
         assert_eq!(rules.rules[0].input_b, 251);
         assert_eq!(rules.rules[0].output_a, 250);
         assert_eq!(rules.rules[0].output_b, 0); // unchanged base ID
+        assert_eq!(rules.rules[0].catalyst, Some(251));
     }
 
     #[test]
@@ -193,11 +260,18 @@ mod tests {
                 output_b: 9,
                 probability: 0.8,
                 temp_delta: 200,
-                min_temp: 0,
-                max_temp: 0,
+                min_temp: QuantizedTemp(0),
+                max_temp: QuantizedTemp(0),
                 pressure_delta: 0,
                 min_charge: 0,
                 max_charge: 0,
+                min_pressure: 0,
+                max_pressure: 0,
+                catalyst: None,
+                oxygen: 0,
+                blast: 0,
+                pattern_a: None,
+                pattern_b: None,
             }],
         };
 
@@ -237,4 +311,40 @@ mod tests {
         assert_eq!(remap.get_internal(10001), Some(250));
         assert_eq!(remap.get_external(5), None);
     }
+
+    #[test]
+    fn test_decay_cooling_migrates_to_freeze() {
+        let mut steam = make_mod_material(10001, "Steam");
+        steam.phase = Phase::Gas;
+        steam.decay_rate = 1;
+        steam.decay_threshold = QuantizedTemp(180);
+        steam.decay_product = 10002;
+        let mut water = make_mod_material(10002, "Water");
+        water.phase = Phase::Liquid;
+        water.phase_change_temp = Kelvin(373.0);
+        water.phase_change_product = 10001;
+        // Burns out into ash, which never turns back into fire: real decay
+        let mut fire = make_mod_material(10003, "Fire");
+        fire.decay_rate = 3;
+        fire.decay_threshold = QuantizedTemp(500);
+        fire.decay_product = 10004;
+        let ash = make_mod_material(10004, "Ash");
+
+        let mut table = MaterialTable {
+            materials: vec![steam, water, fire, ash],
+        };
+        assert_eq!(migrate_decay_cooling(&mut table), vec![10001]);
+
+        let steam = table.get(10001).unwrap();
+        assert_eq!(steam.freeze_temp, QuantizedTemp(180).to_kelvin());
+        assert_eq!(steam.freeze_product, 10002);
+        assert_eq!(
+            (steam.decay_rate, steam.decay_threshold, steam.decay_product),
+            (0, QuantizedTemp(0), 0)
+        );
+        assert_eq!(table.get(10003).unwrap().decay_product, 10004);
+
+        // Already migrated entries are left alone
+        assert!(migrate_decay_cooling(&mut table).is_empty());
+    }
 }
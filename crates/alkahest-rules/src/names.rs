@@ -0,0 +1,328 @@
+//! Material name resolution for rule RON sources.
+//!
+//! Rule files may write material fields as names (`input_a: "Fire"`) instead
+//! of raw IDs. Before the source is handed to the RON parser, a small lexer
+//! finds every material field whose value is a string literal and splices in
+//! the resolved numeric ID. Working on the source text keeps line numbers
+//! exact for error reporting and leaves `InteractionRule` as plain `u16`s.
+
+use std::collections::HashMap;
+
+use alkahest_core::material::MaterialTable;
+
+use crate::loader::LoadError;
+
+/// Rule fields that hold a material ID.
+const MATERIAL_FIELDS: &[&str] = &["input_a", "input_b", "output_a", "output_b", "catalyst"];
+
+type Scope<'a> = HashMap<&'a str, Vec<u16>>;
+
+fn scope(table: &MaterialTable) -> Scope<'_> {
+    let mut scope: Scope = HashMap::new();
+    for mat in &table.materials {
+        scope.entry(mat.name.as_str()).or_default().push(mat.id);
+    }
+    scope
+}
+
+/// Name → ID lookup over one or more material tables, searched in order.
+///
+/// Each table is a scope: the first scope that knows a name wins, so a mod's
+/// own materials shadow base materials of the same name. A name that maps to
+/// several IDs within one scope is ambiguous.
+///
+/// A dependency mod's materials can also be named explicitly as
+/// `"Mod Name:Material Name"`, for mods added with [`MaterialNames::with_mod`].
+pub(crate) struct MaterialNames<'a> {
+    scopes: Vec<Scope<'a>>,
+    mods: HashMap<&'a str, Scope<'a>>,
+}
+
+impl<'a> MaterialNames<'a> {
+    pub(crate) fn new(tables: &[&'a MaterialTable]) -> Self {
+        Self {
+            scopes: tables.iter().map(|table| scope(table)).collect(),
+            mods: HashMap::new(),
+        }
+    }
+
+    /// Make `table` reachable as `mod_name:material_name`.
+    pub(crate) fn with_mod(mut self, mod_name: &'a str, table: &'a MaterialTable) -> Self {
+        self.mods.insert(mod_name, scope(table));
+        self
+    }
+
+    fn resolve(&self, name: &str, line: usize) -> Result<u16, LoadError> {
+        let qualified = name
+            .split_once(':')
+            .and_then(|(mod_name, material)| Some((self.mods.get(mod_name)?, material)));
+        let found = match qualified {
+            Some((scope, material)) => scope.get(material),
+            None => self.scopes.iter().find_map(|scope| scope.get(name)),
+        };
+        let ids = found.ok_or_else(|| match name.split_once(':') {
+            Some((mod_name, _)) if qualified.is_none() => LoadError::UnknownModReference {
+                mod_name: mod_name.to_string(),
+                line,
+            },
+            _ => LoadError::UnknownMaterialName {
+                name: name.to_string(),
+                line,
+            },
+        })?;
+        match ids.as_slice() {
+            [id] => Ok(*id),
+            _ => Err(LoadError::AmbiguousMaterialName {
+                name: name.to_string(),
+                line,
+                ids: ids.clone(),
+            }),
+        }
+    }
+}
+
+/// Replace material-name string literals in rule fields with their IDs.
+/// Comments and other string values are left untouched.
+pub(crate) fn resolve_material_names(
+    source: &str,
+    names: &MaterialNames,
+) -> Result<String, LoadError> {
+    let bytes = source.as_bytes();
+    let mut out = String::with_capacity(source.len());
+    let mut copied = 0;
+    let mut pos = 0;
+    let mut line = 1;
+
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'\n' => {
+                line += 1;
+                pos += 1;
+            }
+            b'/' if bytes.get(pos + 1) == Some(&b'/') => {
+                while pos < bytes.len() && bytes[pos] != b'\n' {
+                    pos += 1;
+                }
+            }
+            b'/' if bytes.get(pos + 1) == Some(&b'*') => {
+                pos = skip_block_comment(bytes, pos, &mut line);
+            }
+            b'"' => {
+                pos = skip_string(bytes, pos, &mut line);
+            }
+            c if c.is_ascii_alphabetic() || c == b'_' => {
+                let start = pos;
+                while pos < bytes.len()
+                    && (bytes[pos].is_ascii_alphanumeric() || bytes[pos] == b'_')
+                {
+                    pos += 1;
+                }
+                if !MATERIAL_FIELDS.contains(&&source[start..pos]) {
+                    continue;
+                }
+
+                // `field` `:` `"name"` or `field` `:` `Some("name")`, allowing
+                // whitespace between tokens
+                let mut value = skip_whitespace(bytes, pos, &mut line);
+                if bytes.get(value) != Some(&b':') {
+                    pos = value;
+                    continue;
+                }
+                value = skip_whitespace(bytes, value + 1, &mut line);
+                if source[value..].starts_with("Some") {
+                    let mut inner_line = line;
+                    let paren = skip_whitespace(bytes, value + 4, &mut inner_line);
+                    if bytes.get(paren) == Some(&b'(') {
+                        value = skip_whitespace(bytes, paren + 1, &mut inner_line);
+                        line = inner_line;
+                    }
+                }
+                pos = value;
+                if bytes.get(value) != Some(&b'"') {
+                    continue;
+                }
+
+                let value_line = line;
+                let end = skip_string(bytes, value, &mut line);
+                let name = &source[value + 1..end - 1];
+                let id = names.resolve(name, value_line)?;
+                out.push_str(&source[copied..value]);
+                out.push_str(&id.to_string());
+                copied = end;
+                pos = end;
+            }
+            _ => pos += 1,
+        }
+    }
+
+    out.push_str(&source[copied..]);
+    Ok(out)
+}
+
+pub(crate) fn skip_whitespace(bytes: &[u8], mut pos: usize, line: &mut usize) -> usize {
+    while pos < bytes.len() && bytes[pos].is_ascii_whitespace() {
+        if bytes[pos] == b'\n' {
+            *line += 1;
+        }
+        pos += 1;
+    }
+    pos
+}
+
+/// Skip a string literal starting at the opening quote; returns the index
+/// just past the closing quote (or the end of input if unterminated).
+pub(crate) fn skip_string(bytes: &[u8], start: usize, line: &mut usize) -> usize {
+    let mut pos = start + 1;
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'\\' => pos += 2,
+            b'"' => return pos + 1,
+            b'\n' => {
+                *line += 1;
+                pos += 1;
+            }
+            _ => pos += 1,
+        }
+    }
+    bytes.len()
+}
+
+/// Skip a (possibly nested) block comment; returns the index just past it.
+pub(crate) fn skip_block_comment(bytes: &[u8], start: usize, line: &mut usize) -> usize {
+    let mut depth = 0;
+    let mut pos = start;
+    while pos < bytes.len() {
+        if bytes[pos] == b'/' && bytes.get(pos + 1) == Some(&b'*') {
+            depth += 1;
+            pos += 2;
+        } else if bytes[pos] == b'*' && bytes.get(pos + 1) == Some(&b'/') {
+            depth -= 1;
+            pos += 2;
+            if depth == 0 {
+                return pos;
+            }
+        } else {
+            if bytes[pos] == b'\n' {
+                *line += 1;
+            }
+            pos += 1;
+        }
+    }
+    bytes.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alkahest_core::material::{MaterialDef, Phase};
+    use alkahest_core::types::{Kelvin, QuantizedTemp};
+
+    fn material(id: u16, name: &str) -> MaterialDef {
+        MaterialDef {
+            id,
+            name: name.into(),
+            phase: Phase::Solid,
+            density: 1000.0,
+            color: (0.5, 0.5, 0.5),
+            emission: 0.0,
+            flammability: 0.0,
+            ignition_temp: Kelvin(0.0),
+            decay_rate: 0,
+            decay_threshold: QuantizedTemp(0),
+            decay_product: 0,
+            viscosity: 0.0,
+            repose_angle: 45.0,
+            cohesion: 0.0,
+            erodibility: 0.0,
+            thermal_conductivity: 0.0,
+            heat_capacity: 1.0,
+            radiative_emissivity: 0.0,
+            phase_change_temp: Kelvin(0.0),
+            phase_change_product: 0,
+            latent_heat: 0.0,
+            freeze_temp: Kelvin(0.0),
+            freeze_product: 0,
+            opacity: None,
+            absorption_rate: 0.0,
+            structural_integrity: 0.0,
+            compressive_strength: None,
+            tensile_strength: None,
+            blast_resistance: None,
+            electrical_conductivity: 0.0,
+            electrical_resistance: 0.0,
+            activation_threshold: 0,
+            charge_emission: 0,
+        }
+    }
+
+    fn table(entries: &[(u16, &str)]) -> MaterialTable {
+        MaterialTable {
+            materials: entries.iter().map(|&(id, n)| material(id, n)).collect(),
+        }
+    }
+
+    #[test]
+    fn test_names_replaced_with_ids() {
+        let base = table(&[(5, "Fire"), (8, "Wood"), (9, "Ash")]);
+        let names = MaterialNames::new(&[&base]);
+        let src = r#"(name: "Fire burns Wood", input_a: "Fire", input_b:"Wood", output_a: 5, output_b: "Ash")"#;
+        let out = resolve_material_names(src, &names).unwrap();
+        assert_eq!(
+            out,
+            r#"(name: "Fire burns Wood", input_a: 5, input_b:8, output_a: 5, output_b: 9)"#
+        );
+    }
+
+    #[test]
+    fn test_optional_catalyst_name_replaced() {
+        let base = table(&[(54, "Platinum")]);
+        let names = MaterialNames::new(&[&base]);
+        let out = resolve_material_names("(catalyst: Some( \"Platinum\"))", &names);
+        assert_eq!(out.unwrap(), "(catalyst: Some( 54))");
+    }
+
+    #[test]
+    fn test_comments_and_other_strings_untouched() {
+        let base = table(&[(5, "Fire")]);
+        let names = MaterialNames::new(&[&base]);
+        let src = "// input_a: \"Nope\"\n/* output_b: \"Nope\" */ (name: \"input_a: \\\"x\\\"\")";
+        assert_eq!(resolve_material_names(src, &names).unwrap(), src);
+    }
+
+    #[test]
+    fn test_unknown_name_reports_line() {
+        let base = table(&[(5, "Fire")]);
+        let names = MaterialNames::new(&[&base]);
+        let src = "[\n  (\n    input_a: \"Fire\",\n    input_b: \"Wod\",\n  ),\n]";
+        match resolve_material_names(src, &names) {
+            Err(LoadError::UnknownMaterialName { name, line }) => {
+                assert_eq!(name, "Wod");
+                assert_eq!(line, 4);
+            }
+            other => panic!("expected UnknownMaterialName, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_ambiguous_name_within_scope() {
+        let base = table(&[(31, "Quartz"), (32, "Quartz")]);
+        let names = MaterialNames::new(&[&base]);
+        match resolve_material_names("(\n\ninput_b: \"Quartz\")", &names) {
+            Err(LoadError::AmbiguousMaterialName { name, line, ids }) => {
+                assert_eq!(name, "Quartz");
+                assert_eq!(line, 3);
+                assert_eq!(ids, vec![31, 32]);
+            }
+            other => panic!("expected AmbiguousMaterialName, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_earlier_scope_shadows_later() {
+        let mod_table = table(&[(10001, "Quartz")]);
+        let base = table(&[(31, "Quartz"), (11, "Lava")]);
+        let names = MaterialNames::new(&[&mod_table, &base]);
+        let out = resolve_material_names("(input_a: \"Lava\", input_b: \"Quartz\")", &names);
+        assert_eq!(out.unwrap(), "(input_a: 11, input_b: 10001)");
+    }
+}
@@ -0,0 +1,358 @@
+//! Structured results of merging a mod into the base data.
+//!
+//! `merge_mod` fills a [`MergeReport`] instead of emitting free-form warning
+//! strings, so the mod loader UI and `alkahest-cli mod check` can filter,
+//! count and display each kind of conflict on their own terms.
+
+use std::collections::BTreeMap;
+
+use alkahest_core::rule::{InteractionRule, RuleSet, UNSET_MATERIAL};
+use serde::Serialize;
+
+/// Everything a single `merge_mod` call changed or put at risk.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MergeReport {
+    /// Name of the merged mod, from its manifest.
+    pub mod_name: String,
+    /// Base rules replaced by a mod rule for the same pair.
+    pub overridden_rules: Vec<RuleOverride>,
+    /// Mod materials whose name was already used by an existing material.
+    pub shadowed_materials: Vec<ShadowedMaterial>,
+    /// Mod material IDs moved from the external (10000+) range.
+    pub remapped_ids: Vec<RemappedId>,
+    /// Rules that could fire before the merge but no longer can.
+    pub unreachable_rules: Vec<UnreachableRule>,
+}
+
+/// A base rule and the mod rule that replaced it.
+#[derive(Debug, Clone, Serialize)]
+pub struct RuleOverride {
+    pub before: InteractionRule,
+    /// The mod rule, with internal material IDs.
+    pub after: InteractionRule,
+}
+
+/// One rule field that differs between the two sides of a [`RuleOverride`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldChange {
+    pub field: &'static str,
+    pub before: String,
+    pub after: String,
+}
+
+/// A mod material that has the same name as an existing one.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ShadowedMaterial {
+    pub name: String,
+    /// ID of the material that already had the name.
+    pub existing_id: u16,
+    /// Internal ID of the mod's material.
+    pub mod_id: u16,
+}
+
+/// A mod material ID and where the remap put it.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct RemappedId {
+    pub name: String,
+    pub external: u16,
+    pub internal: u16,
+}
+
+/// A rule that can no longer fire: its earlier candidates for the pair now
+/// always claim the roll, or it names a material the mod shadowed.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UnreachableRule {
+    pub name: String,
+    /// Earlier candidates for the pair whose probabilities cover the rule.
+    pub blocked_by: Vec<String>,
+    /// Inputs, outputs or catalyst of a base rule that a mod material now
+    /// shadows by name.
+    pub shadowed_materials: Vec<String>,
+}
+
+impl MergeReport {
+    pub fn new(mod_name: impl Into<String>) -> Self {
+        Self {
+            mod_name: mod_name.into(),
+            ..Default::default()
+        }
+    }
+
+    /// Number of findings worth a warning. Remapped IDs are expected and not
+    /// counted.
+    pub fn conflict_count(&self) -> usize {
+        self.overridden_rules.len() + self.shadowed_materials.len() + self.unreachable_rules.len()
+    }
+
+    /// One human-readable line per conflict, for logs.
+    pub fn warnings(&self) -> Vec<String> {
+        let mut lines = Vec::with_capacity(self.conflict_count());
+        for o in &self.overridden_rules {
+            let changes: Vec<String> = o
+                .changes()
+                .iter()
+                .map(|c| format!("{} {} -> {}", c.field, c.before, c.after))
+                .collect();
+            lines.push(format!(
+                "Mod '{}': rule '{}' overrides base rule '{}' ({})",
+                self.mod_name,
+                o.after.name,
+                o.before.name,
+                if changes.is_empty() {
+                    "no changes".to_string()
+                } else {
+                    changes.join(", ")
+                }
+            ));
+        }
+        for s in &self.shadowed_materials {
+            lines.push(format!(
+                "Mod '{}': material '{}' (ID {}) shadows existing material ID {}",
+                self.mod_name, s.name, s.mod_id, s.existing_id
+            ));
+        }
+        for u in &self.unreachable_rules {
+            let quoted = |names: &[String]| {
+                names
+                    .iter()
+                    .map(|name| format!("'{name}'"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            let mut reasons = Vec::new();
+            if !u.blocked_by.is_empty() {
+                reasons.push(format!("covered by {}", quoted(&u.blocked_by)));
+            }
+            if !u.shadowed_materials.is_empty() {
+                reasons.push(format!("shadowed {}", quoted(&u.shadowed_materials)));
+            }
+            lines.push(format!(
+                "Mod '{}': rule '{}' can no longer fire ({})",
+                self.mod_name,
+                u.name,
+                reasons.join("; ")
+            ));
+        }
+        lines
+    }
+}
+
+impl RuleOverride {
+    /// Fields whose values differ, in declaration order. The name is left
+    /// out; it is shown alongside every override anyway.
+    pub fn changes(&self) -> Vec<FieldChange> {
+        let (b, a) = (&self.before, &self.after);
+        let mut changes = Vec::new();
+        macro_rules! compare {
+            ($($field:ident),*) => {
+                $(
+                    if b.$field != a.$field {
+                        changes.push(FieldChange {
+                            field: stringify!($field),
+                            before: format!("{:?}", b.$field),
+                            after: format!("{:?}", a.$field),
+                        });
+                    }
+                )*
+            };
+        }
+        compare!(
+            input_a,
+            input_b,
+            output_a,
+            output_b,
+            probability,
+            temp_delta,
+            min_temp,
+            max_temp,
+            pressure_delta,
+            min_charge,
+            max_charge,
+            min_pressure,
+            max_pressure,
+            catalyst,
+            oxygen
+        );
+        changes
+    }
+}
+
+/// Indices of explicit rules whose earlier candidates add up to a certain
+/// roll wherever the rule's own conditions hold, each with the indices of
+/// those candidates. Candidates gated on a catalyst only claim the roll when
+/// it is present, so they never block a later rule on their own.
+pub(crate) fn unreachable_rules(rules: &RuleSet) -> BTreeMap<usize, Vec<usize>> {
+    let mut pairs: BTreeMap<(u16, u16), Vec<usize>> = BTreeMap::new();
+    for (i, rule) in rules.rules.iter().enumerate() {
+        if rule.input_a == UNSET_MATERIAL || rule.input_b == UNSET_MATERIAL || rule.is_pattern() {
+            continue;
+        }
+        let key = (
+            rule.input_a.min(rule.input_b),
+            rule.input_a.max(rule.input_b),
+        );
+        pairs.entry(key).or_default().push(i);
+    }
+
+    let mut unreachable = BTreeMap::new();
+    for candidates in pairs.values() {
+        for (n, &i) in candidates.iter().enumerate() {
+            let rule = &rules.rules[i];
+            let blockers: Vec<usize> = candidates[..n]
+                .iter()
+                .copied()
+                .filter(|&j| {
+                    let earlier = &rules.rules[j];
+                    earlier.catalyst.is_none() && conditions_cover(earlier, rule)
+                })
+                .collect();
+            let claimed: f32 = blockers
+                .iter()
+                .map(|&j| rules.rules[j].probability.clamp(0.0, 1.0))
+                .sum();
+            if !blockers.is_empty() && claimed >= 1.0 - f32::EPSILON {
+                unreachable.insert(i, blockers);
+            }
+        }
+    }
+    unreachable
+}
+
+/// Whether every temperature, charge, pressure and oxygen level that
+/// satisfies `inner` also satisfies `outer`.
+fn conditions_cover(outer: &InteractionRule, inner: &InteractionRule) -> bool {
+    fn covers(outer: (u32, u32), inner: (u32, u32)) -> bool {
+        let max = |m: u32| if m == 0 { u32::MAX } else { m };
+        outer.0 <= inner.0 && max(outer.1) >= max(inner.1)
+    }
+    covers(
+        (outer.min_temp.0.into(), outer.max_temp.0.into()),
+        (inner.min_temp.0.into(), inner.max_temp.0.into()),
+    ) && covers(
+        (outer.min_charge, outer.max_charge),
+        (inner.min_charge, inner.max_charge),
+    ) && covers(
+        (outer.min_pressure, outer.max_pressure),
+        (inner.min_pressure, inner.max_pressure),
+    ) && outer.oxygen <= inner.oxygen
+}
+
+/// Rules in `after` that can no longer fire: those newly covered by earlier
+/// candidates, and base rules naming a shadowed material. `origin[i]` is the
+/// index in `before` of the base rule `after.rules[i]` still is, if any.
+pub(crate) fn newly_unreachable(
+    before: &RuleSet,
+    after: &RuleSet,
+    origin: &[Option<usize>],
+    shadowed: &[ShadowedMaterial],
+) -> Vec<UnreachableRule> {
+    let was = unreachable_rules(before);
+    let mut blocked = unreachable_rules(after);
+    blocked.retain(|i, _| origin[*i].is_none_or(|b| !was.contains_key(&b)));
+
+    let mut unreachable = Vec::new();
+    for (i, rule) in after.rules.iter().enumerate() {
+        let blocked_by: Vec<String> = blocked
+            .remove(&i)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|j| after.rules[j].name.clone())
+            .collect();
+        let shadowed_materials: Vec<String> = match origin[i] {
+            Some(_) => {
+                let materials = [
+                    Some(rule.input_a),
+                    Some(rule.input_b),
+                    Some(rule.output_a),
+                    Some(rule.output_b),
+                    rule.catalyst,
+                ];
+                shadowed
+                    .iter()
+                    .filter(|s| materials.contains(&Some(s.existing_id)))
+                    .map(|s| s.name.clone())
+                    .collect()
+            }
+            None => Vec::new(),
+        };
+        if !blocked_by.is_empty() || !shadowed_materials.is_empty() {
+            unreachable.push(UnreachableRule {
+                name: rule.name.clone(),
+                blocked_by,
+                shadowed_materials,
+            });
+        }
+    }
+    unreachable
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alkahest_core::types::QuantizedTemp;
+
+    fn rule(name: &str, probability: f32, min_temp: u16, max_temp: u16) -> InteractionRule {
+        InteractionRule {
+            name: name.into(),
+            input_a: 1,
+            input_b: 2,
+            output_a: 3,
+            output_b: 4,
+            probability,
+            temp_delta: 0,
+            min_temp: QuantizedTemp(min_temp),
+            max_temp: QuantizedTemp(max_temp),
+            pressure_delta: 0,
+            min_charge: 0,
+            max_charge: 0,
+            min_pressure: 0,
+            max_pressure: 0,
+            catalyst: None,
+            oxygen: 0,
+            blast: 0,
+            pattern_a: None,
+            pattern_b: None,
+        }
+    }
+
+    #[test]
+    fn test_rule_unreachable_only_when_fully_covered() {
+        let rules = RuleSet {
+            rules: vec![
+                rule("half", 0.5, 0, 0),
+                rule("warm half", 0.5, 100, 0),
+                rule("hot", 0.3, 200, 500),
+                rule("any", 0.3, 0, 0),
+            ],
+        };
+        // "hot" sits inside both earlier windows; "any" also runs below 100,
+        // where only "half" claims the roll
+        let unreachable = unreachable_rules(&rules);
+        assert_eq!(
+            unreachable.into_iter().collect::<Vec<_>>(),
+            vec![(2, vec![0, 1])]
+        );
+    }
+
+    #[test]
+    fn test_catalyst_candidate_does_not_block() {
+        let mut gated = rule("gated", 1.0, 0, 0);
+        gated.catalyst = Some(9);
+        let rules = RuleSet {
+            rules: vec![gated, rule("fallback", 0.2, 0, 0)],
+        };
+        assert!(unreachable_rules(&rules).is_empty());
+    }
+
+    #[test]
+    fn test_changes_list_differing_fields() {
+        let before = rule("base", 0.5, 0, 0);
+        let mut after = rule("mod", 0.5, 300, 0);
+        after.catalyst = Some(50);
+        let changes = RuleOverride { before, after }.changes();
+        let fields: Vec<&str> = changes.iter().map(|c| c.field).collect();
+        assert_eq!(fields, vec!["min_temp", "catalyst"]);
+        assert_eq!(changes[1].before, "None");
+        assert_eq!(changes[1].after, "Some(50)");
+    }
+}
@@ -0,0 +1,321 @@
+//! Mod load-order resolution.
+//!
+//! Mods declare `depends_on` and `conflicts_with` in their manifests, each
+//! with a semver requirement. The resolver checks those constraints against
+//! the set of mods being loaded and returns a load order in which every mod
+//! comes after its dependencies. Independent mods keep `load_order_hint`
+//! order, then the order they were given in.
+
+use std::collections::{BTreeSet, HashMap};
+
+use alkahest_core::mod_manifest::{ModDependency, ModManifest};
+use semver::{Version, VersionReq};
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq)]
+pub enum ResolveError {
+    #[error("Mod '{name}' is loaded more than once")]
+    DuplicateMod { name: String },
+    #[error("Mod '{name}' has invalid version \"{version}\": {reason}")]
+    InvalidVersion {
+        name: String,
+        version: String,
+        reason: String,
+    },
+    #[error(
+        "Mod '{name}' has invalid version requirement \"{requirement}\" for '{other}': {reason}"
+    )]
+    InvalidRequirement {
+        name: String,
+        other: String,
+        requirement: String,
+        reason: String,
+    },
+    #[error("Mod '{name}' depends on '{dependency}', which is not loaded")]
+    MissingDependency { name: String, dependency: String },
+    #[error("Mod '{name}' requires '{dependency}' {requirement}, but version {found} is loaded")]
+    IncompatibleVersion {
+        name: String,
+        dependency: String,
+        requirement: String,
+        found: String,
+    },
+    #[error("Mod '{name}' conflicts with '{other}' {requirement} (version {found} is loaded)")]
+    Conflict {
+        name: String,
+        other: String,
+        requirement: String,
+        found: String,
+    },
+    #[error("Mod dependency cycle: {}", .cycle.join(" -> "))]
+    DependencyCycle { cycle: Vec<String> },
+}
+
+/// Order `manifests` so that every mod loads after the mods it depends on.
+///
+/// Returns indices into `manifests`. All constraint violations are collected;
+/// a dependency cycle is only reported once the constraints themselves hold.
+pub fn resolve_load_order(manifests: &[ModManifest]) -> Result<Vec<usize>, Vec<ResolveError>> {
+    let mut errors = Vec::new();
+
+    let mut by_name: HashMap<&str, usize> = HashMap::new();
+    for (i, manifest) in manifests.iter().enumerate() {
+        if by_name.insert(manifest.name.as_str(), i).is_some() {
+            errors.push(ResolveError::DuplicateMod {
+                name: manifest.name.clone(),
+            });
+        }
+    }
+
+    let versions: Vec<Option<Version>> = manifests
+        .iter()
+        .map(|m| match Version::parse(&m.version) {
+            Ok(version) => Some(version),
+            Err(e) => {
+                errors.push(ResolveError::InvalidVersion {
+                    name: m.name.clone(),
+                    version: m.version.clone(),
+                    reason: e.to_string(),
+                });
+                None
+            }
+        })
+        .collect();
+
+    // dependencies[i] = indices of the mods that must load before mod i
+    let mut dependencies: Vec<Vec<usize>> = vec![Vec::new(); manifests.len()];
+    for (i, manifest) in manifests.iter().enumerate() {
+        for dep in &manifest.depends_on {
+            let Some(&j) = by_name.get(dep.name.as_str()) else {
+                errors.push(ResolveError::MissingDependency {
+                    name: manifest.name.clone(),
+                    dependency: dep.name.clone(),
+                });
+                continue;
+            };
+            dependencies[i].push(j);
+            if let (Some(req), Some(found)) =
+                (parse_requirement(manifest, dep, &mut errors), &versions[j])
+            {
+                if !req.matches(found) {
+                    errors.push(ResolveError::IncompatibleVersion {
+                        name: manifest.name.clone(),
+                        dependency: dep.name.clone(),
+                        requirement: dep.version.clone(),
+                        found: found.to_string(),
+                    });
+                }
+            }
+        }
+
+        for other in &manifest.conflicts_with {
+            let req = parse_requirement(manifest, other, &mut errors);
+            let Some(&j) = by_name.get(other.name.as_str()) else {
+                continue;
+            };
+            if let (Some(req), Some(found)) = (req, &versions[j]) {
+                if req.matches(found) {
+                    errors.push(ResolveError::Conflict {
+                        name: manifest.name.clone(),
+                        other: other.name.clone(),
+                        requirement: other.version.clone(),
+                        found: found.to_string(),
+                    });
+                }
+            }
+        }
+    }
+
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    // Kahn's algorithm; the ready set is ordered by (load_order_hint, index).
+    let mut remaining: Vec<usize> = dependencies.iter().map(Vec::len).collect();
+    let mut dependents: Vec<Vec<usize>> = vec![Vec::new(); manifests.len()];
+    for (i, deps) in dependencies.iter().enumerate() {
+        for &j in deps {
+            dependents[j].push(i);
+        }
+    }
+    let mut ready: BTreeSet<(u32, usize)> = (0..manifests.len())
+        .filter(|&i| remaining[i] == 0)
+        .map(|i| (manifests[i].load_order_hint, i))
+        .collect();
+
+    let mut order = Vec::with_capacity(manifests.len());
+    while let Some((_, i)) = ready.pop_first() {
+        order.push(i);
+        for &k in &dependents[i] {
+            remaining[k] -= 1;
+            if remaining[k] == 0 {
+                ready.insert((manifests[k].load_order_hint, k));
+            }
+        }
+    }
+
+    if order.len() < manifests.len() {
+        return Err(vec![ResolveError::DependencyCycle {
+            cycle: find_cycle(manifests, &dependencies, &remaining),
+        }]);
+    }
+    Ok(order)
+}
+
+fn parse_requirement(
+    manifest: &ModManifest,
+    dep: &ModDependency,
+    errors: &mut Vec<ResolveError>,
+) -> Option<VersionReq> {
+    match VersionReq::parse(&dep.version) {
+        Ok(req) => Some(req),
+        Err(e) => {
+            errors.push(ResolveError::InvalidRequirement {
+                name: manifest.name.clone(),
+                other: dep.name.clone(),
+                requirement: dep.version.clone(),
+                reason: e.to_string(),
+            });
+            None
+        }
+    }
+}
+
+/// Walk unresolved dependencies from any unresolved mod until one repeats.
+/// Every unresolved mod has at least one unresolved dependency, so the walk
+/// always closes a loop.
+fn find_cycle(
+    manifests: &[ModManifest],
+    dependencies: &[Vec<usize>],
+    remaining: &[usize],
+) -> Vec<String> {
+    let start = (0..manifests.len())
+        .find(|&i| remaining[i] > 0)
+        .expect("an unresolved mod exists");
+    let mut path = vec![start];
+    loop {
+        let current = *path.last().unwrap();
+        let next = dependencies[current]
+            .iter()
+            .copied()
+            .find(|&j| remaining[j] > 0)
+            .expect("unresolved mod has an unresolved dependency");
+        if let Some(pos) = path.iter().position(|&i| i == next) {
+            let mut cycle: Vec<String> = path[pos..]
+                .iter()
+                .map(|&i| manifests[i].name.clone())
+                .collect();
+            cycle.push(manifests[next].name.clone());
+            return cycle;
+        }
+        path.push(next);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn manifest(name: &str, version: &str, hint: u32) -> ModManifest {
+        ModManifest {
+            name: name.into(),
+            version: version.into(),
+            author: "Tester".into(),
+            description: String::new(),
+            load_order_hint: hint,
+            depends_on: Vec::new(),
+            conflicts_with: Vec::new(),
+        }
+    }
+
+    fn dep(name: &str, version: &str) -> ModDependency {
+        ModDependency {
+            name: name.into(),
+            version: version.into(),
+        }
+    }
+
+    #[test]
+    fn test_dependencies_load_first() {
+        let mut tools = manifest("Tools", "1.0.0", 10);
+        tools.depends_on.push(dep("Metals", "^2.1"));
+        let metals = manifest("Metals", "2.3.0", 50);
+        let other = manifest("Other", "0.1.0", 0);
+        let order = resolve_load_order(&[tools, metals, other]).unwrap();
+        assert_eq!(order, vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn test_independent_mods_follow_hint_then_input_order() {
+        let order = resolve_load_order(&[
+            manifest("C", "1.0.0", 100),
+            manifest("A", "1.0.0", 100),
+            manifest("B", "1.0.0", 5),
+        ])
+        .unwrap();
+        assert_eq!(order, vec![2, 0, 1]);
+    }
+
+    #[test]
+    fn test_missing_and_incompatible_dependencies() {
+        let mut a = manifest("A", "1.0.0", 0);
+        a.depends_on.push(dep("B", ">=2.0"));
+        a.depends_on.push(dep("Ghost", "*"));
+        let b = manifest("B", "1.4.2", 0);
+        let errors = resolve_load_order(&[a, b]).unwrap_err();
+        assert_eq!(
+            errors,
+            vec![
+                ResolveError::IncompatibleVersion {
+                    name: "A".into(),
+                    dependency: "B".into(),
+                    requirement: ">=2.0".into(),
+                    found: "1.4.2".into(),
+                },
+                ResolveError::MissingDependency {
+                    name: "A".into(),
+                    dependency: "Ghost".into(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_conflict_only_within_version_range() {
+        let mut a = manifest("A", "1.0.0", 0);
+        a.conflicts_with.push(dep("B", "<1.0"));
+        let old_b = manifest("B", "0.9.0", 0);
+        let new_b = manifest("B", "1.0.0", 0);
+        assert!(resolve_load_order(&[a.clone(), new_b]).is_ok());
+        let errors = resolve_load_order(&[a, old_b]).unwrap_err();
+        assert!(matches!(&errors[..], [ResolveError::Conflict { .. }]));
+    }
+
+    #[test]
+    fn test_cycle_rejected() {
+        let mut a = manifest("A", "1.0.0", 0);
+        a.depends_on.push(dep("B", "*"));
+        let mut b = manifest("B", "1.0.0", 0);
+        b.depends_on.push(dep("C", "*"));
+        let mut c = manifest("C", "1.0.0", 0);
+        c.depends_on.push(dep("A", "*"));
+        let root = manifest("Root", "1.0.0", 0);
+        let errors = resolve_load_order(&[root, a, b, c]).unwrap_err();
+        assert_eq!(
+            errors,
+            vec![ResolveError::DependencyCycle {
+                cycle: vec!["A".into(), "B".into(), "C".into(), "A".into()],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_invalid_versions_reported() {
+        let mut a = manifest("A", "one", 0);
+        a.depends_on.push(dep("B", "not a range"));
+        let b = manifest("B", "1.0.0", 0);
+        let errors = resolve_load_order(&[a, b]).unwrap_err();
+        assert!(matches!(errors[0], ResolveError::InvalidVersion { .. }));
+        assert!(matches!(errors[1], ResolveError::InvalidRequirement { .. }));
+    }
+}
@@ -0,0 +1,308 @@
+//! Unit-tagged temperatures in material and rule RON sources.
+//!
+//! Temperature fields may be written as `500K`, `227C` or `q(250)` (raw
+//! quantized units) instead of the bare number the field stores. Like
+//! material names, the tags are rewritten on the source text before RON
+//! parsing: each value is converted to the field's own unit, Kelvin for
+//! `ignition_temp`/`phase_change_temp`/`freeze_temp` and quantized for the
+//! rest, so line numbers stay exact and the structs keep plain numeric
+//! fields.
+//!
+//! Untagged numbers are left alone and keep meaning the field's own unit.
+//! `temp_delta` is a change on the linear scale, so its conversion does not
+//! depend on the active quantization curve.
+
+use alkahest_core::constants::{TEMP_QUANT_MAX_K, TEMP_QUANT_MAX_VALUE};
+use alkahest_core::math::temp_delta_to_steps;
+use alkahest_core::types::{Kelvin, QuantizedTemp};
+
+use crate::loader::LoadError;
+use crate::names::{skip_block_comment, skip_string, skip_whitespace};
+
+/// How a temperature field stores its value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FieldUnit {
+    /// Absolute temperature in Kelvin (`f32`).
+    Kelvin,
+    /// Absolute temperature in quantized units.
+    Quantized,
+    /// Signed change in linear-scale quantized steps.
+    QuantizedDelta,
+}
+
+const TEMPERATURE_FIELDS: &[(&str, FieldUnit)] = &[
+    ("ignition_temp", FieldUnit::Kelvin),
+    ("phase_change_temp", FieldUnit::Kelvin),
+    ("freeze_temp", FieldUnit::Kelvin),
+    ("decay_threshold", FieldUnit::Quantized),
+    ("min_temp", FieldUnit::Quantized),
+    ("max_temp", FieldUnit::Quantized),
+    ("temp_delta", FieldUnit::QuantizedDelta),
+];
+
+/// A parsed unit-tagged value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Tagged {
+    Kelvin(f32),
+    Celsius(f32),
+    Quantized(i64),
+}
+
+/// Replace unit-tagged temperatures in temperature fields with the number
+/// the field stores. Comments and string contents are left untouched.
+pub(crate) fn resolve_temperature_units(source: &str) -> Result<String, LoadError> {
+    resolve_units(source, |field| {
+        TEMPERATURE_FIELDS
+            .iter()
+            .find(|(name, _)| *name == field)
+            .map(|&(_, unit)| unit)
+    })
+}
+
+/// Replace unit-tagged temperatures in the fields named in `fields`, which
+/// all store quantized units, for RON sources outside the rule data (the
+/// sim's recipe scenes) that take temperatures the same way.
+pub fn resolve_quantized_temperatures(source: &str, fields: &[&str]) -> Result<String, LoadError> {
+    resolve_units(source, |field| {
+        fields.contains(&field).then_some(FieldUnit::Quantized)
+    })
+}
+
+fn resolve_units(
+    source: &str,
+    unit_of: impl Fn(&str) -> Option<FieldUnit>,
+) -> Result<String, LoadError> {
+    let bytes = source.as_bytes();
+    let mut out = String::with_capacity(source.len());
+    let mut copied = 0;
+    let mut pos = 0;
+    let mut line = 1;
+
+    while pos < bytes.len() {
+        match bytes[pos] {
+            b'\n' => {
+                line += 1;
+                pos += 1;
+            }
+            b'/' if bytes.get(pos + 1) == Some(&b'/') => {
+                while pos < bytes.len() && bytes[pos] != b'\n' {
+                    pos += 1;
+                }
+            }
+            b'/' if bytes.get(pos + 1) == Some(&b'*') => {
+                pos = skip_block_comment(bytes, pos, &mut line);
+            }
+            b'"' => {
+                pos = skip_string(bytes, pos, &mut line);
+            }
+            c if c.is_ascii_alphabetic() || c == b'_' => {
+                let start = pos;
+                pos = skip_identifier(bytes, pos);
+                let field = &source[start..pos];
+                let Some(unit) = unit_of(field) else {
+                    continue;
+                };
+
+                let mut value = skip_whitespace(bytes, pos, &mut line);
+                if bytes.get(value) != Some(&b':') {
+                    pos = value;
+                    continue;
+                }
+                value = skip_whitespace(bytes, value + 1, &mut line);
+                pos = value;
+                let value_line = line;
+                let Some((tagged, end)) = parse_tagged(source, value, &mut line) else {
+                    continue;
+                };
+
+                let converted =
+                    convert(tagged, unit).map_err(|reason| LoadError::InvalidTemperature {
+                        field: field.to_string(),
+                        value: source[value..end].to_string(),
+                        line: value_line,
+                        reason,
+                    })?;
+                out.push_str(&source[copied..value]);
+                out.push_str(&converted);
+                copied = end;
+                pos = end;
+            }
+            _ => pos += 1,
+        }
+    }
+
+    out.push_str(&source[copied..]);
+    Ok(out)
+}
+
+fn skip_identifier(bytes: &[u8], mut pos: usize) -> usize {
+    while pos < bytes.len() && (bytes[pos].is_ascii_alphanumeric() || bytes[pos] == b'_') {
+        pos += 1;
+    }
+    pos
+}
+
+/// Skip an optionally signed decimal number; returns the index just past it,
+/// or `None` if there are no digits.
+fn skip_number(bytes: &[u8], start: usize, allow_fraction: bool) -> Option<usize> {
+    let mut pos = start;
+    if matches!(bytes.get(pos), Some(b'-' | b'+')) {
+        pos += 1;
+    }
+    let digits = pos;
+    while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+        pos += 1;
+    }
+    if pos == digits {
+        return None;
+    }
+    if allow_fraction && bytes.get(pos) == Some(&b'.') {
+        pos += 1;
+        while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+            pos += 1;
+        }
+    }
+    Some(pos)
+}
+
+/// Parse `<number>K`, `<number>C` or `q(<integer>)` at `start`. Returns the
+/// value and the index just past it, or `None` for anything else (plain
+/// numbers included), which is left for the RON parser.
+fn parse_tagged(source: &str, start: usize, line: &mut usize) -> Option<(Tagged, usize)> {
+    let bytes = source.as_bytes();
+
+    if bytes.get(start) == Some(&b'q') && skip_identifier(bytes, start) == start + 1 {
+        let mut inner_line = *line;
+        let open = skip_whitespace(bytes, start + 1, &mut inner_line);
+        if bytes.get(open) != Some(&b'(') {
+            return None;
+        }
+        let number = skip_whitespace(bytes, open + 1, &mut inner_line);
+        let number_end = skip_number(bytes, number, false)?;
+        let close = skip_whitespace(bytes, number_end, &mut inner_line);
+        if bytes.get(close) != Some(&b')') {
+            return None;
+        }
+        let value = source[number..number_end].parse().ok()?;
+        *line = inner_line;
+        return Some((Tagged::Quantized(value), close + 1));
+    }
+
+    let number_end = skip_number(bytes, start, true)?;
+    let suffix_end = skip_identifier(bytes, number_end);
+    let value: f32 = source[start..number_end].parse().ok()?;
+    match &source[number_end..suffix_end] {
+        "K" => Some((Tagged::Kelvin(value), suffix_end)),
+        "C" => Some((Tagged::Celsius(value), suffix_end)),
+        _ => None,
+    }
+}
+
+/// Render `tagged` in the unit `unit` stores, as RON source.
+fn convert(tagged: Tagged, unit: FieldUnit) -> Result<String, &'static str> {
+    if unit == FieldUnit::QuantizedDelta {
+        // A change of 1 K is a change of 1 °C
+        let delta = match tagged {
+            Tagged::Kelvin(d) | Tagged::Celsius(d) => d,
+            Tagged::Quantized(q) => return Ok(q.to_string()),
+        };
+        return Ok(temp_delta_to_steps(delta).to_string());
+    }
+
+    let kelvin = match tagged {
+        Tagged::Kelvin(k) => Kelvin(k),
+        Tagged::Celsius(c) => Kelvin::from_celsius(c),
+        Tagged::Quantized(q) => {
+            let q = u16::try_from(q)
+                .ok()
+                .filter(|&q| q <= TEMP_QUANT_MAX_VALUE)
+                .map(QuantizedTemp)
+                .ok_or("quantized temperature must be within 0..=4095")?;
+            return Ok(match unit {
+                FieldUnit::Kelvin => format!("{:?}", q.to_kelvin().0),
+                _ => q.0.to_string(),
+            });
+        }
+    };
+    if kelvin.0 < 0.0 {
+        return Err("temperature is below absolute zero");
+    }
+    match unit {
+        FieldUnit::Kelvin => Ok(format!("{:?}", kelvin.0)),
+        _ if kelvin.0 > TEMP_QUANT_MAX_K => {
+            Err("temperature is above the 8000 K quantization limit")
+        }
+        _ => Ok(kelvin.quantize().0.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alkahest_core::math::temp_to_quantized;
+
+    #[test]
+    fn test_quantized_fields_convert_from_kelvin_and_celsius() {
+        let src = "(min_temp: 500K, max_temp: 227C, decay_threshold: q(250))";
+        assert_eq!(
+            resolve_temperature_units(src).unwrap(),
+            format!(
+                "(min_temp: {}, max_temp: {}, decay_threshold: 250)",
+                temp_to_quantized(500.0),
+                temp_to_quantized(500.15)
+            )
+        );
+    }
+
+    #[test]
+    fn test_kelvin_fields_stay_in_kelvin() {
+        let src = "(ignition_temp: 300C, phase_change_temp: 1811.5K)";
+        assert_eq!(
+            resolve_temperature_units(src).unwrap(),
+            "(ignition_temp: 573.15, phase_change_temp: 1811.5)"
+        );
+        let out = resolve_temperature_units("(ignition_temp: q(4095))").unwrap();
+        assert_eq!(out, "(ignition_temp: 8000.0)");
+    }
+
+    #[test]
+    fn test_delta_keeps_sign() {
+        let out = resolve_temperature_units("(temp_delta: -100K)(temp_delta: 100C)").unwrap();
+        let q = temp_delta_to_steps(100.0);
+        assert_eq!(out, format!("(temp_delta: -{q})(temp_delta: {q})"));
+        let out = resolve_temperature_units("(temp_delta: q(-30))").unwrap();
+        assert_eq!(out, "(temp_delta: -30)");
+    }
+
+    #[test]
+    fn test_untagged_and_unrelated_values_untouched() {
+        let src = "// min_temp: 500K\n(name: \"max_temp: 3C\", min_temp: 120, density: 5K)";
+        assert_eq!(resolve_temperature_units(src).unwrap(), src);
+    }
+
+    #[test]
+    fn test_out_of_range_reports_line() {
+        match resolve_temperature_units("(\n  min_temp: -300C,\n)") {
+            Err(LoadError::InvalidTemperature {
+                field, value, line, ..
+            }) => {
+                assert_eq!(field, "min_temp");
+                assert_eq!(value, "-300C");
+                assert_eq!(line, 2);
+            }
+            other => panic!("expected InvalidTemperature, got {:?}", other),
+        }
+        assert!(resolve_temperature_units("(max_temp: q(5000))").is_err());
+        assert!(resolve_temperature_units("(max_temp: 9000K)").is_err());
+    }
+
+    #[test]
+    fn test_named_fields_resolve_as_quantized() {
+        let out =
+            resolve_quantized_temperatures("(temp: 500K, min_temp: 500K)", &["temp"]).unwrap();
+        assert_eq!(
+            out,
+            format!("(temp: {}, min_temp: 500K)", temp_to_quantized(500.0))
+        );
+    }
+}
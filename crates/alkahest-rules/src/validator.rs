@@ -1,9 +1,14 @@
-use alkahest_core::constants::{DIFFUSION_RATE, ELECTRICAL_DIFFUSION_RATE, TEMP_QUANT_MAX_VALUE};
-use alkahest_core::material::MaterialTable;
-use alkahest_core::rule::RuleSet;
-use std::collections::HashSet;
+use alkahest_core::aux_channel::{BLAST, OXYGEN_DEFICIT};
+use alkahest_core::constants::{
+    DIFFUSION_RATE, ELECTRICAL_DIFFUSION_RATE, MAX_PRESSURE, MAX_RULES_PER_PAIR,
+    TEMP_QUANT_MAX_VALUE,
+};
+use alkahest_core::material::{MaterialTable, Phase};
+use alkahest_core::rule::{InteractionRule, RuleSet, UNSET_MATERIAL};
+use std::collections::{BTreeMap, HashSet};
 use thiserror::Error;
 
+use crate::compiler::{expand_patterns, PatternExpansion};
 use crate::defaults::MOD_ID_START;
 
 #[derive(Debug, Error)]
@@ -18,10 +23,53 @@ pub enum ValidationError {
     DecayThresholdExceedsMax { name: String, value: u32, max: u16 },
     #[error("Material '{name}' thermal_conductivity {value} out of range [0.0, 1.0] (C-DATA-4)")]
     ThermalConductivityOutOfRange { name: String, value: f32 },
-    #[error("CFL stability violated: DIFFUSION_RATE({rate}) * max_conductivity({conductivity}) * 26 = {product} >= 1.0")]
+    #[error("Material '{name}' heat_capacity {value} must be positive")]
+    HeatCapacityOutOfRange { name: String, value: f32 },
+    #[error("Material '{name}' radiative_emissivity {value} out of range [0.0, 1.0]")]
+    RadiativeEmissivityOutOfRange { name: String, value: f32 },
+    #[error("Material '{name}' repose_angle {value} out of range [0.0, 90.0]")]
+    ReposeAngleOutOfRange { name: String, value: f32 },
+    #[error("Material '{name}' cohesion {value} out of range [0.0, 1.0]")]
+    CohesionOutOfRange { name: String, value: f32 },
+    #[error("Material '{name}' erodibility {value} out of range [0.0, 1.0]")]
+    ErodibilityOutOfRange { name: String, value: f32 },
+    #[error("Material '{name}' latent_heat {value} must not be negative")]
+    LatentHeatOutOfRange { name: String, value: f32 },
+    #[error("Material '{name}' {field} {value} must not be negative")]
+    StrengthOutOfRange {
+        name: String,
+        field: &'static str,
+        value: f32,
+    },
+    #[error("Material '{name}' freeze_product {id} does not exist")]
+    UnknownFreezeProduct { name: String, id: u16 },
+    #[error("Material '{name}' freezes into '{product}', which is not a more condensed phase ({from:?} -> {to:?})")]
+    FreezeNotCondensing {
+        name: String,
+        product: String,
+        from: Phase,
+        to: Phase,
+    },
+    #[error("Material '{name}' freezes into '{product}', whose phase change leads to ID {melts_into} instead of back")]
+    UnpairedPhaseChange {
+        name: String,
+        product: String,
+        melts_into: u16,
+    },
+    #[error("Material '{name}' freeze_temp {freeze}K is not below '{product}' phase_change_temp {melt}K (no hysteresis)")]
+    NoPhaseChangeHysteresis {
+        name: String,
+        product: String,
+        freeze: f32,
+        melt: f32,
+    },
+    #[error("CFL stability violated for '{name}': DIFFUSION_RATE({rate}) * conductivity({conductivity}) * 26 / heat_capacity({heat_capacity}) = {product} >= 1.0")]
     CflStabilityViolation {
+        name: String,
         rate: f32,
+        /// Conductivity between the material and the best conductor.
         conductivity: f32,
+        heat_capacity: f32,
         product: f32,
     },
     #[error("Material '{name}' electrical_conductivity {value} out of range [0.0, 1.0]")]
@@ -38,12 +86,47 @@ pub enum ValidationError {
     },
     #[error("Rule '{name}' references unknown material ID {id}")]
     UnknownMaterialRef { name: String, id: u16 },
+    #[error("Rule '{name}' has no {field} and no pattern in its place")]
+    MissingInput { name: String, field: &'static str },
+    #[error("Pattern rule '{name}' matches no materials")]
+    EmptyPattern { name: String },
     #[error(
         "Rule '{name}' has temp_delta > 0 without transforming any material (C-DATA-3: energy from nothing)"
     )]
     EnergyFromNothing { name: String },
     #[error("Potential infinite loop: rules '{a}' and '{b}' form A->B->A cycle with overlapping temp ranges")]
     InfiniteLoop { a: String, b: String },
+    #[error("Rule '{name}' {field} {value} out of range [0, {max}]")]
+    PressureOutOfRange {
+        name: String,
+        field: &'static str,
+        value: u32,
+        max: u32,
+    },
+    #[error("Rule '{name}' oxygen {value} is more than a fresh air cell holds ({max})")]
+    OxygenOutOfRange { name: String, value: u32, max: u32 },
+    #[error("Rule '{name}' blast {value} is stronger than a blast front can be ({max})")]
+    BlastOutOfRange { name: String, value: u32, max: u32 },
+    #[error("Material pair ({a}, {b}) has {count} candidate rules (max {max})")]
+    TooManyPairRules {
+        a: u16,
+        b: u16,
+        count: usize,
+        max: u32,
+    },
+    #[error("Rule '{name}' is starved: earlier rules for the same pair with overlapping conditions already claim probability {claimed:.2}")]
+    StarvedRule { name: String, claimed: f32 },
+}
+
+impl ValidationError {
+    /// Heuristic findings that flag questionable data without making it
+    /// unusable. Loaders report these and carry on.
+    pub fn is_warning(&self) -> bool {
+        matches!(
+            self,
+            ValidationError::InfiniteLoop { .. } | ValidationError::StarvedRule { .. }
+        )
+    }
 }
 
 /// Validate a material table for constraint compliance.
@@ -60,17 +143,17 @@ pub fn validate_materials(table: &MaterialTable) -> Result<(), Vec<ValidationErr
 
     // Check property ranges (C-DATA-4)
     for mat in &table.materials {
-        if mat.ignition_temp > 8000.0 {
+        if mat.ignition_temp.0 > 8000.0 {
             errors.push(ValidationError::IgnitionTempExceedsMax {
                 name: mat.name.clone(),
-                value: mat.ignition_temp,
+                value: mat.ignition_temp.0,
                 max: 8000.0,
             });
         }
-        if mat.decay_threshold > TEMP_QUANT_MAX_VALUE as u32 {
+        if mat.decay_threshold.0 > TEMP_QUANT_MAX_VALUE {
             errors.push(ValidationError::DecayThresholdExceedsMax {
                 name: mat.name.clone(),
-                value: mat.decay_threshold,
+                value: mat.decay_threshold.0.into(),
                 max: TEMP_QUANT_MAX_VALUE,
             });
         }
@@ -82,6 +165,63 @@ pub fn validate_materials(table: &MaterialTable) -> Result<(), Vec<ValidationErr
             });
         }
 
+        // Written so NaN fails too
+        if !(mat.heat_capacity > 0.0 && mat.heat_capacity.is_finite()) {
+            errors.push(ValidationError::HeatCapacityOutOfRange {
+                name: mat.name.clone(),
+                value: mat.heat_capacity,
+            });
+        }
+
+        if !(0.0..=1.0).contains(&mat.radiative_emissivity) {
+            errors.push(ValidationError::RadiativeEmissivityOutOfRange {
+                name: mat.name.clone(),
+                value: mat.radiative_emissivity,
+            });
+        }
+
+        if !(0.0..=90.0).contains(&mat.repose_angle) {
+            errors.push(ValidationError::ReposeAngleOutOfRange {
+                name: mat.name.clone(),
+                value: mat.repose_angle,
+            });
+        }
+
+        if !(0.0..=1.0).contains(&mat.cohesion) {
+            errors.push(ValidationError::CohesionOutOfRange {
+                name: mat.name.clone(),
+                value: mat.cohesion,
+            });
+        }
+
+        if !(0.0..=1.0).contains(&mat.erodibility) {
+            errors.push(ValidationError::ErodibilityOutOfRange {
+                name: mat.name.clone(),
+                value: mat.erodibility,
+            });
+        }
+
+        if !(mat.latent_heat >= 0.0 && mat.latent_heat.is_finite()) {
+            errors.push(ValidationError::LatentHeatOutOfRange {
+                name: mat.name.clone(),
+                value: mat.latent_heat,
+            });
+        }
+
+        for (field, value) in [
+            ("compressive_strength", mat.compressive_strength),
+            ("tensile_strength", mat.tensile_strength),
+            ("blast_resistance", mat.blast_resistance),
+        ] {
+            if let Some(value) = value.filter(|v| !(*v >= 0.0 && v.is_finite())) {
+                errors.push(ValidationError::StrengthOutOfRange {
+                    name: mat.name.clone(),
+                    field,
+                    value,
+                });
+            }
+        }
+
         if mat.electrical_conductivity < 0.0 || mat.electrical_conductivity > 1.0 {
             errors.push(ValidationError::ElectricalConductivityOutOfRange {
                 name: mat.name.clone(),
@@ -104,19 +244,67 @@ pub fn validate_materials(table: &MaterialTable) -> Result<(), Vec<ValidationErr
         }
     }
 
-    // CFL stability check: DIFFUSION_RATE * max_conductivity * 26 < 1.0
+    // Downward phase changes must undo an upward one: the product melts or
+    // boils straight back into the material, at a higher temperature so a
+    // voxel between the two does not flip every tick.
+    for mat in &table.materials {
+        if mat.freeze_temp.0 <= 0.0 {
+            continue;
+        }
+        let Some(product) = table.get(mat.freeze_product) else {
+            errors.push(ValidationError::UnknownFreezeProduct {
+                name: mat.name.clone(),
+                id: mat.freeze_product,
+            });
+            continue;
+        };
+        if condensation_rank(product.phase) <= condensation_rank(mat.phase) {
+            errors.push(ValidationError::FreezeNotCondensing {
+                name: mat.name.clone(),
+                product: product.name.clone(),
+                from: mat.phase,
+                to: product.phase,
+            });
+        }
+        if product.phase_change_temp.0 <= 0.0 || product.phase_change_product != mat.id {
+            errors.push(ValidationError::UnpairedPhaseChange {
+                name: mat.name.clone(),
+                product: product.name.clone(),
+                melts_into: product.phase_change_product,
+            });
+        } else if mat.freeze_temp.quantize() >= product.phase_change_temp.quantize() {
+            errors.push(ValidationError::NoPhaseChangeHysteresis {
+                name: mat.name.clone(),
+                product: product.name.clone(),
+                freeze: mat.freeze_temp.0,
+                melt: product.phase_change_temp.0,
+            });
+        }
+    }
+
+    // CFL stability check, per material: the fastest its temperature can
+    // change is next to the best conductor, scaled by its own heat capacity.
+    // DIFFUSION_RATE * (k + max_k) / 2 * 26 / heat_capacity < 1.0
     let max_conductivity = table
         .materials
         .iter()
         .map(|m| m.thermal_conductivity)
         .fold(0.0f32, f32::max);
-    let cfl_product = DIFFUSION_RATE * max_conductivity * 26.0;
-    if cfl_product >= 1.0 {
-        errors.push(ValidationError::CflStabilityViolation {
-            rate: DIFFUSION_RATE,
-            conductivity: max_conductivity,
-            product: cfl_product,
-        });
+    for mat in &table.materials {
+        if mat.heat_capacity <= 0.0 {
+            continue; // Reported above
+        }
+        let conductivity = (mat.thermal_conductivity + max_conductivity) * 0.5;
+        let cfl_product = DIFFUSION_RATE * conductivity * 26.0 / mat.heat_capacity;
+        if cfl_product >= 1.0 {
+            errors.push(ValidationError::CflStabilityViolation {
+                name: mat.name.clone(),
+                rate: DIFFUSION_RATE,
+                conductivity,
+                heat_capacity: mat.heat_capacity,
+                product: cfl_product,
+            });
+        }
     }
 
     // Electrical CFL: ELECTRICAL_DIFFUSION_RATE * max_electrical_conductivity * 6 < 1.0
@@ -141,18 +329,68 @@ pub fn validate_materials(table: &MaterialTable) -> Result<(), Vec<ValidationErr
     }
 }
 
+/// Order of phases from gas to condensed, for checking freeze direction.
+fn condensation_rank(phase: Phase) -> u8 {
+    match phase {
+        Phase::Gas => 0,
+        Phase::Liquid => 1,
+        Phase::Solid | Phase::Powder => 2,
+    }
+}
+
+/// Report how many concrete rules each pattern rule expands into.
+pub fn report_pattern_expansions(
+    rules: &RuleSet,
+    materials: &MaterialTable,
+) -> Vec<PatternExpansion> {
+    expand_patterns(materials, rules).1
+}
+
 /// Validate a rule set against the material table.
+///
+/// Pattern rules are expanded first, so the per-rule checks below run on the
+/// same concrete rules the compiler uploads.
 pub fn validate_rules(
     rules: &RuleSet,
     materials: &MaterialTable,
 ) -> Result<(), Vec<ValidationError>> {
     let mut errors = Vec::new();
 
+    for rule in &rules.rules {
+        let inputs = [
+            ("input_a", rule.input_a, rule.pattern_a.is_some()),
+            ("input_b", rule.input_b, rule.pattern_b.is_some()),
+        ];
+        for (field, id, has_pattern) in inputs {
+            if id == UNSET_MATERIAL && !has_pattern {
+                errors.push(ValidationError::MissingInput {
+                    name: rule.name.clone(),
+                    field,
+                });
+            }
+        }
+    }
+
+    let (expanded, expansions) = expand_patterns(materials, rules);
+    for expansion in &expansions {
+        if expansion.generated == 0 && expansion.shadowed == 0 {
+            errors.push(ValidationError::EmptyPattern {
+                name: expansion.rule.clone(),
+            });
+        }
+    }
+    let rules = &expanded;
+
     let valid_ids: HashSet<u16> = materials.materials.iter().map(|m| m.id).collect();
 
     for rule in &rules.rules {
-        // Check all referenced material IDs exist
-        for &id in &[rule.input_a, rule.input_b, rule.output_a, rule.output_b] {
+        if rule.input_a == UNSET_MATERIAL || rule.input_b == UNSET_MATERIAL {
+            // Already reported as MissingInput
+            continue;
+        }
+        // Check all referenced material IDs exist, including the catalyst
+        let refs = [rule.input_a, rule.input_b, rule.output_a, rule.output_b];
+        for &id in refs.iter().chain(&rule.catalyst) {
             if !valid_ids.contains(&id) {
                 errors.push(ValidationError::UnknownMaterialRef {
                     name: rule.name.clone(),
@@ -167,6 +405,77 @@ pub fn validate_rules(
                 name: rule.name.clone(),
             });
         }
+
+        // Pressure windows gate on the 6-bit pressure field
+        for (field, value) in [
+            ("min_pressure", rule.min_pressure),
+            ("max_pressure", rule.max_pressure),
+        ] {
+            if value > MAX_PRESSURE {
+                errors.push(ValidationError::PressureOutOfRange {
+                    name: rule.name.clone(),
+                    field,
+                    value,
+                    max: MAX_PRESSURE,
+                });
+            }
+        }
+
+        // Oxygen comes out of one cell's 8-bit oxygen deficit
+        if rule.oxygen > OXYGEN_DEFICIT.max() {
+            errors.push(ValidationError::OxygenOutOfRange {
+                name: rule.name.clone(),
+                value: rule.oxygen,
+                max: OXYGEN_DEFICIT.max(),
+            });
+        }
+
+        // Blast seeds one voxel's 8-bit blast strength
+        if rule.blast > BLAST.max() {
+            errors.push(ValidationError::BlastOutOfRange {
+                name: rule.name.clone(),
+                value: rule.blast,
+                max: BLAST.max(),
+            });
+        }
+    }
+
+    // Candidate lists: every rule for a pair becomes one of its candidates.
+    // Overlapping candidates share one roll, so a rule only gets whatever
+    // probability the earlier overlapping ones leave over.
+    let mut pairs: BTreeMap<(u16, u16), Vec<&InteractionRule>> = BTreeMap::new();
+    for rule in &rules.rules {
+        if rule.input_a == UNSET_MATERIAL || rule.input_b == UNSET_MATERIAL {
+            continue;
+        }
+        let key = (
+            rule.input_a.min(rule.input_b),
+            rule.input_a.max(rule.input_b),
+        );
+        pairs.entry(key).or_default().push(rule);
+    }
+    for (&(a, b), candidates) in &pairs {
+        if candidates.len() > MAX_RULES_PER_PAIR as usize {
+            errors.push(ValidationError::TooManyPairRules {
+                a,
+                b,
+                count: candidates.len(),
+                max: MAX_RULES_PER_PAIR,
+            });
+        }
+        for (i, rule) in candidates.iter().enumerate() {
+            let claimed: f32 = candidates[..i]
+                .iter()
+                .filter(|earlier| earlier.conditions_overlap(rule))
+                .map(|earlier| earlier.probability.clamp(0.0, 1.0))
+                .sum();
+            if claimed > 0.0 && claimed + rule.probability > 1.0 + f32::EPSILON {
+                errors.push(ValidationError::StarvedRule {
+                    name: rule.name.clone(),
+                    claimed,
+                });
+            }
+        }
     }
 
     // Infinite loop detection: if rule A->B and B->A with overlapping temp ranges
@@ -193,17 +502,17 @@ pub fn validate_rules(
 
             if forward_cycle && backward_cycle {
                 // Check overlapping temperature ranges
-                let ra_min = ra.min_temp;
-                let ra_max = if ra.max_temp == 0 {
-                    u32::MAX
+                let ra_min = ra.min_temp.0;
+                let ra_max = if ra.max_temp.0 == 0 {
+                    u16::MAX
                 } else {
-                    ra.max_temp
+                    ra.max_temp.0
                 };
-                let rb_min = rb.min_temp;
-                let rb_max = if rb.max_temp == 0 {
-                    u32::MAX
+                let rb_min = rb.min_temp.0;
+                let rb_max = if rb.max_temp.0 == 0 {
+                    u16::MAX
                 } else {
-                    rb.max_temp
+                    rb.max_temp.0
                 };
 
                 if ra_min <= rb_max && rb_min <= ra_max {
@@ -253,8 +562,9 @@ pub fn validate_mod_materials(table: &MaterialTable) -> Result<(), Vec<Validatio
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alkahest_core::material::{MaterialDef, Phase};
-    use alkahest_core::rule::InteractionRule;
+    use alkahest_core::material::MaterialDef;
+    use alkahest_core::rule::{InteractionRule, MaterialPattern};
+    use alkahest_core::types::{Kelvin, QuantizedTemp};
 
     fn make_material(id: u16, name: &str) -> MaterialDef {
         MaterialDef {
@@ -265,15 +575,26 @@ mod tests {
             color: (0.5, 0.5, 0.5),
             emission: 0.0,
             flammability: 0.0,
-            ignition_temp: 0.0,
+            ignition_temp: Kelvin(0.0),
             decay_rate: 0,
-            decay_threshold: 0,
+            decay_threshold: QuantizedTemp(0),
             decay_product: 0,
             viscosity: 0.0,
+            repose_angle: 45.0,
+            cohesion: 0.0,
+            erodibility: 0.0,
             thermal_conductivity: 0.0,
-            phase_change_temp: 0.0,
+            heat_capacity: 1.0,
+            radiative_emissivity: 0.0,
+            phase_change_temp: Kelvin(0.0),
             phase_change_product: 0,
+            latent_heat: 0.0,
+            freeze_temp: Kelvin(0.0),
+            freeze_product: 0,
             structural_integrity: 0.0,
+            compressive_strength: None,
+            tensile_strength: None,
+            blast_resistance: None,
             opacity: None,
             absorption_rate: 0.0,
             electrical_conductivity: 0.0,
@@ -325,7 +646,7 @@ mod tests {
         let table = MaterialTable {
             materials: vec![{
                 let mut m = make_material(0, "TooHot");
-                m.ignition_temp = 9000.0; // exceeds 8000K
+                m.ignition_temp = Kelvin(9000.0); // exceeds 8000K
                 m
             }],
         };
@@ -349,11 +670,18 @@ mod tests {
                 output_b: 9,
                 probability: 1.0,
                 temp_delta: 0,
-                min_temp: 0,
-                max_temp: 0,
+                min_temp: QuantizedTemp(0),
+                max_temp: QuantizedTemp(0),
                 pressure_delta: 0,
                 min_charge: 0,
                 max_charge: 0,
+                min_pressure: 0,
+                max_pressure: 0,
+                catalyst: None,
+                oxygen: 0,
+                blast: 0,
+                pattern_a: None,
+                pattern_b: None,
             }],
         };
         let result = validate_rules(&rules, &table);
@@ -376,11 +704,18 @@ mod tests {
                 output_b: 2, // unchanged
                 probability: 1.0,
                 temp_delta: 100, // positive delta with no transform = energy from nothing
-                min_temp: 0,
-                max_temp: 0,
+                min_temp: QuantizedTemp(0),
+                max_temp: QuantizedTemp(0),
                 pressure_delta: 0,
                 min_charge: 0,
                 max_charge: 0,
+                min_pressure: 0,
+                max_pressure: 0,
+                catalyst: None,
+                oxygen: 0,
+                blast: 0,
+                pattern_a: None,
+                pattern_b: None,
             }],
         };
         let result = validate_rules(&rules, &table);
@@ -391,6 +726,70 @@ mod tests {
             .any(|e| matches!(e, ValidationError::EnergyFromNothing { .. })));
     }
 
+    #[test]
+    fn test_pattern_rules_validated_after_expansion() {
+        let mut table = twelve_materials();
+        table.materials[3].phase = Phase::Liquid; // Water
+        table.materials[4].phase = Phase::Liquid; // Oil
+        let liquid_rule = InteractionRule {
+            name: "Lava boils liquids".into(),
+            input_a: 11,
+            input_b: UNSET_MATERIAL,
+            output_a: 11,
+            output_b: 7,
+            probability: 0.5,
+            temp_delta: 10,
+            min_temp: QuantizedTemp(0),
+            max_temp: QuantizedTemp(0),
+            pressure_delta: 0,
+            min_charge: 0,
+            max_charge: 0,
+            min_pressure: 0,
+            max_pressure: 0,
+            catalyst: None,
+            oxygen: 0,
+            blast: 0,
+            pattern_a: None,
+            pattern_b: Some(MaterialPattern::Phase(Phase::Liquid)),
+        };
+        let rules = RuleSet {
+            rules: vec![liquid_rule.clone()],
+        };
+        assert!(validate_rules(&rules, &table).is_ok());
+
+        let report = report_pattern_expansions(&rules, &table);
+        assert_eq!(report.len(), 1);
+        assert_eq!(report[0].rule, "Lava boils liquids");
+        assert_eq!(report[0].generated, 2);
+
+        let empty = RuleSet {
+            rules: vec![InteractionRule {
+                name: "Nothing matches".into(),
+                pattern_b: Some(MaterialPattern::Category("Exotic".into())),
+                ..liquid_rule.clone()
+            }],
+        };
+        let errors = validate_rules(&empty, &table).unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| matches!(e, ValidationError::EmptyPattern { .. })));
+
+        let missing = RuleSet {
+            rules: vec![InteractionRule {
+                pattern_b: None,
+                ..liquid_rule
+            }],
+        };
+        let errors = validate_rules(&missing, &table).unwrap_err();
+        assert!(errors.iter().any(|e| matches!(
+            e,
+            ValidationError::MissingInput {
+                field: "input_b",
+                ..
+            }
+        )));
+    }
+
     #[test]
     fn test_infinite_loop_detected() {
         let table = MaterialTable {
@@ -411,11 +810,18 @@ mod tests {
                     output_b: 3,
                     probability: 1.0,
                     temp_delta: 0,
-                    min_temp: 0,
-                    max_temp: 0,
+                    min_temp: QuantizedTemp(0),
+                    max_temp: QuantizedTemp(0),
                     pressure_delta: 0,
                     min_charge: 0,
                     max_charge: 0,
+                    min_pressure: 0,
+                    max_pressure: 0,
+                    catalyst: None,
+                    oxygen: 0,
+                    blast: 0,
+                    pattern_a: None,
+                    pattern_b: None,
                 },
                 InteractionRule {
                     name: "BtoA".into(),
@@ -425,11 +831,18 @@ mod tests {
                     output_b: 1,
                     probability: 1.0,
                     temp_delta: 0,
-                    min_temp: 0,
-                    max_temp: 0,
+                    min_temp: QuantizedTemp(0),
+                    max_temp: QuantizedTemp(0),
                     pressure_delta: 0,
                     min_charge: 0,
                     max_charge: 0,
+                    min_pressure: 0,
+                    max_pressure: 0,
+                    catalyst: None,
+                    oxygen: 0,
+                    blast: 0,
+                    pattern_a: None,
+                    pattern_b: None,
                 },
             ],
         };
@@ -488,6 +901,166 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_cfl_accounts_for_heat_capacity() {
+        let material = |id, name: &str, conductivity, heat_capacity| {
+            let mut m = make_material(id, name);
+            m.thermal_conductivity = conductivity;
+            m.heat_capacity = heat_capacity;
+            m
+        };
+        // 0.03 * (0.1 + 1.0) / 2 * 26 = 0.43, stable at capacity 1.0
+        let stable = MaterialTable {
+            materials: vec![
+                material(1, "Copper", 1.0, 1.0),
+                material(2, "Steam", 0.1, 1.0),
+            ],
+        };
+        assert!(validate_materials(&stable).is_ok());
+
+        // The same steam at capacity 0.3 overshoots next to copper: 1.43
+        let unstable = MaterialTable {
+            materials: vec![
+                material(1, "Copper", 1.0, 1.0),
+                material(2, "Steam", 0.1, 0.3),
+            ],
+        };
+        let errors = validate_materials(&unstable).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            &errors[0],
+            ValidationError::CflStabilityViolation { name, .. } if name == "Steam"
+        ));
+    }
+
+    #[test]
+    fn test_heat_capacity_latent_heat_and_emissivity_ranges() {
+        let table = MaterialTable {
+            materials: vec![{
+                let mut m = make_material(1, "Odd");
+                m.heat_capacity = 0.0;
+                m.latent_heat = -5.0;
+                m.radiative_emissivity = 1.5;
+                m
+            }],
+        };
+        let errors = validate_materials(&table).unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| matches!(e, ValidationError::RadiativeEmissivityOutOfRange { .. })));
+        assert!(errors
+            .iter()
+            .any(|e| matches!(e, ValidationError::HeatCapacityOutOfRange { .. })));
+        assert!(errors
+            .iter()
+            .any(|e| matches!(e, ValidationError::LatentHeatOutOfRange { .. })));
+    }
+
+    #[test]
+    fn test_repose_angle_and_cohesion_ranges() {
+        let table = MaterialTable {
+            materials: vec![{
+                let mut m = make_material(1, "Odd");
+                m.repose_angle = 95.0;
+                m.cohesion = -0.5;
+                m
+            }],
+        };
+        let errors = validate_materials(&table).unwrap_err();
+        assert!(matches!(
+            &errors[..],
+            [
+                ValidationError::ReposeAngleOutOfRange { value, .. },
+                ValidationError::CohesionOutOfRange { .. },
+            ] if *value == 95.0
+        ));
+    }
+
+    #[test]
+    fn test_erodibility_range() {
+        let table = MaterialTable {
+            materials: vec![{
+                let mut m = make_material(1, "Silt");
+                m.erodibility = 1.5;
+                m
+            }],
+        };
+        let errors = validate_materials(&table).unwrap_err();
+        assert!(matches!(
+            &errors[..],
+            [ValidationError::ErodibilityOutOfRange { value, .. }] if *value == 1.5
+        ));
+    }
+
+    #[test]
+    fn test_negative_strength_rejected() {
+        let table = MaterialTable {
+            materials: vec![{
+                let mut m = make_material(1, "Brittle");
+                m.compressive_strength = Some(100.0);
+                m.tensile_strength = Some(-1.0);
+                m
+            }],
+        };
+        let errors = validate_materials(&table).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            errors[0],
+            ValidationError::StrengthOutOfRange {
+                field: "tensile_strength",
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_freeze_must_pair_with_phase_change() {
+        let pair = |freeze: f32, boil: f32| {
+            let mut water = make_material(3, "Water");
+            water.phase = Phase::Liquid;
+            water.phase_change_temp = Kelvin(boil);
+            water.phase_change_product = 7;
+            let mut steam = make_material(7, "Steam");
+            steam.phase = Phase::Gas;
+            steam.freeze_temp = Kelvin(freeze);
+            steam.freeze_product = 3;
+            MaterialTable {
+                materials: vec![water, steam],
+            }
+        };
+        assert!(validate_materials(&pair(363.0, 373.0)).is_ok());
+
+        // Condensing above the boiling point would flip every tick
+        let errors = validate_materials(&pair(380.0, 373.0)).unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| matches!(e, ValidationError::NoPhaseChangeHysteresis { .. })));
+
+        // Water that boils into something else does not pair with steam
+        let mut table = pair(363.0, 373.0);
+        table.materials[0].phase_change_product = 11;
+        let errors = validate_materials(&table).unwrap_err();
+        assert!(errors.iter().any(|e| matches!(
+            e,
+            ValidationError::UnpairedPhaseChange { melts_into: 11, .. }
+        )));
+
+        // Freezing must move to a more condensed phase
+        let mut table = pair(363.0, 373.0);
+        table.materials[0].phase = Phase::Gas;
+        let errors = validate_materials(&table).unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| matches!(e, ValidationError::FreezeNotCondensing { .. })));
+
+        let mut table = pair(363.0, 373.0);
+        table.materials[1].freeze_product = 99;
+        let errors = validate_materials(&table).unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| matches!(e, ValidationError::UnknownFreezeProduct { id: 99, .. })));
+    }
+
     #[test]
     fn test_mod_id_below_range_rejected() {
         let table = MaterialTable {
@@ -622,11 +1195,18 @@ mod tests {
                     output_b: 9, // transforms Wood -> Ash
                     probability: 0.8,
                     temp_delta: 200,
-                    min_temp: 0,
-                    max_temp: 0,
+                    min_temp: QuantizedTemp(0),
+                    max_temp: QuantizedTemp(0),
                     pressure_delta: 0,
                     min_charge: 0,
                     max_charge: 0,
+                    min_pressure: 0,
+                    max_pressure: 0,
+                    catalyst: None,
+                    oxygen: 0,
+                    blast: 0,
+                    pattern_a: None,
+                    pattern_b: None,
                 },
                 InteractionRule {
                     name: "Water+Lava".into(),
@@ -636,11 +1216,18 @@ mod tests {
                     output_b: 1, // Lava -> Stone
                     probability: 1.0,
                     temp_delta: -100,
-                    min_temp: 0,
-                    max_temp: 0,
+                    min_temp: QuantizedTemp(0),
+                    max_temp: QuantizedTemp(0),
                     pressure_delta: 0,
                     min_charge: 0,
                     max_charge: 0,
+                    min_pressure: 0,
+                    max_pressure: 0,
+                    catalyst: None,
+                    oxygen: 0,
+                    blast: 0,
+                    pattern_a: None,
+                    pattern_b: None,
                 },
             ],
         };
@@ -652,7 +1239,7 @@ mod tests {
         let table = MaterialTable {
             materials: vec![{
                 let mut m = make_material(0, "OverDecay");
-                m.decay_threshold = 5000; // exceeds TEMP_QUANT_MAX_VALUE (4095)
+                m.decay_threshold = QuantizedTemp(5000); // exceeds TEMP_QUANT_MAX_VALUE (4095)
                 m
             }],
         };
@@ -703,11 +1290,18 @@ mod tests {
                     output_b: 9,
                     probability: 1.0,
                     temp_delta: 0,
-                    min_temp: 0,
-                    max_temp: 0,
+                    min_temp: QuantizedTemp(0),
+                    max_temp: QuantizedTemp(0),
                     pressure_delta: 0,
                     min_charge: 0,
                     max_charge: 0,
+                    min_pressure: 0,
+                    max_pressure: 0,
+                    catalyst: None,
+                    oxygen: 0,
+                    blast: 0,
+                    pattern_a: None,
+                    pattern_b: None,
                 },
                 InteractionRule {
                     name: "BadRef2".into(),
@@ -717,11 +1311,18 @@ mod tests {
                     output_b: 0,
                     probability: 1.0,
                     temp_delta: 0,
-                    min_temp: 0,
-                    max_temp: 0,
+                    min_temp: QuantizedTemp(0),
+                    max_temp: QuantizedTemp(0),
                     pressure_delta: 0,
                     min_charge: 0,
                     max_charge: 0,
+                    min_pressure: 0,
+                    max_pressure: 0,
+                    catalyst: None,
+                    oxygen: 0,
+                    blast: 0,
+                    pattern_a: None,
+                    pattern_b: None,
                 },
             ],
         };
@@ -762,11 +1363,18 @@ mod tests {
                     output_b: 3,
                     probability: 1.0,
                     temp_delta: 0,
-                    min_temp: 100,
-                    max_temp: 200,
+                    min_temp: QuantizedTemp(100),
+                    max_temp: QuantizedTemp(200),
                     pressure_delta: 0,
                     min_charge: 0,
                     max_charge: 0,
+                    min_pressure: 0,
+                    max_pressure: 0,
+                    catalyst: None,
+                    oxygen: 0,
+                    blast: 0,
+                    pattern_a: None,
+                    pattern_b: None,
                 },
                 InteractionRule {
                     name: "BtoA_hot".into(),
@@ -776,11 +1384,18 @@ mod tests {
                     output_b: 1,
                     probability: 1.0,
                     temp_delta: 0,
-                    min_temp: 300,
-                    max_temp: 400,
+                    min_temp: QuantizedTemp(300),
+                    max_temp: QuantizedTemp(400),
                     pressure_delta: 0,
                     min_charge: 0,
                     max_charge: 0,
+                    min_pressure: 0,
+                    max_pressure: 0,
+                    catalyst: None,
+                    oxygen: 0,
+                    blast: 0,
+                    pattern_a: None,
+                    pattern_b: None,
                 },
             ],
         };
@@ -789,4 +1404,177 @@ mod tests {
             "disjoint temp ranges should not be flagged as infinite loop"
         );
     }
+
+    fn candidate(name: &str, probability: f32, min_temp: u16, max_temp: u16) -> InteractionRule {
+        InteractionRule {
+            name: name.into(),
+            input_a: 0,
+            input_b: 1,
+            output_a: 0,
+            output_b: 1,
+            probability,
+            temp_delta: 0,
+            min_temp: QuantizedTemp(min_temp),
+            max_temp: QuantizedTemp(max_temp),
+            pressure_delta: 0,
+            min_charge: 0,
+            max_charge: 0,
+            min_pressure: 0,
+            max_pressure: 0,
+            catalyst: None,
+            oxygen: 0,
+            blast: 0,
+            pattern_a: None,
+            pattern_b: None,
+        }
+    }
+
+    #[test]
+    fn test_starved_candidate_reported() {
+        let table = MaterialTable {
+            materials: vec![make_material(0, "A"), make_material(1, "B")],
+        };
+        let mut second = candidate("second", 0.5, 0, 0);
+        // Written from B's side, still the same unordered pair
+        std::mem::swap(&mut second.input_a, &mut second.input_b);
+        let rules = RuleSet {
+            rules: vec![candidate("first", 0.8, 0, 0), second],
+        };
+        let errors = validate_rules(&rules, &table).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].is_warning());
+        assert!(matches!(
+            &errors[0],
+            ValidationError::StarvedRule { name, claimed }
+                if name == "second" && (*claimed - 0.8).abs() < 1e-6
+        ));
+    }
+
+    #[test]
+    fn test_disjoint_candidates_not_starved() {
+        let table = MaterialTable {
+            materials: vec![make_material(0, "A"), make_material(1, "B")],
+        };
+        let rules = RuleSet {
+            rules: vec![
+                candidate("cool", 1.0, 0, 500),
+                candidate("hot", 1.0, 501, 0),
+            ],
+        };
+        assert!(validate_rules(&rules, &table).is_ok());
+    }
+
+    #[test]
+    fn test_too_many_pair_candidates_rejected() {
+        let table = MaterialTable {
+            materials: vec![make_material(0, "A"), make_material(1, "B")],
+        };
+        let rules = RuleSet {
+            rules: (0..=MAX_RULES_PER_PAIR as u16)
+                .map(|i| candidate(&format!("band{i}"), 1.0, i * 100 + 1, i * 100 + 100))
+                .collect(),
+        };
+        let errors = validate_rules(&rules, &table).unwrap_err();
+        assert!(errors.iter().any(|e| !e.is_warning()
+            && matches!(e, ValidationError::TooManyPairRules { a: 0, b: 1, .. })));
+    }
+
+    #[test]
+    fn test_pressure_window_out_of_range_rejected() {
+        let table = MaterialTable {
+            materials: vec![make_material(0, "A"), make_material(1, "B")],
+        };
+        let rules = RuleSet {
+            rules: vec![InteractionRule {
+                min_pressure: 40,
+                max_pressure: 64,
+                ..candidate("squeeze", 0.5, 0, 0)
+            }],
+        };
+        let errors = validate_rules(&rules, &table).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(matches!(
+            &errors[0],
+            ValidationError::PressureOutOfRange {
+                field: "max_pressure",
+                value: 64,
+                ..
+            }
+        ));
+    }
+
+    #[test]
+    fn test_oxygen_out_of_range_rejected() {
+        let table = MaterialTable {
+            materials: vec![make_material(0, "A"), make_material(1, "B")],
+        };
+        let rules = RuleSet {
+            rules: vec![InteractionRule {
+                oxygen: 255,
+                ..candidate("breathe", 0.5, 0, 0)
+            }],
+        };
+        assert!(validate_rules(&rules, &table).is_ok());
+        let rules = RuleSet {
+            rules: vec![InteractionRule {
+                oxygen: 256,
+                ..candidate("gasp", 0.5, 0, 0)
+            }],
+        };
+        let errors = validate_rules(&rules, &table).unwrap_err();
+        assert!(matches!(
+            &errors[..],
+            [ValidationError::OxygenOutOfRange {
+                value: 256,
+                max: 255,
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn test_blast_out_of_range_rejected() {
+        let table = MaterialTable {
+            materials: vec![make_material(0, "A"), make_material(1, "B")],
+        };
+        let rules = RuleSet {
+            rules: vec![
+                InteractionRule {
+                    blast: 255,
+                    ..candidate("bang", 0.5, 0, 0)
+                },
+                InteractionRule {
+                    blast: 300,
+                    ..candidate("kaboom", 0.5, 0, 0)
+                },
+            ],
+        };
+        let errors = validate_rules(&rules, &table).unwrap_err();
+        assert!(matches!(
+            &errors[..],
+            [ValidationError::BlastOutOfRange {
+                value: 300,
+                max: 255,
+                ..
+            }]
+        ));
+    }
+
+    #[test]
+    fn test_unknown_catalyst_rejected() {
+        let table = MaterialTable {
+            materials: vec![make_material(0, "A"), make_material(1, "B")],
+        };
+        let rules = RuleSet {
+            rules: vec![InteractionRule {
+                catalyst: Some(77),
+                ..candidate("catalyzed", 0.5, 0, 0)
+            }],
+        };
+        let errors = validate_rules(&rules, &table).unwrap_err();
+        assert!(matches!(
+            &errors[..],
+            [ValidationError::UnknownMaterialRef { id: 77, .. }]
+        ));
+    }
 }
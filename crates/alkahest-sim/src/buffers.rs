@@ -1,12 +1,14 @@
-use alkahest_core::constants::{BYTES_PER_CHUNK, MAX_CHUNK_SLOTS, VOXELS_PER_CHUNK};
+use alkahest_core::aux_channel;
+use alkahest_core::constants::{BYTES_PER_CHUNK, MAX_CHUNK_SLOTS};
 
 /// Total byte size of one chunk's voxel data (32^3 * 8 bytes = 256 KB).
 pub const CHUNK_BUFFER_SIZE: u64 = BYTES_PER_CHUNK as u64;
 
-/// Total byte size of one chunk's charge data (32^3 * 4 bytes = 128 KB).
-/// Each voxel stores 1 u32 for charge (only low 8 bits used, but u32 avoids
-/// WGSL alignment issues and cross-thread write conflicts in packed bytes).
-pub const CHARGE_SLOT_SIZE: u64 = VOXELS_PER_CHUNK as u64 * 4;
+/// Total byte size of one chunk's auxiliary channel data
+/// (32^3 * `aux_channel::WORDS_PER_VOXEL` u32s; 128 KB per word).
+/// Channels are packed into whole u32 words per voxel, which avoids WGSL
+/// alignment issues and cross-thread write conflicts in packed bytes.
+pub const AUX_SLOT_SIZE: u64 = aux_channel::WORDS_PER_CHUNK as u64 * 4;
 
 /// Double-buffered chunk pool for multi-chunk simulation (C-SIM-1).
 ///
@@ -14,21 +16,22 @@ pub const CHARGE_SLOT_SIZE: u64 = VOXELS_PER_CHUNK as u64 * 4;
 /// the other is read-write (next state). Each pool is divided into fixed-size 256 KB slots,
 /// one per loaded chunk. After all passes complete, `swap()` flips the pool roles.
 ///
-/// Additionally, two charge pool buffers store per-voxel electrical charge (M15).
-/// Charge pools follow the same double-buffering and slot layout.
+/// Additionally, two aux pool buffers store the registered auxiliary channels
+/// (`alkahest_core::aux_channel`), electrical charge among them. Aux pools
+/// follow the same double-buffering and slot layout.
 ///
 /// Pool capacity is determined by `min(device.maxBufferSize / CHUNK_BUFFER_SIZE, MAX_CHUNK_SLOTS)`.
 pub struct ChunkPool {
     pools: [wgpu::Buffer; 2],
-    charge_pools: [wgpu::Buffer; 2],
+    aux_pools: [wgpu::Buffer; 2],
     /// 0 or 1: index of the pool currently used for reading.
     read_index: u32,
     /// Number of slots available in each pool.
     slot_count: u32,
     /// Total byte size of each pool buffer.
     pool_byte_size: u64,
-    /// Total byte size of each charge pool buffer.
-    charge_pool_byte_size: u64,
+    /// Total byte size of each aux pool buffer.
+    aux_pool_byte_size: u64,
 }
 
 impl ChunkPool {
@@ -47,12 +50,12 @@ impl ChunkPool {
         }
 
         let pool_byte_size = slot_count as u64 * CHUNK_BUFFER_SIZE;
-        let charge_pool_byte_size = slot_count as u64 * CHARGE_SLOT_SIZE;
+        let aux_pool_byte_size = slot_count as u64 * AUX_SLOT_SIZE;
         log::info!(
-            "ChunkPool: {} slots, {} MB per pool, {} MB per charge pool",
+            "ChunkPool: {} slots, {} MB per pool, {} MB per aux pool",
             slot_count,
             pool_byte_size / (1024 * 1024),
-            charge_pool_byte_size / (1024 * 1024),
+            aux_pool_byte_size / (1024 * 1024),
         );
 
         let usage = wgpu::BufferUsages::STORAGE
@@ -73,27 +76,27 @@ impl ChunkPool {
             mapped_at_creation: false,
         });
 
-        let charge_a = device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("charge-pool-a"),
-            size: charge_pool_byte_size,
+        let aux_a = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("aux-pool-a"),
+            size: aux_pool_byte_size,
             usage,
             mapped_at_creation: false,
         });
 
-        let charge_b = device.create_buffer(&wgpu::BufferDescriptor {
-            label: Some("charge-pool-b"),
-            size: charge_pool_byte_size,
+        let aux_b = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("aux-pool-b"),
+            size: aux_pool_byte_size,
             usage,
             mapped_at_creation: false,
         });
 
         Self {
             pools: [pool_a, pool_b],
-            charge_pools: [charge_a, charge_b],
+            aux_pools: [aux_a, aux_b],
             read_index: 0,
             slot_count,
             pool_byte_size,
-            charge_pool_byte_size,
+            aux_pool_byte_size,
         }
     }
 
@@ -107,14 +110,14 @@ impl ChunkPool {
         &self.pools[1 - self.read_index as usize]
     }
 
-    /// The charge pool buffer used for reading this tick.
-    pub fn charge_read_pool(&self) -> &wgpu::Buffer {
-        &self.charge_pools[self.read_index as usize]
+    /// The aux pool buffer used for reading this tick.
+    pub fn aux_read_pool(&self) -> &wgpu::Buffer {
+        &self.aux_pools[self.read_index as usize]
     }
 
-    /// The charge pool buffer used for writing this tick.
-    pub fn charge_write_pool(&self) -> &wgpu::Buffer {
-        &self.charge_pools[1 - self.read_index as usize]
+    /// The aux pool buffer used for writing this tick.
+    pub fn aux_write_pool(&self) -> &wgpu::Buffer {
+        &self.aux_pools[1 - self.read_index as usize]
     }
 
     /// Swap read/write pool roles. Call after all passes complete.
@@ -137,9 +140,9 @@ impl ChunkPool {
         self.pool_byte_size
     }
 
-    /// Total byte size of each charge pool buffer.
-    pub fn charge_pool_byte_size(&self) -> u64 {
-        self.charge_pool_byte_size
+    /// Total byte size of each aux pool buffer.
+    pub fn aux_pool_byte_size(&self) -> u64 {
+        self.aux_pool_byte_size
     }
 
     /// Byte offset of a given slot within a pool buffer.
@@ -147,9 +150,9 @@ impl ChunkPool {
         slot as u64 * CHUNK_BUFFER_SIZE
     }
 
-    /// Byte offset of a given slot within a charge pool buffer.
-    pub fn charge_slot_byte_offset(slot: u32) -> u64 {
-        slot as u64 * CHARGE_SLOT_SIZE
+    /// Byte offset of a given slot within an aux pool buffer.
+    pub fn aux_slot_byte_offset(slot: u32) -> u64 {
+        slot as u64 * AUX_SLOT_SIZE
     }
 
     /// Upload voxel data for one chunk slot to the write pool.
@@ -167,6 +170,15 @@ impl ChunkPool {
         queue.write_buffer(&self.pools[1], byte_offset, byte_data);
     }
 
+    /// Upload packed aux words for one chunk slot to both aux pools (for loading
+    /// saves).
+    pub fn upload_aux_data_both(&self, queue: &wgpu::Queue, slot: u32, data: &[u32]) {
+        let byte_offset = Self::aux_slot_byte_offset(slot);
+        let byte_data: &[u8] = bytemuck::cast_slice(data);
+        queue.write_buffer(&self.aux_pools[0], byte_offset, byte_data);
+        queue.write_buffer(&self.aux_pools[1], byte_offset, byte_data);
+    }
+
     /// Copy one slot from read pool to write pool (pre-pass copy for simulation).
     pub fn copy_slot_read_to_write(&self, encoder: &mut wgpu::CommandEncoder, slot: u32) {
         let byte_offset = Self::slot_byte_offset(slot);
@@ -179,15 +191,16 @@ impl ChunkPool {
         );
     }
 
-    /// Copy one charge slot from read to write (pre-pass copy for electrical simulation).
-    pub fn copy_charge_slot_read_to_write(&self, encoder: &mut wgpu::CommandEncoder, slot: u32) {
-        let byte_offset = Self::charge_slot_byte_offset(slot);
+    /// Copy one aux slot from read to write (pre-pass copy, so passes that
+    /// update one channel carry the others over).
+    pub fn copy_aux_slot_read_to_write(&self, encoder: &mut wgpu::CommandEncoder, slot: u32) {
+        let byte_offset = Self::aux_slot_byte_offset(slot);
         encoder.copy_buffer_to_buffer(
-            self.charge_read_pool(),
+            self.aux_read_pool(),
             byte_offset,
-            self.charge_write_pool(),
+            self.aux_write_pool(),
             byte_offset,
-            CHARGE_SLOT_SIZE,
+            AUX_SLOT_SIZE,
         );
     }
 }
@@ -203,9 +216,9 @@ mod tests {
     }
 
     #[test]
-    fn test_charge_slot_size() {
-        // 32^3 voxels * 1 u32 * 4 bytes = 131072 bytes = 128 KB
-        assert_eq!(CHARGE_SLOT_SIZE, 131_072);
+    fn test_aux_slot_size() {
+        // 32^3 voxels * 1 u32 * 4 bytes = 131072 bytes = 128 KB per aux word
+        assert_eq!(AUX_SLOT_SIZE, 131_072 * aux_channel::WORDS_PER_VOXEL as u64);
     }
 
     #[test]
@@ -216,9 +229,9 @@ mod tests {
     }
 
     #[test]
-    fn test_charge_slot_byte_offset() {
-        assert_eq!(ChunkPool::charge_slot_byte_offset(0), 0);
-        assert_eq!(ChunkPool::charge_slot_byte_offset(1), 131_072);
-        assert_eq!(ChunkPool::charge_slot_byte_offset(2), 262_144);
+    fn test_aux_slot_byte_offset() {
+        assert_eq!(ChunkPool::aux_slot_byte_offset(0), 0);
+        assert_eq!(ChunkPool::aux_slot_byte_offset(1), AUX_SLOT_SIZE);
+        assert_eq!(ChunkPool::aux_slot_byte_offset(2), 2 * AUX_SLOT_SIZE);
     }
 }
@@ -1,18 +1,46 @@
+use alkahest_core::constants::BALLISTIC_SUBSTEPS;
 use alkahest_core::direction::{GRAVITY_DIRECTIONS, MOVEMENT_DIRECTIONS};
 
 /// A single sub-pass in the movement dispatch schedule.
 ///
-/// Each sub-pass processes voxels moving in one direction,
-/// filtered by checkerboard parity so no two processed voxels
-/// target the same destination cell.
+/// Each sub-pass processes voxels moving in one direction. Parity is taken
+/// along `axis`, an axis the direction moves along, so every source cell has
+/// the parity and every destination the other: no processed voxel moves into
+/// a cell another one is leaving, and no two share a destination.
 #[derive(Debug, Clone, Copy)]
 pub struct SubPass {
     /// The movement direction offset as [x, y, z].
     pub direction: [i32; 3],
-    /// Checkerboard parity: 0 = even cells (x+z even), 1 = odd cells (x+z odd).
+    /// Axis the parity is taken along: 0 = x, 1 = y, 2 = z.
+    pub axis: u32,
+    /// Axis parity: 0 = even coordinate along `axis`, 1 = odd.
     pub parity: u32,
 }
 
+impl SubPass {
+    /// Sub-pass moving along `direction` with the given parity, taken along
+    /// y if the direction has a vertical component, else along x, else z.
+    pub fn new(direction: [i32; 3], parity: u32) -> Self {
+        let axis = if direction[1] != 0 {
+            1
+        } else if direction[0] != 0 {
+            0
+        } else {
+            2
+        };
+        Self {
+            direction,
+            axis,
+            parity,
+        }
+    }
+
+    /// Whether the cell at local `pos` is a source in this sub-pass.
+    pub fn processes(&self, pos: [i32; 3]) -> bool {
+        pos[self.axis as usize].rem_euclid(2) as u32 == self.parity
+    }
+}
+
 /// Build the complete M2 movement sub-pass schedule (C-SIM-2: fixed order every tick).
 ///
 /// For each gravity direction, dispatch twice: even parity then odd parity.
@@ -25,14 +53,8 @@ pub fn build_gravity_schedule() -> Vec<SubPass> {
         let offset = dir.offset();
         let direction = [offset.x, offset.y, offset.z];
         // Even parity first, then odd
-        schedule.push(SubPass {
-            direction,
-            parity: 0,
-        });
-        schedule.push(SubPass {
-            direction,
-            parity: 1,
-        });
+        schedule.push(SubPass::new(direction, 0));
+        schedule.push(SubPass::new(direction, 1));
     }
     schedule
 }
@@ -46,14 +68,48 @@ pub fn build_movement_schedule() -> Vec<SubPass> {
     for dir in MOVEMENT_DIRECTIONS {
         let offset = dir.offset();
         let direction = [offset.x, offset.y, offset.z];
-        schedule.push(SubPass {
-            direction,
-            parity: 0,
-        });
-        schedule.push(SubPass {
-            direction,
-            parity: 1,
-        });
+        schedule.push(SubPass::new(direction, 0));
+        schedule.push(SubPass::new(direction, 1));
+    }
+    schedule
+}
+
+/// A single sub-pass in the ballistic movement schedule.
+///
+/// Moves voxels one cell along one axis, in one sign. Parity is taken along
+/// the same axis, so every source cell has the parity and every destination
+/// the other: no processed voxel moves into a cell another one is leaving,
+/// and no two share a destination.
+#[derive(Debug, Clone, Copy)]
+pub struct BallisticSubPass {
+    /// Axis of travel: 0 = x, 1 = y, 2 = z.
+    pub axis: u32,
+    /// Direction along the axis, -1 or 1.
+    pub sign: i32,
+    /// Axis parity: 0 = even coordinate along `axis`, 1 = odd.
+    pub parity: u32,
+    /// Substep of the tick (0..BALLISTIC_SUBSTEPS).
+    pub substep: u32,
+}
+
+/// Build the ballistic sub-pass schedule: per substep, the y axis then x and
+/// z, each sign, each parity (BALLISTIC_SUBSTEPS x 3 x 2 x 2 sub-passes, in a
+/// fixed order per C-SIM-2).
+pub fn build_ballistic_schedule() -> Vec<BallisticSubPass> {
+    let mut schedule = Vec::with_capacity(BALLISTIC_SUBSTEPS as usize * 12);
+    for substep in 0..BALLISTIC_SUBSTEPS {
+        for axis in [1, 0, 2] {
+            for sign in [-1, 1] {
+                for parity in 0..2 {
+                    schedule.push(BallisticSubPass {
+                        axis,
+                        sign,
+                        parity,
+                        substep,
+                    });
+                }
+            }
+        }
     }
     schedule
 }
@@ -64,18 +120,35 @@ pub fn build_movement_schedule() -> Vec<SubPass> {
 pub struct MovementUniforms {
     /// Direction offset (x, y, z) as i32.
     pub direction: [i32; 3],
-    /// Checkerboard parity (0 or 1).
+    /// Axis parity (0 or 1).
     pub parity: u32,
     /// Current simulation tick number.
     pub tick: u32,
+    /// Axis the parity is taken along: 0 = x, 1 = y, 2 = z.
+    pub axis: u32,
     /// Padding to 32 bytes (aligned for WebGPU uniform requirements).
-    pub _pad: [u32; 3],
+    pub _pad: [u32; 2],
+}
+
+/// GPU-uploadable uniform for a ballistic sub-pass.
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+pub struct BallisticUniforms {
+    pub axis: u32,
+    pub sign: i32,
+    pub parity: u32,
+    pub substep: u32,
+    /// Current simulation tick number.
+    pub tick: u32,
+    /// Number of chunks dispatched this tick (entries in the descriptor table).
+    pub chunk_count: u32,
+    /// Padding to 32 bytes (aligned for WebGPU uniform requirements).
+    pub _pad: [u32; 2],
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use alkahest_core::direction::Direction;
 
     #[test]
     fn test_gravity_schedule_length() {
@@ -164,26 +237,69 @@ mod tests {
     }
 
     #[test]
-    fn test_checkerboard_no_conflict() {
-        // For the Down direction (0,-1,0): source (x,y,z) targets (x,y-1,z).
-        // With parity=0 filtering x+z even, no two sources share a target.
-        let dir = Direction::Down.offset();
-        for parity in 0..2u32 {
+    fn test_movement_sources_and_destinations_disjoint() {
+        // Within a sub-pass no destination is another source, and no two
+        // sources share a destination, for every direction.
+        for sp in build_movement_schedule() {
+            let mut sources = std::collections::HashSet::new();
             let mut targets = std::collections::HashSet::new();
-            for x in 0i32..8 {
-                for z in 0i32..8 {
-                    if ((x + z) as u32 % 2) != parity {
-                        continue;
+            for x in 0i32..6 {
+                for y in 0i32..6 {
+                    for z in 0i32..6 {
+                        let pos = [x, y, z];
+                        if !sp.processes(pos) {
+                            continue;
+                        }
+                        let d = sp.direction;
+                        let target = [x + d[0], y + d[1], z + d[2]];
+                        sources.insert(pos);
+                        assert!(targets.insert(target), "shared target {target:?}");
                     }
-                    for y in 1i32..8 {
-                        let target = (x + dir.x, y + dir.y, z + dir.z);
-                        assert!(
-                            targets.insert(target),
-                            "conflict at target {target:?} in parity {parity}"
-                        );
+                }
+            }
+            assert!(sources.is_disjoint(&targets), "{sp:?}");
+        }
+    }
+
+    #[test]
+    fn test_ballistic_schedule_order() {
+        let schedule = build_ballistic_schedule();
+        assert_eq!(schedule.len(), BALLISTIC_SUBSTEPS as usize * 12);
+        // Falling first within each substep
+        assert_eq!(
+            (schedule[0].axis, schedule[0].sign, schedule[0].substep),
+            (1, -1, 0)
+        );
+        assert_eq!(schedule[12].substep, 1);
+    }
+
+    #[test]
+    fn test_ballistic_sources_and_destinations_disjoint() {
+        // Within a sub-pass no destination is another source, and no two
+        // sources share a destination, for every axis and sign.
+        for sp in build_ballistic_schedule().iter().take(12) {
+            let mut sources = std::collections::HashSet::new();
+            let mut targets = std::collections::HashSet::new();
+            for x in 0i32..6 {
+                for y in 0i32..6 {
+                    for z in 0i32..6 {
+                        let pos = [x, y, z];
+                        if (pos[sp.axis as usize] as u32) % 2 != sp.parity {
+                            continue;
+                        }
+                        let mut target = pos;
+                        target[sp.axis as usize] += sp.sign;
+                        sources.insert(pos);
+                        assert!(targets.insert(target), "shared target {target:?}");
                     }
                 }
             }
+            assert!(sources.is_disjoint(&targets), "{sp:?}");
         }
     }
+
+    #[test]
+    fn test_ballistic_uniforms_size() {
+        assert_eq!(std::mem::size_of::<BallisticUniforms>(), 32);
+    }
 }
@@ -0,0 +1,2085 @@
+//! CPU reference implementation of the simulation tick.
+//!
+//! `CpuSimulator` runs the same eleven passes as `SimPipeline` (commands,
+//! ballistic movement, movement, erosion, reactions, oxygen, thermal,
+//! electrical, pressure, blast, activity) over
+//! `[u32; 2]` voxels and the `CompiledTables` produced by the rule compiler.
+//! Every pass follows its WGSL counterpart line for line, including the
+//! read-pool/write-pool split and the `sim_hash` rolls, so it serves as a
+//! GPU-free oracle for tests and headless tools.
+//!
+//! Structural load is solved on the host between ticks, as for the GPU:
+//! the activity pass notes a tick that broke a structure, and
+//! [`CpuSimulator::solve_structures`] queues what gives way as commands
+//! for the next tick (see [`crate::structural`]).
+//!
+//! Movement and ballistic sub-passes run the GPU schedule from
+//! [`crate::conflict`] in the same order. Neither can race: a sub-pass takes
+//! its parity along an axis of travel, so sources and destinations are
+//! disjoint, and a movement sub-pass decides every move before making any.
+//! The order the CPU visits cells in therefore gives the same result as any
+//! order GPU threads run in, and the shared passes are bit-identical.
+
+use alkahest_core::aux_channel::{
+    self, AuxChannel, BLAST, BLAST_FRONT, CHARGE, COLUMN, HEAD, OXYGEN_DEFICIT, SEDIMENT,
+};
+use alkahest_core::constants::{
+    ACTIVITY_BLAST_MAX, ACTIVITY_BLAST_SHIFT, ACTIVITY_DIRTY, AMBIENT_TEMP_QUANTIZED,
+    BALLISTIC_FRICTION, BALLISTIC_GRAVITY, BALLISTIC_MIN_SPEED, BALLISTIC_RESTITUTION,
+    BALLISTIC_SUBSTEPS, BLAST_DECAY, BLAST_FALLOFF, BLAST_FRAGMENT_SPEED_DIVISOR, BLAST_FRONT_LIVE,
+    BLAST_FRONT_NONE, BLAST_FRONT_SEEDED, BLAST_FRONT_SOURCE, BYTES_PER_CHUNK, CHARGE_DECAY_RATE,
+    CHARGE_MAX, CHUNK_DESC_STRIDE, CHUNK_SIZE, COHESION_MAX_OVERHANG, CONVECTION_THRESHOLD,
+    DIFFUSION_RATE, ELECTRICAL_DIFFUSION_RATE, ENTROPY_DRAIN_RATE, EROSION_MIN_FLOW, FLOW_ACCEL,
+    FLOW_DRAG, FLOW_SPEED_MAX, HYDROSTATIC_DECAY_PERIOD, HYDROSTATIC_PUSH_HEAD,
+    JOULE_HEATING_FACTOR, MAX_PRESSURE, NO_CATALYST, NO_RULE, OXYGEN_DIFFUSION_RATE,
+    OXYGEN_DRAFT_RATE, PRESSURE_DIFFUSION_RATE, RADIATION_MAX_GAP_FRACTION, RADIATION_RADIUS,
+    RADIATION_RATE, SEDIMENT_SETTLE_FLOW, SENTINEL_NEIGHBOR, STRUCTURAL_WEIGHT_PER_DENSITY,
+    TEMP_QUANT_MAX_K, TEMP_QUANT_MAX_VALUE, THERMAL_PRESSURE_FACTOR, VELOCITY_PER_CELL,
+    VOXELS_PER_CHUNK,
+};
+use alkahest_core::math::{step_temperature, world_to_chunk};
+use alkahest_core::types::{ChunkCoord, MaterialId};
+use alkahest_core::voxel::Voxel;
+use alkahest_rules::compiler::{rule_range, GpuMaterialProps, GpuRuleEntry};
+use alkahest_rules::CompiledTables;
+
+use crate::conflict::{
+    build_ballistic_schedule, build_movement_schedule, BallisticSubPass, SubPass,
+};
+use crate::passes::commands::{
+    PendingCommand, SimCommand, MAX_COMMANDS, TOOL_HEAT, TOOL_PLACE, TOOL_PUSH, TOOL_REMOVE,
+};
+use crate::rng::{hash_to_float, sim_hash};
+use crate::structural::{self, Role, StructuralTable, StructuralWorld};
+use std::collections::HashMap;
+
+const AMBIENT_TEMP: u32 = AMBIENT_TEMP_QUANTIZED as u32;
+const TEMP_MAX: u32 = TEMP_QUANT_MAX_VALUE as u32;
+
+const PHASE_GAS: u32 = 0;
+const PHASE_LIQUID: u32 = 1;
+const PHASE_SOLID: u32 = 2;
+const PHASE_POWDER: u32 = 3;
+
+const BRUSH_SINGLE: u32 = 0;
+const BRUSH_SPHERE: u32 = 2;
+
+/// Neighbor order used by reactions.wgsl.
+const REACTION_OFFSETS: [[i32; 3]; 6] = [
+    [0, -1, 0],
+    [0, 1, 0],
+    [0, 0, -1],
+    [0, 0, 1],
+    [1, 0, 0],
+    [-1, 0, 0],
+];
+
+/// Neighbor order used by electrical.wgsl, pressure.wgsl and blast.wgsl.
+const FACE_OFFSETS: [[i32; 3]; 6] = [
+    [1, 0, 0],
+    [-1, 0, 0],
+    [0, 1, 0],
+    [0, -1, 0],
+    [0, 0, 1],
+    [0, 0, -1],
+];
+
+/// Build descriptor data for chunks that have no loaded neighbors.
+///
+/// Each listed slot gets one `CHUNK_DESC_STRIDE` entry with every neighbor set
+/// to `SENTINEL_NEIGHBOR`, so cross-chunk reads return air. Multi-chunk worlds
+/// should use `DispatchList::build_descriptor_data()` from alkahest-world.
+pub fn isolated_chunk_descriptors(slots: &[u32]) -> Vec<u32> {
+    let stride = CHUNK_DESC_STRIDE as usize;
+    let mut data = vec![0u32; slots.len() * stride];
+    for (i, &slot) in slots.iter().enumerate() {
+        let base = i * stride;
+        data[base] = slot * BYTES_PER_CHUNK;
+        for n in 0..26 {
+            data[base + 1 + n] = SENTINEL_NEIGHBOR;
+        }
+    }
+    data
+}
+
+/// CPU mirror of `SimPipeline`: same pool layout, descriptors, and pass order.
+pub struct CpuSimulator {
+    material_props: Vec<GpuMaterialProps>,
+    rule_lookup: Vec<u32>,
+    rule_entries: Vec<GpuRuleEntry>,
+    material_count: u32,
+    rule_hash: u64,
+    temp_lut: Vec<f32>,
+    /// Whether any material is emissive; see `CompiledTables::radiates`.
+    radiates: bool,
+    slot_count: u32,
+    pools: [Vec<[u32; 2]>; 2],
+    /// Packed aux channel words, `aux_channel::WORDS_PER_VOXEL` per voxel.
+    aux_pools: [Vec<u32>; 2],
+    read_index: usize,
+    chunk_descriptors: Vec<u32>,
+    /// World coordinate of each dispatched chunk, in dispatch order.
+    chunk_coords: Vec<ChunkCoord>,
+    structural: StructuralTable,
+    activity_flags: Vec<u32>,
+    /// Whether a tick broke a structure since the last structural solve.
+    structure_broken: bool,
+    movement_schedule: Vec<SubPass>,
+    /// Moves decided in the current movement sub-pass, kept to reuse its
+    /// allocation.
+    moves: Vec<([i32; 3], u32)>,
+    /// Cells a voxel moved into in the even sub-pass of the current
+    /// direction, which the odd one leaves alone.
+    landed: Vec<bool>,
+    ballistic_schedule: Vec<BallisticSubPass>,
+    pending_commands: Vec<PendingCommand>,
+    tick_count: u64,
+}
+
+impl CpuSimulator {
+    /// Create a simulator with `slot_count` chunk slots, all initialized to air.
+    pub fn new(tables: CompiledTables, slot_count: u32) -> Self {
+        let voxels = slot_count as usize * VOXELS_PER_CHUNK as usize;
+        let aux_words = slot_count as usize * aux_channel::WORDS_PER_CHUNK;
+        Self {
+            structural: StructuralTable::from_props(&tables.material_props),
+            radiates: tables.radiates(),
+            material_props: tables.material_props,
+            rule_lookup: tables.rule_lookup,
+            rule_entries: tables.rule_entries,
+            material_count: tables.material_count,
+            rule_hash: tables.rule_hash,
+            temp_lut: tables.temp_lut,
+            slot_count,
+            pools: [vec![[0, 0]; voxels], vec![[0, 0]; voxels]],
+            aux_pools: [vec![0; aux_words], vec![0; aux_words]],
+            read_index: 0,
+            chunk_descriptors: Vec::new(),
+            chunk_coords: Vec::new(),
+            activity_flags: Vec::new(),
+            structure_broken: false,
+            movement_schedule: build_movement_schedule(),
+            moves: Vec::new(),
+            landed: vec![false; voxels],
+            ballistic_schedule: build_ballistic_schedule(),
+            pending_commands: Vec::new(),
+            tick_count: 0,
+        }
+    }
+
+    /// Number of chunk slots in the pool.
+    pub fn slot_count(&self) -> u32 {
+        self.slot_count
+    }
+
+    /// Upload chunk voxel data into the read pool at the given slot.
+    pub fn upload_chunk_data(&mut self, slot: u32, data: &[[u32; 2]]) {
+        let range = Self::slot_range(slot, data.len());
+        self.pools[self.read_index][range].copy_from_slice(data);
+    }
+
+    /// Upload chunk voxel data into both pools at the given slot.
+    pub fn upload_chunk_data_both(&mut self, slot: u32, data: &[[u32; 2]]) {
+        let range = Self::slot_range(slot, data.len());
+        self.pools[0][range.clone()].copy_from_slice(data);
+        self.pools[1][range].copy_from_slice(data);
+    }
+
+    /// Current voxel state of a slot (the read pool after the last tick).
+    pub fn chunk_data(&self, slot: u32) -> &[[u32; 2]] {
+        let range = Self::slot_range(slot, VOXELS_PER_CHUNK as usize);
+        &self.pools[self.read_index][range]
+    }
+
+    /// Upload packed aux channel words into both aux pools at the given slot.
+    pub fn upload_chunk_aux_both(&mut self, slot: u32, words: &[u32]) {
+        let range = Self::aux_slot_range(slot, words.len());
+        self.aux_pools[0][range.clone()].copy_from_slice(words);
+        self.aux_pools[1][range].copy_from_slice(words);
+    }
+
+    /// Current packed aux words of a slot (the aux read pool after the last tick).
+    pub fn chunk_aux(&self, slot: u32) -> &[u32] {
+        let range = Self::aux_slot_range(slot, aux_channel::WORDS_PER_CHUNK);
+        &self.aux_pools[self.read_index][range]
+    }
+
+    /// Current value of one aux channel for every voxel of a slot.
+    pub fn chunk_channel(&self, slot: u32, channel: AuxChannel) -> Vec<u32> {
+        let words = self.chunk_aux(slot);
+        (0..VOXELS_PER_CHUNK as usize)
+            .map(|i| channel.read(words, i))
+            .collect()
+    }
+
+    /// Replace the chunk descriptor table (same layout as the GPU buffer).
+    pub fn upload_chunk_descriptors(&mut self, descriptor_data: &[u32]) {
+        self.chunk_descriptors = descriptor_data.to_vec();
+    }
+
+    /// Replace the world coordinates of the dispatched chunks, in descriptor
+    /// table order. The structural solve only sees chunks with a coordinate.
+    pub fn upload_chunk_coords(&mut self, coords: &[ChunkCoord]) {
+        self.chunk_coords = coords.to_vec();
+    }
+
+    /// Enqueue a player command, whose `chunk_dispatch_idx` indexes the
+    /// coordinates last passed to [`Self::upload_chunk_coords`]. A tick applies
+    /// up to `MAX_COMMANDS`; the rest wait for the ticks after it and still
+    /// reach their chunk if the dispatch order changes. Commands for a chunk
+    /// that is not dispatched are dropped.
+    pub fn enqueue_command(&mut self, cmd: SimCommand) {
+        self.pending_commands
+            .extend(PendingCommand::new(cmd, &self.chunk_coords));
+    }
+
+    /// If a tick broke a structure since the last call, solve the load on
+    /// every dispatched chunk with a coordinate (see [`structural::solve`])
+    /// and queue what gives way as push commands for the next tick. Call it
+    /// between ticks, once the next tick's descriptors and coordinates are
+    /// uploaded, as the host does for `SimPipeline`.
+    pub fn solve_structures(&mut self, active_chunk_count: u32) {
+        if !std::mem::take(&mut self.structure_broken) {
+            return;
+        }
+        let voxels = VOXELS_PER_CHUNK as usize;
+        let read = self.read_index;
+        let mut dispatch = HashMap::new();
+        let mut world = StructuralWorld::new();
+        for (i, &coord) in self.chunk_coords.iter().enumerate() {
+            if i as u32 >= active_chunk_count {
+                break;
+            }
+            let base = self.slot_voxel_offset(i as u32);
+            world.insert(coord, &self.pools[read][base..base + voxels]);
+            dispatch.insert(coord, i as u32);
+        }
+        let report = structural::solve(&world, &self.structural);
+        let commands: Vec<PendingCommand> = structural::launches(&world, &self.structural, &report)
+            .iter()
+            .map(|launch| {
+                let coord = world_to_chunk(launch.pos);
+                PendingCommand {
+                    coord,
+                    cmd: launch.command(dispatch[&coord]),
+                }
+            })
+            .collect();
+        self.pending_commands.extend(commands);
+    }
+
+    /// Per-chunk dirty flags from the last tick, in dispatch order.
+    pub fn activity_flags(&self) -> &[u32] {
+        &self.activity_flags
+    }
+
+    pub fn tick_count(&self) -> u64 {
+        self.tick_count
+    }
+
+    pub fn set_tick_count(&mut self, tick: u64) {
+        self.tick_count = tick;
+    }
+
+    pub fn rule_hash(&self) -> u64 {
+        self.rule_hash
+    }
+
+    /// Run one simulation tick over all active chunks.
+    ///
+    /// `active_slots` is the list of pool slot indices for active chunks, and
+    /// the descriptor table must hold `active_chunk_count` entries.
+    pub fn tick(&mut self, active_chunk_count: u32, active_slots: &[u32]) {
+        if active_chunk_count == 0 {
+            self.pending_commands.clear();
+            return;
+        }
+
+        // Copy read pool → write pool for each active chunk slot
+        let (read, write) = self.split_pools();
+        for &slot in active_slots {
+            let range = Self::slot_range(slot, VOXELS_PER_CHUNK as usize);
+            write.voxels[range.clone()].copy_from_slice(&read.voxels[range]);
+            let range = Self::aux_slot_range(slot, aux_channel::WORDS_PER_CHUNK);
+            write.aux[range.clone()].copy_from_slice(&read.aux[range]);
+        }
+
+        let tick = self.tick_count as u32;
+        self.pass_commands(active_chunk_count);
+        self.pass_ballistic(tick, active_chunk_count);
+        for i in 0..self.movement_schedule.len() {
+            let sub_pass = self.movement_schedule[i];
+            self.pass_movement(sub_pass, tick, active_chunk_count);
+        }
+        self.pass_erosion(tick, active_chunk_count);
+        self.pass_reactions(tick, active_chunk_count);
+        self.pass_oxygen(active_chunk_count);
+        self.pass_thermal(active_chunk_count);
+        self.pass_electrical(active_chunk_count);
+        self.pass_pressure(tick, active_chunk_count);
+        self.pass_blast(active_chunk_count);
+        self.pass_activity(active_chunk_count);
+
+        self.read_index = 1 - self.read_index;
+        self.tick_count += 1;
+        let applied = self.pending_commands.len().min(MAX_COMMANDS as usize);
+        self.pending_commands.drain(..applied);
+    }
+
+    // ── Pass 1: commands ──
+
+    fn pass_commands(&mut self, active_chunk_count: u32) {
+        let coords = &self.chunk_coords[..self.chunk_coords.len().min(active_chunk_count as usize)];
+        let commands: Vec<SimCommand> = self
+            .pending_commands
+            .iter()
+            .take(MAX_COMMANDS as usize)
+            .filter_map(|pending| pending.resolve(coords))
+            .collect();
+        for cmd in &commands {
+            let center = [cmd.pos_x, cmd.pos_y, cmd.pos_z];
+            let chunk_idx = cmd.chunk_dispatch_idx;
+            let radius = cmd.brush_radius as i32;
+
+            if radius == 0 || cmd.brush_shape == BRUSH_SINGLE {
+                self.apply_command(cmd, center, chunk_idx);
+                continue;
+            }
+
+            let r = radius.min(16);
+            for dz in -r..=r {
+                for dy in -r..=r {
+                    for dx in -r..=r {
+                        if cmd.brush_shape == BRUSH_SPHERE && dx * dx + dy * dy + dz * dz > r * r {
+                            continue;
+                        }
+                        let pos = [center[0] + dx, center[1] + dy, center[2] + dz];
+                        self.apply_command(cmd, pos, chunk_idx);
+                    }
+                }
+            }
+        }
+    }
+
+    fn apply_command(&mut self, cmd: &SimCommand, pos: [i32; 3], chunk_idx: u32) {
+        if !in_bounds(pos) {
+            return;
+        }
+        let idx = self.pool_index(pos, chunk_idx);
+        let write = 1 - self.read_index;
+        let current = self.pools[write][idx];
+        let current_mat = material_of(current);
+
+        match cmd.tool_type {
+            TOOL_PLACE => {
+                let mat_id = cmd.material_id;
+                let mut temp = AMBIENT_TEMP;
+                if mat_id > 0 {
+                    let props = self.props(mat_id);
+                    let decay_rate = props.decay_rate as u32;
+                    let decay_threshold = props.decay_threshold as u32;
+                    if decay_rate > 0 && decay_threshold > 0 {
+                        temp = (decay_threshold * 3).min(TEMP_MAX);
+                    }
+                    // Molten and gaseous materials that would freeze at ambient start hot
+                    let freeze_temp_q = props.freeze_temp_quantized as u32;
+                    if freeze_temp_q > AMBIENT_TEMP {
+                        temp = (freeze_temp_q * 3).min(TEMP_MAX);
+                    }
+                }
+                self.pools[write][idx] = Voxel::new(MaterialId(mat_id as u16))
+                    .with_temperature(temp as u16)
+                    .into();
+            }
+            TOOL_REMOVE => {
+                self.pools[write][idx] = [0, 0];
+            }
+            TOOL_HEAT if current_mat != 0 => {
+                let delta = cmd.material_id as i32;
+                let new_temp = (temperature_of(current) as i32 + delta).clamp(0, TEMP_MAX as i32);
+                self.pools[write][idx] = repack(current, current_mat, new_temp as u32);
+            }
+            TOOL_PUSH if current_mat != 0 => {
+                // Direction is packed as three biased-128 bytes
+                let packed = cmd.material_id;
+                let dx = (packed & 0xFF) as i32 - 128;
+                let dy = ((packed >> 8) & 0xFF) as i32 - 128;
+                let dz = ((packed >> 16) & 0xFF) as i32 - 128;
+                let [vx, vy, vz] = velocity_of(current);
+                self.pools[write][idx] = with_velocity(
+                    current,
+                    [vx + dx, vy + dy, vz + dz].map(|v| v.clamp(-127, 127)),
+                );
+            }
+            _ => {}
+        }
+    }
+
+    // ── Pass 2a: ballistic movement ──
+
+    fn pass_ballistic(&mut self, tick: u32, active_chunk_count: u32) {
+        // Only flying voxels start impacts, so a chunk none has flown into
+        // has nothing to step (a CPU-only shortcut; the result is the same)
+        let mut flying = vec![false; active_chunk_count as usize];
+        self.for_each_voxel(active_chunk_count, |sim, pos, chunk_idx| {
+            flying[chunk_idx as usize] |= sim.integrate_ballistic(pos, chunk_idx);
+        });
+        let cs = CHUNK_SIZE as i32;
+        for i in 0..self.ballistic_schedule.len() {
+            let sub_pass = self.ballistic_schedule[i];
+            for chunk_idx in 0..active_chunk_count {
+                if !flying[chunk_idx as usize] {
+                    continue;
+                }
+                for z in 0..cs {
+                    for y in 0..cs {
+                        for x in 0..cs {
+                            let pos = [x, y, z];
+                            if (pos[sub_pass.axis as usize] as u32) % 2 != sub_pass.parity {
+                                continue;
+                            }
+                            let entered = self.step_ballistic(
+                                pos,
+                                sub_pass,
+                                tick,
+                                chunk_idx,
+                                active_chunk_count,
+                            );
+                            if let Some(entered) = entered {
+                                flying[entered as usize] = true;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns whether the voxel is still flying.
+    fn integrate_ballistic(&mut self, pos: [i32; 3], chunk_idx: u32) -> bool {
+        let write = 1 - self.read_index;
+        let idx = self.pool_index(pos, chunk_idx);
+        let voxel = self.pools[write][idx];
+        if !self.is_ballistic(voxel) {
+            return false;
+        }
+        let [vx, vy, vz] = velocity_of(voxel);
+        let mut vel = [vx, (vy - BALLISTIC_GRAVITY as i32).max(-127), vz];
+        let flying = settle(&mut vel);
+        self.pools[write][idx] = with_velocity(voxel, vel);
+        flying
+    }
+
+    /// Returns the dispatch index of a neighboring chunk the step changed.
+    fn step_ballistic(
+        &mut self,
+        pos: [i32; 3],
+        sub_pass: BallisticSubPass,
+        tick: u32,
+        chunk_idx: u32,
+        active_chunk_count: u32,
+    ) -> Option<u32> {
+        let write = 1 - self.read_index;
+        let src_idx = self.pool_index(pos, chunk_idx);
+        let src_voxel = self.pools[write][src_idx];
+        if !self.is_ballistic(src_voxel) {
+            return None;
+        }
+        let axis = sub_pass.axis as usize;
+        let src_mat = material_of(src_voxel);
+        let mut vel = velocity_of(src_voxel);
+        let speed = vel[axis] * sub_pass.sign;
+        if speed <= 0 || !ballistic_step_due(speed as u32, tick, sub_pass.substep) {
+            return None;
+        }
+
+        let mut dst_pos = pos;
+        dst_pos[axis] += sub_pass.sign;
+        // The edge of the loaded world is a wall
+        let Some(dst_idx) = self.cross_chunk_index(dst_pos, chunk_idx) else {
+            vel[axis] = clamp_velocity(-BALLISTIC_RESTITUTION * vel[axis] as f32);
+            apply_friction(&mut vel, axis);
+            settle(&mut vel);
+            self.pools[write][src_idx] = with_velocity(src_voxel, vel);
+            return None;
+        };
+        // A neighbor left out of this dispatch has no live write slot: wait at
+        // its edge until it wakes
+        let mut entered = None;
+        if !in_bounds(dst_pos) {
+            let slot_offset = self.descriptor(chunk_idx, 1 + neighbor_dir(dst_pos));
+            entered = (0..active_chunk_count).find(|&c| self.descriptor(c, 0) == slot_offset);
+            entered?;
+        }
+        let dst_voxel = self.pools[write][dst_idx];
+
+        // Air and gas give way
+        let dst_mat = material_of(dst_voxel);
+        if dst_mat == 0 || self.is_gas(dst_voxel) {
+            self.pools[write][dst_idx] = src_voxel;
+            self.pools[write][src_idx] = dst_voxel;
+            self.carry_aux(src_idx, dst_idx);
+            return entered;
+        }
+
+        // Impact along the axis, unless the voxel ahead is pulling away
+        let mut dst_vel = velocity_of(dst_voxel);
+        if (vel[axis] - dst_vel[axis]) * sub_pass.sign <= 0 {
+            return None;
+        }
+        let src_mass = self.props(src_mat).density.max(1.0);
+        let dst_props = *self.props(dst_mat);
+        let anchored = dst_props.phase as u32 == PHASE_SOLID && !self.is_ballistic(dst_voxel);
+        let va = vel[axis] as f32;
+        if anchored {
+            vel[axis] = clamp_velocity(-BALLISTIC_RESTITUTION * va);
+        } else {
+            // Momentum is shared; the two keep BALLISTIC_RESTITUTION of their
+            // closing speed apart
+            let dst_mass = dst_props.density.max(1.0);
+            let vb = dst_vel[axis] as f32;
+            let total = src_mass + dst_mass;
+            let shared = (src_mass * va + dst_mass * vb) / total;
+            let rebound = BALLISTIC_RESTITUTION * (va - vb) / total;
+            vel[axis] = clamp_velocity(shared - rebound * dst_mass);
+            dst_vel[axis] = clamp_velocity(shared + rebound * src_mass);
+            settle(&mut dst_vel);
+            self.pools[write][dst_idx] = with_velocity(dst_voxel, dst_vel);
+        }
+        apply_friction(&mut vel, axis);
+        settle(&mut vel);
+        self.pools[write][src_idx] = with_velocity(src_voxel, vel);
+        entered
+    }
+
+    // ── Pass 2: movement ──
+
+    fn pass_movement(&mut self, sub_pass: SubPass, tick: u32, active_chunk_count: u32) {
+        // Every move is decided against the state the sub-pass starts from,
+        // then made, as movement.wgsl does in its decide and apply entry
+        // points. Sources and destinations are disjoint, so the order cells
+        // are visited in (the order GPU threads happen to run) cannot matter.
+        // A voxel moved in the even sub-pass lands on an odd cell and stays
+        // there for the odd one: one cell per direction per tick.
+        let dir = sub_pass.direction;
+        let cs = CHUNK_SIZE as i32;
+        let mut moves = std::mem::take(&mut self.moves);
+        for chunk_idx in 0..active_chunk_count {
+            for z in 0..cs {
+                for y in 0..cs {
+                    for x in 0..cs {
+                        let pos = [x, y, z];
+                        if !sub_pass.processes(pos) {
+                            continue;
+                        }
+                        let idx = self.pool_index(pos, chunk_idx);
+                        if std::mem::take(&mut self.landed[idx]) {
+                            continue;
+                        }
+                        if self.decide_move(pos, dir, tick, chunk_idx) {
+                            moves.push((pos, chunk_idx));
+                        }
+                    }
+                }
+            }
+        }
+        for &(pos, chunk_idx) in &moves {
+            let dst_idx = self.apply_move(pos, dir, chunk_idx);
+            if sub_pass.parity == 0 {
+                self.landed[dst_idx] = true;
+            }
+        }
+        moves.clear();
+        self.moves = moves;
+    }
+
+    /// Whether the voxel at `pos` moves along `dir` this sub-pass. Reads only.
+    fn decide_move(&self, pos: [i32; 3], dir: [i32; 3], tick: u32, chunk_idx: u32) -> bool {
+        let write = 1 - self.read_index;
+        let src_idx = self.pool_index(pos, chunk_idx);
+        let src_voxel = self.pools[write][src_idx];
+        let src_mat = material_of(src_voxel);
+        if src_mat == 0 {
+            return false;
+        }
+
+        let src_props = *self.props(src_mat);
+        let src_phase = src_props.phase as u32;
+        if src_phase == PHASE_SOLID {
+            return false;
+        }
+
+        // Flying voxels move in the ballistic sub-passes
+        if self.is_ballistic(src_voxel) {
+            return false;
+        }
+
+        if dir[1] < 0 {
+            if src_phase != PHASE_POWDER && src_phase != PHASE_LIQUID {
+                return false;
+            }
+            if src_phase == PHASE_POWDER && !self.powder_falls(pos, dir, &src_props, chunk_idx) {
+                return false;
+            }
+            // Nothing slides down a diagonal while it can still fall straight
+            let below = [pos[0], pos[1] - 1, pos[2]];
+            if (dir[0] != 0 || dir[2] != 0)
+                && in_bounds(below)
+                && self.is_open(self.pools[write][self.pool_index(below, chunk_idx)])
+            {
+                return false;
+            }
+        } else if dir[1] == 0 {
+            if src_phase == PHASE_POWDER {
+                // Powder only creeps sideways toward a drop within its run
+                if !self.powder_creeps(pos, dir, &src_props, chunk_idx) {
+                    return false;
+                }
+            } else if src_phase != PHASE_LIQUID {
+                return false;
+            } else if src_props.viscosity > 0.0 {
+                let roll = hash_to_float(sim_hash(pos[0], pos[1], pos[2], tick));
+                if roll < src_props.viscosity {
+                    return false;
+                }
+            }
+        } else if src_phase == PHASE_LIQUID {
+            // Liquid only rises straight up, pushed by the head around it
+            if dir != [0, 1, 0] || !self.pushed_up(pos, chunk_idx) {
+                return false;
+            }
+        } else if src_phase != PHASE_GAS || src_props.density <= 0.0 {
+            return false;
+        }
+
+        let dst_pos = [pos[0] + dir[0], pos[1] + dir[1], pos[2] + dir[2]];
+
+        // Writes are confined to the current chunk, as on the GPU
+        if !in_bounds(dst_pos) {
+            return false;
+        }
+        let dst_idx = self.pool_index(dst_pos, chunk_idx);
+        // Read the destination as this tick has left it, so a cell filled
+        // earlier in the tick is not overwritten
+        let dst_voxel = self.pools[write][dst_idx];
+        let dst_mat = material_of(dst_voxel);
+
+        // A liquid pushed up by the head around it does not fall back, nor
+        // into a hole that liquid under more head is about to refill
+        if src_phase == PHASE_LIQUID
+            && dir[1] < 0
+            && self.is_open(dst_voxel)
+            && (self.column_pushed(pos, chunk_idx)
+                || self.refilled(dst_pos, src_voxel, chunk_idx)
+                || self.lifted(dst_pos, chunk_idx))
+        {
+            return false;
+        }
+        // A liquid spreading into a hole gives way to higher head beyond it,
+        // and to equal head when it spreads toward negative coordinates, so
+        // the hole is filled from one side and does not slosh back
+        if src_phase == PHASE_LIQUID && dir[1] == 0 && dst_mat == 0 {
+            let beyond = [dst_pos[0] + dir[0], dst_pos[1], dst_pos[2] + dir[2]];
+            if in_bounds(beyond) {
+                let beyond_voxel = self.pools[write][self.pool_index(beyond, chunk_idx)];
+                let (head, beyond_head) = (pressure_of(src_voxel), pressure_of(beyond_voxel));
+                if self.is_liquid(beyond_voxel)
+                    && (beyond_head > head || beyond_head == head && dir[0] + dir[2] < 0)
+                {
+                    return false;
+                }
+            }
+        }
+        // A rising liquid only moves into air
+        if src_phase == PHASE_LIQUID && dir[1] > 0 && dst_mat != 0 {
+            return false;
+        }
+
+        if dst_mat == 0 {
+            return true;
+        }
+        let dst_props = self.props(dst_mat);
+        dst_props.phase as u32 != PHASE_SOLID && src_props.density > dst_props.density
+    }
+
+    /// Move the voxel at `pos` one cell along `dir`, swapping it with what
+    /// was there, and return the pool index it moved to. Both cells belong to
+    /// this source for the sub-pass.
+    fn apply_move(&mut self, pos: [i32; 3], dir: [i32; 3], chunk_idx: u32) -> usize {
+        let write = 1 - self.read_index;
+        let src_idx = self.pool_index(pos, chunk_idx);
+        let dst_idx = self.pool_index(
+            [pos[0] + dir[0], pos[1] + dir[1], pos[2] + dir[2]],
+            chunk_idx,
+        );
+        let src_voxel = self.pools[write][src_idx];
+        let dst_voxel = self.pools[write][dst_idx];
+
+        // Liquid picks up speed as it flows
+        let moved = if self.is_liquid(src_voxel) {
+            flow(src_voxel, dir)
+        } else {
+            src_voxel
+        };
+        self.pools[write][dst_idx] = moved;
+        self.pools[write][src_idx] = if material_of(dst_voxel) == 0 {
+            [0, 0]
+        } else {
+            dst_voxel
+        };
+        self.carry_aux(src_idx, dst_idx);
+        dst_idx
+    }
+
+    /// Swap the sediment and head of two cells whose voxels just swapped, so
+    /// they stay with the voxel that has them.
+    fn carry_aux(&mut self, a: usize, b: usize) {
+        let aux = &mut self.aux_pools[1 - self.read_index];
+        for channel in [SEDIMENT, HEAD] {
+            let (value_a, value_b) = (channel.read(aux, a), channel.read(aux, b));
+            channel.write(aux, a, value_b);
+            channel.write(aux, b, value_a);
+        }
+    }
+
+    // ── Pass 2b: erosion ──
+
+    fn pass_erosion(&mut self, tick: u32, active_chunk_count: u32) {
+        self.for_each_voxel(active_chunk_count, |sim, pos, chunk_idx| {
+            sim.erode_voxel(pos, tick, chunk_idx);
+        });
+    }
+
+    fn erode_voxel(&mut self, pos: [i32; 3], tick: u32, chunk_idx: u32) {
+        let write = 1 - self.read_index;
+        let idx = self.pool_index(pos, chunk_idx);
+        let voxel = self.pools[write][idx];
+        let mat_id = material_of(voxel);
+        let sediment = SEDIMENT.read(&self.aux_pools[write], idx);
+        // Sediment dropped in place keeps the voxel's temperature and flags
+        let dropped = Voxel::new(MaterialId(sediment as u16))
+            .with_temperature(temperature_of(voxel) as u16)
+            .with_flags(Voxel::from(voxel).flags())
+            .into();
+
+        if self.is_liquid(voxel) {
+            // A flying liquid keeps its ballistic velocity and its load
+            if self.is_ballistic(voxel) {
+                return;
+            }
+            if sediment != 0 && flow_speed(voxel) < SEDIMENT_SETTLE_FLOW {
+                // Slack water drops what it carries
+                self.pools[write][idx] = dropped;
+                SEDIMENT.write(&mut self.aux_pools[write], idx, 0);
+                return;
+            }
+            self.pools[write][idx] = with_velocity(voxel, velocity_of(voxel).map(drag));
+            return;
+        }
+
+        if sediment != 0 {
+            // Liquid that boiled away drops its sediment in place of the gas;
+            // in anything else (ice) the sediment waits for liquid again
+            if self.is_open(voxel) {
+                self.pools[write][idx] = dropped;
+                SEDIMENT.write(&mut self.aux_pools[write], idx, 0);
+            }
+            return;
+        }
+
+        let props = *self.props(mat_id);
+        let phase = props.phase as u32;
+        if props.erodibility <= 0.0
+            || (phase != PHASE_POWDER && phase != PHASE_SOLID)
+            || self.is_ballistic(voxel)
+        {
+            return;
+        }
+
+        // Fastest liquid flowing past at the start of the tick
+        let mut fastest = None;
+        let mut fastest_speed = EROSION_MIN_FLOW - 1;
+        for offset in &FACE_OFFSETS {
+            let neighbor_pos = [pos[0] + offset[0], pos[1] + offset[1], pos[2] + offset[2]];
+            let neighbor = self.cross_chunk_voxel(neighbor_pos, chunk_idx);
+            if !self.is_liquid(neighbor) || self.is_ballistic(neighbor) {
+                continue;
+            }
+            let speed = flow_speed(neighbor);
+            if speed > fastest_speed {
+                fastest = Some(neighbor);
+                fastest_speed = speed;
+            }
+        }
+        let Some(liquid) = fastest else {
+            return;
+        };
+
+        // Picked up: the voxel becomes liquid moving with the flow, carrying
+        // its material as sediment
+        let chance = props.erodibility * fastest_speed as f32 / FLOW_SPEED_MAX as f32;
+        let roll = hash_to_float(sim_hash(pos[0] + 7, pos[1], pos[2], tick));
+        if roll < chance {
+            self.pools[write][idx] = Voxel::from(liquid)
+                .with_temperature(temperature_of(voxel) as u16)
+                .with_pressure(0)
+                .with_flags(Voxel::from(voxel).flags())
+                .into();
+            SEDIMENT.write(&mut self.aux_pools[write], idx, mat_id);
+        }
+    }
+
+    // ── Pass 3: reactions ──
+
+    fn pass_reactions(&mut self, tick: u32, active_chunk_count: u32) {
+        self.for_each_voxel(active_chunk_count, |sim, pos, chunk_idx| {
+            sim.react_voxel(pos, tick, chunk_idx);
+        });
+    }
+
+    fn react_voxel(&mut self, pos: [i32; 3], tick: u32, chunk_idx: u32) {
+        let write = 1 - self.read_index;
+        let idx = self.pool_index(pos, chunk_idx);
+        let mut voxel = self.pools[write][idx];
+        let mat_id = material_of(voxel);
+        if mat_id == 0 {
+            return;
+        }
+
+        let mut my_temp = temperature_of(voxel);
+        let my_charge = CHARGE.read(&self.aux_pools[self.read_index], idx);
+        let my_pressure = pressure_of(voxel);
+        let my_oxygen_deficit = OXYGEN_DEFICIT.read(&self.aux_pools[self.read_index], idx);
+
+        // Self-decay
+        let props = *self.props(mat_id);
+        let decay_rate = props.decay_rate as u32;
+        let decay_threshold = props.decay_threshold as u32;
+        let decay_product = props.decay_product_id as u32;
+        if decay_rate > 0 {
+            my_temp = self.step_temp(my_temp, -(decay_rate as f32));
+            if my_temp < decay_threshold {
+                self.pools[write][idx] = repack(voxel, decay_product, my_temp);
+                return;
+            }
+            voxel = repack(voxel, mat_id, my_temp);
+        }
+
+        // Upward phase change
+        let current_props = self.props(material_of(voxel));
+        let phase_change_temp_q = current_props.phase_change_temp_quantized as u32;
+        let phase_change_product = current_props.phase_change_product_id as u32;
+        let latent_heat_steps = current_props.latent_heat_steps;
+        if phase_change_temp_q > 0 && my_temp >= phase_change_temp_q {
+            // Heat above the transition point goes into the latent heat
+            let mut product = phase_change_product;
+            if latent_heat_steps > 0.0 {
+                let excess =
+                    self.temp_lut[my_temp as usize] - self.temp_lut[phase_change_temp_q as usize];
+                let roll = hash_to_float(sim_hash(pos[0] + 6, pos[1], pos[2], tick));
+                if roll * latent_heat_steps >= excess {
+                    product = material_of(voxel);
+                }
+                my_temp = phase_change_temp_q;
+            }
+            voxel = repack(voxel, product, my_temp);
+        }
+
+        // Downward phase change; like self-decay it ends the voxel's turn
+        let current_props = self.props(material_of(voxel));
+        let freeze_temp_q = current_props.freeze_temp_quantized as u32;
+        if freeze_temp_q > 0 && my_temp < freeze_temp_q {
+            self.pools[write][idx] = repack(voxel, current_props.freeze_product_id as u32, my_temp);
+            return;
+        }
+
+        // Pairwise reactions (lookup keyed on the original material).
+        // Neighbors are fetched up front so catalyst and oxygen checks can see
+        // all six; oxygen-consuming rules need the oxygen in a gas voxel's own
+        // cell, or in the best-supplied gas neighbor of anything else.
+        // Liquid carrying sediment sits out pairwise reactions on both sides
+        // of the pair, so the load is never lost with the liquid.
+        let my_gas = self.is_gas(voxel);
+        let my_sediment = SEDIMENT.read(&self.aux_pools[write], idx);
+        let mut oxygen_in_reach = self.cell_oxygen(voxel, Some(idx));
+        let mut neighbor_carries = [false; 6];
+        let neighbor_mats: [u32; 6] = std::array::from_fn(|n| {
+            let offset = REACTION_OFFSETS[n];
+            let neighbor_pos = [pos[0] + offset[0], pos[1] + offset[1], pos[2] + offset[2]];
+            let neighbor = self.cross_chunk_voxel(neighbor_pos, chunk_idx);
+            let neighbor_idx = self.cross_chunk_index(neighbor_pos, chunk_idx);
+            if !my_gas {
+                oxygen_in_reach = oxygen_in_reach.max(self.cell_oxygen(neighbor, neighbor_idx));
+            }
+            neighbor_carries[n] = neighbor_idx
+                .is_some_and(|i| SEDIMENT.read(&self.aux_pools[self.read_index], i) != 0);
+            material_of(neighbor)
+        });
+        for (n, &neighbor_mat) in neighbor_mats.iter().enumerate() {
+            if my_sediment != 0 || neighbor_carries[n] {
+                continue;
+            }
+            let candidates = rule_range(self.lookup(mat_id, neighbor_mat));
+            if candidates.is_empty() {
+                continue;
+            }
+
+            // One roll per neighbor; eligible candidates claim consecutive
+            // slices of the probability range in rule order.
+            let roll = sim_hash(pos[0] + n as i32, pos[1], pos[2], tick);
+            let mut cumulative = 0u32;
+            let mut chosen = None;
+            for rule in &self.rule_entries[candidates] {
+                if rule.min_temp > 0 && my_temp < rule.min_temp as u32 {
+                    continue;
+                }
+                if rule.max_temp > 0 && my_temp > rule.max_temp as u32 {
+                    continue;
+                }
+                if rule.min_charge > 0 && my_charge < rule.min_charge as u32 {
+                    continue;
+                }
+                if rule.max_charge > 0 && my_charge > rule.max_charge as u32 {
+                    continue;
+                }
+                if rule.min_pressure > 0 && my_pressure < rule.min_pressure as u32 {
+                    continue;
+                }
+                if rule.max_pressure > 0 && my_pressure > rule.max_pressure as u32 {
+                    continue;
+                }
+                if rule.catalyst as u32 != NO_CATALYST
+                    && !neighbor_mats.contains(&(rule.catalyst as u32))
+                {
+                    continue;
+                }
+                let oxygen = rule.oxygen as u32;
+                if oxygen > 0
+                    && (oxygen_in_reach < oxygen
+                        || my_oxygen_deficit + oxygen > OXYGEN_DEFICIT.max())
+                {
+                    continue;
+                }
+                cumulative = cumulative.saturating_add(rule.probability_u32);
+                if cumulative == u32::MAX || roll <= cumulative {
+                    chosen = Some(*rule);
+                    break;
+                }
+            }
+            let Some(rule) = chosen else {
+                continue;
+            };
+
+            let new_temp = self.step_temp(my_temp, rule.temp_delta as f32);
+            let new_pressure =
+                (pressure_of(voxel) as i32 + rule.pressure_delta).clamp(0, MAX_PRESSURE as i32);
+            voxel = Voxel::from(repack(voxel, rule.input_a_becomes as u32, new_temp))
+                .with_pressure(new_pressure as u8)
+                .into();
+            if rule.oxygen > 0 {
+                // Fuel that burns away into gas leaves its cell full of exhaust
+                let mut new_deficit = my_oxygen_deficit + rule.oxygen as u32;
+                if !my_gas && self.is_gas(voxel) {
+                    new_deficit = OXYGEN_DEFICIT.max();
+                }
+                OXYGEN_DEFICIT.write(&mut self.aux_pools[write], idx, new_deficit);
+            }
+            if rule.blast > 0 {
+                BLAST.write(&mut self.aux_pools[write], idx, rule.blast as u32);
+                BLAST_FRONT.write(&mut self.aux_pools[write], idx, BLAST_FRONT_SEEDED);
+            }
+            break;
+        }
+
+        self.pools[write][idx] = voxel;
+    }
+
+    // ── Pass 3b: oxygen ──
+
+    fn pass_oxygen(&mut self, active_chunk_count: u32) {
+        // With no deficit anywhere there is nothing to flow (a CPU-only
+        // shortcut; the result is the same)
+        if self.channel_clear(OXYGEN_DEFICIT) {
+            return;
+        }
+        self.for_each_voxel(active_chunk_count, |sim, pos, chunk_idx| {
+            sim.exchange_oxygen(pos, chunk_idx);
+        });
+    }
+
+    fn exchange_oxygen(&mut self, pos: [i32; 3], chunk_idx: u32) {
+        let read = self.read_index;
+        let write = 1 - read;
+        let idx = self.pool_index(pos, chunk_idx);
+        let voxel = self.pools[write][idx];
+        let my_deficit = OXYGEN_DEFICIT.read(&self.aux_pools[read], idx) as i32;
+        let burned = OXYGEN_DEFICIT.read(&self.aux_pools[write], idx) as i32 - my_deficit;
+        let my_gas = self.is_gas(voxel);
+        let my_temp = temperature_of(voxel);
+
+        let mut flow = 0i32;
+        for offset in &FACE_OFFSETS {
+            let neighbor_pos = [pos[0] + offset[0], pos[1] + offset[1], pos[2] + offset[2]];
+            // Unloaded neighbors are fresh ambient air
+            let (neighbor_deficit, neighbor_gas, neighbor_temp) =
+                match self.cross_chunk_index(neighbor_pos, chunk_idx) {
+                    Some(n) => {
+                        let neighbor = self.pools[write][n];
+                        (
+                            OXYGEN_DEFICIT.read(&self.aux_pools[read], n) as i32,
+                            self.is_gas(neighbor),
+                            temperature_of(neighbor),
+                        )
+                    }
+                    None => (0, true, AMBIENT_TEMP),
+                };
+
+            if !my_gas && !neighbor_gas {
+                continue;
+            }
+            let mut diff = neighbor_deficit - my_deficit;
+            if !my_gas {
+                diff = diff.min(0);
+            } else if !neighbor_gas {
+                diff = diff.max(0);
+            }
+
+            // Draft: hot gas below a cooler cell pulls air through faster
+            let mut rate = OXYGEN_DIFFUSION_RATE;
+            if offset[1] != 0 {
+                let (lower, upper) = if offset[1] < 0 {
+                    (neighbor_temp, my_temp)
+                } else {
+                    (my_temp, neighbor_temp)
+                };
+                if lower > upper + CONVECTION_THRESHOLD {
+                    rate = OXYGEN_DRAFT_RATE;
+                }
+            }
+            flow += (diff as f32 * rate) as i32;
+        }
+
+        let new_deficit = (my_deficit + flow + burned).clamp(0, OXYGEN_DEFICIT.max() as i32);
+        OXYGEN_DEFICIT.write(&mut self.aux_pools[write], idx, new_deficit as u32);
+    }
+
+    // ── Pass 4a: thermal ──
+
+    fn pass_thermal(&mut self, active_chunk_count: u32) {
+        self.for_each_voxel(active_chunk_count, |sim, pos, chunk_idx| {
+            sim.diffuse_heat(pos, chunk_idx);
+        });
+    }
+
+    fn diffuse_heat(&mut self, pos: [i32; 3], chunk_idx: u32) {
+        let write = 1 - self.read_index;
+        let idx = self.pool_index(pos, chunk_idx);
+        let voxel = self.pools[write][idx];
+        let mat_id = material_of(voxel);
+        if mat_id == 0 {
+            return;
+        }
+
+        let my_temp = temperature_of(voxel);
+        let my_linear = self.temp_lut[my_temp as usize];
+        let my_conductivity = self.props(mat_id).thermal_conductivity;
+        let inv_heat_capacity = self.props(mat_id).inv_heat_capacity;
+        let my_emissivity = self.props(mat_id).radiative_emissivity;
+        let radiates = self.radiates && my_emissivity > 0.0;
+        let my_power = radiant_power(my_linear);
+
+        let mut delta = 0.0f32;
+        let mut radiation = 0.0f32;
+        for dz in -1..=1i32 {
+            for dy in -1..=1i32 {
+                for dx in -1..=1i32 {
+                    if dx == 0 && dy == 0 && dz == 0 {
+                        continue;
+                    }
+                    let neighbor =
+                        self.cross_chunk_voxel([pos[0] + dx, pos[1] + dy, pos[2] + dz], chunk_idx);
+                    let neighbor_mat = material_of(neighbor);
+                    if radiates
+                        && (neighbor_mat == 0 || self.props(neighbor_mat).phase as u32 == PHASE_GAS)
+                    {
+                        radiation += self.radiate_along(
+                            pos,
+                            [dx, dy, dz],
+                            chunk_idx,
+                            my_emissivity,
+                            my_linear,
+                            my_power,
+                        );
+                    }
+                    if neighbor_mat == 0 {
+                        continue;
+                    }
+                    let neighbor_conductivity = self.props(neighbor_mat).thermal_conductivity;
+                    let weight = match dx.abs() + dy.abs() + dz.abs() {
+                        1 => 1.0,
+                        2 => 0.7,
+                        _ => 0.5,
+                    };
+                    let k_avg = (my_conductivity + neighbor_conductivity) * 0.5;
+                    let neighbor_linear = self.temp_lut[temperature_of(neighbor) as usize];
+                    delta += weight * k_avg * (neighbor_linear - my_linear);
+                }
+            }
+        }
+
+        // delta and radiation are heat flows; heat capacity turns them into a temperature change
+        let heat = DIFFUSION_RATE * delta / 26.0 + radiation;
+        let mut new_temp = self.step_temp(my_temp, heat * inv_heat_capacity);
+
+        // Entropy drain
+        if new_temp > AMBIENT_TEMP {
+            new_temp = self
+                .step_temp(new_temp, -(ENTROPY_DRAIN_RATE as f32))
+                .max(AMBIENT_TEMP);
+        } else if new_temp < AMBIENT_TEMP {
+            new_temp = self
+                .step_temp(new_temp, ENTROPY_DRAIN_RATE as f32)
+                .min(AMBIENT_TEMP);
+        }
+
+        // Convection
+        let phase = self.props(mat_id).phase as u32;
+        let mut vel = velocity_of(voxel);
+        if (phase == PHASE_LIQUID || phase == PHASE_GAS)
+            && !self.is_ballistic(voxel)
+            && self.temp_lut[new_temp as usize]
+                > self.temp_lut[AMBIENT_TEMP as usize] + CONVECTION_THRESHOLD as f32
+        {
+            vel[1] = 1;
+        }
+
+        self.pools[write][idx] = with_velocity(repack(voxel, mat_id, new_temp), vel);
+    }
+
+    /// Mirror of `radiate_along` in thermal.wgsl.
+    fn radiate_along(
+        &self,
+        pos: [i32; 3],
+        dir: [i32; 3],
+        chunk_idx: u32,
+        my_emissivity: f32,
+        my_linear: f32,
+        my_power: f32,
+    ) -> f32 {
+        let unit_dist2 = (dir[0] * dir[0] + dir[1] * dir[1] + dir[2] * dir[2]) as f32;
+        let mut flow = 0.0f32;
+        for k in 2..=RADIATION_RADIUS as i32 {
+            let other = self.cross_chunk_voxel(
+                [
+                    pos[0] + dir[0] * k,
+                    pos[1] + dir[1] * k,
+                    pos[2] + dir[2] * k,
+                ],
+                chunk_idx,
+            );
+            let other_mat = material_of(other);
+            if other_mat == 0 {
+                continue;
+            }
+            let other_props = self.props(other_mat);
+            let emissivity = my_emissivity.min(other_props.radiative_emissivity);
+            if emissivity > 0.0 {
+                let other_linear = self.temp_lut[temperature_of(other) as usize];
+                let exchange =
+                    RADIATION_RATE * emissivity * (radiant_power(other_linear) - my_power)
+                        / (unit_dist2 * (k * k) as f32);
+                let cap = (other_linear - my_linear).abs() * RADIATION_MAX_GAP_FRACTION;
+                flow += exchange.clamp(-cap, cap);
+            }
+            if other_props.phase as u32 != PHASE_GAS {
+                break;
+            }
+        }
+        flow
+    }
+
+    // ── Pass 4b: electrical ──
+
+    fn pass_electrical(&mut self, active_chunk_count: u32) {
+        self.for_each_voxel(active_chunk_count, |sim, pos, chunk_idx| {
+            sim.propagate_charge(pos, chunk_idx);
+        });
+    }
+
+    fn propagate_charge(&mut self, pos: [i32; 3], chunk_idx: u32) {
+        let read = self.read_index;
+        let write = 1 - read;
+        let idx = self.pool_index(pos, chunk_idx);
+        let voxel = self.pools[write][idx];
+        let mat_id = material_of(voxel);
+
+        if mat_id == 0 {
+            CHARGE.write(&mut self.aux_pools[write], idx, 0);
+            return;
+        }
+
+        let props = *self.props(mat_id);
+        let conductivity = props.electrical_conductivity;
+        let resistance = props.electrical_resistance;
+        let activation_threshold = props.activation_threshold as u32;
+        let charge_emission = props.charge_emission as u32;
+        let current_charge = CHARGE.read(&self.aux_pools[read], idx);
+        let decayed = current_charge.saturating_sub(CHARGE_DECAY_RATE);
+
+        if charge_emission > 0 {
+            CHARGE.write(&mut self.aux_pools[write], idx, charge_emission);
+            return;
+        }
+        if conductivity == 0.0 {
+            CHARGE.write(&mut self.aux_pools[write], idx, decayed);
+            return;
+        }
+        if conductivity > 0.9 && resistance == 0.0 {
+            CHARGE.write(&mut self.aux_pools[write], idx, 0);
+            return;
+        }
+
+        let mut charged_count = 0u32;
+        let mut charge_sum = 0u32;
+        for offset in &FACE_OFFSETS {
+            let neighbor_pos = [pos[0] + offset[0], pos[1] + offset[1], pos[2] + offset[2]];
+            let neighbor_charge = self.neighbor_charge(neighbor_pos, chunk_idx);
+            if neighbor_charge > 0 {
+                charged_count += 1;
+                charge_sum += neighbor_charge;
+            }
+        }
+
+        let new_charge = if charged_count >= activation_threshold {
+            let diffused = charge_sum as f32 * conductivity * ELECTRICAL_DIFFUSION_RATE;
+            let mut c = (diffused as u32).min(CHARGE_MAX);
+            if current_charge > CHARGE_DECAY_RATE {
+                c = c.max(decayed);
+            }
+            c
+        } else {
+            decayed
+        };
+        CHARGE.write(&mut self.aux_pools[write], idx, new_charge);
+
+        // Joule heating
+        if new_charge > 0 && resistance > 0.0 {
+            let my_temp = temperature_of(voxel);
+            let heat = (new_charge * new_charge) as f32 * resistance * JOULE_HEATING_FACTOR;
+            let new_temp = (my_temp as i32 + heat as i32).clamp(0, TEMP_MAX as i32);
+            if new_temp as u32 != my_temp {
+                self.pools[write][idx] = repack(voxel, mat_id, new_temp as u32);
+            }
+        }
+    }
+
+    // ── Pass 5: pressure ──
+
+    fn pass_pressure(&mut self, tick: u32, active_chunk_count: u32) {
+        for chunk_idx in 0..active_chunk_count {
+            self.update_columns(chunk_idx);
+        }
+        self.for_each_voxel(active_chunk_count, |sim, pos, chunk_idx| {
+            sim.update_pressure(pos, tick, chunk_idx);
+        });
+    }
+
+    /// Store in `COLUMN` the weight of the liquid (write pool) from each cell
+    /// of the chunk up to the top of its column, as pressure.wgsl `columns`
+    /// does: one walk down each column, starting from the liquid running on
+    /// into the chunk above (at most CHUNK_SIZE voxels of it).
+    fn update_columns(&mut self, chunk_idx: u32) {
+        let write = 1 - self.read_index;
+        let cs = CHUNK_SIZE as i32;
+        for z in 0..cs {
+            for x in 0..cs {
+                let mut weight = 0;
+                for y in cs..2 * cs {
+                    let Some(idx) = self.cross_chunk_index([x, y, z], chunk_idx) else {
+                        break;
+                    };
+                    let voxel = self.pools[write][idx];
+                    if !self.is_liquid(voxel) || weight >= MAX_PRESSURE as i32 {
+                        break;
+                    }
+                    weight += head_weight(self.props(material_of(voxel)));
+                }
+                for y in (0..cs).rev() {
+                    let idx = self.pool_index([x, y, z], chunk_idx);
+                    let voxel = self.pools[write][idx];
+                    weight = if self.is_liquid(voxel) {
+                        (weight + head_weight(self.props(material_of(voxel))))
+                            .min(MAX_PRESSURE as i32)
+                    } else {
+                        0
+                    };
+                    COLUMN.write(&mut self.aux_pools[write], idx, weight as u32);
+                }
+            }
+        }
+    }
+
+    fn update_pressure(&mut self, pos: [i32; 3], tick: u32, chunk_idx: u32) {
+        let write = 1 - self.read_index;
+        let idx = self.pool_index(pos, chunk_idx);
+        let voxel = self.pools[write][idx];
+        let mat_id = material_of(voxel);
+        let own_head = HEAD.read(&self.aux_pools[write], idx) as i32;
+        if mat_id == 0 {
+            if own_head != 0 {
+                HEAD.write(&mut self.aux_pools[write], idx, 0);
+            }
+            return;
+        }
+
+        let my_temp = temperature_of(voxel);
+        // The pressure the voxel has built up, without its head
+        let mut my_pressure = (pressure_of(voxel) as i32 - own_head).max(0);
+        let props = *self.props(mat_id);
+        let phase = props.phase as u32;
+
+        let mut non_air_count = 0u32;
+        let mut neighbor_pressure_sum = 0i32;
+        let mut liquid_load = 0i32;
+        for offset in &FACE_OFFSETS {
+            let neighbor_pos = [pos[0] + offset[0], pos[1] + offset[1], pos[2] + offset[2]];
+            let neighbor = self.cross_chunk_voxel(neighbor_pos, chunk_idx);
+            if material_of(neighbor) != 0 {
+                non_air_count += 1;
+            }
+            let neighbor_head = self
+                .cross_chunk_index(neighbor_pos, chunk_idx)
+                .map_or(0, |i| HEAD.read(&self.aux_pools[self.read_index], i));
+            neighbor_pressure_sum += (pressure_of(neighbor) as i32 - neighbor_head as i32).max(0);
+            if self.is_liquid(neighbor) {
+                liquid_load = liquid_load.max(pressure_of(neighbor) as i32);
+            }
+        }
+
+        // Thermal pressure generation
+        if non_air_count == 6
+            && (phase == PHASE_GAS || phase == PHASE_LIQUID)
+            && my_temp > AMBIENT_TEMP
+        {
+            my_pressure = (my_pressure + THERMAL_PRESSURE_FACTOR as i32).min(MAX_PRESSURE as i32);
+        }
+
+        // Pressure diffusion, of what the neighbors built up
+        let avg_neighbor = neighbor_pressure_sum as f32 / FACE_OFFSETS.len() as f32;
+        let diff = avg_neighbor - my_pressure as f32;
+        my_pressure += (PRESSURE_DIFFUSION_RATE * diff) as i32;
+        my_pressure = my_pressure.clamp(0, MAX_PRESSURE as i32);
+
+        // Hydrostatic head, on top
+        let head = if phase == PHASE_LIQUID {
+            self.hydrostatic_pressure(pos, tick, chunk_idx)
+        } else {
+            0
+        };
+        my_pressure = (my_pressure + head).min(MAX_PRESSURE as i32);
+
+        // Rupture, under its own pressure or, for a solid, the load of the
+        // liquid against it
+        let load = if phase == PHASE_SOLID {
+            my_pressure.max(liquid_load)
+        } else {
+            my_pressure
+        };
+        if props.structural_integrity > 0.0 && load as f32 > props.structural_integrity {
+            let h = sim_hash(pos[0], pos[1], pos[2], tick);
+            let blast_dir = FACE_OFFSETS[(h % 6) as usize];
+            let blast_speed = (load / 8).clamp(1, 4);
+            self.pools[write][idx] = Voxel::from(with_velocity(
+                repack(voxel, 0, my_temp),
+                blast_dir.map(|d| d * blast_speed),
+            ))
+            .with_pressure(load as u8)
+            .into();
+            HEAD.write(&mut self.aux_pools[write], idx, 0);
+            return;
+        }
+
+        self.pools[write][idx] = Voxel::from(voxel).with_pressure(my_pressure as u8).into();
+        HEAD.write(&mut self.aux_pools[write], idx, head as u32);
+    }
+
+    /// Hydrostatic head of the liquid at `pos`: the weight of the liquid
+    /// column from it up to the surface, or the head passed on by the liquid
+    /// beside and below it if that is more. Liquid at the edge of an open
+    /// surface spills rather than holding head, so it only has its column.
+    ///
+    /// Liquid is found where movement left it this tick (`COLUMN`, which this
+    /// pass does not change), and head as the neighbors had it at the start
+    /// of the tick.
+    fn hydrostatic_pressure(&self, pos: [i32; 3], tick: u32, chunk_idx: u32) -> i32 {
+        let write = 1 - self.read_index;
+        let column = COLUMN.read(&self.aux_pools[write], self.pool_index(pos, chunk_idx)) as i32;
+        let decay = tick.is_multiple_of(HYDROSTATIC_DECAY_PERIOD) as i32;
+        let mut head = column;
+        for offset in FACE_OFFSETS.iter().filter(|o| o[1] <= 0) {
+            let neighbor_pos = [pos[0] + offset[0], pos[1] + offset[1], pos[2] + offset[2]];
+            if offset[1] == 0
+                && self.is_open(self.cross_chunk_voxel(neighbor_pos, chunk_idx))
+                && self.is_open(self.cross_chunk_voxel(
+                    [neighbor_pos[0], neighbor_pos[1] + 1, neighbor_pos[2]],
+                    chunk_idx,
+                ))
+            {
+                return column;
+            }
+            let Some(idx) = self.cross_chunk_index(neighbor_pos, chunk_idx) else {
+                continue;
+            };
+            let voxel = self.pools[self.read_index][idx];
+            if !self.is_liquid(voxel) || COLUMN.read(&self.aux_pools[write], idx) == 0 {
+                continue;
+            }
+            // Head climbs the liquid below less its weight, and spreads
+            // sideways as is, fading if nothing renews it
+            let neighbor_head = HEAD.read(&self.aux_pools[self.read_index], idx) as i32;
+            head = head.max(if offset[1] < 0 {
+                neighbor_head - head_weight(self.props(material_of(voxel)))
+            } else {
+                neighbor_head - decay
+            });
+        }
+        head.min(MAX_PRESSURE as i32)
+    }
+
+    // ── Pass 5b: blast ──
+
+    fn pass_blast(&mut self, active_chunk_count: u32) {
+        // With no front and nothing remembered anywhere there is nothing to
+        // propagate or decay (a CPU-only shortcut; the result is the same)
+        if self.channel_clear(BLAST_FRONT) && self.channel_clear(BLAST) {
+            return;
+        }
+        self.for_each_voxel(active_chunk_count, |sim, pos, chunk_idx| {
+            sim.propagate_blast(pos, chunk_idx);
+        });
+    }
+
+    fn propagate_blast(&mut self, pos: [i32; 3], chunk_idx: u32) {
+        let read = self.read_index;
+        let write = 1 - read;
+        let idx = self.pool_index(pos, chunk_idx);
+
+        // A detonation this tick: the reactions pass already set its strength
+        if BLAST_FRONT.read(&self.aux_pools[write], idx) == BLAST_FRONT_SEEDED {
+            BLAST_FRONT.write(&mut self.aux_pools[write], idx, BLAST_FRONT_SOURCE);
+            return;
+        }
+
+        let remembered = BLAST
+            .read(&self.aux_pools[read], idx)
+            .saturating_sub(BLAST_DECAY);
+
+        // Strongest offer from a live neighbor, and the direction of travel
+        // weighted by every offer that gets through
+        let mut incoming = 0u32;
+        let mut push = [0i32; 3];
+        for offset in &FACE_OFFSETS {
+            let neighbor_pos = [pos[0] + offset[0], pos[1] + offset[1], pos[2] + offset[2]];
+            let Some(n) = self.cross_chunk_index(neighbor_pos, chunk_idx) else {
+                continue;
+            };
+            let neighbor_front = BLAST_FRONT.read(&self.aux_pools[read], n);
+            if neighbor_front == BLAST_FRONT_NONE {
+                continue;
+            }
+            let mut through = BLAST.read(&self.aux_pools[read], n) as f32;
+            if neighbor_front == BLAST_FRONT_LIVE {
+                through = (through - self.blast_resistance(self.pools[read][n])).max(0.0);
+            }
+            let carried = (through * BLAST_FALLOFF) as u32;
+            if carried <= remembered {
+                continue;
+            }
+            incoming = incoming.max(carried);
+            for axis in 0..3 {
+                push[axis] -= offset[axis] * carried as i32;
+            }
+        }
+
+        if incoming == 0 {
+            BLAST.write(&mut self.aux_pools[write], idx, remembered);
+            BLAST_FRONT.write(&mut self.aux_pools[write], idx, BLAST_FRONT_NONE);
+            return;
+        }
+
+        let voxel = self.pools[write][idx];
+        let resistance = self.blast_resistance(voxel);
+        BLAST.write(&mut self.aux_pools[write], idx, incoming);
+
+        // Held: the front stops here
+        if incoming as f32 <= resistance {
+            BLAST_FRONT.write(&mut self.aux_pools[write], idx, BLAST_FRONT_NONE);
+            return;
+        }
+        BLAST_FRONT.write(&mut self.aux_pools[write], idx, BLAST_FRONT_LIVE);
+
+        // Broken loose: a fragment flying away from the blast
+        if !self.is_gas(voxel) {
+            let surplus = (incoming as f32 - resistance) as u32;
+            let speed = (surplus / BLAST_FRAGMENT_SPEED_DIVISOR).clamp(1, 127) as i32;
+            let spread = push.iter().map(|p| p.abs()).max().unwrap_or(0);
+            let vel = if spread > 0 {
+                push.map(|p| p * speed / spread)
+            } else {
+                [0; 3]
+            };
+            self.pools[write][idx] = with_velocity(voxel, vel);
+        }
+    }
+
+    /// Whether the change from `before` to `after` can move what structures
+    /// stand: a structural voxel at rest is gone or flying, or a structural
+    /// fragment came to rest (possibly with nothing under it).
+    fn changes_structure(&self, before: [u32; 2], after: [u32; 2]) -> bool {
+        let structural =
+            |v: [u32; 2]| self.structural.role(material_of(v) as u16) == Role::Structural;
+        let broke = structural(before)
+            && !self.is_ballistic(before)
+            && (material_of(after) != material_of(before) || self.is_ballistic(after));
+        let landed = structural(after) && self.is_ballistic(before) && !self.is_ballistic(after);
+        before != after && (broke || landed)
+    }
+
+    // ── Pass 6: activity ──
+
+    fn pass_activity(&mut self, active_chunk_count: u32) {
+        let read = self.read_index;
+        let write = 1 - read;
+        let mut structure_broken = false;
+        self.activity_flags = (0..active_chunk_count)
+            .map(|chunk_idx| {
+                let base = self.slot_voxel_offset(chunk_idx);
+                let range = base..base + VOXELS_PER_CHUNK as usize;
+                let mut dirty = self.pools[read][range.clone()] != self.pools[write][range.clone()];
+                let mut blast_yield = 0u32;
+                for i in range {
+                    structure_broken |=
+                        self.changes_structure(self.pools[read][i], self.pools[write][i]);
+                    let word = self.aux_pools[write][BLAST.word_index(i)];
+                    let front = BLAST_FRONT.get(word);
+                    dirty |= BLAST.get(word) != 0 || front != BLAST_FRONT_NONE;
+                    if front == BLAST_FRONT_SOURCE {
+                        blast_yield = blast_yield.saturating_add(BLAST.get(word));
+                    }
+                }
+                u32::from(dirty) * ACTIVITY_DIRTY
+                    + (blast_yield.min(ACTIVITY_BLAST_MAX) << ACTIVITY_BLAST_SHIFT)
+            })
+            .collect();
+        self.structure_broken |= structure_broken;
+    }
+
+    // ── Helpers ──
+
+    fn for_each_voxel(
+        &mut self,
+        active_chunk_count: u32,
+        mut f: impl FnMut(&mut Self, [i32; 3], u32),
+    ) {
+        let cs = CHUNK_SIZE as i32;
+        for chunk_idx in 0..active_chunk_count {
+            for z in 0..cs {
+                for y in 0..cs {
+                    for x in 0..cs {
+                        f(self, [x, y, z], chunk_idx);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Whether `channel` is zero for every voxel of both aux pools.
+    fn channel_clear(&self, channel: AuxChannel) -> bool {
+        let voxels = self.slot_count as usize * VOXELS_PER_CHUNK as usize;
+        self.aux_pools
+            .iter()
+            .all(|words| (0..voxels).all(|i| channel.read(words, i) == 0))
+    }
+
+    fn split_pools(&mut self) -> (PoolView<'_>, PoolView<'_>) {
+        let (p0, p1) = self.pools.split_at_mut(1);
+        let (a0, a1) = self.aux_pools.split_at_mut(1);
+        let a = PoolView {
+            voxels: &mut p0[0],
+            aux: &mut a0[0],
+        };
+        let b = PoolView {
+            voxels: &mut p1[0],
+            aux: &mut a1[0],
+        };
+        if self.read_index == 0 {
+            (a, b)
+        } else {
+            (b, a)
+        }
+    }
+
+    fn slot_range(slot: u32, len: usize) -> std::ops::Range<usize> {
+        let start = slot as usize * VOXELS_PER_CHUNK as usize;
+        start..start + len
+    }
+
+    fn aux_slot_range(slot: u32, len: usize) -> std::ops::Range<usize> {
+        let start = slot as usize * aux_channel::WORDS_PER_CHUNK;
+        start..start + len
+    }
+
+    fn descriptor(&self, chunk_idx: u32, entry: u32) -> u32 {
+        self.chunk_descriptors[(chunk_idx * CHUNK_DESC_STRIDE + entry) as usize]
+    }
+
+    /// Voxel offset of the chunk's own slot (descriptor entry 0 is in bytes).
+    fn slot_voxel_offset(&self, chunk_idx: u32) -> usize {
+        (self.descriptor(chunk_idx, 0) / 8) as usize
+    }
+
+    fn pool_index(&self, pos: [i32; 3], chunk_idx: u32) -> usize {
+        self.slot_voxel_offset(chunk_idx) + voxel_index(pos)
+    }
+
+    /// Pool index of a position that may lie in a neighboring chunk, or None if unloaded.
+    fn cross_chunk_index(&self, pos: [i32; 3], chunk_idx: u32) -> Option<usize> {
+        if in_bounds(pos) {
+            return Some(self.pool_index(pos, chunk_idx));
+        }
+        let neighbor_offset = self.descriptor(chunk_idx, 1 + neighbor_dir(pos));
+        if neighbor_offset == SENTINEL_NEIGHBOR {
+            return None;
+        }
+        Some((neighbor_offset / 8) as usize + voxel_index(remap_coords(pos)))
+    }
+
+    /// Read a voxel from the read pool, handling cross-chunk access.
+    fn cross_chunk_voxel(&self, pos: [i32; 3], chunk_idx: u32) -> [u32; 2] {
+        match self.cross_chunk_index(pos, chunk_idx) {
+            Some(idx) => self.pools[self.read_index][idx],
+            None => [0, 0],
+        }
+    }
+
+    /// Read a charge from the aux read pool, handling cross-chunk access.
+    fn neighbor_charge(&self, pos: [i32; 3], chunk_idx: u32) -> u32 {
+        match self.cross_chunk_index(pos, chunk_idx) {
+            Some(idx) => CHARGE.read(&self.aux_pools[self.read_index], idx),
+            None => 0,
+        }
+    }
+
+    /// Oxygen left in the cell of `voxel` at pool index `idx` (None for an
+    /// unloaded cell, which is fresh air), or 0 if the cell holds no gas.
+    fn cell_oxygen(&self, voxel: [u32; 2], idx: Option<usize>) -> u32 {
+        let Some(idx) = idx else {
+            return OXYGEN_DEFICIT.max();
+        };
+        if !self.is_gas(voxel) {
+            return 0;
+        }
+        OXYGEN_DEFICIT.max() - OXYGEN_DEFICIT.read(&self.aux_pools[self.read_index], idx)
+    }
+
+    fn is_open(&self, voxel: [u32; 2]) -> bool {
+        material_of(voxel) == 0 || self.is_gas(voxel)
+    }
+
+    fn is_liquid(&self, voxel: [u32; 2]) -> bool {
+        material_of(voxel) != 0 && self.props(material_of(voxel)).phase as u32 == PHASE_LIQUID
+    }
+
+    /// Whether the liquid column through `pos` is pushed up: the liquid at
+    /// `pos` holds HYDROSTATIC_PUSH_HEAD voxel weights more than the column
+    /// over it (`COLUMN` as the last pressure pass found it) bears on it.
+    fn column_pushed(&self, pos: [i32; 3], chunk_idx: u32) -> bool {
+        let write = 1 - self.read_index;
+        let idx = self.pool_index(pos, chunk_idx);
+        let liquid = self.pools[write][idx];
+        let column = COLUMN.read(&self.aux_pools[self.read_index], idx) as i32;
+        pressure_of(liquid) as i32
+            >= column + HYDROSTATIC_PUSH_HEAD as i32 * head_weight(self.props(material_of(liquid)))
+    }
+
+    /// Whether the liquid at `pos` rises into the air above it: into a gap
+    /// under a pushed column or one lighter than its own head, or at an open
+    /// surface that is pushed itself.
+    fn pushed_up(&self, pos: [i32; 3], chunk_idx: u32) -> bool {
+        let write = 1 - self.read_index;
+        let liquid = self.pools[write][self.pool_index(pos, chunk_idx)];
+        let over = [pos[0], pos[1] + 2, pos[2]];
+        if in_bounds(over) {
+            let column = self.pools[write][self.pool_index(over, chunk_idx)];
+            if self.is_liquid(column) {
+                let weight = head_weight(self.props(material_of(liquid)));
+                return self.column_pushed(over, chunk_idx)
+                    || pressure_of(liquid) as i32
+                        >= pressure_of(column) as i32
+                            + (1 + HYDROSTATIC_PUSH_HEAD as i32) * weight;
+            }
+        }
+        self.surface_pushed(liquid)
+    }
+
+    /// Whether liquid with nothing above it holds enough head to be pushed up.
+    fn surface_pushed(&self, liquid: [u32; 2]) -> bool {
+        let weight = head_weight(self.props(material_of(liquid)));
+        pressure_of(liquid) as i32 >= (1 + HYDROSTATIC_PUSH_HEAD as i32) * weight
+    }
+
+    /// Whether the hole at `pos` is about to be refilled from the side by
+    /// liquid under more head than `liquid` above it, so that liquid need not
+    /// fall into it.
+    fn refilled(&self, pos: [i32; 3], liquid: [u32; 2], chunk_idx: u32) -> bool {
+        let write = 1 - self.read_index;
+        let needed = pressure_of(liquid) as i32
+            + HYDROSTATIC_PUSH_HEAD as i32 * head_weight(self.props(material_of(liquid)));
+        FACE_OFFSETS.iter().filter(|o| o[1] == 0).any(|offset| {
+            let side = [pos[0] + offset[0], pos[1], pos[2] + offset[2]];
+            if !in_bounds(side) {
+                return false;
+            }
+            let voxel = self.pools[write][self.pool_index(side, chunk_idx)];
+            self.is_liquid(voxel) && pressure_of(voxel) as i32 >= needed
+        })
+    }
+
+    /// Whether the hole at `pos` is about to be filled from below by liquid
+    /// pushed up into it.
+    fn lifted(&self, pos: [i32; 3], chunk_idx: u32) -> bool {
+        let write = 1 - self.read_index;
+        let below = [pos[0], pos[1] - 1, pos[2]];
+        in_bounds(below)
+            && self.is_liquid(self.pools[write][self.pool_index(below, chunk_idx)])
+            && self.pushed_up(below, chunk_idx)
+    }
+
+    /// Whether `voxel` bears what rests on it: a solid or powder that is not
+    /// flying.
+    fn supports(&self, voxel: [u32; 2]) -> bool {
+        let phase = self.props(material_of(voxel)).phase as u32;
+        material_of(voxel) != 0
+            && (phase == PHASE_SOLID || phase == PHASE_POWDER)
+            && !self.is_ballistic(voxel)
+    }
+
+    /// Whether the powder at `pos` may move along `dir` (down or a down
+    /// diagonal): not while cohesion holds it up over nothing, and down a
+    /// diagonal only into a drop at least as deep as its repose slope.
+    fn powder_falls(
+        &self,
+        pos: [i32; 3],
+        dir: [i32; 3],
+        props: &GpuMaterialProps,
+        chunk_idx: u32,
+    ) -> bool {
+        let write = 1 - self.read_index;
+        let below = [pos[0], pos[1] - 1, pos[2]];
+        let reach = cohesion_reach(props);
+        if reach > 0
+            && in_bounds(below)
+            && !self.supports(self.pools[write][self.pool_index(below, chunk_idx)])
+            && self.cohered(pos, reach, chunk_idx)
+        {
+            return false;
+        }
+        if dir[0] == 0 && dir[2] == 0 {
+            return true;
+        }
+        (2..=repose_drop(props)).all(|depth| {
+            let cell = [pos[0] + dir[0], pos[1] - depth, pos[2] + dir[2]];
+            in_bounds(cell) && !self.supports(self.pools[write][self.pool_index(cell, chunk_idx)])
+        })
+    }
+
+    /// Whether the resting powder at `pos` creeps one voxel along the lateral
+    /// `dir`: its repose run is more than one voxel, and the surface that way
+    /// stays clear until it drops away within the run.
+    fn powder_creeps(
+        &self,
+        pos: [i32; 3],
+        dir: [i32; 3],
+        props: &GpuMaterialProps,
+        chunk_idx: u32,
+    ) -> bool {
+        let write = 1 - self.read_index;
+        let run = repose_run(props);
+        let below = [pos[0], pos[1] - 1, pos[2]];
+        if run < 2
+            || !in_bounds(below)
+            || !self.supports(self.pools[write][self.pool_index(below, chunk_idx)])
+        {
+            return false;
+        }
+        for step in 1..=run {
+            let cell = [pos[0] + dir[0] * step, pos[1], pos[2] + dir[2] * step];
+            let under = [cell[0], cell[1] - 1, cell[2]];
+            if !in_bounds(cell)
+                || self.supports(self.pools[write][self.pool_index(cell, chunk_idx)])
+            {
+                return false;
+            }
+            if !self.supports(self.pools[write][self.pool_index(under, chunk_idx)]) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Whether the cohesive powder at `pos`, with nothing under it, is held
+    /// up: a run of the same powder out to one side reaches, within `reach`
+    /// voxels, a voxel resting on something. Grains hold to grains only, so
+    /// powder does not stick to walls.
+    fn cohered(&self, pos: [i32; 3], reach: i32, chunk_idx: u32) -> bool {
+        let write = 1 - self.read_index;
+        let mat = material_of(self.pools[write][self.pool_index(pos, chunk_idx)]);
+        FACE_OFFSETS.iter().filter(|o| o[1] == 0).any(|offset| {
+            for step in 1..=reach {
+                let cell = [pos[0] + offset[0] * step, pos[1], pos[2] + offset[2] * step];
+                if !in_bounds(cell) {
+                    return false;
+                }
+                let voxel = self.pools[write][self.pool_index(cell, chunk_idx)];
+                let below = [cell[0], cell[1] - 1, cell[2]];
+                if material_of(voxel) != mat || self.is_ballistic(voxel) || !in_bounds(below) {
+                    return false;
+                }
+                if self.supports(self.pools[write][self.pool_index(below, chunk_idx)]) {
+                    return true;
+                }
+            }
+            false
+        })
+    }
+
+    fn is_gas(&self, voxel: [u32; 2]) -> bool {
+        self.props(material_of(voxel)).phase as u32 == PHASE_GAS
+    }
+
+    /// Whether `voxel` flies: not air or gas, and fast enough on some axis.
+    fn is_ballistic(&self, voxel: [u32; 2]) -> bool {
+        let fast = Voxel::from(voxel)
+            .velocity()
+            .iter()
+            .any(|v| v.unsigned_abs() as u32 >= BALLISTIC_MIN_SPEED);
+        fast && material_of(voxel) != 0 && !self.is_gas(voxel)
+    }
+
+    /// Blast resistance of `voxel`; gas has none.
+    fn blast_resistance(&self, voxel: [u32; 2]) -> f32 {
+        if self.is_gas(voxel) {
+            return 0.0;
+        }
+        self.props(material_of(voxel)).blast_resistance
+    }
+
+    /// Mirror of `step_temperature` in temperature.wgsl.
+    fn step_temp(&self, temp: u32, delta: f32) -> u32 {
+        step_temperature(&self.temp_lut, temp as u16, delta) as u32
+    }
+
+    fn props(&self, mat_id: u32) -> &GpuMaterialProps {
+        const EMPTY: GpuMaterialProps = GpuMaterialProps {
+            density: 0.0,
+            phase: 0.0,
+            flammability: 0.0,
+            ignition_temp_quantized: 0.0,
+            decay_rate: 0.0,
+            decay_threshold: 0.0,
+            decay_product_id: 0.0,
+            viscosity: 0.0,
+            thermal_conductivity: 0.0,
+            phase_change_temp_quantized: 0.0,
+            phase_change_product_id: 0.0,
+            structural_integrity: 0.0,
+            electrical_conductivity: 0.0,
+            electrical_resistance: 0.0,
+            activation_threshold: 0.0,
+            charge_emission: 0.0,
+            inv_heat_capacity: 0.0,
+            latent_heat_steps: 0.0,
+            freeze_temp_quantized: 0.0,
+            freeze_product_id: 0.0,
+            radiative_emissivity: 0.0,
+            blast_resistance: 0.0,
+            repose_slope: 0.0,
+            cohesion: 0.0,
+            erodibility: 0.0,
+            compressive_strength: 0.0,
+            tensile_strength: 0.0,
+            _reserved: [0.0; 1],
+        };
+        self.material_props.get(mat_id as usize).unwrap_or(&EMPTY)
+    }
+
+    fn lookup(&self, a: u32, b: u32) -> u32 {
+        if a >= self.material_count || b >= self.material_count {
+            return NO_RULE;
+        }
+        self.rule_lookup[(a * self.material_count + b) as usize]
+    }
+}
+
+struct PoolView<'a> {
+    voxels: &'a mut Vec<[u32; 2]>,
+    aux: &'a mut Vec<u32>,
+}
+
+fn in_bounds(pos: [i32; 3]) -> bool {
+    let cs = CHUNK_SIZE as i32;
+    pos.iter().all(|&c| (0..cs).contains(&c))
+}
+
+fn voxel_index(pos: [i32; 3]) -> usize {
+    let cs = CHUNK_SIZE as usize;
+    pos[0] as usize + pos[1] as usize * cs + pos[2] as usize * cs * cs
+}
+
+/// Neighbor direction index matching `compute_neighbor_dir` in coords.wgsl.
+fn neighbor_dir(pos: [i32; 3]) -> u32 {
+    let cs = CHUNK_SIZE as i32;
+    let d = |c: i32| -> u32 {
+        if c < 0 {
+            0
+        } else if c >= cs {
+            2
+        } else {
+            1
+        }
+    };
+    let flat = d(pos[2]) * 9 + d(pos[1]) * 3 + d(pos[0]);
+    if flat < 13 {
+        flat
+    } else {
+        flat - 1
+    }
+}
+
+fn remap_coords(pos: [i32; 3]) -> [i32; 3] {
+    let cs = CHUNK_SIZE as i32;
+    [
+        pos[0].rem_euclid(cs),
+        pos[1].rem_euclid(cs),
+        pos[2].rem_euclid(cs),
+    ]
+}
+
+/// Mirror of `radiant_power` in thermal.wgsl: (T / 1000 K)^4.
+fn radiant_power(linear: f32) -> f32 {
+    let t = linear * (TEMP_QUANT_MAX_K / TEMP_QUANT_MAX_VALUE as f32) / 1000.0;
+    let t2 = t * t;
+    t2 * t2
+}
+
+/// Whether a ballistic voxel moving at `speed` along an axis steps this
+/// substep. Spreads speed / VELOCITY_PER_CELL steps a tick evenly over the
+/// substeps, the same for every voxel, so voxels flying together stay
+/// together.
+fn ballistic_step_due(speed: u32, tick: u32, substep: u32) -> bool {
+    let period = VELOCITY_PER_CELL * BALLISTIC_SUBSTEPS;
+    let phase = (tick % VELOCITY_PER_CELL) * BALLISTIC_SUBSTEPS + substep;
+    (speed * phase) % period + speed >= period
+}
+
+/// Pressure a voxel of liquid adds to the column under it: its weight, in
+/// whole pressure units and at least one.
+fn head_weight(props: &GpuMaterialProps) -> i32 {
+    ((props.density * STRUCTURAL_WEIGHT_PER_DENSITY + 0.5).floor() as i32).max(1)
+}
+
+/// Voxels of drop a powder needs beside it to slide down a diagonal.
+fn repose_drop(props: &GpuMaterialProps) -> i32 {
+    ((props.repose_slope + 0.5).floor() as i32).max(1)
+}
+
+/// Voxels across within which a powder creeps sideways toward a drop.
+fn repose_run(props: &GpuMaterialProps) -> i32 {
+    ((1.0 / props.repose_slope + 0.5).floor() as i32).max(1)
+}
+
+/// Longest overhang, in voxels, a powder stands out over nothing.
+fn cohesion_reach(props: &GpuMaterialProps) -> i32 {
+    (props.cohesion * COHESION_MAX_OVERHANG as f32 + 0.5).floor() as i32
+}
+
+/// `liquid` after moving one step along `dir`: FLOW_ACCEL faster along each
+/// axis it moved, up to FLOW_SPEED_MAX.
+fn flow(liquid: [u32; 2], dir: [i32; 3]) -> [u32; 2] {
+    let max = FLOW_SPEED_MAX as i32;
+    let mut vel = velocity_of(liquid);
+    for (v, d) in vel.iter_mut().zip(dir) {
+        if d != 0 {
+            *v = (*v + d * FLOW_ACCEL as i32).clamp(-max, max);
+        }
+    }
+    with_velocity(liquid, vel)
+}
+
+/// Speed of a flowing liquid: its largest axis of velocity.
+fn flow_speed(liquid: [u32; 2]) -> u32 {
+    Voxel::from(liquid)
+        .velocity()
+        .iter()
+        .map(|v| v.unsigned_abs() as u32)
+        .max()
+        .unwrap_or(0)
+}
+
+/// One tick of FLOW_DRAG on an axis of flow velocity.
+fn drag(v: i32) -> i32 {
+    v.signum() * (v.abs() - FLOW_DRAG as i32).max(0)
+}
+
+/// Zeroes a velocity too slow to fly on any axis, so the voxel comes to rest
+/// and ordinary movement takes over. Returns whether it still flies.
+fn settle(vel: &mut [i32; 3]) -> bool {
+    let flying = vel.iter().any(|v| v.unsigned_abs() >= BALLISTIC_MIN_SPEED);
+    if !flying {
+        *vel = [0; 3];
+    }
+    flying
+}
+
+/// Velocity field value for `v`, truncated toward zero like WGSL `i32()`.
+fn clamp_velocity(v: f32) -> i32 {
+    (v as i32).clamp(-127, 127)
+}
+
+/// Takes BALLISTIC_FRICTION off every axis but the one of an impact.
+fn apply_friction(vel: &mut [i32; 3], axis: usize) {
+    for (a, v) in vel.iter_mut().enumerate() {
+        if a != axis {
+            *v = clamp_velocity(*v as f32 * (1.0 - BALLISTIC_FRICTION));
+        }
+    }
+}
+
+fn material_of(v: [u32; 2]) -> u32 {
+    Voxel::from(v).material_id().0 as u32
+}
+
+fn temperature_of(v: [u32; 2]) -> u32 {
+    Voxel::from(v).temperature() as u32
+}
+
+fn pressure_of(v: [u32; 2]) -> u32 {
+    Voxel::from(v).pressure() as u32
+}
+
+fn velocity_of(v: [u32; 2]) -> [i32; 3] {
+    Voxel::from(v).velocity().map(i32::from)
+}
+
+/// `v` with its velocity replaced; components must fit in an i8.
+fn with_velocity(v: [u32; 2], vel: [i32; 3]) -> [u32; 2] {
+    Voxel::from(v).with_velocity(vel.map(|c| c as i8)).into()
+}
+
+/// Repack with new material and temperature, preserving velocity/pressure/flags.
+fn repack(old: [u32; 2], mat: u32, temp: u32) -> [u32; 2] {
+    Voxel::from(old)
+        .with_material_id(MaterialId(mat as u16))
+        .with_temperature(temp as u16)
+        .into()
+}
@@ -1,3 +1,4 @@
+pub mod cpu;
 pub mod pipeline;
 pub mod structural;
 
@@ -1,7 +1,7 @@
 use alkahest_core::constants::CHUNK_SIZE;
 
 /// Create the activity scan compute pipeline.
-/// Uses a separate bind group layout (5 bindings) from the main sim passes.
+/// Uses a separate bind group layout (6 bindings) from the main sim passes.
 pub fn create_activity_pipeline(
     device: &wgpu::Device,
     bind_group_layout: &wgpu::BindGroupLayout,
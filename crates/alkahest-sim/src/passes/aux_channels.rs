@@ -0,0 +1,75 @@
+use alkahest_core::aux_channel::AuxChannel;
+
+use crate::buffers::ChunkPool;
+
+/// The auxiliary channels a pass uses.
+///
+/// Passes that declare channels get the aux pools at @group(1): binding 0 is
+/// `aux_read` (storage, read) and, if the pass writes any channel, binding 1
+/// is `aux_write` (storage, read_write). All channels share the two pools, so
+/// a pass costs at most two storage buffers however many channels it touches
+/// (C-GPU-3). Writers must update only their own channels in the write pool
+/// (`with_aux_<name>`); the pre-pass copy carries the rest over.
+#[derive(Debug, Clone, Copy)]
+pub struct AuxAccess {
+    pub reads: &'static [AuxChannel],
+    pub writes: &'static [AuxChannel],
+}
+
+impl AuxAccess {
+    /// Whether the pass uses no channels (and so has no @group(1)).
+    pub fn is_empty(&self) -> bool {
+        self.reads.is_empty() && self.writes.is_empty()
+    }
+
+    /// Create the @group(1) bind group layout for this access.
+    pub fn create_bind_group_layout(
+        &self,
+        device: &wgpu::Device,
+        label: &str,
+    ) -> wgpu::BindGroupLayout {
+        let entry = |binding: u32, read_only: bool| wgpu::BindGroupLayoutEntry {
+            binding,
+            visibility: wgpu::ShaderStages::COMPUTE,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Storage { read_only },
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        };
+        let mut entries = vec![entry(0, true)];
+        if !self.writes.is_empty() {
+            entries.push(entry(1, false));
+        }
+        device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+            label: Some(label),
+            entries: &entries,
+        })
+    }
+
+    /// Bind the current aux read (and write) pools to `layout`.
+    pub fn create_bind_group(
+        &self,
+        device: &wgpu::Device,
+        layout: &wgpu::BindGroupLayout,
+        chunk_pool: &ChunkPool,
+        label: &str,
+    ) -> wgpu::BindGroup {
+        let mut entries = vec![wgpu::BindGroupEntry {
+            binding: 0,
+            resource: chunk_pool.aux_read_pool().as_entire_binding(),
+        }];
+        if !self.writes.is_empty() {
+            entries.push(wgpu::BindGroupEntry {
+                binding: 1,
+                resource: chunk_pool.aux_write_pool().as_entire_binding(),
+            });
+        }
+        device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some(label),
+            layout,
+            entries: &entries,
+        })
+    }
+}
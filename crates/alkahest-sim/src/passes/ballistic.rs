@@ -0,0 +1,61 @@
+use alkahest_core::aux_channel::{HEAD, SEDIMENT};
+use alkahest_core::constants::CHUNK_SIZE;
+
+use super::aux_channels::AuxAccess;
+
+/// A flying voxel takes its sediment and head along, in the write pool.
+pub const AUX_ACCESS: AuxAccess = AuxAccess {
+    reads: &[],
+    writes: &[SEDIMENT, HEAD],
+};
+
+/// Create a ballistic compute pipeline for `entry_point`: `integrate` for the
+/// once-a-tick gravity step, `main` for the per-sub-pass moves. Main sim
+/// (@group(0)) + aux (@group(1)).
+pub fn create_ballistic_pipeline(
+    device: &wgpu::Device,
+    bind_group_layout: &wgpu::BindGroupLayout,
+    aux_bind_group_layout: &wgpu::BindGroupLayout,
+    shader_source: &str,
+    entry_point: &str,
+) -> wgpu::ComputePipeline {
+    let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("ballistic-shader"),
+        source: wgpu::ShaderSource::Wgsl(shader_source.into()),
+    });
+
+    let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+        label: Some("ballistic-pipeline-layout"),
+        bind_group_layouts: &[bind_group_layout, aux_bind_group_layout],
+        push_constant_ranges: &[],
+    });
+
+    device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+        label: Some("ballistic-pipeline"),
+        layout: Some(&layout),
+        module: &module,
+        entry_point: Some(entry_point),
+        compilation_options: Default::default(),
+        cache: None,
+    })
+}
+
+/// Dispatch the ballistic integrate step or a single ballistic sub-pass over
+/// all active chunks.
+/// Workgroup is 8x8x4, dispatch z = active_chunk_count * (CHUNK_SIZE / 4).
+pub fn dispatch_ballistic(
+    pass: &mut wgpu::ComputePass,
+    pipeline: &wgpu::ComputePipeline,
+    bind_group: &wgpu::BindGroup,
+    aux_bind_group: &wgpu::BindGroup,
+    active_chunk_count: u32,
+) {
+    pass.set_pipeline(pipeline);
+    pass.set_bind_group(0, bind_group, &[]);
+    pass.set_bind_group(1, aux_bind_group, &[]);
+    pass.dispatch_workgroups(
+        CHUNK_SIZE / 8,
+        CHUNK_SIZE / 8,
+        active_chunk_count * (CHUNK_SIZE / 4),
+    );
+}
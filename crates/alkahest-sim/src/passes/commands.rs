@@ -1,5 +1,13 @@
-/// Maximum number of player commands per frame.
-pub const MAX_COMMANDS: u32 = 64;
+use alkahest_core::types::ChunkCoord;
+
+/// Maximum number of commands one tick applies: player tools, and the
+/// pushes a structural collapse is handed back as. Commands past it wait for
+/// the next tick.
+pub const MAX_COMMANDS: u32 = 1024;
+
+/// Commands per workgroup of the command pass (`@workgroup_size` in
+/// commands.wgsl).
+const COMMANDS_PER_WORKGROUP: u32 = 64;
 
 /// GPU-uploadable player command. Must match SimCommand in commands.wgsl.
 #[repr(C)]
@@ -17,14 +25,40 @@ pub struct SimCommand {
     pub brush_shape: u32,
 }
 
+/// A queued command, held by the coordinate of its chunk rather than its
+/// dispatch index: a command deferred past `MAX_COMMANDS` runs in a later
+/// tick, whose dispatch list may order the chunks differently.
+#[derive(Clone, Copy)]
+pub struct PendingCommand {
+    pub coord: ChunkCoord,
+    pub cmd: SimCommand,
+}
+
+impl PendingCommand {
+    /// Address `cmd` by the chunk its `chunk_dispatch_idx` names in
+    /// `coords`, the dispatch order it was built against. None if there is
+    /// no such chunk.
+    pub fn new(cmd: SimCommand, coords: &[ChunkCoord]) -> Option<Self> {
+        let coord = *coords.get(cmd.chunk_dispatch_idx as usize)?;
+        Some(Self { coord, cmd })
+    }
+
+    /// The command addressed to its chunk's index in `coords`, the dispatch
+    /// order of the tick that applies it. None if the chunk is not
+    /// dispatched in that tick.
+    pub fn resolve(&self, coords: &[ChunkCoord]) -> Option<SimCommand> {
+        let idx = coords.iter().position(|&c| c == self.coord)?;
+        Some(SimCommand {
+            chunk_dispatch_idx: idx as u32,
+            ..self.cmd
+        })
+    }
+}
+
 /// Tool type constants matching the shader.
-#[allow(dead_code)]
 pub const TOOL_PLACE: u32 = 1;
-#[allow(dead_code)]
 pub const TOOL_REMOVE: u32 = 2;
-#[allow(dead_code)]
 pub const TOOL_HEAT: u32 = 3;
-#[allow(dead_code)]
 pub const TOOL_PUSH: u32 = 4;
 
 /// GPU-uploadable simulation parameters. Must match SimParams in commands.wgsl.
@@ -76,5 +110,5 @@ pub fn dispatch_commands(
     }
     pass.set_pipeline(pipeline);
     pass.set_bind_group(0, bind_group, &[]);
-    pass.dispatch_workgroups(1, 1, 1);
+    pass.dispatch_workgroups(command_count.div_ceil(COMMANDS_PER_WORKGROUP), 1, 1);
 }
@@ -1,7 +1,12 @@
 pub(crate) mod activity;
+pub(crate) mod aux_channels;
+pub(crate) mod ballistic;
+pub(crate) mod blast;
 pub(crate) mod commands;
 pub(crate) mod electrical;
+pub(crate) mod erosion;
 pub(crate) mod movement;
+pub(crate) mod oxygen;
 pub(crate) mod pressure;
 pub(crate) mod reactions;
 pub(crate) mod thermal;
@@ -1,10 +1,47 @@
-use alkahest_core::constants::CHUNK_SIZE;
+use alkahest_core::aux_channel::{COLUMN, HEAD, SEDIMENT};
+use alkahest_core::constants::{CHUNK_SIZE, VOXELS_PER_CHUNK};
 
-/// Create the movement compute pipeline.
+use super::aux_channels::AuxAccess;
+
+/// Movement swaps each moving voxel's sediment and head along with it, in
+/// the write pool, and reads the liquid columns the last pressure pass found.
+pub const AUX_ACCESS: AuxAccess = AuxAccess {
+    reads: &[COLUMN],
+    writes: &[SEDIMENT, HEAD],
+};
+
+/// Bytes of move flags per dispatched chunk: a decided and a landed bit per
+/// voxel (movement.wgsl).
+pub const MOVE_FLAGS_PER_CHUNK: u64 = 2 * VOXELS_PER_CHUNK as u64 / 8;
+
+/// Create the move flags bind group layout @group(2): one read-write storage
+/// buffer of `MOVE_FLAGS_PER_CHUNK` bytes per chunk slot.
+pub fn create_move_flags_bind_group_layout(device: &wgpu::Device) -> wgpu::BindGroupLayout {
+    device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+        label: Some("move-flags-bind-group-layout"),
+        entries: &[wgpu::BindGroupLayoutEntry {
+            binding: 0,
+            visibility: wgpu::ShaderStages::COMPUTE,
+            ty: wgpu::BindingType::Buffer {
+                ty: wgpu::BufferBindingType::Storage { read_only: false },
+                has_dynamic_offset: false,
+                min_binding_size: None,
+            },
+            count: None,
+        }],
+    })
+}
+
+/// Create a movement compute pipeline for `entry_point`: `decide` to mark the
+/// voxels that move in a sub-pass, `apply` to move them. Main sim
+/// (@group(0)) + aux (@group(1)) + move flags (@group(2)).
 pub fn create_movement_pipeline(
     device: &wgpu::Device,
     bind_group_layout: &wgpu::BindGroupLayout,
+    aux_bind_group_layout: &wgpu::BindGroupLayout,
+    move_flags_bind_group_layout: &wgpu::BindGroupLayout,
     shader_source: &str,
+    entry_point: &str,
 ) -> wgpu::ComputePipeline {
     let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
         label: Some("movement-shader"),
@@ -13,7 +50,11 @@ pub fn create_movement_pipeline(
 
     let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
         label: Some("movement-pipeline-layout"),
-        bind_group_layouts: &[bind_group_layout],
+        bind_group_layouts: &[
+            bind_group_layout,
+            aux_bind_group_layout,
+            move_flags_bind_group_layout,
+        ],
         push_constant_ranges: &[],
     });
 
@@ -21,25 +62,32 @@ pub fn create_movement_pipeline(
         label: Some("movement-pipeline"),
         layout: Some(&layout),
         module: &module,
-        entry_point: Some("main"),
+        entry_point: Some(entry_point),
         compilation_options: Default::default(),
         cache: None,
     })
 }
 
-/// Dispatch a single movement sub-pass over all active chunks.
+/// Dispatch a single movement sub-pass over all active chunks: every decide
+/// thread, then every apply thread.
 /// Workgroup is 8x8x4, dispatch z = active_chunk_count * (CHUNK_SIZE / 4).
 pub fn dispatch_movement(
     pass: &mut wgpu::ComputePass,
-    pipeline: &wgpu::ComputePipeline,
+    pipelines: [&wgpu::ComputePipeline; 2],
     bind_group: &wgpu::BindGroup,
+    aux_bind_group: &wgpu::BindGroup,
+    move_flags_bind_group: &wgpu::BindGroup,
     active_chunk_count: u32,
 ) {
-    pass.set_pipeline(pipeline);
     pass.set_bind_group(0, bind_group, &[]);
-    pass.dispatch_workgroups(
-        CHUNK_SIZE / 8,
-        CHUNK_SIZE / 8,
-        active_chunk_count * (CHUNK_SIZE / 4),
-    );
+    pass.set_bind_group(1, aux_bind_group, &[]);
+    pass.set_bind_group(2, move_flags_bind_group, &[]);
+    for pipeline in pipelines {
+        pass.set_pipeline(pipeline);
+        pass.dispatch_workgroups(
+            CHUNK_SIZE / 8,
+            CHUNK_SIZE / 8,
+            active_chunk_count * (CHUNK_SIZE / 4),
+        );
+    }
 }
@@ -0,0 +1,58 @@
+use alkahest_core::aux_channel::OXYGEN_DEFICIT;
+use alkahest_core::constants::CHUNK_SIZE;
+
+use super::aux_channels::AuxAccess;
+
+/// The oxygen pass spreads the oxygen deficit through gas cells, carrying
+/// over what the reactions pass burned this tick.
+pub const AUX_ACCESS: AuxAccess = AuxAccess {
+    reads: &[OXYGEN_DEFICIT],
+    writes: &[OXYGEN_DEFICIT],
+};
+
+/// Create the oxygen exchange pipeline: main sim (@group(0)) + aux (@group(1)).
+pub fn create_oxygen_pipeline(
+    device: &wgpu::Device,
+    bind_group_layout: &wgpu::BindGroupLayout,
+    aux_bind_group_layout: &wgpu::BindGroupLayout,
+    shader_source: &str,
+) -> wgpu::ComputePipeline {
+    let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+        label: Some("oxygen-shader"),
+        source: wgpu::ShaderSource::Wgsl(shader_source.into()),
+    });
+
+    let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+        label: Some("oxygen-pipeline-layout"),
+        bind_group_layouts: &[bind_group_layout, aux_bind_group_layout],
+        push_constant_ranges: &[],
+    });
+
+    device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+        label: Some("oxygen-pipeline"),
+        layout: Some(&layout),
+        module: &module,
+        entry_point: Some("main"),
+        compilation_options: Default::default(),
+        cache: None,
+    })
+}
+
+/// Dispatch the oxygen pass over all active chunks.
+/// Workgroup is 8x8x4, dispatch z = active_chunk_count * (CHUNK_SIZE / 4).
+pub fn dispatch_oxygen(
+    pass: &mut wgpu::ComputePass,
+    pipeline: &wgpu::ComputePipeline,
+    bind_group: &wgpu::BindGroup,
+    aux_bind_group: &wgpu::BindGroup,
+    active_chunk_count: u32,
+) {
+    pass.set_pipeline(pipeline);
+    pass.set_bind_group(0, bind_group, &[]);
+    pass.set_bind_group(1, aux_bind_group, &[]);
+    pass.dispatch_workgroups(
+        CHUNK_SIZE / 8,
+        CHUNK_SIZE / 8,
+        active_chunk_count * (CHUNK_SIZE / 4),
+    );
+}
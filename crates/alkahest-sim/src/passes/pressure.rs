@@ -1,10 +1,25 @@
+use alkahest_core::aux_channel::{COLUMN, HEAD};
 use alkahest_core::constants::CHUNK_SIZE;
 
-/// Create the pressure compute pipeline.
+use super::aux_channels::AuxAccess;
+
+/// The pressure pass keeps each liquid's head apart from the pressure it
+/// builds up, reading the neighbors' from the read pool, and caches the
+/// liquid columns it finds in the write pool.
+pub const AUX_ACCESS: AuxAccess = AuxAccess {
+    reads: &[HEAD],
+    writes: &[HEAD, COLUMN],
+};
+
+/// Create a pressure compute pipeline for `entry_point`: `columns` to weigh
+/// the liquid columns, `main` for the per-voxel update. Main sim (@group(0))
+/// + aux (@group(1)).
 pub fn create_pressure_pipeline(
     device: &wgpu::Device,
     bind_group_layout: &wgpu::BindGroupLayout,
+    aux_bind_group_layout: &wgpu::BindGroupLayout,
     shader_source: &str,
+    entry_point: &str,
 ) -> wgpu::ComputePipeline {
     let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
         label: Some("pressure-shader"),
@@ -13,7 +28,7 @@ pub fn create_pressure_pipeline(
 
     let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
         label: Some("pressure-pipeline-layout"),
-        bind_group_layouts: &[bind_group_layout],
+        bind_group_layouts: &[bind_group_layout, aux_bind_group_layout],
         push_constant_ranges: &[],
     });
 
@@ -21,22 +36,28 @@ pub fn create_pressure_pipeline(
         label: Some("pressure-pipeline"),
         layout: Some(&layout),
         module: &module,
-        entry_point: Some("main"),
+        entry_point: Some(entry_point),
         compilation_options: Default::default(),
         cache: None,
     })
 }
 
-/// Dispatch the pressure pass over all active chunks.
-/// Workgroup is 8x8x4, dispatch z = active_chunk_count * (CHUNK_SIZE / 4).
+/// Dispatch the pressure pass over all active chunks: the `columns` step,
+/// one 8x8x1 workgroup per 8x8 columns of a chunk, then `main`, 8x8x4 with
+/// dispatch z = active_chunk_count * (CHUNK_SIZE / 4).
 pub fn dispatch_pressure(
     pass: &mut wgpu::ComputePass,
-    pipeline: &wgpu::ComputePipeline,
+    pipelines: [&wgpu::ComputePipeline; 2],
     bind_group: &wgpu::BindGroup,
+    aux_bind_group: &wgpu::BindGroup,
     active_chunk_count: u32,
 ) {
-    pass.set_pipeline(pipeline);
+    let [columns, main] = pipelines;
     pass.set_bind_group(0, bind_group, &[]);
+    pass.set_bind_group(1, aux_bind_group, &[]);
+    pass.set_pipeline(columns);
+    pass.dispatch_workgroups(CHUNK_SIZE / 8, CHUNK_SIZE / 8, active_chunk_count);
+    pass.set_pipeline(main);
     pass.dispatch_workgroups(
         CHUNK_SIZE / 8,
         CHUNK_SIZE / 8,
@@ -1,13 +1,22 @@
-// Superseded by electrical::create_charge_reaction_pipeline and
-// electrical::dispatch_reactions_with_charge (M15), but retained for reference.
-#![allow(dead_code)]
-
+use alkahest_core::aux_channel::{BLAST, BLAST_FRONT, CHARGE, OXYGEN_DEFICIT, SEDIMENT};
 use alkahest_core::constants::CHUNK_SIZE;
 
-/// Create the reaction compute pipeline (single bind group, no charge conditions).
+use super::aux_channels::AuxAccess;
+
+/// Reactions read charge for charge-gated rules (M15), read and burn
+/// oxygen for oxygen-consuming ones, read sediment to leave liquid carrying
+/// it alone, and seed blast fronts where they detonate. The main sim bind group is at the 8-binding limit, so the aux
+/// pools go in @group(1).
+pub const AUX_ACCESS: AuxAccess = AuxAccess {
+    reads: &[CHARGE, OXYGEN_DEFICIT, SEDIMENT],
+    writes: &[OXYGEN_DEFICIT, BLAST, BLAST_FRONT],
+};
+
+/// Create the reaction compute pipeline: main sim (@group(0)) + aux (@group(1)).
 pub fn create_reaction_pipeline(
     device: &wgpu::Device,
     bind_group_layout: &wgpu::BindGroupLayout,
+    aux_bind_group_layout: &wgpu::BindGroupLayout,
     shader_source: &str,
 ) -> wgpu::ComputePipeline {
     let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
@@ -17,7 +26,7 @@ pub fn create_reaction_pipeline(
 
     let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
         label: Some("reactions-pipeline-layout"),
-        bind_group_layouts: &[bind_group_layout],
+        bind_group_layouts: &[bind_group_layout, aux_bind_group_layout],
         push_constant_ranges: &[],
     });
 
@@ -37,10 +46,12 @@ pub fn dispatch_reactions(
     pass: &mut wgpu::ComputePass,
     pipeline: &wgpu::ComputePipeline,
     bind_group: &wgpu::BindGroup,
+    aux_bind_group: &wgpu::BindGroup,
     active_chunk_count: u32,
 ) {
     pass.set_pipeline(pipeline);
     pass.set_bind_group(0, bind_group, &[]);
+    pass.set_bind_group(1, aux_bind_group, &[]);
     pass.dispatch_workgroups(
         CHUNK_SIZE / 8,
         CHUNK_SIZE / 8,
@@ -1,19 +1,36 @@
 use alkahest_core::constants::{CHUNK_DESC_STRIDE, CHUNK_SIZE, MAX_CHUNK_SLOTS, VOXELS_PER_CHUNK};
+use alkahest_core::types::ChunkCoord;
 
 use alkahest_rules::GpuRuleData;
 
 use crate::buffers::ChunkPool;
-use crate::conflict::{build_movement_schedule, MovementUniforms, SubPass};
+use crate::conflict::{
+    build_ballistic_schedule, build_movement_schedule, BallisticSubPass, BallisticUniforms,
+    MovementUniforms, SubPass,
+};
+use crate::passes::aux_channels::AuxAccess;
+use crate::passes::ballistic;
+use crate::passes::blast;
 pub use crate::passes::commands::SimCommand;
-use crate::passes::commands::{self, SimParams, MAX_COMMANDS};
+use crate::passes::commands::{self, PendingCommand, SimParams, MAX_COMMANDS};
 use crate::passes::electrical;
+use crate::passes::erosion;
 use crate::passes::movement;
+use crate::passes::oxygen;
 use crate::passes::pressure;
+use crate::passes::reactions;
 use crate::passes::thermal;
 
 /// GPU debug buffer size (C-GPU-10).
 const DEBUG_BUFFER_SIZE: u64 = 4096;
 
+/// Size of the uniform buffer, which covers all pass uniform structs.
+const UNIFORM_SIZE: u64 = 32;
+
+/// Uniform slots in the staging ring: room for every pass of the four ticks
+/// a frame batches into one encoder.
+const UNIFORM_STAGING_SLOTS: u64 = 4 * 128;
+
 /// Reaction uniform struct uploaded each tick. Must match ReactionUniforms in reactions.wgsl.
 #[repr(C)]
 #[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
@@ -28,16 +45,34 @@ struct ReactionUniforms {
     _pad5: u32,
 }
 
+/// Thermal uniform struct uploaded each tick. Must match ThermalUniforms in thermal.wgsl.
+#[repr(C)]
+#[derive(Clone, Copy, bytemuck::Pod, bytemuck::Zeroable)]
+struct ThermalUniforms {
+    tick: u32,
+    material_count: u32,
+    /// 1 when some material is emissive, else the pass skips radiation.
+    radiates: u32,
+    _pad0: u32,
+    _pad1: u32,
+    _pad2: u32,
+    _pad3: u32,
+    _pad4: u32,
+}
+
 /// Per-pass timing breakdown from an instrumented tick.
 /// Only available via `tick_instrumented()` which serializes GPU work (not for game loop).
 #[derive(Debug, Clone, Default)]
 pub struct TickTimings {
     pub commands_ms: f64,
     pub movement_ms: f64,
+    pub erosion_ms: f64,
     pub reactions_ms: f64,
+    pub oxygen_ms: f64,
     pub thermal_ms: f64,
     pub electrical_ms: f64,
     pub pressure_ms: f64,
+    pub blast_ms: f64,
     pub activity_ms: f64,
     pub total_ms: f64,
 }
@@ -52,27 +87,61 @@ pub struct SimPipeline {
     rule_data_buffer: wgpu::Buffer,
     command_buffer: wgpu::Buffer,
     uniform_buffer: wgpu::Buffer,
+    /// Ring of per-pass uniforms, copied into `uniform_buffer` in encoder
+    /// order (see `stage_uniforms`).
+    uniform_staging_buffer: wgpu::Buffer,
+    uniform_slot: u64,
     chunk_desc_buffer: wgpu::Buffer,
     #[allow(dead_code)]
     debug_buffer: wgpu::Buffer,
     bind_group_layout: wgpu::BindGroupLayout,
     command_pipeline: wgpu::ComputePipeline,
-    movement_pipeline: wgpu::ComputePipeline,
+    ballistic_integrate_pipeline: wgpu::ComputePipeline,
+    ballistic_pipeline: wgpu::ComputePipeline,
+    ballistic_aux_bind_group_layout: wgpu::BindGroupLayout,
+    movement_decide_pipeline: wgpu::ComputePipeline,
+    movement_apply_pipeline: wgpu::ComputePipeline,
+    movement_aux_bind_group_layout: wgpu::BindGroupLayout,
+    move_flags_bind_group: wgpu::BindGroup,
+    erosion_pipeline: wgpu::ComputePipeline,
+    erosion_aux_bind_group_layout: wgpu::BindGroupLayout,
     reaction_pipeline: wgpu::ComputePipeline,
+    oxygen_pipeline: wgpu::ComputePipeline,
     thermal_pipeline: wgpu::ComputePipeline,
     electrical_pipeline: wgpu::ComputePipeline,
     electrical_bind_group_layout: wgpu::BindGroupLayout,
-    charge_bind_group_layout: wgpu::BindGroupLayout,
+    electrical_aux_bind_group_layout: wgpu::BindGroupLayout,
+    reactions_aux_bind_group_layout: wgpu::BindGroupLayout,
+    oxygen_aux_bind_group_layout: wgpu::BindGroupLayout,
+    pressure_columns_pipeline: wgpu::ComputePipeline,
     pressure_pipeline: wgpu::ComputePipeline,
+    pressure_aux_bind_group_layout: wgpu::BindGroupLayout,
+    blast_pipeline: wgpu::ComputePipeline,
+    blast_aux_bind_group_layout: wgpu::BindGroupLayout,
     activity_pipeline: wgpu::ComputePipeline,
     activity_bind_group_layout: wgpu::BindGroupLayout,
     activity_flags_buffer: wgpu::Buffer,
-    /// Double-buffered staging for async readback of activity flags (C-GPU-8).
+    /// One u32 the activity pass sets when a tick breaks a structure; only
+    /// `clear_structure_broken` resets it.
+    structure_broken_buffer: wgpu::Buffer,
+    /// Double-buffered staging for async readback of activity flags, then
+    /// the structure word (C-GPU-8).
     staging_buffers: [wgpu::Buffer; 2],
     staging_index: usize,
+    /// Host copy of the structure word, as of the last readback.
+    structure_broken: bool,
+    ballistic_schedule: Vec<BallisticSubPass>,
     movement_schedule: Vec<SubPass>,
     material_count: u32,
-    pending_commands: Vec<SimCommand>,
+    /// See `GpuRuleData::radiates`.
+    radiates: bool,
+    pending_commands: Vec<PendingCommand>,
+    /// World coordinate of each dispatched chunk, in dispatch order.
+    chunk_coords: Vec<ChunkCoord>,
+    /// How many pending commands the last `upload_commands` covered, and how
+    /// many of those it wrote to the command buffer; the next tick applies
+    /// and drains them.
+    uploaded_commands: (usize, u32),
     tick_count: u64,
     paused: bool,
     single_step_requested: bool,
@@ -95,10 +164,25 @@ impl SimPipeline {
         // Uniform buffer (32 bytes, covers all pass uniform structs)
         let uniform_buffer = device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("sim-uniform-buffer"),
-            size: 32,
+            size: UNIFORM_SIZE,
             usage: wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
             mapped_at_creation: false,
         });
+        let uniform_staging_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("sim-uniform-staging-buffer"),
+            size: UNIFORM_STAGING_SLOTS * UNIFORM_SIZE,
+            usage: wgpu::BufferUsages::COPY_SRC | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        // Move flags: decided and landed bits per voxel of each dispatched
+        // chunk, all clear between movement sub-pass pairs
+        let move_flags_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("move-flags-buffer"),
+            size: chunk_pool.slot_count() as u64 * movement::MOVE_FLAGS_PER_CHUNK,
+            usage: wgpu::BufferUsages::STORAGE,
+            mapped_at_creation: false,
+        });
 
         // Chunk descriptor buffer: CHUNK_DESC_STRIDE * 4 bytes per chunk entry
         let chunk_desc_size = MAX_CHUNK_SLOTS as u64 * CHUNK_DESC_STRIDE as u64 * 4;
@@ -130,17 +214,27 @@ impl SimPipeline {
             mapped_at_creation: false,
         });
 
+        let structure_broken_buffer = device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("structure-broken-buffer"),
+            size: 4,
+            usage: wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_SRC
+                | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
         // Staging buffers for async readback (C-GPU-8)
+        let staging_size = activity_flags_size + 4;
         let staging_buffers = [
             device.create_buffer(&wgpu::BufferDescriptor {
                 label: Some("activity-staging-0"),
-                size: activity_flags_size,
+                size: staging_size,
                 usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
                 mapped_at_creation: false,
             }),
             device.create_buffer(&wgpu::BufferDescriptor {
                 label: Some("activity-staging-1"),
-                size: activity_flags_size,
+                size: staging_size,
                 usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
                 mapped_at_creation: false,
             }),
@@ -241,7 +335,7 @@ impl SimPipeline {
             ],
         });
 
-        // Activity scan bind group layout (separate, 5 bindings)
+        // Activity scan bind group layout (separate, 8 bindings)
         let activity_bind_group_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
                 label: Some("activity-bind-group-layout"),
@@ -301,14 +395,62 @@ impl SimPipeline {
                         },
                         count: None,
                     },
+                    // binding 5: aux write pool (storage, read — post-sim blast fronts)
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 5,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: true },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    // binding 6: material properties (storage, read)
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 6,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: true },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
+                    // binding 7: structure broken (storage, read_write)
+                    wgpu::BindGroupLayoutEntry {
+                        binding: 7,
+                        visibility: wgpu::ShaderStages::COMPUTE,
+                        ty: wgpu::BindingType::Buffer {
+                            ty: wgpu::BufferBindingType::Storage { read_only: false },
+                            has_dynamic_offset: false,
+                            min_binding_size: None,
+                        },
+                        count: None,
+                    },
                 ],
             });
 
-        // Electrical bind group layout (separate, 7 bindings — M15)
+        // Electrical bind group layout (separate, 5 bindings — M15)
         let electrical_bind_group_layout = electrical::create_electrical_bind_group_layout(device);
 
-        // Charge read bind group layout for reactions @group(1) (M15)
-        let charge_bind_group_layout = electrical::create_charge_bind_group_layout(device);
+        // Aux channel layouts at @group(1) for the passes that declare channels
+        let electrical_aux_bind_group_layout = electrical::AUX_ACCESS
+            .create_bind_group_layout(device, "electrical-aux-bind-group-layout");
+        let ballistic_aux_bind_group_layout = ballistic::AUX_ACCESS
+            .create_bind_group_layout(device, "ballistic-aux-bind-group-layout");
+        let movement_aux_bind_group_layout =
+            movement::AUX_ACCESS.create_bind_group_layout(device, "movement-aux-bind-group-layout");
+        let erosion_aux_bind_group_layout =
+            erosion::AUX_ACCESS.create_bind_group_layout(device, "erosion-aux-bind-group-layout");
+        let reactions_aux_bind_group_layout = reactions::AUX_ACCESS
+            .create_bind_group_layout(device, "reactions-aux-bind-group-layout");
+        let oxygen_aux_bind_group_layout =
+            oxygen::AUX_ACCESS.create_bind_group_layout(device, "oxygen-aux-bind-group-layout");
+        let pressure_aux_bind_group_layout =
+            pressure::AUX_ACCESS.create_bind_group_layout(device, "pressure-aux-bind-group-layout");
+        let blast_aux_bind_group_layout =
+            blast::AUX_ACCESS.create_bind_group_layout(device, "blast-aux-bind-group-layout");
 
         // Compose shader sources
         let constants_preamble = format!(
@@ -326,10 +468,44 @@ impl SimPipeline {
              const PRESSURE_DIFFUSION_RATE: f32 = {:.6};\n\
              const MAX_PRESSURE: u32 = {}u;\n\
              const THERMAL_PRESSURE_FACTOR: u32 = {}u;\n\
+             const HYDROSTATIC_DECAY_PERIOD: u32 = {}u;\n\
+             const HYDROSTATIC_PUSH_HEAD: u32 = {}u;\n\
+             const STRUCTURAL_WEIGHT_PER_DENSITY: f32 = {:.6};\n\
              const ELECTRICAL_DIFFUSION_RATE: f32 = {:.6};\n\
              const CHARGE_MAX: u32 = {}u;\n\
              const CHARGE_DECAY_RATE: u32 = {}u;\n\
-             const JOULE_HEATING_FACTOR: f32 = {:.6};\n",
+             const JOULE_HEATING_FACTOR: f32 = {:.6};\n\
+             const RADIATION_RATE: f32 = {:.6};\n\
+             const RADIATION_RADIUS: u32 = {}u;\n\
+             const RADIATION_MAX_GAP_FRACTION: f32 = {:.6};\n\
+             const OXYGEN_DIFFUSION_RATE: f32 = {:.6};\n\
+             const OXYGEN_DRAFT_RATE: f32 = {:.6};\n\
+             const VELOCITY_PER_CELL: u32 = {}u;\n\
+             const BALLISTIC_SUBSTEPS: u32 = {}u;\n\
+             const BALLISTIC_MIN_SPEED: u32 = {}u;\n\
+             const BALLISTIC_GRAVITY: u32 = {}u;\n\
+             const BALLISTIC_RESTITUTION: f32 = {:.6};\n\
+             const BALLISTIC_FRICTION: f32 = {:.6};\n\
+             const COHESION_MAX_OVERHANG: u32 = {}u;\n\
+             const FLOW_ACCEL: u32 = {}u;\n\
+             const FLOW_DRAG: u32 = {}u;\n\
+             const FLOW_SPEED_MAX: u32 = {}u;\n\
+             const EROSION_MIN_FLOW: u32 = {}u;\n\
+             const SEDIMENT_SETTLE_FLOW: u32 = {}u;\n\
+             const BLAST_FALLOFF: f32 = {:.6};\n\
+             const BLAST_DECAY: u32 = {}u;\n\
+             const BLAST_FRAGMENT_SPEED_DIVISOR: u32 = {}u;\n\
+             const BLAST_FRONT_NONE: u32 = {}u;\n\
+             const BLAST_FRONT_LIVE: u32 = {}u;\n\
+             const BLAST_FRONT_SOURCE: u32 = {}u;\n\
+             const BLAST_FRONT_SEEDED: u32 = {}u;\n\
+             const ACTIVITY_DIRTY: u32 = {}u;\n\
+             const ACTIVITY_BLAST_SHIFT: u32 = {}u;\n\
+             const ACTIVITY_BLAST_MAX: u32 = {}u;\n\
+             const TEMP_QUANT_MAX_K: f32 = {:.6};\n\
+             const RULE_COUNT_BITS: u32 = {}u;\n\
+             const MATERIAL_PROPS_STRIDE: u32 = {}u;\n\
+             const NO_CATALYST: u32 = {}u;\n",
             CHUNK_SIZE,
             VOXELS_PER_CHUNK,
             alkahest_core::constants::DIFFUSION_RATE,
@@ -345,67 +521,197 @@ impl SimPipeline {
             alkahest_core::constants::PRESSURE_DIFFUSION_RATE,
             alkahest_core::constants::MAX_PRESSURE,
             alkahest_core::constants::THERMAL_PRESSURE_FACTOR,
+            alkahest_core::constants::HYDROSTATIC_DECAY_PERIOD,
+            alkahest_core::constants::HYDROSTATIC_PUSH_HEAD,
+            alkahest_core::constants::STRUCTURAL_WEIGHT_PER_DENSITY,
             alkahest_core::constants::ELECTRICAL_DIFFUSION_RATE,
             alkahest_core::constants::CHARGE_MAX,
             alkahest_core::constants::CHARGE_DECAY_RATE,
             alkahest_core::constants::JOULE_HEATING_FACTOR,
+            alkahest_core::constants::RADIATION_RATE,
+            alkahest_core::constants::RADIATION_RADIUS,
+            alkahest_core::constants::RADIATION_MAX_GAP_FRACTION,
+            alkahest_core::constants::OXYGEN_DIFFUSION_RATE,
+            alkahest_core::constants::OXYGEN_DRAFT_RATE,
+            alkahest_core::constants::VELOCITY_PER_CELL,
+            alkahest_core::constants::BALLISTIC_SUBSTEPS,
+            alkahest_core::constants::BALLISTIC_MIN_SPEED,
+            alkahest_core::constants::BALLISTIC_GRAVITY,
+            alkahest_core::constants::BALLISTIC_RESTITUTION,
+            alkahest_core::constants::BALLISTIC_FRICTION,
+            alkahest_core::constants::COHESION_MAX_OVERHANG,
+            alkahest_core::constants::FLOW_ACCEL,
+            alkahest_core::constants::FLOW_DRAG,
+            alkahest_core::constants::FLOW_SPEED_MAX,
+            alkahest_core::constants::EROSION_MIN_FLOW,
+            alkahest_core::constants::SEDIMENT_SETTLE_FLOW,
+            alkahest_core::constants::BLAST_FALLOFF,
+            alkahest_core::constants::BLAST_DECAY,
+            alkahest_core::constants::BLAST_FRAGMENT_SPEED_DIVISOR,
+            alkahest_core::constants::BLAST_FRONT_NONE,
+            alkahest_core::constants::BLAST_FRONT_LIVE,
+            alkahest_core::constants::BLAST_FRONT_SOURCE,
+            alkahest_core::constants::BLAST_FRONT_SEEDED,
+            alkahest_core::constants::ACTIVITY_DIRTY,
+            alkahest_core::constants::ACTIVITY_BLAST_SHIFT,
+            alkahest_core::constants::ACTIVITY_BLAST_MAX,
+            alkahest_core::constants::TEMP_QUANT_MAX_K,
+            alkahest_core::constants::RULE_COUNT_BITS,
+            alkahest_core::constants::MATERIAL_PROPS_STRIDE,
+            alkahest_core::constants::NO_CATALYST,
         );
         let types_wgsl = include_str!("../../../shaders/common/types.wgsl");
         let coords_wgsl = include_str!("../../../shaders/common/coords.wgsl");
         let rng_wgsl = include_str!("../../../shaders/common/rng.wgsl");
+        let temperature_wgsl = include_str!("../../../shaders/common/temperature.wgsl");
+        let aux_channels_wgsl = include_str!("../../../shaders/common/aux_channels.wgsl");
         let commands_wgsl = include_str!("../../../shaders/sim/commands.wgsl");
+        let ballistic_wgsl = include_str!("../../../shaders/sim/ballistic.wgsl");
         let movement_wgsl = include_str!("../../../shaders/sim/movement.wgsl");
+        let erosion_wgsl = include_str!("../../../shaders/sim/erosion.wgsl");
         let reactions_wgsl = include_str!("../../../shaders/sim/reactions.wgsl");
+        let oxygen_wgsl = include_str!("../../../shaders/sim/oxygen.wgsl");
         let thermal_wgsl = include_str!("../../../shaders/sim/thermal.wgsl");
         let electrical_wgsl = include_str!("../../../shaders/sim/electrical.wgsl");
         let pressure_wgsl = include_str!("../../../shaders/sim/pressure.wgsl");
+        let blast_wgsl = include_str!("../../../shaders/sim/blast.wgsl");
         let activity_wgsl = include_str!("../../../shaders/sim/activity.wgsl");
 
         let command_shader_source = format!(
             "{constants_preamble}\n{types_wgsl}\n{coords_wgsl}\n{rng_wgsl}\n{commands_wgsl}"
         );
+        let ballistic_shader_source = format!(
+            "{constants_preamble}\n{types_wgsl}\n{coords_wgsl}\n{}\n{ballistic_wgsl}",
+            aux_prelude(&ballistic::AUX_ACCESS, aux_channels_wgsl)
+        );
         let movement_shader_source = format!(
-            "{constants_preamble}\n{types_wgsl}\n{coords_wgsl}\n{rng_wgsl}\n{movement_wgsl}"
+            "{constants_preamble}\n{types_wgsl}\n{coords_wgsl}\n{rng_wgsl}\n{}\n{movement_wgsl}",
+            aux_prelude(&movement::AUX_ACCESS, aux_channels_wgsl)
+        );
+        let erosion_shader_source = format!(
+            "{constants_preamble}\n{types_wgsl}\n{coords_wgsl}\n{rng_wgsl}\n{}\n{erosion_wgsl}",
+            aux_prelude(&erosion::AUX_ACCESS, aux_channels_wgsl)
         );
         let reactions_shader_source = format!(
-            "{constants_preamble}\n{types_wgsl}\n{coords_wgsl}\n{rng_wgsl}\n{reactions_wgsl}"
+            "{constants_preamble}\n{types_wgsl}\n{coords_wgsl}\n{rng_wgsl}\n{temperature_wgsl}\n{}\n{reactions_wgsl}",
+            aux_prelude(&reactions::AUX_ACCESS, aux_channels_wgsl)
+        );
+        let oxygen_shader_source = format!(
+            "{constants_preamble}\n{types_wgsl}\n{coords_wgsl}\n{}\n{oxygen_wgsl}",
+            aux_prelude(&oxygen::AUX_ACCESS, aux_channels_wgsl)
         );
         let thermal_shader_source = format!(
-            "{constants_preamble}\n{types_wgsl}\n{coords_wgsl}\n{rng_wgsl}\n{thermal_wgsl}"
+            "{constants_preamble}\n{types_wgsl}\n{coords_wgsl}\n{rng_wgsl}\n{temperature_wgsl}\n{thermal_wgsl}"
         );
         let electrical_shader_source = format!(
-            "{constants_preamble}\n{types_wgsl}\n{coords_wgsl}\n{rng_wgsl}\n{electrical_wgsl}"
+            "{constants_preamble}\n{types_wgsl}\n{coords_wgsl}\n{rng_wgsl}\n{}\n{electrical_wgsl}",
+            aux_prelude(&electrical::AUX_ACCESS, aux_channels_wgsl)
         );
         let pressure_shader_source = format!(
-            "{constants_preamble}\n{types_wgsl}\n{coords_wgsl}\n{rng_wgsl}\n{pressure_wgsl}"
+            "{constants_preamble}\n{types_wgsl}\n{coords_wgsl}\n{rng_wgsl}\n{}\n{pressure_wgsl}",
+            aux_prelude(&pressure::AUX_ACCESS, aux_channels_wgsl)
+        );
+        let blast_shader_source = format!(
+            "{constants_preamble}\n{types_wgsl}\n{coords_wgsl}\n{}\n{blast_wgsl}",
+            aux_prelude(&blast::AUX_ACCESS, aux_channels_wgsl)
         );
-        let activity_shader_source = format!("{constants_preamble}\n{activity_wgsl}");
+        let activity_shader_source =
+            format!("{constants_preamble}\n{types_wgsl}\n{aux_channels_wgsl}\n{activity_wgsl}");
 
         let command_pipeline =
             commands::create_command_pipeline(device, &bind_group_layout, &command_shader_source);
-        let movement_pipeline =
-            movement::create_movement_pipeline(device, &bind_group_layout, &movement_shader_source);
-        let reaction_pipeline = electrical::create_charge_reaction_pipeline(
+        let ballistic_integrate_pipeline = ballistic::create_ballistic_pipeline(
+            device,
+            &bind_group_layout,
+            &ballistic_aux_bind_group_layout,
+            &ballistic_shader_source,
+            "integrate",
+        );
+        let ballistic_pipeline = ballistic::create_ballistic_pipeline(
             device,
             &bind_group_layout,
-            &charge_bind_group_layout,
+            &ballistic_aux_bind_group_layout,
+            &ballistic_shader_source,
+            "main",
+        );
+        let move_flags_bind_group_layout = movement::create_move_flags_bind_group_layout(device);
+        let move_flags_bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("move-flags-bind-group"),
+            layout: &move_flags_bind_group_layout,
+            entries: &[wgpu::BindGroupEntry {
+                binding: 0,
+                resource: move_flags_buffer.as_entire_binding(),
+            }],
+        });
+        let movement_decide_pipeline = movement::create_movement_pipeline(
+            device,
+            &bind_group_layout,
+            &movement_aux_bind_group_layout,
+            &move_flags_bind_group_layout,
+            &movement_shader_source,
+            "decide",
+        );
+        let movement_apply_pipeline = movement::create_movement_pipeline(
+            device,
+            &bind_group_layout,
+            &movement_aux_bind_group_layout,
+            &move_flags_bind_group_layout,
+            &movement_shader_source,
+            "apply",
+        );
+        let erosion_pipeline = erosion::create_erosion_pipeline(
+            device,
+            &bind_group_layout,
+            &erosion_aux_bind_group_layout,
+            &erosion_shader_source,
+        );
+        let reaction_pipeline = reactions::create_reaction_pipeline(
+            device,
+            &bind_group_layout,
+            &reactions_aux_bind_group_layout,
             &reactions_shader_source,
         );
+        let oxygen_pipeline = oxygen::create_oxygen_pipeline(
+            device,
+            &bind_group_layout,
+            &oxygen_aux_bind_group_layout,
+            &oxygen_shader_source,
+        );
         let thermal_pipeline =
             thermal::create_thermal_pipeline(device, &bind_group_layout, &thermal_shader_source);
         let electrical_pipeline = electrical::create_electrical_pipeline(
             device,
             &electrical_bind_group_layout,
+            &electrical_aux_bind_group_layout,
             &electrical_shader_source,
         );
-        let pressure_pipeline =
-            pressure::create_pressure_pipeline(device, &bind_group_layout, &pressure_shader_source);
+        let pressure_columns_pipeline = pressure::create_pressure_pipeline(
+            device,
+            &bind_group_layout,
+            &pressure_aux_bind_group_layout,
+            &pressure_shader_source,
+            "columns",
+        );
+        let pressure_pipeline = pressure::create_pressure_pipeline(
+            device,
+            &bind_group_layout,
+            &pressure_aux_bind_group_layout,
+            &pressure_shader_source,
+            "main",
+        );
+        let blast_pipeline = blast::create_blast_pipeline(
+            device,
+            &bind_group_layout,
+            &blast_aux_bind_group_layout,
+            &blast_shader_source,
+        );
         let activity_pipeline = crate::passes::activity::create_activity_pipeline(
             device,
             &activity_bind_group_layout,
             &activity_shader_source,
         );
 
+        let ballistic_schedule = build_ballistic_schedule();
         let movement_schedule = build_movement_schedule();
 
         Self {
@@ -415,25 +721,48 @@ impl SimPipeline {
             rule_data_buffer: rule_data.rule_data_buffer,
             command_buffer,
             uniform_buffer,
+            uniform_staging_buffer,
+            uniform_slot: 0,
             chunk_desc_buffer,
             debug_buffer,
             bind_group_layout,
             command_pipeline,
-            movement_pipeline,
+            ballistic_integrate_pipeline,
+            ballistic_pipeline,
+            ballistic_aux_bind_group_layout,
+            movement_decide_pipeline,
+            movement_apply_pipeline,
+            movement_aux_bind_group_layout,
+            move_flags_bind_group,
+            erosion_pipeline,
+            erosion_aux_bind_group_layout,
             reaction_pipeline,
+            oxygen_pipeline,
             thermal_pipeline,
             electrical_pipeline,
             electrical_bind_group_layout,
-            charge_bind_group_layout,
+            electrical_aux_bind_group_layout,
+            reactions_aux_bind_group_layout,
+            oxygen_aux_bind_group_layout,
+            pressure_columns_pipeline,
             pressure_pipeline,
+            pressure_aux_bind_group_layout,
+            blast_pipeline,
+            blast_aux_bind_group_layout,
             activity_pipeline,
             activity_bind_group_layout,
             activity_flags_buffer,
+            structure_broken_buffer,
             staging_buffers,
             staging_index: 0,
+            structure_broken: false,
+            ballistic_schedule,
             movement_schedule,
             material_count: rule_data.material_count,
+            radiates: rule_data.radiates,
             pending_commands: Vec::new(),
+            chunk_coords: Vec::new(),
+            uploaded_commands: (0, 0),
             tick_count: 0,
             paused: false,
             single_step_requested: false,
@@ -451,22 +780,39 @@ impl SimPipeline {
         self.chunk_pool.slot_count()
     }
 
-    /// Enqueue a player command for the next tick.
+    /// Replace the world coordinates of the dispatched chunks, in descriptor
+    /// table order.
+    pub fn upload_chunk_coords(&mut self, coords: &[ChunkCoord]) {
+        self.chunk_coords = coords.to_vec();
+    }
+
+    /// Enqueue a player command, whose `chunk_dispatch_idx` indexes the
+    /// coordinates last passed to [`Self::upload_chunk_coords`]. A tick applies
+    /// up to `MAX_COMMANDS`; the rest wait for the ticks after it and still
+    /// reach their chunk if the dispatch order changes. Commands for a chunk
+    /// that is not dispatched are dropped.
     pub fn enqueue_command(&mut self, cmd: SimCommand) {
-        if self.pending_commands.len() < MAX_COMMANDS as usize {
-            self.pending_commands.push(cmd);
-        }
+        self.pending_commands
+            .extend(PendingCommand::new(cmd, &self.chunk_coords));
     }
 
-    /// Upload pending commands to the GPU command buffer.
+    /// Upload the commands the next tick applies to the GPU command buffer,
+    /// addressed by the current chunk coordinates.
     pub fn upload_commands(&mut self, queue: &wgpu::Queue) {
-        if !self.pending_commands.is_empty() {
-            queue.write_buffer(
-                &self.command_buffer,
-                0,
-                bytemuck::cast_slice(&self.pending_commands),
-            );
+        let taken = self.tick_command_count();
+        let commands: Vec<SimCommand> = self.pending_commands[..taken]
+            .iter()
+            .filter_map(|pending| pending.resolve(&self.chunk_coords))
+            .collect();
+        if !commands.is_empty() {
+            queue.write_buffer(&self.command_buffer, 0, bytemuck::cast_slice(&commands));
         }
+        self.uploaded_commands = (taken, commands.len() as u32);
+    }
+
+    /// How many pending commands the next tick applies.
+    fn tick_command_count(&self) -> usize {
+        self.pending_commands.len().min(MAX_COMMANDS as usize)
     }
 
     /// Upload chunk descriptor data to the GPU.
@@ -480,16 +826,84 @@ impl SimPipeline {
         }
     }
 
-    /// Create the charge read bind group for reactions @group(1).
-    fn create_charge_read_bind_group(&self, device: &wgpu::Device) -> wgpu::BindGroup {
-        device.create_bind_group(&wgpu::BindGroupDescriptor {
-            label: Some("charge-read-bind-group"),
-            layout: &self.charge_bind_group_layout,
-            entries: &[wgpu::BindGroupEntry {
-                binding: 0,
-                resource: self.chunk_pool.charge_read_pool().as_entire_binding(),
-            }],
-        })
+    /// Create the aux channel bind group for the ballistic passes @group(1).
+    fn create_ballistic_aux_bind_group(&self, device: &wgpu::Device) -> wgpu::BindGroup {
+        ballistic::AUX_ACCESS.create_bind_group(
+            device,
+            &self.ballistic_aux_bind_group_layout,
+            &self.chunk_pool,
+            "ballistic-aux-bind-group",
+        )
+    }
+
+    /// Create the aux channel bind group for the movement pass @group(1).
+    fn create_movement_aux_bind_group(&self, device: &wgpu::Device) -> wgpu::BindGroup {
+        movement::AUX_ACCESS.create_bind_group(
+            device,
+            &self.movement_aux_bind_group_layout,
+            &self.chunk_pool,
+            "movement-aux-bind-group",
+        )
+    }
+
+    /// Create the aux channel bind group for the erosion pass @group(1).
+    fn create_erosion_aux_bind_group(&self, device: &wgpu::Device) -> wgpu::BindGroup {
+        erosion::AUX_ACCESS.create_bind_group(
+            device,
+            &self.erosion_aux_bind_group_layout,
+            &self.chunk_pool,
+            "erosion-aux-bind-group",
+        )
+    }
+
+    /// Create the aux channel bind group for the pressure pass @group(1).
+    fn create_pressure_aux_bind_group(&self, device: &wgpu::Device) -> wgpu::BindGroup {
+        pressure::AUX_ACCESS.create_bind_group(
+            device,
+            &self.pressure_aux_bind_group_layout,
+            &self.chunk_pool,
+            "pressure-aux-bind-group",
+        )
+    }
+
+    /// Create the aux channel bind group for reactions @group(1).
+    fn create_reactions_aux_bind_group(&self, device: &wgpu::Device) -> wgpu::BindGroup {
+        reactions::AUX_ACCESS.create_bind_group(
+            device,
+            &self.reactions_aux_bind_group_layout,
+            &self.chunk_pool,
+            "reactions-aux-bind-group",
+        )
+    }
+
+    /// Create the aux channel bind group for the oxygen pass @group(1).
+    fn create_oxygen_aux_bind_group(&self, device: &wgpu::Device) -> wgpu::BindGroup {
+        oxygen::AUX_ACCESS.create_bind_group(
+            device,
+            &self.oxygen_aux_bind_group_layout,
+            &self.chunk_pool,
+            "oxygen-aux-bind-group",
+        )
+    }
+
+    /// Create the aux channel bind group for the blast pass @group(1).
+    fn create_blast_aux_bind_group(&self, device: &wgpu::Device) -> wgpu::BindGroup {
+        blast::AUX_ACCESS.create_bind_group(
+            device,
+            &self.blast_aux_bind_group_layout,
+            &self.chunk_pool,
+            "blast-aux-bind-group",
+        )
+    }
+
+    /// Create the aux channel bind group for the electrical pass @group(1).
+    fn create_electrical_aux_bind_group(&self, device: &wgpu::Device) -> wgpu::BindGroup {
+        electrical::AUX_ACCESS.create_bind_group(
+            device,
+            &self.electrical_aux_bind_group_layout,
+            &self.chunk_pool,
+            "electrical-aux-bind-group",
+        )
     }
 
     /// Create the electrical bind group for the electrical propagation pass.
@@ -512,24 +926,40 @@ impl SimPipeline {
                 },
                 wgpu::BindGroupEntry {
                     binding: 3,
-                    resource: self.chunk_pool.charge_read_pool().as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 4,
-                    resource: self.chunk_pool.charge_write_pool().as_entire_binding(),
-                },
-                wgpu::BindGroupEntry {
-                    binding: 5,
                     resource: self.uniform_buffer.as_entire_binding(),
                 },
                 wgpu::BindGroupEntry {
-                    binding: 6,
+                    binding: 4,
                     resource: self.chunk_desc_buffer.as_entire_binding(),
                 },
             ],
         })
     }
 
+    /// Stage `uniforms` for the next pass recorded into `encoder`.
+    ///
+    /// `queue.write_buffer` lands before anything in the encoder runs, so a
+    /// tick writing the uniform buffer between passes would run every pass
+    /// with the last write. Each pass instead gets a slot of the staging ring,
+    /// copied into the uniform buffer in encoder order.
+    fn stage_uniforms(
+        &mut self,
+        queue: &wgpu::Queue,
+        encoder: &mut wgpu::CommandEncoder,
+        uniforms: &[u8],
+    ) {
+        let offset = self.uniform_slot * UNIFORM_SIZE;
+        self.uniform_slot = (self.uniform_slot + 1) % UNIFORM_STAGING_SLOTS;
+        queue.write_buffer(&self.uniform_staging_buffer, offset, uniforms);
+        encoder.copy_buffer_to_buffer(
+            &self.uniform_staging_buffer,
+            offset,
+            &self.uniform_buffer,
+            0,
+            uniforms.len() as u64,
+        );
+    }
+
     /// Run one simulation tick over all active chunks.
     ///
     /// `active_slots` is the list of pool slot indices for active chunks.
@@ -542,6 +972,8 @@ impl SimPipeline {
         active_chunk_count: u32,
         active_slots: &[u32],
     ) -> bool {
+        let (taken, command_count) = std::mem::take(&mut self.uploaded_commands);
+
         // Check pause state
         if self.paused && !self.single_step_requested {
             self.pending_commands.clear();
@@ -554,13 +986,10 @@ impl SimPipeline {
             return true;
         }
 
-        let command_count = self.pending_commands.len() as u32;
-
         // Copy read pool → write pool for each active chunk slot
         for &slot in active_slots {
             self.chunk_pool.copy_slot_read_to_write(encoder, slot);
-            self.chunk_pool
-                .copy_charge_slot_read_to_write(encoder, slot);
+            self.chunk_pool.copy_aux_slot_read_to_write(encoder, slot);
         }
 
         // Create bind group for this tick (references current read/write pools)
@@ -603,8 +1032,15 @@ impl SimPipeline {
             ],
         });
 
-        // Charge read bind group for reactions @group(1) (M15)
-        let charge_bind_group = self.create_charge_read_bind_group(device);
+        // Aux channel bind groups for ballistic, movement, erosion, reactions, oxygen,
+        // pressure and blast @group(1)
+        let ballistic_aux_bind_group = self.create_ballistic_aux_bind_group(device);
+        let movement_aux_bind_group = self.create_movement_aux_bind_group(device);
+        let erosion_aux_bind_group = self.create_erosion_aux_bind_group(device);
+        let reactions_aux_bind_group = self.create_reactions_aux_bind_group(device);
+        let oxygen_aux_bind_group = self.create_oxygen_aux_bind_group(device);
+        let pressure_aux_bind_group = self.create_pressure_aux_bind_group(device);
+        let blast_aux_bind_group = self.create_blast_aux_bind_group(device);
 
         // Pass 1: Apply player commands
         if command_count > 0 {
@@ -614,7 +1050,7 @@ impl SimPipeline {
                 _pad0: 0,
                 _pad1: 0,
             };
-            queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&params));
+            self.stage_uniforms(queue, encoder, bytemuck::bytes_of(&params));
 
             let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
                 label: Some("sim-commands-pass"),
@@ -628,15 +1064,58 @@ impl SimPipeline {
             );
         }
 
-        // Pass 2: Movement sub-passes (28 sub-passes, batched over all active chunks)
-        for sub_pass in &self.movement_schedule {
+        // Pass 2a: Ballistic integrate, then 48 ballistic sub-passes (batched)
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("sim-ballistic-integrate-pass"),
+                timestamp_writes: None,
+            });
+            ballistic::dispatch_ballistic(
+                &mut pass,
+                &self.ballistic_integrate_pipeline,
+                &bind_group,
+                &ballistic_aux_bind_group,
+                active_chunk_count,
+            );
+        }
+        for i in 0..self.ballistic_schedule.len() {
+            let sub_pass = self.ballistic_schedule[i];
+            let uniforms = BallisticUniforms {
+                axis: sub_pass.axis,
+                sign: sub_pass.sign,
+                parity: sub_pass.parity,
+                substep: sub_pass.substep,
+                tick: self.tick_count as u32,
+                chunk_count: active_chunk_count,
+                _pad: [0; 2],
+            };
+            self.stage_uniforms(queue, encoder, bytemuck::bytes_of(&uniforms));
+
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("sim-ballistic-pass"),
+                timestamp_writes: None,
+            });
+            ballistic::dispatch_ballistic(
+                &mut pass,
+                &self.ballistic_pipeline,
+                &bind_group,
+                &ballistic_aux_bind_group,
+                active_chunk_count,
+            );
+        }
+
+        // Pass 2: Movement sub-passes (28 sub-passes, each a decide and an apply
+        // dispatch, batched over all active chunks)
+        for i in 0..self.movement_schedule.len() {
+            let sub_pass = self.movement_schedule[i];
             let uniforms = MovementUniforms {
                 direction: sub_pass.direction,
                 parity: sub_pass.parity,
                 tick: self.tick_count as u32,
-                _pad: [0; 3],
+                axis: sub_pass.axis,
+                _pad: [0; 2],
             };
-            queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&uniforms));
+            self.stage_uniforms(queue, encoder, bytemuck::bytes_of(&uniforms));
 
             let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
                 label: Some("sim-movement-pass"),
@@ -644,8 +1123,40 @@ impl SimPipeline {
             });
             movement::dispatch_movement(
                 &mut pass,
-                &self.movement_pipeline,
+                [
+                    &self.movement_decide_pipeline,
+                    &self.movement_apply_pipeline,
+                ],
                 &bind_group,
+                &movement_aux_bind_group,
+                &self.move_flags_bind_group,
+                active_chunk_count,
+            );
+        }
+
+        // Pass 2b: Erosion and sediment transport (batched)
+        {
+            let uniforms = ReactionUniforms {
+                tick: self.tick_count as u32,
+                material_count: self.material_count,
+                _pad0: 0,
+                _pad1: 0,
+                _pad2: 0,
+                _pad3: 0,
+                _pad4: 0,
+                _pad5: 0,
+            };
+            self.stage_uniforms(queue, encoder, bytemuck::bytes_of(&uniforms));
+
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("sim-erosion-pass"),
+                timestamp_writes: None,
+            });
+            erosion::dispatch_erosion(
+                &mut pass,
+                &self.erosion_pipeline,
+                &bind_group,
+                &erosion_aux_bind_group,
                 active_chunk_count,
             );
         }
@@ -662,34 +1173,49 @@ impl SimPipeline {
                 _pad4: 0,
                 _pad5: 0,
             };
-            queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&uniforms));
+            self.stage_uniforms(queue, encoder, bytemuck::bytes_of(&uniforms));
 
             let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
                 label: Some("sim-reactions-pass"),
                 timestamp_writes: None,
             });
-            electrical::dispatch_reactions_with_charge(
+            reactions::dispatch_reactions(
                 &mut pass,
                 &self.reaction_pipeline,
                 &bind_group,
-                &charge_bind_group,
+                &reactions_aux_bind_group,
+                active_chunk_count,
+            );
+        }
+
+        // Pass 3b: Oxygen exchange (batched; no uniforms)
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("sim-oxygen-pass"),
+                timestamp_writes: None,
+            });
+            oxygen::dispatch_oxygen(
+                &mut pass,
+                &self.oxygen_pipeline,
+                &bind_group,
+                &oxygen_aux_bind_group,
                 active_chunk_count,
             );
         }
 
         // Pass 4a: Thermal diffusion (batched)
         {
-            let uniforms = ReactionUniforms {
+            let uniforms = ThermalUniforms {
                 tick: self.tick_count as u32,
                 material_count: self.material_count,
+                radiates: self.radiates as u32,
                 _pad0: 0,
                 _pad1: 0,
                 _pad2: 0,
                 _pad3: 0,
                 _pad4: 0,
-                _pad5: 0,
             };
-            queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&uniforms));
+            self.stage_uniforms(queue, encoder, bytemuck::bytes_of(&uniforms));
 
             let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
                 label: Some("sim-thermal-pass"),
@@ -715,9 +1241,10 @@ impl SimPipeline {
                 _pad4: 0,
                 _pad5: 0,
             };
-            queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&uniforms));
+            self.stage_uniforms(queue, encoder, bytemuck::bytes_of(&uniforms));
 
             let electrical_bind_group = self.create_electrical_bind_group(device);
+            let electrical_aux_bind_group = self.create_electrical_aux_bind_group(device);
 
             let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
                 label: Some("sim-electrical-pass"),
@@ -727,6 +1254,7 @@ impl SimPipeline {
                 &mut pass,
                 &self.electrical_pipeline,
                 &electrical_bind_group,
+                &electrical_aux_bind_group,
                 active_chunk_count,
             );
         }
@@ -743,7 +1271,7 @@ impl SimPipeline {
                 _pad4: 0,
                 _pad5: 0,
             };
-            queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&uniforms));
+            self.stage_uniforms(queue, encoder, bytemuck::bytes_of(&uniforms));
 
             let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
                 label: Some("sim-pressure-pass"),
@@ -751,8 +1279,24 @@ impl SimPipeline {
             });
             pressure::dispatch_pressure(
                 &mut pass,
-                &self.pressure_pipeline,
+                [&self.pressure_columns_pipeline, &self.pressure_pipeline],
+                &bind_group,
+                &pressure_aux_bind_group,
+                active_chunk_count,
+            );
+        }
+
+        // Pass 5b: Blast fronts (batched; no uniforms)
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("sim-blast-pass"),
+                timestamp_writes: None,
+            });
+            blast::dispatch_blast(
+                &mut pass,
+                &self.blast_pipeline,
                 &bind_group,
+                &blast_aux_bind_group,
                 active_chunk_count,
             );
         }
@@ -769,11 +1313,7 @@ impl SimPipeline {
                 _pad4: 0,
                 _pad5: 0,
             };
-            queue.write_buffer(
-                &self.uniform_buffer,
-                0,
-                bytemuck::bytes_of(&activity_uniforms),
-            );
+            self.stage_uniforms(queue, encoder, bytemuck::bytes_of(&activity_uniforms));
 
             // Clear activity flags before scan (each workgroup atomicOr's into these)
             encoder.clear_buffer(&self.activity_flags_buffer, 0, None);
@@ -802,6 +1342,18 @@ impl SimPipeline {
                         binding: 4,
                         resource: self.chunk_desc_buffer.as_entire_binding(),
                     },
+                    wgpu::BindGroupEntry {
+                        binding: 5,
+                        resource: self.chunk_pool.aux_write_pool().as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 6,
+                        resource: self.material_props_buffer.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 7,
+                        resource: self.structure_broken_buffer.as_entire_binding(),
+                    },
                 ],
             });
 
@@ -820,7 +1372,7 @@ impl SimPipeline {
         // Swap pool read/write indices
         self.chunk_pool.swap();
         self.tick_count += 1;
-        self.pending_commands.clear();
+        self.pending_commands.drain(..taken);
 
         // Request async readback of activity flags
         self.request_readback(encoder, active_chunk_count);
@@ -845,6 +1397,7 @@ impl SimPipeline {
     ) -> Option<TickTimings> {
         use std::time::Instant;
 
+        let (taken, command_count) = std::mem::take(&mut self.uploaded_commands);
         if self.paused && !self.single_step_requested {
             self.pending_commands.clear();
             return None;
@@ -857,7 +1410,6 @@ impl SimPipeline {
         }
 
         let total_start = Instant::now();
-        let command_count = self.pending_commands.len() as u32;
 
         // Copy read pool → write pool
         {
@@ -867,7 +1419,7 @@ impl SimPipeline {
             for &slot in active_slots {
                 self.chunk_pool.copy_slot_read_to_write(&mut encoder, slot);
                 self.chunk_pool
-                    .copy_charge_slot_read_to_write(&mut encoder, slot);
+                    .copy_aux_slot_read_to_write(&mut encoder, slot);
             }
             queue.submit(std::iter::once(encoder.finish()));
             device.poll(wgpu::Maintain::Wait);
@@ -913,8 +1465,15 @@ impl SimPipeline {
             ],
         });
 
-        // Charge read bind group for reactions @group(1)
-        let charge_bind_group = self.create_charge_read_bind_group(device);
+        // Aux channel bind groups for ballistic, movement, erosion, reactions, oxygen,
+        // pressure and blast @group(1)
+        let ballistic_aux_bind_group = self.create_ballistic_aux_bind_group(device);
+        let movement_aux_bind_group = self.create_movement_aux_bind_group(device);
+        let erosion_aux_bind_group = self.create_erosion_aux_bind_group(device);
+        let reactions_aux_bind_group = self.create_reactions_aux_bind_group(device);
+        let oxygen_aux_bind_group = self.create_oxygen_aux_bind_group(device);
+        let pressure_aux_bind_group = self.create_pressure_aux_bind_group(device);
+        let blast_aux_bind_group = self.create_blast_aux_bind_group(device);
 
         let mut timings = TickTimings::default();
 
@@ -950,15 +1509,63 @@ impl SimPipeline {
             timings.commands_ms = start.elapsed().as_secs_f64() * 1000.0;
         }
 
-        // Pass 2: Movement
+        // Pass 2: Ballistic movement, then movement (timed together)
         {
             let start = Instant::now();
+            let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("instrumented-ballistic-integrate"),
+            });
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("instrumented-ballistic-integrate-pass"),
+                timestamp_writes: None,
+            });
+            ballistic::dispatch_ballistic(
+                &mut pass,
+                &self.ballistic_integrate_pipeline,
+                &bind_group,
+                &ballistic_aux_bind_group,
+                active_chunk_count,
+            );
+            drop(pass);
+            queue.submit(std::iter::once(encoder.finish()));
+            device.poll(wgpu::Maintain::Wait);
+            for sub_pass in &self.ballistic_schedule.clone() {
+                let uniforms = BallisticUniforms {
+                    axis: sub_pass.axis,
+                    sign: sub_pass.sign,
+                    parity: sub_pass.parity,
+                    substep: sub_pass.substep,
+                    tick: self.tick_count as u32,
+                    chunk_count: active_chunk_count,
+                    _pad: [0; 2],
+                };
+                queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&uniforms));
+
+                let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                    label: Some("instrumented-ballistic"),
+                });
+                let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                    label: Some("instrumented-ballistic-pass"),
+                    timestamp_writes: None,
+                });
+                ballistic::dispatch_ballistic(
+                    &mut pass,
+                    &self.ballistic_pipeline,
+                    &bind_group,
+                    &ballistic_aux_bind_group,
+                    active_chunk_count,
+                );
+                drop(pass);
+                queue.submit(std::iter::once(encoder.finish()));
+                device.poll(wgpu::Maintain::Wait);
+            }
             for sub_pass in &self.movement_schedule.clone() {
                 let uniforms = MovementUniforms {
                     direction: sub_pass.direction,
                     parity: sub_pass.parity,
                     tick: self.tick_count as u32,
-                    _pad: [0; 3],
+                    axis: sub_pass.axis,
+                    _pad: [0; 2],
                 };
                 queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&uniforms));
 
@@ -971,8 +1578,13 @@ impl SimPipeline {
                 });
                 movement::dispatch_movement(
                     &mut pass,
-                    &self.movement_pipeline,
+                    [
+                        &self.movement_decide_pipeline,
+                        &self.movement_apply_pipeline,
+                    ],
                     &bind_group,
+                    &movement_aux_bind_group,
+                    &self.move_flags_bind_group,
                     active_chunk_count,
                 );
                 drop(pass);
@@ -982,6 +1594,41 @@ impl SimPipeline {
             timings.movement_ms = start.elapsed().as_secs_f64() * 1000.0;
         }
 
+        // Pass 2b: Erosion
+        {
+            let start = Instant::now();
+            let uniforms = ReactionUniforms {
+                tick: self.tick_count as u32,
+                material_count: self.material_count,
+                _pad0: 0,
+                _pad1: 0,
+                _pad2: 0,
+                _pad3: 0,
+                _pad4: 0,
+                _pad5: 0,
+            };
+            queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&uniforms));
+
+            let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("instrumented-erosion"),
+            });
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("instrumented-erosion-pass"),
+                timestamp_writes: None,
+            });
+            erosion::dispatch_erosion(
+                &mut pass,
+                &self.erosion_pipeline,
+                &bind_group,
+                &erosion_aux_bind_group,
+                active_chunk_count,
+            );
+            drop(pass);
+            queue.submit(std::iter::once(encoder.finish()));
+            device.poll(wgpu::Maintain::Wait);
+            timings.erosion_ms = start.elapsed().as_secs_f64() * 1000.0;
+        }
+
         // Pass 3: Reactions with charge conditions
         {
             let start = Instant::now();
@@ -1004,11 +1651,11 @@ impl SimPipeline {
                 label: Some("instrumented-reactions-pass"),
                 timestamp_writes: None,
             });
-            electrical::dispatch_reactions_with_charge(
+            reactions::dispatch_reactions(
                 &mut pass,
                 &self.reaction_pipeline,
                 &bind_group,
-                &charge_bind_group,
+                &reactions_aux_bind_group,
                 active_chunk_count,
             );
             drop(pass);
@@ -1017,18 +1664,41 @@ impl SimPipeline {
             timings.reactions_ms = start.elapsed().as_secs_f64() * 1000.0;
         }
 
+        // Pass 3b: Oxygen
+        {
+            let start = Instant::now();
+            let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("instrumented-oxygen"),
+            });
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("instrumented-oxygen-pass"),
+                timestamp_writes: None,
+            });
+            oxygen::dispatch_oxygen(
+                &mut pass,
+                &self.oxygen_pipeline,
+                &bind_group,
+                &oxygen_aux_bind_group,
+                active_chunk_count,
+            );
+            drop(pass);
+            queue.submit(std::iter::once(encoder.finish()));
+            device.poll(wgpu::Maintain::Wait);
+            timings.oxygen_ms = start.elapsed().as_secs_f64() * 1000.0;
+        }
+
         // Pass 4a: Thermal
         {
             let start = Instant::now();
-            let uniforms = ReactionUniforms {
+            let uniforms = ThermalUniforms {
                 tick: self.tick_count as u32,
                 material_count: self.material_count,
+                radiates: self.radiates as u32,
                 _pad0: 0,
                 _pad1: 0,
                 _pad2: 0,
                 _pad3: 0,
                 _pad4: 0,
-                _pad5: 0,
             };
             queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&uniforms));
 
@@ -1067,6 +1737,7 @@ impl SimPipeline {
             queue.write_buffer(&self.uniform_buffer, 0, bytemuck::bytes_of(&uniforms));
 
             let electrical_bind_group = self.create_electrical_bind_group(device);
+            let electrical_aux_bind_group = self.create_electrical_aux_bind_group(device);
 
             let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
                 label: Some("instrumented-electrical"),
@@ -1079,6 +1750,7 @@ impl SimPipeline {
                 &mut pass,
                 &self.electrical_pipeline,
                 &electrical_bind_group,
+                &electrical_aux_bind_group,
                 active_chunk_count,
             );
             drop(pass);
@@ -1111,8 +1783,9 @@ impl SimPipeline {
             });
             pressure::dispatch_pressure(
                 &mut pass,
-                &self.pressure_pipeline,
+                [&self.pressure_columns_pipeline, &self.pressure_pipeline],
                 &bind_group,
+                &pressure_aux_bind_group,
                 active_chunk_count,
             );
             drop(pass);
@@ -1121,6 +1794,29 @@ impl SimPipeline {
             timings.pressure_ms = start.elapsed().as_secs_f64() * 1000.0;
         }
 
+        // Pass 5b: Blast
+        {
+            let start = Instant::now();
+            let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("instrumented-blast"),
+            });
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor {
+                label: Some("instrumented-blast-pass"),
+                timestamp_writes: None,
+            });
+            blast::dispatch_blast(
+                &mut pass,
+                &self.blast_pipeline,
+                &bind_group,
+                &blast_aux_bind_group,
+                active_chunk_count,
+            );
+            drop(pass);
+            queue.submit(std::iter::once(encoder.finish()));
+            device.poll(wgpu::Maintain::Wait);
+            timings.blast_ms = start.elapsed().as_secs_f64() * 1000.0;
+        }
+
         // Pass 6: Activity scan
         {
             let start = Instant::now();
@@ -1169,6 +1865,18 @@ impl SimPipeline {
                         binding: 4,
                         resource: self.chunk_desc_buffer.as_entire_binding(),
                     },
+                    wgpu::BindGroupEntry {
+                        binding: 5,
+                        resource: self.chunk_pool.aux_write_pool().as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 6,
+                        resource: self.material_props_buffer.as_entire_binding(),
+                    },
+                    wgpu::BindGroupEntry {
+                        binding: 7,
+                        resource: self.structure_broken_buffer.as_entire_binding(),
+                    },
                 ],
             });
 
@@ -1191,13 +1899,14 @@ impl SimPipeline {
         // Swap and increment
         self.chunk_pool.swap();
         self.tick_count += 1;
-        self.pending_commands.clear();
+        self.pending_commands.drain(..taken);
 
         timings.total_ms = total_start.elapsed().as_secs_f64() * 1000.0;
         Some(timings)
     }
 
-    /// Copy activity flags to staging buffer and initiate async readback (C-GPU-8).
+    /// Copy activity flags and the structure word to staging buffer and
+    /// initiate async readback (C-GPU-8).
     fn request_readback(&mut self, encoder: &mut wgpu::CommandEncoder, active_chunk_count: u32) {
         let copy_size = (active_chunk_count as u64 * 4).min(MAX_CHUNK_SLOTS as u64 * 4);
         if copy_size == 0 {
@@ -1206,14 +1915,22 @@ impl SimPipeline {
 
         let staging = &self.staging_buffers[self.staging_index];
         encoder.copy_buffer_to_buffer(&self.activity_flags_buffer, 0, staging, 0, copy_size);
+        encoder.copy_buffer_to_buffer(
+            &self.structure_broken_buffer,
+            0,
+            staging,
+            MAX_CHUNK_SLOTS as u64 * 4,
+            4,
+        );
 
         self.staging_index = 1 - self.staging_index;
     }
 
     /// Poll for readback completion and return activity flags if available.
-    /// Returns None if no readback has completed yet.
+    /// Returns None if no readback has completed yet. Also picks up whether
+    /// a tick broke a structure (see `structure_broken`).
     pub fn poll_readback(
-        &self,
+        &mut self,
         device: &wgpu::Device,
         active_chunk_count: u32,
     ) -> Option<Vec<u32>> {
@@ -1221,7 +1938,7 @@ impl SimPipeline {
         let read_idx = 1 - self.staging_index;
         let staging = &self.staging_buffers[read_idx];
 
-        let slice = staging.slice(..active_chunk_count as u64 * 4);
+        let slice = staging.slice(..);
 
         // Try to map synchronously (non-blocking check)
         let (tx, rx) = std::sync::mpsc::channel();
@@ -1235,7 +1952,9 @@ impl SimPipeline {
         match rx.try_recv() {
             Ok(Ok(())) => {
                 let data = slice.get_mapped_range();
-                let flags: Vec<u32> = bytemuck::cast_slice(&data).to_vec();
+                let words: &[u32] = bytemuck::cast_slice(&data);
+                let flags = words[..active_chunk_count as usize].to_vec();
+                self.structure_broken |= words[MAX_CHUNK_SLOTS as usize] != 0;
                 drop(data);
                 staging.unmap();
                 Some(flags)
@@ -1244,14 +1963,31 @@ impl SimPipeline {
         }
     }
 
+    /// Whether a tick broke a structure since the last
+    /// `clear_structure_broken`, as of the last activity readback. The host
+    /// then reads the dispatched chunks back, solves them and enqueues what
+    /// gives way (see [`crate::structural::launches`]).
+    pub fn structure_broken(&self) -> bool {
+        self.structure_broken
+    }
+
+    /// Encode a reset of the structure word and forget the host copy. Encode
+    /// it with the copy of the chunks the solve reads, so a break that lands
+    /// after the copy sets the word again.
+    pub fn clear_structure_broken(&mut self, encoder: &mut wgpu::CommandEncoder) {
+        encoder.clear_buffer(&self.structure_broken_buffer, 0, None);
+        self.structure_broken = false;
+    }
+
     /// Get the read pool buffer (for renderer to sample from).
     pub fn get_read_pool(&self) -> &wgpu::Buffer {
         self.chunk_pool.read_pool()
     }
 
-    /// Get the charge read pool buffer (for renderer to read charge values).
-    pub fn get_charge_read_pool(&self) -> &wgpu::Buffer {
-        self.chunk_pool.charge_read_pool()
+    /// Get the aux read pool buffer (for the renderer's pick buffer to read
+    /// aux channel values).
+    pub fn get_aux_read_pool(&self) -> &wgpu::Buffer {
+        self.chunk_pool.aux_read_pool()
     }
 
     /// Get the chunk descriptor buffer (for renderer).
@@ -1298,3 +2034,12 @@ impl SimPipeline {
         &mut self.chunk_pool
     }
 }
+
+/// The aux channel accessors, for shaders of passes that declare channels.
+fn aux_prelude(access: &AuxAccess, aux_channels_wgsl: &'static str) -> &'static str {
+    if access.is_empty() {
+        ""
+    } else {
+        aux_channels_wgsl
+    }
+}
@@ -7,7 +7,6 @@
 /// Hash a voxel position and tick into a deterministic pseudo-random u32.
 ///
 /// This must produce identical results to the WGSL `sim_hash` function.
-#[allow(dead_code)] // Used by tests and future shader verification
 pub(crate) fn sim_hash(x: i32, y: i32, z: i32, tick: u32) -> u32 {
     // Combine inputs into a single seed using prime multipliers
     let mut state = (x as u32)
@@ -27,7 +26,6 @@ pub(crate) fn sim_hash(x: i32, y: i32, z: i32, tick: u32) -> u32 {
 }
 
 /// Convert a hash value to a float in [0, 1).
-#[allow(dead_code)] // Used by shaders and future milestones
 pub(crate) fn hash_to_float(hash: u32) -> f32 {
     (hash >> 8) as f32 / 16_777_216.0 // 2^24
 }
@@ -1,149 +1,661 @@
-//! Structural collapse detection via bounded CPU-side BFS flood-fill (M6).
+//! World-level structural stress solver (CPU).
 //!
-//! When a structural voxel is destroyed, nearby solid voxels may become
-//! disconnected from the ground (y=0). This module detects such disconnected
-//! components and returns their indices so the caller can mark them for
-//! gravity collapse (velocity_y = -1).
+//! Solid voxels with a structural role (nonzero compressive or tensile
+//! strength) bond to their face neighbors. The solver finds what holds each
+//! connected structure up (the world floor, powder or rubble it rests on,
+//! or a chunk it cannot see) and sends every voxel's weight, plus any
+//! powder or liquid piled on it, down the cheapest path through the
+//! structure to that support, where a step sideways or up costs many steps
+//! down, so load runs straight down wherever it can. Load handed to the
+//! voxel below is
+//! compression; load handed sideways or upward (beams, bridges, anything
+//! hanging) is tension. A voxel carrying more than its material's
+//! compressive or tensile strength fails. The most overloaded voxel fails
+//! first and the load is solved again without it, so a failure can shift
+//! load onto its neighbors and cascade. Structures left with no support
+//! are reported as detached, for the caller to drop.
 //!
-//! The BFS is bounded to STRUCTURAL_BFS_RADIUS to keep frame-time impact
-//! predictable. Runs on the main thread (Worker integration deferred per M5
-//! precedent).
-
-use alkahest_core::constants::{CHUNK_SIZE, STRUCTURAL_BFS_RADIUS};
-use std::collections::{HashSet, VecDeque};
-
-/// Face-adjacent neighbor offsets (6-connected).
-const FACE_OFFSETS: [(i32, i32, i32); 6] = [
-    (1, 0, 0),
-    (-1, 0, 0),
-    (0, 1, 0),
-    (0, -1, 0),
-    (0, 0, 1),
-    (0, 0, -1),
+//! The solver reads `[u32; 2]` chunk data for any set of chunks, and bonds
+//! and supports cross chunk borders. Fragments in flight are ignored. It
+//! runs on demand on the host, between ticks, not every tick: the activity
+//! pass of either simulator notes when a tick broke a structure, and the
+//! host solves the chunks it dispatches and hands [`launches`] back to the
+//! simulator as push commands for the next tick.
+
+use alkahest_core::constants::{
+    BALLISTIC_MIN_SPEED, CHUNK_SIZE, STRUCTURAL_WEIGHT_PER_DENSITY, WORLD_CHUNKS_X, WORLD_CHUNKS_Y,
+    WORLD_CHUNKS_Z,
+};
+use alkahest_core::material::{MaterialTable, Phase};
+use alkahest_core::math::{chunk_local_to_world, world_to_chunk, world_to_local};
+use alkahest_core::types::{ChunkCoord, WorldCoord};
+use alkahest_core::voxel::Voxel;
+use alkahest_rules::compiler::GpuMaterialProps;
+use glam::IVec3;
+
+use crate::passes::commands::{SimCommand, TOOL_PUSH};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+
+/// Face-adjacent neighbor offsets (6-connected). Index 3 is straight down.
+const FACE_OFFSETS: [IVec3; 6] = [
+    IVec3::new(1, 0, 0),
+    IVec3::new(-1, 0, 0),
+    IVec3::new(0, 1, 0),
+    IVec3::new(0, -1, 0),
+    IVec3::new(0, 0, 1),
+    IVec3::new(0, 0, -1),
 ];
+const DOWN: usize = 3;
+
+/// Sides a failed structural voxel spalls out through, in order of preference.
+const SPALL_OFFSETS: [IVec3; 6] = [
+    IVec3::new(1, 0, 0),
+    IVec3::new(-1, 0, 0),
+    IVec3::new(0, 0, 1),
+    IVec3::new(0, 0, -1),
+    IVec3::new(0, -1, 0),
+    IVec3::new(0, 1, 0),
+];
+
+/// What handing load one voxel on costs, in the units of `Graph::distances`.
+/// Down is cheap, so load goes straight down wherever it can and only runs
+/// sideways or up through a structure (or into an unseen chunk beside it)
+/// where it has to.
+const DOWN_STEP_COST: u32 = 1;
+const SIDE_STEP_COST: u32 = 16;
+
+const NO_NODE: u32 = u32::MAX;
+const UNREACHED: u32 = u32::MAX;
 
 /// Convert (x, y, z) to linear index within a chunk.
-fn voxel_index(x: u32, y: u32, z: u32) -> usize {
-    (x + y * CHUNK_SIZE + z * CHUNK_SIZE * CHUNK_SIZE) as usize
+fn voxel_index(local: IVec3) -> usize {
+    let cs = CHUNK_SIZE as i32;
+    (local.x + local.y * cs + local.z * cs * cs) as usize
+}
+
+fn voxel_local(index: usize) -> IVec3 {
+    let cs = CHUNK_SIZE as usize;
+    IVec3::new(
+        (index % cs) as i32,
+        ((index / cs) % cs) as i32,
+        (index / (cs * cs)) as i32,
+    )
+}
+
+/// Cost of handing load one voxel along `FACE_OFFSETS[dir]`.
+fn step_cost(dir: usize) -> u32 {
+    if dir == DOWN {
+        DOWN_STEP_COST
+    } else {
+        SIDE_STEP_COST
+    }
 }
 
-/// Extract material_id from packed voxel data (low 16 bits of first u32).
 fn extract_material_id(voxel: [u32; 2]) -> u16 {
-    (voxel[0] & 0xFFFF) as u16
+    Voxel::from(voxel).material_id().0
 }
 
-/// Detect disconnected structural components within a single chunk.
-///
-/// `chunk_data` — The chunk's voxel data as `[u32; 2]` pairs (read back from GPU).
-/// `structural_ids` — Material IDs that count as structural (solids with integrity > 0).
-///
-/// Returns indices of voxels in disconnected components (should have velocity_y set to -1).
+/// Whether the voxel moves fast enough to be ballistic.
+fn in_flight(voxel: [u32; 2]) -> bool {
+    Voxel::from(voxel)
+        .velocity()
+        .iter()
+        .any(|v| v.unsigned_abs() as u32 >= BALLISTIC_MIN_SPEED)
+}
+
+/// How a material takes part in the solve.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) enum Role {
+    /// Air and gases: carry nothing, weigh nothing.
+    #[default]
+    Empty,
+    /// Liquids: weigh on whatever holds them up, but hold nothing up.
+    Payload,
+    /// Powders and solids without strength: weigh on what holds them up,
+    /// and structures standing on them count as grounded.
+    Ground,
+    /// Solids with strength: bond into structures that carry load.
+    Structural,
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+struct StructuralProps {
+    role: Role,
+    weight: f32,
+    compression: f32,
+    tension: f32,
+}
+
+impl StructuralProps {
+    fn new(phase: Phase, density: f32, compression: f32, tension: f32) -> Self {
+        let compression = compression.max(0.0);
+        let tension = tension.max(0.0);
+        let role = match phase {
+            Phase::Gas => Role::Empty,
+            Phase::Liquid => Role::Payload,
+            Phase::Powder => Role::Ground,
+            Phase::Solid if compression > 0.0 || tension > 0.0 => Role::Structural,
+            Phase::Solid => Role::Ground,
+        };
+        Self {
+            role,
+            weight: density * STRUCTURAL_WEIGHT_PER_DENSITY,
+            compression,
+            tension,
+        }
+    }
+}
+
+/// Structural properties of every material, indexed by material ID.
+#[derive(Debug, Clone, Default)]
+pub struct StructuralTable {
+    props: Vec<StructuralProps>,
+}
+
+impl StructuralTable {
+    pub fn from_materials(table: &MaterialTable) -> Self {
+        let mut props = vec![StructuralProps::default(); table.max_id() as usize + 1];
+        for mat in &table.materials {
+            props[mat.id as usize] = StructuralProps::new(
+                mat.phase,
+                mat.density,
+                mat.compression_limit(),
+                mat.tension_limit(),
+            );
+        }
+        Self { props }
+    }
+
+    /// Build from compiled material properties, indexed by material ID (the
+    /// form the simulators hold).
+    pub fn from_props(materials: &[GpuMaterialProps]) -> Self {
+        let props = materials
+            .iter()
+            .map(|mat| {
+                // Phase discriminants, as packed by the compiler
+                let phase = match mat.phase as u32 {
+                    1 => Phase::Liquid,
+                    2 => Phase::Solid,
+                    3 => Phase::Powder,
+                    _ => Phase::Gas,
+                };
+                StructuralProps::new(
+                    phase,
+                    mat.density,
+                    mat.compressive_strength,
+                    mat.tensile_strength,
+                )
+            })
+            .collect();
+        Self { props }
+    }
+
+    fn get(&self, id: u16) -> StructuralProps {
+        self.props.get(id as usize).copied().unwrap_or_default()
+    }
+
+    pub(crate) fn role(&self, id: u16) -> Role {
+        self.get(id).role
+    }
+}
+
+/// The chunks the solver can see, by chunk coordinate.
 ///
-/// Algorithm:
-/// 1. Find all structural voxels in the chunk.
-/// 2. For each unvisited structural voxel, BFS flood-fill (bounded to STRUCTURAL_BFS_RADIUS).
-/// 3. If the component touches y=0, it's grounded — skip.
-/// 4. If the component does NOT touch y=0 within the BFS radius, mark all its voxels.
-pub fn detect_collapse(chunk_data: &[[u32; 2]], structural_ids: &[u16]) -> Vec<usize> {
-    let structural_set: HashSet<u16> = structural_ids.iter().copied().collect();
-    let cs = CHUNK_SIZE;
-    let total = (cs * cs * cs) as usize;
-
-    if chunk_data.len() < total {
-        return Vec::new();
-    }
-
-    // Build set of structural voxel indices
-    let mut is_structural = vec![false; total];
-    for z in 0..cs {
-        for y in 0..cs {
-            for x in 0..cs {
-                let idx = voxel_index(x, y, z);
-                let mat_id = extract_material_id(chunk_data[idx]);
-                if structural_set.contains(&mat_id) {
-                    is_structural[idx] = true;
-                }
+/// Below the world floor is solid ground. A chunk inside the world bounds
+/// that was not inserted counts as solid too: the solver cannot tell what
+/// is there, so it assumes it holds, rather than dropping everything built
+/// into the edge of the loaded area. Outside the world bounds is empty.
+#[derive(Debug, Default)]
+pub struct StructuralWorld<'a> {
+    chunks: HashMap<ChunkCoord, &'a [[u32; 2]]>,
+}
+
+/// What a world position holds, as far as the solver is concerned.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Cell {
+    Floor,
+    Unseen,
+    Outside,
+    Voxel(u16),
+}
+
+impl<'a> StructuralWorld<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a chunk's voxel data. Data shorter than a full chunk is ignored.
+    pub fn insert(&mut self, coord: ChunkCoord, data: &'a [[u32; 2]]) {
+        if data.len() >= (CHUNK_SIZE * CHUNK_SIZE * CHUNK_SIZE) as usize {
+            self.chunks.insert(coord, data);
+        }
+    }
+
+    fn cell(&self, pos: WorldCoord) -> Cell {
+        if pos.y < 0 {
+            return Cell::Floor;
+        }
+        let chunk = world_to_chunk(pos);
+        if let Some(data) = self.chunks.get(&chunk) {
+            let voxel = data[voxel_index(world_to_local(pos))];
+            // A fragment in flight is no part of what it passes through
+            if in_flight(voxel) {
+                return Cell::Voxel(0);
             }
+            return Cell::Voxel(extract_material_id(voxel));
+        }
+        let in_world = (0..WORLD_CHUNKS_X as i32).contains(&chunk.x)
+            && (0..WORLD_CHUNKS_Y as i32).contains(&chunk.y)
+            && (0..WORLD_CHUNKS_Z as i32).contains(&chunk.z);
+        if in_world {
+            Cell::Unseen
+        } else {
+            Cell::Outside
         }
     }
+}
+
+/// Outcome of a structural solve.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct StressReport {
+    /// Voxels that gave way, in the order they failed.
+    pub failed: Vec<WorldCoord>,
+    /// Structures left with no support once the failed voxels are gone, one
+    /// list per connected component.
+    pub detached: Vec<Vec<WorldCoord>>,
+}
+
+/// Where a supported voxel hands its load on to.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Support {
+    /// Something it rests on: the floor, ground, or an unseen chunk below.
+    Below,
+    /// An unseen chunk beside or above it.
+    Beside,
+}
+
+/// The structural voxels in view and their bonds.
+struct Graph {
+    pos: Vec<WorldCoord>,
+    props: Vec<StructuralProps>,
+    /// Own weight plus the powder and liquid resting on top.
+    weight: Vec<f32>,
+    neighbors: Vec<[u32; 6]>,
+    support: Vec<Option<Support>>,
+}
 
-    let mut visited = vec![false; total];
-    let mut disconnected = Vec::new();
+impl Graph {
+    fn build(world: &StructuralWorld, table: &StructuralTable) -> Self {
+        // Chunks in coordinate order so node order (and tie-breaking) is
+        // deterministic
+        let mut coords: Vec<ChunkCoord> = world.chunks.keys().copied().collect();
+        coords.sort_by_key(|c| (c.x, c.y, c.z));
 
-    for start_idx in 0..total {
-        if !is_structural[start_idx] || visited[start_idx] {
-            continue;
+        let mut pos = Vec::new();
+        let mut props = Vec::new();
+        let mut node_of: HashMap<ChunkCoord, Vec<u32>> = HashMap::new();
+        for &coord in &coords {
+            let data = world.chunks[&coord];
+            let mut nodes = vec![NO_NODE; (CHUNK_SIZE * CHUNK_SIZE * CHUNK_SIZE) as usize];
+            for (index, node) in nodes.iter_mut().enumerate() {
+                let p = table.get(extract_material_id(data[index]));
+                if p.role == Role::Structural {
+                    *node = pos.len() as u32;
+                    pos.push(chunk_local_to_world(coord, voxel_local(index)));
+                    props.push(p);
+                }
+            }
+            node_of.insert(coord, nodes);
         }
 
-        // BFS from this structural voxel
-        let mut queue = VecDeque::new();
-        let mut component = Vec::new();
-        let mut grounded = false;
+        let node_at = |p: WorldCoord| -> u32 {
+            node_of
+                .get(&world_to_chunk(p))
+                .map_or(NO_NODE, |nodes| nodes[voxel_index(world_to_local(p))])
+        };
+        let role_at = |cell: Cell| match cell {
+            Cell::Voxel(id) => table.get(id).role,
+            _ => Role::Empty,
+        };
 
-        queue.push_back(start_idx);
-        visited[start_idx] = true;
+        let mut weight = Vec::with_capacity(pos.len());
+        let mut neighbors = Vec::with_capacity(pos.len());
+        let mut support = Vec::with_capacity(pos.len());
+        for (node, &p) in pos.iter().enumerate() {
+            let mut adjacent = [NO_NODE; 6];
+            let mut held = None;
+            for (dir, &offset) in FACE_OFFSETS.iter().enumerate() {
+                let cell = world.cell(p + offset);
+                adjacent[dir] = node_at(p + offset);
+                let rests_on = dir == DOWN
+                    && (matches!(cell, Cell::Floor | Cell::Unseen)
+                        || role_at(cell) == Role::Ground);
+                if rests_on {
+                    held = Some(Support::Below);
+                } else if cell == Cell::Unseen && held.is_none() {
+                    held = Some(Support::Beside);
+                }
+            }
 
-        while let Some(current) = queue.pop_front() {
-            component.push(current);
+            // Powder and liquid piled straight on top weigh on this voxel
+            let mut load = props[node].weight;
+            let mut above = p + IVec3::Y;
+            while let Cell::Voxel(id) = world.cell(above) {
+                let resting = table.get(id);
+                if !matches!(resting.role, Role::Ground | Role::Payload) {
+                    break;
+                }
+                load += resting.weight;
+                above += IVec3::Y;
+            }
 
-            // Decode position
-            let ci = current as u32;
-            let x = ci % cs;
-            let y = (ci / cs) % cs;
-            let z = ci / (cs * cs);
+            weight.push(load);
+            neighbors.push(adjacent);
+            support.push(held);
+        }
 
-            // Check if grounded (y=0)
-            if y == 0 {
-                grounded = true;
+        Self {
+            pos,
+            props,
+            weight,
+            neighbors,
+            support,
+        }
+    }
+
+    /// Cost of handing this node's load straight to what supports it, or
+    /// None if nothing does.
+    fn support_cost(&self, node: usize) -> Option<u32> {
+        self.support[node].map(|support| match support {
+            Support::Below => 0,
+            Support::Beside => SIDE_STEP_COST,
+        })
+    }
+
+    /// Cost of the cheapest path (see `step_cost`) from every live node to
+    /// support along the structure, or UNREACHED.
+    fn distances(&self, alive: &[bool]) -> Vec<u32> {
+        let mut dist = vec![UNREACHED; self.pos.len()];
+        let mut queue = BinaryHeap::new();
+        for node in 0..self.pos.len() {
+            if let (true, Some(cost)) = (alive[node], self.support_cost(node)) {
+                dist[node] = cost;
+                queue.push(Reverse((cost, node)));
+            }
+        }
+        while let Some(Reverse((cost, node))) = queue.pop() {
+            if cost > dist[node] {
+                continue;
+            }
+            for (dir, &next) in self.neighbors[node].iter().enumerate() {
+                let next = next as usize;
+                if next == NO_NODE as usize || !alive[next] {
+                    continue;
+                }
+                // Load runs the other way, from `next` into this node
+                let through = cost + step_cost(dir ^ 1);
+                if through < dist[next] {
+                    dist[next] = through;
+                    queue.push(Reverse((through, next)));
+                }
             }
+        }
+        dist
+    }
+
+    /// How overloaded every live, supported node is: the larger of its
+    /// compression and tension over the material's strength (> 1.0 fails).
+    fn overload(&self, alive: &[bool], dist: &[u32]) -> Vec<f32> {
+        let n = self.pos.len();
+        let mut order: Vec<usize> = (0..n).filter(|&i| dist[i] != UNREACHED).collect();
+        order.sort_by_key(|&i| std::cmp::Reverse(dist[i]));
 
-            // Check BFS radius bound from start
-            let si = start_idx as u32;
-            let sx = si % cs;
-            let sy = (si / cs) % cs;
-            let sz = si / (cs * cs);
-            let dist = x.abs_diff(sx) + y.abs_diff(sy) + z.abs_diff(sz);
-            if dist >= STRUCTURAL_BFS_RADIUS {
+        let mut load = self.weight.clone();
+        let mut compression_in = vec![0.0f32; n];
+        let mut compression_out = vec![0.0f32; n];
+        let mut tension = vec![0.0f32; n];
+        for &node in &order {
+            if self.support_cost(node) == Some(dist[node]) {
+                match self.support[node] {
+                    Some(Support::Below) => compression_out[node] = load[node],
+                    _ => tension[node] = load[node],
+                }
                 continue;
             }
 
-            // Expand to face neighbors
-            for &(dx, dy, dz) in &FACE_OFFSETS {
-                let nx = x as i32 + dx;
-                let ny = y as i32 + dy;
-                let nz = z as i32 + dz;
-
-                if nx < 0
-                    || nx >= cs as i32
-                    || ny < 0
-                    || ny >= cs as i32
-                    || nz < 0
-                    || nz >= cs as i32
-                {
-                    // Out of chunk bounds — treat as potentially grounded
-                    // (cross-chunk connectivity not resolved here)
-                    continue;
+            // Split the load evenly over the neighbors on a cheapest path
+            let nearer = |dir: &usize| {
+                let next = self.neighbors[node][*dir];
+                next != NO_NODE
+                    && alive[next as usize]
+                    && dist[next as usize] + step_cost(*dir) == dist[node]
+            };
+            let count = (0..6).filter(nearer).count() as f32;
+            let share = load[node] / count;
+            for dir in (0..6).filter(nearer) {
+                let next = self.neighbors[node][dir] as usize;
+                load[next] += share;
+                if dir == DOWN {
+                    compression_out[node] += share;
+                    compression_in[next] += share;
+                } else {
+                    tension[node] += share;
                 }
+            }
+        }
 
-                let neighbor_idx = voxel_index(nx as u32, ny as u32, nz as u32);
-                if !visited[neighbor_idx] && is_structural[neighbor_idx] {
-                    visited[neighbor_idx] = true;
-                    queue.push_back(neighbor_idx);
+        let ratio = |value: f32, limit: f32| {
+            if value <= 0.0 {
+                0.0
+            } else if limit <= 0.0 {
+                f32::INFINITY
+            } else {
+                value / limit
+            }
+        };
+        (0..n)
+            .map(|i| {
+                let props = &self.props[i];
+                let compression = compression_in[i].max(compression_out[i]);
+                ratio(compression, props.compression).max(ratio(tension[i], props.tension))
+            })
+            .collect()
+    }
+
+    /// Connected components of live nodes with no path to support.
+    fn detached(&self, alive: &[bool], dist: &[u32]) -> Vec<Vec<WorldCoord>> {
+        let mut seen = vec![false; self.pos.len()];
+        let mut components = Vec::new();
+        for start in 0..self.pos.len() {
+            if !alive[start] || dist[start] != UNREACHED || seen[start] {
+                continue;
+            }
+            let mut component = Vec::new();
+            let mut queue = VecDeque::from([start]);
+            seen[start] = true;
+            while let Some(node) = queue.pop_front() {
+                component.push(self.pos[node]);
+                for &next in &self.neighbors[node] {
+                    let next = next as usize;
+                    if next != NO_NODE as usize && alive[next] && !seen[next] {
+                        seen[next] = true;
+                        queue.push_back(next);
+                    }
                 }
             }
+            components.push(component);
+        }
+        components
+    }
+}
+
+/// Solve the load on every structure in `world`, failing overloaded voxels
+/// one at a time (weakest link first) until what is left stands.
+///
+/// Algorithm:
+/// 1. Collect the structural voxels, their bonds, and what each rests on.
+/// 2. Search outward from supported voxels to get the cost of each voxel's
+///    cheapest path to support; unreached voxels are detached and carry no
+///    load.
+/// 3. From the costliest voxel inward, hand each voxel's load in equal
+///    shares to its bonded neighbors on a cheapest path.
+/// 4. If any voxel's compression or tension exceeds its strength, fail the
+///    one most over its limit and go back to 2.
+pub fn solve(world: &StructuralWorld, table: &StructuralTable) -> StressReport {
+    let graph = Graph::build(world, table);
+    let mut alive = vec![true; graph.pos.len()];
+    let mut report = StressReport::default();
+
+    loop {
+        let dist = graph.distances(&alive);
+        let overload = graph.overload(&alive, &dist);
+        // First of equals wins, so ties break in node order
+        let worst = overload
+            .iter()
+            .enumerate()
+            .filter(|&(i, &ratio)| alive[i] && ratio > 1.0)
+            .fold(None, |best: Option<(usize, f32)>, (i, &ratio)| match best {
+                Some((_, top)) if top >= ratio => best,
+                _ => Some((i, ratio)),
+            });
+        match worst {
+            Some((node, _)) => {
+                alive[node] = false;
+                report.failed.push(graph.pos[node]);
+            }
+            None => {
+                report.detached = graph.detached(&alive, &dist);
+                return report;
+            }
         }
+    }
+}
+
+/// A voxel that gives way, and the velocity it is set moving with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Launch {
+    pub pos: WorldCoord,
+    pub velocity: IVec3,
+}
 
-        if !grounded {
-            disconnected.extend_from_slice(&component);
+impl Launch {
+    /// The push command that sets the voxel moving, for the chunk at
+    /// `chunk_dispatch_idx` in the dispatch list of the tick it runs in.
+    /// Pushes add to a voxel's velocity, and what gives way is at rest.
+    pub fn command(&self, chunk_dispatch_idx: u32) -> SimCommand {
+        let local = world_to_local(self.pos);
+        let biased = |v: i32| (v + 128).clamp(0, 255) as u32;
+        SimCommand {
+            tool_type: TOOL_PUSH,
+            pos_x: local.x,
+            pos_y: local.y,
+            pos_z: local.z,
+            material_id: biased(self.velocity.x)
+                | (biased(self.velocity.y) << 8)
+                | (biased(self.velocity.z) << 16),
+            chunk_dispatch_idx,
+            brush_radius: 0,
+            brush_shape: 0,
         }
     }
+}
 
-    disconnected
+/// What gives way in a solve of `world`: a failed voxel spalls out as a
+/// fragment through its first open side (the four sides, then down, then
+/// up), and one with no open side stays where it is; detached structures
+/// come down as fragments, voxel by voxel. Fragments start at
+/// `BALLISTIC_MIN_SPEED`.
+pub fn launches(
+    world: &StructuralWorld,
+    table: &StructuralTable,
+    report: &StressReport,
+) -> Vec<Launch> {
+    let speed = BALLISTIC_MIN_SPEED as i32;
+    let open = |pos: WorldCoord| match world.cell(pos) {
+        Cell::Voxel(id) => table.role(id) == Role::Empty,
+        _ => false,
+    };
+    let spalled = report.failed.iter().filter_map(|&pos| {
+        let side = SPALL_OFFSETS.iter().find(|&&offset| open(pos + offset))?;
+        Some(Launch {
+            pos,
+            velocity: *side * speed,
+        })
+    });
+    let fallen = report.detached.iter().flatten().map(|&pos| Launch {
+        pos,
+        velocity: IVec3::new(0, -speed, 0),
+    });
+    spalled.chain(fallen).collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use alkahest_core::material::MaterialDef;
+    use alkahest_core::types::{Kelvin, QuantizedTemp};
+
+    const STONE: u16 = 1;
+    const SAND: u16 = 2;
+    const BRICK: u16 = 3;
+    const WATER: u16 = 4;
+    const PLANK: u16 = 5;
+
+    fn material(id: u16, phase: Phase, density: f32, integrity: f32) -> MaterialDef {
+        MaterialDef {
+            id,
+            name: format!("M{id}"),
+            phase,
+            density,
+            color: (0.5, 0.5, 0.5),
+            emission: 0.0,
+            flammability: 0.0,
+            ignition_temp: Kelvin(0.0),
+            decay_rate: 0,
+            decay_threshold: QuantizedTemp(0),
+            decay_product: 0,
+            viscosity: 0.0,
+            repose_angle: 45.0,
+            cohesion: 0.0,
+            erodibility: 0.0,
+            thermal_conductivity: 0.0,
+            heat_capacity: 1.0,
+            radiative_emissivity: 0.0,
+            phase_change_temp: Kelvin(0.0),
+            phase_change_product: 0,
+            latent_heat: 0.0,
+            freeze_temp: Kelvin(0.0),
+            freeze_product: 0,
+            structural_integrity: integrity,
+            compressive_strength: None,
+            tensile_strength: None,
+            blast_resistance: None,
+            opacity: None,
+            absorption_rate: 0.0,
+            electrical_conductivity: 0.0,
+            electrical_resistance: 0.0,
+            activation_threshold: 0,
+            charge_emission: 0,
+        }
+    }
+
+    /// Stone (weight 5, holds 2000 / 50), Brick, Sand, Water, and a light
+    /// Plank (weight 1) that holds 20 in tension.
+    fn table() -> StructuralTable {
+        let mut plank = material(PLANK, Phase::Solid, 1000.0, 10.0);
+        plank.tensile_strength = Some(20.0);
+        StructuralTable::from_materials(&MaterialTable {
+            materials: vec![
+                material(0, Phase::Gas, 0.0, 0.0),
+                material(STONE, Phase::Solid, 5000.0, 50.0),
+                material(SAND, Phase::Powder, 2500.0, 0.0),
+                material(BRICK, Phase::Solid, 2000.0, 30.0),
+                material(WATER, Phase::Liquid, 1000.0, 0.0),
+                plank,
+            ],
+        })
+    }
 
     /// Create empty chunk data (all air).
     fn empty_chunk() -> Vec<[u32; 2]> {
@@ -152,20 +664,49 @@ mod tests {
 
     /// Set a voxel's material ID in chunk data.
     fn set_material(chunk: &mut [[u32; 2]], x: u32, y: u32, z: u32, mat_id: u16) {
-        let idx = voxel_index(x, y, z);
+        let idx = voxel_index(IVec3::new(x as i32, y as i32, z as i32));
         chunk[idx][0] = (chunk[idx][0] & !0xFFFF) | mat_id as u32;
     }
 
+    /// Solve a world of chunks at the given coordinates.
+    fn solve_chunks(chunks: &[(ChunkCoord, &[[u32; 2]])]) -> StressReport {
+        let mut world = StructuralWorld::new();
+        for &(coord, data) in chunks {
+            world.insert(coord, data);
+        }
+        solve(&world, &table())
+    }
+
+    /// Solve a single chunk at the world origin, with empty chunks around it
+    /// so nothing leans on unseen neighbors.
+    fn solve_alone(chunk: &[[u32; 2]]) -> StressReport {
+        let empty = empty_chunk();
+        solve_chunks(&[
+            (IVec3::ZERO, chunk),
+            (IVec3::X, &empty),
+            (IVec3::Y, &empty),
+            (IVec3::Z, &empty),
+        ])
+    }
+
+    fn detached_count(report: &StressReport) -> usize {
+        report.detached.iter().map(Vec::len).sum()
+    }
+
     #[test]
     fn test_structural_flood_fill_connected() {
         // Stone column from y=0 to y=5 — all connected to ground
         let mut chunk = empty_chunk();
         for y in 0..6 {
-            set_material(&mut chunk, 5, y, 5, 1); // Stone = 1
+            set_material(&mut chunk, 5, y, 5, STONE);
         }
 
-        let result = detect_collapse(&chunk, &[1]);
-        assert!(result.is_empty(), "Connected column should not be flagged");
+        let result = solve_alone(&chunk);
+        assert_eq!(
+            result,
+            StressReport::default(),
+            "Connected column should stand"
+        );
     }
 
     #[test]
@@ -174,44 +715,45 @@ mod tests {
         let mut chunk = empty_chunk();
         for x in 4..7 {
             for z in 4..7 {
-                set_material(&mut chunk, x, 10, z, 1); // Stone
+                set_material(&mut chunk, x, 10, z, STONE);
             }
         }
 
-        let result = detect_collapse(&chunk, &[1]);
+        let result = solve_alone(&chunk);
+        assert!(result.failed.is_empty());
         assert_eq!(
-            result.len(),
+            detached_count(&result),
             9,
             "3x1x3 floating block = 9 disconnected voxels"
         );
     }
 
     #[test]
-    fn test_structural_flood_fill_bounded() {
-        // Very tall column NOT touching ground (starts at y=1)
-        // BFS should still process within bounds
+    fn test_structural_flood_fill_tall() {
+        // Column the full height of the chunk NOT touching ground (starts at y=1)
         let mut chunk = empty_chunk();
         for y in 1..CHUNK_SIZE {
-            set_material(&mut chunk, 5, y, 5, 1); // Stone
+            set_material(&mut chunk, 5, y, 5, STONE);
         }
 
-        let result = detect_collapse(&chunk, &[1]);
+        let result = solve_alone(&chunk);
         // All 31 voxels should be disconnected (none touch y=0)
-        assert_eq!(result.len(), (CHUNK_SIZE - 1) as usize);
+        assert_eq!(detached_count(&result), (CHUNK_SIZE - 1) as usize);
     }
 
     #[test]
     fn test_structural_mixed_materials() {
-        // Only Stone (1) is structural, Sand (2) is not
+        // Only solids are structural; Sand is not
         let mut chunk = empty_chunk();
         // Stone on ground
-        set_material(&mut chunk, 5, 0, 5, 1);
+        set_material(&mut chunk, 5, 0, 5, STONE);
         // Sand floating (not structural, should be ignored)
-        set_material(&mut chunk, 10, 10, 10, 2);
+        set_material(&mut chunk, 10, 10, 10, SAND);
 
-        let result = detect_collapse(&chunk, &[1]);
-        assert!(
-            result.is_empty(),
+        let result = solve_alone(&chunk);
+        assert_eq!(
+            result,
+            StressReport::default(),
             "Grounded stone + non-structural sand = no collapse"
         );
     }
@@ -219,8 +761,8 @@ mod tests {
     #[test]
     fn test_structural_empty_chunk() {
         let chunk = empty_chunk();
-        let result = detect_collapse(&chunk, &[1]);
-        assert!(result.is_empty());
+        let result = solve_alone(&chunk);
+        assert_eq!(result, StressReport::default());
     }
 
     #[test]
@@ -229,18 +771,18 @@ mod tests {
         let mut chunk = empty_chunk();
         // Vertical arm
         for y in 0..4 {
-            set_material(&mut chunk, 5, y, 5, 1);
+            set_material(&mut chunk, 5, y, 5, STONE);
         }
         // Horizontal arm on ground
         for x in 6..9 {
-            set_material(&mut chunk, x, 0, 5, 1);
+            set_material(&mut chunk, x, 0, 5, STONE);
         }
 
-        let result = detect_collapse(&chunk, &[1]);
-        assert!(
-            result.is_empty(),
-            "L-shape touching y=0 should be fully connected, got {} disconnected",
-            result.len()
+        let result = solve_alone(&chunk);
+        assert_eq!(
+            result,
+            StressReport::default(),
+            "L-shape touching y=0 should stand"
         );
     }
 
@@ -251,43 +793,247 @@ mod tests {
         // Block A: at y=10
         for x in 2..4 {
             for z in 2..4 {
-                set_material(&mut chunk, x, 10, z, 1);
+                set_material(&mut chunk, x, 10, z, STONE);
             }
         }
         // Block B: at y=20
         for x in 20..22 {
             for z in 20..22 {
-                set_material(&mut chunk, x, 20, z, 1);
+                set_material(&mut chunk, x, 20, z, STONE);
             }
         }
 
-        let result = detect_collapse(&chunk, &[1]);
-        assert_eq!(
-            result.len(),
-            8,
-            "two 2x1x2 floating blocks = 8 disconnected voxels, got {}",
-            result.len()
-        );
+        let result = solve_alone(&chunk);
+        let mut sizes: Vec<usize> = result.detached.iter().map(Vec::len).collect();
+        sizes.sort();
+        assert_eq!(sizes, [4, 4], "two 2x1x2 floating blocks");
     }
 
     #[test]
-    fn test_multiple_structural_ids() {
-        // Both Stone (1) and Brick (3) are structural; they should connect
+    fn test_multiple_structural_materials() {
+        // Stone and Brick are both structural; they should bond
         let mut chunk = empty_chunk();
         // Stone on ground
-        set_material(&mut chunk, 5, 0, 5, 1);
+        set_material(&mut chunk, 5, 0, 5, STONE);
         // Brick on top of stone
-        set_material(&mut chunk, 5, 1, 5, 3);
+        set_material(&mut chunk, 5, 1, 5, BRICK);
         // Brick floating separately
-        set_material(&mut chunk, 20, 15, 20, 3);
+        set_material(&mut chunk, 20, 15, 20, BRICK);
 
-        let result = detect_collapse(&chunk, &[1, 3]);
+        let result = solve_alone(&chunk);
         // The stone+brick column is grounded, so only the floating brick is disconnected
+        assert_eq!(result.detached, [vec![IVec3::new(20, 15, 20)]]);
+    }
+
+    #[test]
+    fn test_structure_resting_on_sand_is_grounded() {
+        let mut chunk = empty_chunk();
+        for x in 0..CHUNK_SIZE {
+            for z in 0..CHUNK_SIZE {
+                set_material(&mut chunk, x, 0, z, SAND);
+            }
+        }
+        for y in 1..4 {
+            set_material(&mut chunk, 5, y, 5, STONE);
+        }
+
+        assert_eq!(solve_alone(&chunk), StressReport::default());
+    }
+
+    #[test]
+    fn test_bridge_across_chunk_border_stands() {
+        // Stone deck from x=28 in chunk 0 to x=40 in chunk 1, on a pillar in
+        // each chunk. Neither half reaches the ground within its own chunk.
+        let mut left = empty_chunk();
+        let mut right = empty_chunk();
+        for y in 0..6 {
+            set_material(&mut left, 28, y, 5, STONE);
+            set_material(&mut right, 8, y, 5, STONE);
+        }
+        for x in 28..CHUNK_SIZE {
+            set_material(&mut left, x, 6, 5, STONE);
+        }
+        for x in 0..9 {
+            set_material(&mut right, x, 6, 5, STONE);
+        }
+        let empty = empty_chunk();
+        let around = [
+            (IVec3::new(0, 1, 0), empty.as_slice()),
+            (IVec3::new(1, 1, 0), empty.as_slice()),
+            (IVec3::new(2, 0, 0), empty.as_slice()),
+            (IVec3::new(0, 0, 1), empty.as_slice()),
+            (IVec3::new(1, 0, 1), empty.as_slice()),
+        ];
+        let mut chunks = vec![(IVec3::ZERO, left.as_slice()), (IVec3::X, right.as_slice())];
+        chunks.extend(around);
+        assert_eq!(solve_chunks(&chunks), StressReport::default());
+
+        // Without the right pillar the deck hangs off the left one: twelve
+        // voxels of stone is more than its root can hold in tension
+        let mut no_pillar = right.clone();
+        for y in 0..6 {
+            set_material(&mut no_pillar, 8, y, 5, 0);
+        }
+        chunks[1] = (IVec3::X, no_pillar.as_slice());
+        let result = solve_chunks(&chunks);
+        assert_eq!(result.failed.first(), Some(&IVec3::new(29, 6, 5)));
+        assert_eq!(detached_count(&result), 40 - 29);
+    }
+
+    #[test]
+    fn test_structure_leaning_on_unseen_chunk_stands() {
+        // A ledge running into the unloaded chunk at x=1 is held there
+        let mut chunk = empty_chunk();
+        for x in 24..CHUNK_SIZE {
+            set_material(&mut chunk, x, 10, 5, STONE);
+        }
+        let empty = empty_chunk();
+        let result = solve_chunks(&[(IVec3::ZERO, &chunk), (IVec3::Y, &empty)]);
+        assert_eq!(result, StressReport::default());
+    }
+
+    #[test]
+    fn test_solid_mass_at_the_edge_of_view_stands() {
+        // Bedrock filling the chunk up to y=20, against unloaded chunks at
+        // x=1 and z=1, carries its weight down rather than into them
+        let mut chunk = empty_chunk();
+        for z in 0..CHUNK_SIZE {
+            for y in 0..20 {
+                for x in 0..CHUNK_SIZE {
+                    set_material(&mut chunk, x, y, z, STONE);
+                }
+            }
+        }
+        let empty = empty_chunk();
+        let result = solve_chunks(&[(IVec3::ZERO, &chunk), (IVec3::Y, &empty)]);
+        assert_eq!(result, StressReport::default());
+    }
+
+    #[test]
+    fn test_overhang_fails_at_its_root_first() {
+        // Stone ledge sticking out of a pillar. Each voxel weighs 5 and the
+        // root holds 50 in tension, so ten voxels hang and eleven snap.
+        let ledge = |length: u32| {
+            let mut chunk = empty_chunk();
+            for y in 0..10 {
+                set_material(&mut chunk, 5, y, 5, STONE);
+            }
+            for x in 6..6 + length {
+                set_material(&mut chunk, x, 9, 5, STONE);
+            }
+            solve_alone(&chunk)
+        };
+
+        assert_eq!(ledge(10), StressReport::default());
+
+        let result = ledge(11);
+        assert_eq!(result.failed, [IVec3::new(6, 9, 5)]);
+        assert_eq!(detached_count(&result), 10);
+    }
+
+    #[test]
+    fn test_snapped_ledge_spalls_and_falls() {
+        let mut chunk = empty_chunk();
+        for y in 0..10 {
+            set_material(&mut chunk, 5, y, 5, STONE);
+        }
+        for x in 6..17 {
+            set_material(&mut chunk, x, 9, 5, STONE);
+        }
+        let empty = empty_chunk();
+        let mut world = StructuralWorld::new();
+        world.insert(IVec3::ZERO, &chunk);
+        for coord in [IVec3::X, IVec3::Y, IVec3::Z] {
+            world.insert(coord, &empty);
+        }
+        let report = solve(&world, &table());
+        let launches = launches(&world, &table(), &report);
+
+        // The root has pillar and ledge on either side, so it spalls out
+        // through the first open side after them; the rest falls
+        let speed = BALLISTIC_MIN_SPEED as i32;
+        assert_eq!(launches.len(), 11);
+        assert_eq!(
+            launches[0],
+            Launch {
+                pos: IVec3::new(6, 9, 5),
+                velocity: IVec3::new(0, 0, speed),
+            }
+        );
+        assert!(launches[1..]
+            .iter()
+            .all(|l| l.velocity == IVec3::new(0, -speed, 0)));
+
+        let cmd = launches[0].command(3);
+        assert_eq!((cmd.tool_type, cmd.chunk_dispatch_idx), (TOOL_PUSH, 3));
+        assert_eq!([cmd.pos_x, cmd.pos_y, cmd.pos_z], [6, 9, 5]);
         assert_eq!(
-            result.len(),
-            1,
-            "only the floating brick should be disconnected, got {}",
-            result.len()
+            cmd.material_id,
+            128 | (128 << 8) | ((128 + speed as u32) << 16)
         );
     }
+
+    #[test]
+    fn test_weight_on_a_beam_breaks_it() {
+        // A plank beam between two stone pillars holds itself, but gives way
+        // under a pile of sand and a pool of water
+        let beam = |sand: u32, water: u32| {
+            let mut chunk = empty_chunk();
+            for y in 0..5 {
+                set_material(&mut chunk, 4, y, 5, STONE);
+                set_material(&mut chunk, 12, y, 5, STONE);
+            }
+            for x in 5..12 {
+                set_material(&mut chunk, x, 4, 5, PLANK);
+            }
+            for y in 5..5 + sand {
+                set_material(&mut chunk, 8, y, 5, SAND);
+            }
+            for y in 5 + sand..5 + sand + water {
+                set_material(&mut chunk, 8, y, 5, WATER);
+            }
+            solve_alone(&chunk)
+        };
+
+        assert_eq!(beam(0, 0), StressReport::default());
+        assert_eq!(beam(6, 4), StressReport::default());
+
+        let result = beam(12, 6);
+        assert!(!result.failed.is_empty(), "the loaded plank should fail");
+        assert!(result
+            .failed
+            .iter()
+            .all(|p| (5..12).contains(&p.x) && p.y == 4));
+    }
+
+    #[test]
+    fn test_tall_column_crushes_at_its_base() {
+        // Brick holds 30 * 40 = 1200 in compression at weight 2 per voxel, so
+        // a 601-voxel column crushes at the bottom. Stack chunks to build it.
+        let mut column = empty_chunk();
+        for y in 0..CHUNK_SIZE {
+            set_material(&mut column, 0, y, 0, BRICK);
+        }
+        let stacked: Vec<(ChunkCoord, &[[u32; 2]])> = (0..WORLD_CHUNKS_Y as i32)
+            .map(|y| (IVec3::new(0, y, 0), column.as_slice()))
+            .collect();
+        let mut world = StructuralWorld::new();
+        for &(coord, data) in &stacked {
+            world.insert(coord, data);
+        }
+        let empty = empty_chunk();
+        for y in 0..WORLD_CHUNKS_Y as i32 {
+            world.insert(IVec3::new(1, y, 0), &empty);
+            world.insert(IVec3::new(0, y, 1), &empty);
+        }
+
+        let mut strong = table();
+        assert_eq!(solve(&world, &strong), StressReport::default());
+
+        // Weaken the brick until the world-height column is too much for it
+        let height = (CHUNK_SIZE * WORLD_CHUNKS_Y) as f32;
+        strong.props[BRICK as usize].compression = 2.0 * height - 1.0;
+        let result = solve(&world, &strong);
+        assert_eq!(result.failed.first(), Some(&IVec3::ZERO));
+    }
 }
@@ -1,12 +1,446 @@
-/// Deterministic test infrastructure for simulation snapshot testing.
-///
-/// GPU tests are gated behind `#[cfg(feature = "gpu_tests")]` since they
-/// require a GPU device. CPU-only unit tests for direction, rng, and
-/// conflict scheduling run without this feature.
+//! Deterministic test infrastructure for simulation snapshot testing.
+//!
+//! GPU tests are gated behind `#[cfg(feature = "gpu_tests")]` since they
+//! require a GPU device. CPU-only unit tests for direction, rng, and
+//! conflict scheduling run without this feature. Behavioral tests run the
+//! `CpuSimulator` oracle against the production material and rule data.
+//!
+//! Recipe regression scenes live in `tests/recipes/*.ron`. Each scene
+//! builds a single isolated chunk from declarative boxes of named
+//! materials, runs it for a fixed tick count, and checks material counts,
+//! temperatures, charge, and optionally a golden hash of the final voxel
+//! state. Temperatures are unit-tagged as in the rule data (`1500K`,
+//! `1227C` or `q(768)`). Run the tests with
+//! `ALKAHEST_BLESS=1` to rewrite stale golden hashes in place.
+
+use std::path::{Path, PathBuf};
+
+use alkahest_core::aux_channel::CHARGE;
+use alkahest_core::constants::{AMBIENT_TEMP_QUANTIZED, CHUNK_SIZE, VOXELS_PER_CHUNK};
+use alkahest_core::material::MaterialTable;
+use alkahest_core::types::{ChunkCoord, MaterialId};
+use alkahest_core::voxel::Voxel;
+use alkahest_rules::compiler::compile_tables;
+use alkahest_rules::loader::{load_all_materials, load_all_rules};
+use alkahest_rules::resolve_quantized_temperatures;
+use serde::Deserialize;
+
+use crate::cpu::{isolated_chunk_descriptors, CpuSimulator};
+
+/// The full base material table.
+pub(crate) fn base_materials() -> MaterialTable {
+    load_all_materials(&[
+        include_str!("../../../data/materials/naturals.ron"),
+        include_str!("../../../data/materials/organics.ron"),
+        include_str!("../../../data/materials/energy.ron"),
+        include_str!("../../../data/materials/explosives.ron"),
+        include_str!("../../../data/materials/metals.ron"),
+        include_str!("../../../data/materials/synthetics.ron"),
+        include_str!("../../../data/materials/exotic.ron"),
+        include_str!("../../../data/materials/electrical.ron"),
+    ])
+    .expect("base materials should load")
+}
+
+/// CPU simulator over the full base material and rule data, with a single
+/// isolated chunk in slot 0.
+pub(crate) fn base_simulator() -> CpuSimulator {
+    let materials = base_materials();
+    let rules = load_all_rules(
+        &[
+            include_str!("../../../data/rules/combustion.ron"),
+            include_str!("../../../data/rules/structural.ron"),
+            include_str!("../../../data/rules/phase_change.ron"),
+            include_str!("../../../data/rules/dissolution.ron"),
+            include_str!("../../../data/rules/displacement.ron"),
+            include_str!("../../../data/rules/biological.ron"),
+            include_str!("../../../data/rules/thermal.ron"),
+            include_str!("../../../data/rules/synthesis.ron"),
+            include_str!("../../../data/rules/electrical.ron"),
+        ],
+        &materials,
+    )
+    .expect("base rules should load");
+
+    let mut sim = CpuSimulator::new(compile_tables(&materials, &rules), 1);
+    sim.upload_chunk_descriptors(&isolated_chunk_descriptors(&[0]));
+    sim.upload_chunk_coords(&[ChunkCoord::ZERO]);
+    sim
+}
+
+/// Voxel coordinate within the scene chunk.
+pub(crate) type ScenePos = (u32, u32, u32);
+
+/// A declarative single-chunk test scene, loaded from RON.
+#[derive(Debug, Deserialize)]
+pub(crate) struct Scene {
+    /// Human-readable scene name, used in failure messages.
+    pub name: String,
+    /// Section number of the recipe in `docs/recipes.md` this scene covers.
+    #[serde(default)]
+    pub recipe: Option<u32>,
+    /// Number of ticks to simulate.
+    pub ticks: u32,
+    /// Boxes of material, painted in order (later boxes overwrite earlier ones).
+    pub regions: Vec<SceneRegion>,
+    /// Checks on the final state.
+    #[serde(default)]
+    pub expect: Vec<Expectation>,
+    /// FNV-1a hash of the final voxel data. `None` skips the snapshot check.
+    #[serde(default)]
+    pub golden_hash: Option<u64>,
+    /// Why the recipe does not currently reproduce. When set, at least one
+    /// expectation must fail; the scene still guards its golden hash.
+    #[serde(default)]
+    pub known_failure: Option<String>,
+}
+
+/// An inclusive box of a single material.
+#[derive(Debug, Deserialize)]
+pub(crate) struct SceneRegion {
+    /// Material name, resolved against the base material table.
+    pub material: String,
+    pub min: ScenePos,
+    pub max: ScenePos,
+    /// Temperature (quantized once loaded). Defaults to ambient.
+    #[serde(default)]
+    pub temp: Option<u16>,
+}
+
+/// A single assertion on the final scene state. Materials are named.
+#[derive(Debug, Deserialize)]
+pub(crate) enum Expectation {
+    /// At least `count` voxels of `material` exist.
+    CountAtLeast { material: String, count: usize },
+    /// At most `count` voxels of `material` exist.
+    CountAtMost { material: String, count: usize },
+    /// The voxel at `pos` is `material`.
+    MaterialAt { pos: ScenePos, material: String },
+    /// The voxel at `pos` is at least `temp` (quantized once loaded).
+    TempAtLeast { pos: ScenePos, temp: u16 },
+    /// The hottest voxel of `material` is at least `temp` (quantized once
+    /// loaded).
+    MaxTempAtLeast { material: String, temp: u16 },
+    /// The voxel at `pos` holds at least `charge`.
+    ChargeAtLeast { pos: ScenePos, charge: u32 },
+    /// The voxel at `pos` holds at most `charge`.
+    ChargeAtMost { pos: ScenePos, charge: u32 },
+}
+
+/// Directory holding the recipe scene files.
+pub(crate) fn recipe_scene_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("../../tests/recipes")
+}
+
+/// Parse a scene file, resolving its unit-tagged temperatures.
+pub(crate) fn load_scene(path: &Path) -> Scene {
+    let source = std::fs::read_to_string(path)
+        .unwrap_or_else(|e| panic!("failed to read {}: {e}", path.display()));
+    let source = resolve_quantized_temperatures(&source, &["temp"])
+        .unwrap_or_else(|e| panic!("failed to parse {}: {e}", path.display()));
+    ron::Options::default()
+        .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+        .from_str(&source)
+        .unwrap_or_else(|e| panic!("failed to parse {}: {e}", path.display()))
+}
+
+/// ID of the material called `name`, panicking if no material or more than
+/// one has that name.
+pub(crate) fn material_id(materials: &MaterialTable, name: &str) -> u16 {
+    let mut ids = materials
+        .materials
+        .iter()
+        .filter(|m| m.name == name)
+        .map(|m| m.id);
+    match (ids.next(), ids.next()) {
+        (Some(id), None) => id,
+        (None, _) => panic!("unknown material \"{name}\""),
+        (Some(a), Some(b)) => panic!("ambiguous material \"{name}\" (IDs {a} and {b})"),
+    }
+}
+
+/// Paint the scene's regions into a fresh chunk.
+pub(crate) fn build_scene(scene: &Scene, materials: &MaterialTable) -> Vec<[u32; 2]> {
+    let mut data = vec![[0u32, 0u32]; VOXELS_PER_CHUNK as usize];
+    for region in &scene.regions {
+        let temp = region.temp.unwrap_or(AMBIENT_TEMP_QUANTIZED);
+        let v: [u32; 2] = Voxel::new(MaterialId(material_id(materials, &region.material)))
+            .with_temperature(temp)
+            .into();
+        for z in region.min.2..=region.max.2 {
+            for y in region.min.1..=region.max.1 {
+                for x in region.min.0..=region.max.0 {
+                    data[scene_index((x, y, z))] = v;
+                }
+            }
+        }
+    }
+    data
+}
+
+/// Run a scene to completion and return the simulator for inspection.
+pub(crate) fn run_scene(scene: &Scene, materials: &MaterialTable) -> CpuSimulator {
+    let mut sim = base_simulator();
+    sim.upload_chunk_data(0, &build_scene(scene, materials));
+    for _ in 0..scene.ticks {
+        sim.solve_structures(1);
+        sim.tick(1, &[0]);
+    }
+    sim
+}
+
+/// Run a scene file and check its expectations and golden hash, panicking
+/// with every failure listed.
+pub(crate) fn check_scene_file(file_name: &str) {
+    let path = recipe_scene_dir().join(file_name);
+    let scene = load_scene(&path);
+    let materials = base_materials();
+    let sim = run_scene(&scene, &materials);
+    let voxels = sim.chunk_data(0);
+    let charge = sim.chunk_channel(0, CHARGE);
+
+    let unmet: Vec<String> = scene
+        .expect
+        .iter()
+        .filter_map(|e| check_expectation(e, &materials, voxels, &charge).err())
+        .collect();
+    let mut failures = match &scene.known_failure {
+        None => unmet,
+        Some(_) if !unmet.is_empty() => Vec::new(),
+        Some(reason) => vec![format!(
+            "all expectations now pass; remove known_failure (\"{reason}\")"
+        )],
+    };
+
+    let hash = snapshot_hash(voxels);
+    if let Some(golden) = scene.golden_hash {
+        if golden != hash {
+            if std::env::var_os("ALKAHEST_BLESS").is_some() {
+                bless_golden_hash(&path, hash);
+            } else {
+                failures.push(format!(
+                    "golden hash mismatch: expected {golden:#018x}, got {hash:#018x} \
+                     (rerun with ALKAHEST_BLESS=1 to update)"
+                ));
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "scene '{}' failed:\n  {}",
+        scene.name,
+        failures.join("\n  ")
+    );
+}
+
+fn check_expectation(
+    expectation: &Expectation,
+    materials: &MaterialTable,
+    voxels: &[[u32; 2]],
+    charge: &[u32],
+) -> Result<(), String> {
+    let count_of = |material: &str| {
+        let id = material_id(materials, material);
+        voxels.iter().filter(|v| material_of(v) == id).count()
+    };
+    match expectation {
+        Expectation::CountAtLeast { material, count } => {
+            let actual = count_of(material);
+            if actual >= *count {
+                return Ok(());
+            }
+            Err(format!(
+                "{material}: expected at least {count}, found {actual}"
+            ))
+        }
+        Expectation::CountAtMost { material, count } => {
+            let actual = count_of(material);
+            if actual <= *count {
+                return Ok(());
+            }
+            Err(format!(
+                "{material}: expected at most {count}, found {actual}"
+            ))
+        }
+        Expectation::MaterialAt { pos, material } => {
+            let actual = material_of(&voxels[scene_index(*pos)]);
+            if actual == material_id(materials, material) {
+                return Ok(());
+            }
+            let found = materials.get(actual).map_or("?", |m| m.name.as_str());
+            Err(format!("{pos:?}: expected {material}, found {found}"))
+        }
+        Expectation::TempAtLeast { pos, temp } => {
+            let actual = temperature_of(&voxels[scene_index(*pos)]);
+            if actual >= *temp {
+                return Ok(());
+            }
+            Err(format!("{pos:?}: expected temp >= {temp}, found {actual}"))
+        }
+        Expectation::MaxTempAtLeast { material, temp } => {
+            let id = material_id(materials, material);
+            let actual = voxels
+                .iter()
+                .filter(|v| material_of(v) == id)
+                .map(temperature_of)
+                .max();
+            match actual {
+                Some(actual) if actual >= *temp => Ok(()),
+                Some(actual) => Err(format!(
+                    "{material}: expected max temp >= {temp}, found {actual}"
+                )),
+                None => Err(format!("{material}: none present")),
+            }
+        }
+        Expectation::ChargeAtLeast { pos, charge: min } => {
+            let actual = charge[scene_index(*pos)];
+            if actual >= *min {
+                return Ok(());
+            }
+            Err(format!("{pos:?}: expected charge >= {min}, found {actual}"))
+        }
+        Expectation::ChargeAtMost { pos, charge: max } => {
+            let actual = charge[scene_index(*pos)];
+            if actual <= *max {
+                return Ok(());
+            }
+            Err(format!("{pos:?}: expected charge <= {max}, found {actual}"))
+        }
+    }
+}
+
+/// FNV-1a over the packed voxel words.
+pub(crate) fn snapshot_hash(voxels: &[[u32; 2]]) -> u64 {
+    let mut hash = 0xcbf2_9ce4_8422_2325u64;
+    for word in voxels.iter().flatten() {
+        for byte in word.to_le_bytes() {
+            hash ^= byte as u64;
+            hash = hash.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+    hash
+}
+
+/// Rewrite the `golden_hash:` line of a scene file.
+fn bless_golden_hash(path: &Path, hash: u64) {
+    let source = std::fs::read_to_string(path).expect("scene file readable");
+    let blessed: Vec<String> = source
+        .lines()
+        .map(|line| {
+            let indent = &line[..line.len() - line.trim_start().len()];
+            if line.trim_start().starts_with("golden_hash:") {
+                format!("{indent}golden_hash: Some({hash:#018x}),")
+            } else {
+                line.to_string()
+            }
+        })
+        .collect();
+    std::fs::write(path, blessed.join("\n") + "\n").expect("scene file writable");
+}
+
+fn scene_index((x, y, z): ScenePos) -> usize {
+    assert!(
+        x < CHUNK_SIZE && y < CHUNK_SIZE && z < CHUNK_SIZE,
+        "scene position {:?} outside the chunk",
+        (x, y, z)
+    );
+    (x + y * CHUNK_SIZE + z * CHUNK_SIZE * CHUNK_SIZE) as usize
+}
+
+fn material_of(v: &[u32; 2]) -> u16 {
+    Voxel::from(*v).material_id().0
+}
+
+fn temperature_of(v: &[u32; 2]) -> u16 {
+    Voxel::from(*v).temperature()
+}
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use crate::passes::commands::{SimCommand, MAX_COMMANDS, TOOL_PLACE, TOOL_REMOVE};
     use crate::rng::sim_hash;
+    use crate::structural::{self, StressReport, StructuralTable, StructuralWorld};
+    use alkahest_core::aux_channel::{BLAST, BLAST_FRONT, SEDIMENT, WORDS_PER_CHUNK};
+    use alkahest_core::constants::{
+        ACTIVITY_BLAST_MAX, ACTIVITY_BLAST_SHIFT, ACTIVITY_DIRTY, AMBIENT_TEMP_K,
+        BLAST_FRONT_SOURCE, BYTES_PER_CHUNK, CHUNK_DESC_STRIDE, FLOW_SPEED_MAX,
+        THERMAL_PRESSURE_FACTOR,
+    };
+    use alkahest_core::math::{temp_to_quantized, TempCurve, AMBIENT_DETAIL_CURVE};
+    use alkahest_core::rule::RuleSet;
+    use alkahest_core::types::Kelvin;
+
+    const STONE: u16 = 1;
+    const SAND: u16 = 2;
+    const WATER: u16 = 3;
+    const FIRE: u16 = 5;
+    const STEAM: u16 = 7;
+    const WOOD: u16 = 8;
+    const ASH: u16 = 9;
+    const ICE: u16 = 10;
+    const GUNPOWDER: u16 = 12;
+    const SEALED_METAL: u16 = 13;
+    const GLASS: u16 = 14;
+    const GRAVEL: u16 = 22;
+    const MUD: u16 = 23;
+    const IRON: u16 = 50;
+    const COPPER: u16 = 51;
+    const GOLD: u16 = 52;
+    const COPPER_WIRE: u16 = 550;
+    const POWER_SOURCE: u16 = 556;
+    const PUTTY: u16 = 205;
+    const WET_SAND: u16 = 570;
+
+    fn empty_chunk() -> Vec<[u32; 2]> {
+        vec![[0, 0]; VOXELS_PER_CHUNK as usize]
+    }
+
+    fn idx(x: u32, y: u32, z: u32) -> usize {
+        (x + y * CHUNK_SIZE + z * CHUNK_SIZE * CHUNK_SIZE) as usize
+    }
+
+    fn voxel(mat: u16, temp: u16) -> [u32; 2] {
+        Voxel::new(MaterialId(mat)).with_temperature(temp).into()
+    }
+
+    fn material_at(data: &[[u32; 2]], x: u32, y: u32, z: u32) -> u16 {
+        material_of(&data[idx(x, y, z)])
+    }
+
+    fn temp_at(data: &[[u32; 2]], x: u32, y: u32, z: u32) -> u16 {
+        temperature_of(&data[idx(x, y, z)])
+    }
+
+    fn count(data: &[[u32; 2]], mat: u16) -> usize {
+        data.iter().filter(|v| material_of(v) == mat).count()
+    }
+
+    fn stone_floor(data: &mut [[u32; 2]]) {
+        for z in 0..CHUNK_SIZE {
+            for x in 0..CHUNK_SIZE {
+                data[idx(x, 0, z)] = voxel(STONE, AMBIENT_TEMP_QUANTIZED);
+            }
+        }
+    }
+
+    /// CPU simulator over the base materials with only the given rules.
+    fn simulator_with_rules(rules_ron: &str) -> CpuSimulator {
+        let materials = base_materials();
+        let rules = load_all_rules(&[rules_ron], &materials).unwrap();
+        let mut sim = CpuSimulator::new(compile_tables(&materials, &rules), 1);
+        sim.upload_chunk_descriptors(&isolated_chunk_descriptors(&[0]));
+        sim.upload_chunk_coords(&[ChunkCoord::ZERO]);
+        sim
+    }
+
+    fn run(sim: &mut CpuSimulator, ticks: u32) {
+        for _ in 0..ticks {
+            sim.solve_structures(1);
+            sim.tick(1, &[0]);
+        }
+    }
 
     #[test]
     fn test_prng_determinism_across_ticks() {
@@ -26,4 +460,1522 @@ mod tests {
         let b = sim_hash(3, 2, 1, 0);
         assert_ne!(a, b);
     }
+
+    #[test]
+    fn test_cpu_sand_falls_to_floor() {
+        let mut sim = base_simulator();
+        let mut data = empty_chunk();
+        stone_floor(&mut data);
+        data[idx(16, 20, 16)] = voxel(SAND, AMBIENT_TEMP_QUANTIZED);
+        sim.upload_chunk_data(0, &data);
+
+        run(&mut sim, 30);
+
+        let out = sim.chunk_data(0);
+        assert_eq!(count(out, SAND), 1, "sand must be conserved");
+        let resting = (0..VOXELS_PER_CHUNK as usize)
+            .find(|&i| material_of(&out[i]) == SAND)
+            .unwrap();
+        let y = (resting as u32 / CHUNK_SIZE) % CHUNK_SIZE;
+        assert_eq!(y, 1, "sand should come to rest on the stone floor");
+    }
+
+    #[test]
+    fn test_cpu_movement_never_lands_on_a_filled_cell() {
+        // Sand flying under a falling column lands in cells the column is
+        // about to fall into; the column must see them filled
+        let mut sim = simulator_with_rules("[]");
+        let mut data = empty_chunk();
+        stone_floor(&mut data);
+        fill(
+            &mut data,
+            voxel(SAND, AMBIENT_TEMP_QUANTIZED),
+            [16, 4, 12],
+            [16, 20, 20],
+        );
+        for z in 12..=20 {
+            for x in 4..=12 {
+                data[idx(x, 3, z)] = flying(SAND, [32, 0, 0]);
+            }
+        }
+        sim.upload_chunk_data(0, &data);
+        let sand = count(&data, SAND);
+
+        for _ in 0..20 {
+            run(&mut sim, 1);
+            assert_eq!(count(sim.chunk_data(0), SAND), sand, "no sand may be lost");
+        }
+    }
+
+    #[test]
+    fn test_cpu_stone_is_static() {
+        let mut sim = base_simulator();
+        let mut data = empty_chunk();
+        stone_floor(&mut data);
+        data[idx(4, 10, 4)] = voxel(STONE, AMBIENT_TEMP_QUANTIZED);
+        sim.upload_chunk_data(0, &data);
+
+        run(&mut sim, 5);
+
+        assert_eq!(material_at(sim.chunk_data(0), 4, 10, 4), STONE);
+        assert_eq!(sim.activity_flags(), &[0], "settled ambient stone is idle");
+    }
+
+    #[test]
+    fn test_cpu_fire_burns_wood() {
+        let mut sim = base_simulator();
+        let mut data = empty_chunk();
+        stone_floor(&mut data);
+        for x in 8..16 {
+            data[idx(x, 1, 8)] = voxel(WOOD, AMBIENT_TEMP_QUANTIZED);
+        }
+        data[idx(7, 1, 8)] = voxel(FIRE, 1500);
+        sim.upload_chunk_data(0, &data);
+
+        run(&mut sim, 20);
+
+        let out = sim.chunk_data(0);
+        assert!(count(out, WOOD) < 8, "fire should consume some wood");
+        assert!(count(out, ASH) > 0, "burnt wood should leave ash");
+    }
+
+    #[test]
+    fn test_cpu_heat_diffuses_between_neighbors() {
+        let mut sim = base_simulator();
+        let mut data = empty_chunk();
+        // Hot 3x3x3 iron cube flanked by ambient iron slabs on both x sides,
+        // the slabs on legs so the piece stands. The cube stays below iron's
+        // melting point: a melt would flow, and flow takes the lateral
+        // sub-passes one direction at a time.
+        let hot = temp_to_quantized(1700.0);
+        for z in 10..13 {
+            for y in 0..10 {
+                data[idx(9, y, z)] = voxel(IRON, AMBIENT_TEMP_QUANTIZED);
+                data[idx(13, y, z)] = voxel(IRON, AMBIENT_TEMP_QUANTIZED);
+            }
+            for y in 10..13 {
+                for x in 9..14 {
+                    let temp = if (10..13).contains(&x) {
+                        hot
+                    } else {
+                        AMBIENT_TEMP_QUANTIZED
+                    };
+                    data[idx(x, y, z)] = voxel(IRON, temp);
+                }
+            }
+        }
+        sim.upload_chunk_data(0, &data);
+
+        run(&mut sim, 5);
+
+        let out = sim.chunk_data(0);
+        assert!(temp_at(out, 11, 11, 11) < hot, "hot core should cool");
+        assert!(
+            temp_at(out, 9, 11, 11) > AMBIENT_TEMP_QUANTIZED,
+            "adjacent slab should warm up"
+        );
+        assert_eq!(
+            temp_at(out, 9, 11, 11),
+            temp_at(out, 13, 11, 11),
+            "diffusion must be symmetric"
+        );
+    }
+
+    #[test]
+    fn test_cpu_heat_capacity_slows_warming() {
+        let rise = |heat_capacity: f32| {
+            let mut materials = base_materials();
+            let iron = materials.materials.iter_mut().find(|m| m.id == IRON);
+            iron.unwrap().heat_capacity = heat_capacity;
+            let mut sim = CpuSimulator::new(compile_tables(&materials, &RuleSet::default()), 1);
+            sim.upload_chunk_descriptors(&isolated_chunk_descriptors(&[0]));
+
+            // Ambient iron enclosed by hot stone (below its melting point)
+            let mut data = empty_chunk();
+            for z in 9..12 {
+                for y in 9..12 {
+                    for x in 9..12 {
+                        data[idx(x, y, z)] = voxel(STONE, 700);
+                    }
+                }
+            }
+            data[idx(10, 10, 10)] = voxel(IRON, AMBIENT_TEMP_QUANTIZED);
+            sim.upload_chunk_data(0, &data);
+            run(&mut sim, 3);
+            temp_at(sim.chunk_data(0), 10, 10, 10) - AMBIENT_TEMP_QUANTIZED
+        };
+        let light = rise(1.0);
+        let heavy = rise(2.0);
+        assert!(heavy > 0, "iron should still warm up");
+        assert!(
+            (heavy as f32) < light as f32 * 0.6,
+            "doubling heat capacity should roughly halve warming: {light} vs {heavy}"
+        );
+    }
+
+    #[test]
+    fn test_cpu_radiation_crosses_air_gap_until_occluded() {
+        // Hot iron 4 voxels from a stone target across air, optionally with a
+        // stone blocker in between; returns the target's rise and the iron's drop
+        let exchange = |iron_emissivity: f32, stone_emissivity: f32, blocked: bool| {
+            let mut materials = base_materials();
+            let iron = materials
+                .materials
+                .iter_mut()
+                .find(|m| m.id == IRON)
+                .unwrap();
+            iron.radiative_emissivity = iron_emissivity;
+            // Stays solid at ~2900 K
+            iron.phase_change_temp = Kelvin(0.0);
+            let stone = materials
+                .materials
+                .iter_mut()
+                .find(|m| m.id == STONE)
+                .unwrap();
+            stone.radiative_emissivity = stone_emissivity;
+            let mut sim = CpuSimulator::new(compile_tables(&materials, &RuleSet::default()), 1);
+            sim.upload_chunk_descriptors(&isolated_chunk_descriptors(&[0]));
+
+            let mut data = empty_chunk();
+            data[idx(8, 10, 10)] = voxel(IRON, 1500);
+            data[idx(12, 10, 10)] = voxel(STONE, AMBIENT_TEMP_QUANTIZED);
+            if blocked {
+                data[idx(10, 10, 10)] = voxel(STONE, AMBIENT_TEMP_QUANTIZED);
+            }
+            sim.upload_chunk_data(0, &data);
+            run(&mut sim, 3);
+            let out = sim.chunk_data(0);
+            (
+                temp_at(out, 12, 10, 10) - AMBIENT_TEMP_QUANTIZED,
+                1500 - temp_at(out, 8, 10, 10),
+            )
+        };
+
+        let (warmed, _) = exchange(0.9, 0.9, false);
+        assert!(warmed > 0, "radiation should warm stone across the gap");
+        assert_eq!(
+            exchange(0.0, 0.9, false).0,
+            0,
+            "no emissivity, no radiation"
+        );
+        assert_eq!(
+            exchange(0.9, 0.0, false),
+            exchange(0.0, 0.0, false),
+            "a non-emissive target neither absorbs nor drains the emitter"
+        );
+        assert_eq!(
+            exchange(0.9, 0.9, true).0,
+            0,
+            "the blocker should shade the target"
+        );
+
+        // Without radiation the iron only drains toward ambient; radiating
+        // it also loses what the stone gains
+        let (_, drained) = exchange(0.0, 0.9, false);
+        let (_, radiated) = exchange(0.9, 0.9, false);
+        assert!(radiated > drained, "the emitter should cool as it radiates");
+    }
+
+    #[test]
+    fn test_cpu_sealed_fire_smothers_and_vented_fire_draws() {
+        // Fire spreading through a block of Wood on a Stone plinth, either
+        // sealed inside it or with a shaft up to the open air. Returns the
+        // Wood burned after 40 and after 100 ticks.
+        let burn = |vented: bool| {
+            let mut sim = simulator_with_rules(
+                r#"[(name: "burn", input_a: "Fire", input_b: "Wood", output_a: "Fire",
+                     output_b: "Fire", probability: 0.2, temp_delta: 2000, oxygen: 64)]"#,
+            );
+            let mut data = empty_chunk();
+            for z in 13..=19 {
+                for y in 0..=19 {
+                    for x in 13..=19 {
+                        let mat = if y < 13 { STONE } else { WOOD };
+                        data[idx(x, y, z)] = voxel(mat, AMBIENT_TEMP_QUANTIZED);
+                    }
+                }
+            }
+            data[idx(16, 16, 16)] = voxel(FIRE, 4000);
+            if vented {
+                for y in 17..=19 {
+                    data[idx(16, y, 16)] = voxel(0, AMBIENT_TEMP_QUANTIZED);
+                }
+            }
+            sim.upload_chunk_data(0, &data);
+            let wood = count(&data, WOOD);
+            run(&mut sim, 40);
+            let early = wood - count(sim.chunk_data(0), WOOD);
+            run(&mut sim, 60);
+            (early, wood - count(sim.chunk_data(0), WOOD))
+        };
+
+        // Sealed in, the fire uses up the air it started with and goes out
+        let (sealed_early, sealed) = burn(false);
+        assert!(sealed_early > 0, "the fire should catch");
+        assert_eq!(sealed, sealed_early, "a sealed fire should smother");
+
+        // Vented, fresh air keeps arriving down the shaft
+        let (_, vented) = burn(true);
+        assert!(
+            vented > 3 * sealed,
+            "vented fire burned {vented}, sealed {sealed}"
+        );
+    }
+
+    /// Gunpowder resting on the floor at (4, 1, 16), set off on the first
+    /// tick by a rule against the Stone under it.
+    fn blast_scene() -> (CpuSimulator, Vec<[u32; 2]>) {
+        let sim = simulator_with_rules(
+            r#"[(name: "bang", input_a: "Gunpowder", input_b: "Stone", output_a: "Air",
+                 output_b: "Stone", probability: 1.0, blast: 120)]"#,
+        );
+        let mut data = empty_chunk();
+        stone_floor(&mut data);
+        data[idx(4, 1, 16)] = voxel(GUNPOWDER, AMBIENT_TEMP_QUANTIZED);
+        (sim, data)
+    }
+
+    fn velocity_at(data: &[[u32; 2]], x: u32, y: u32, z: u32) -> [i8; 3] {
+        Voxel::from(data[idx(x, y, z)]).velocity()
+    }
+
+    #[test]
+    fn test_cpu_blast_breaks_near_glass_and_fades_before_far_glass() {
+        let (mut sim, mut data) = blast_scene();
+        data[idx(8, 1, 16)] = voxel(GLASS, AMBIENT_TEMP_QUANTIZED);
+        data[idx(28, 1, 16)] = voxel(GLASS, AMBIENT_TEMP_QUANTIZED);
+        sim.upload_chunk_data(0, &data);
+
+        // Seeded on tick 1, the front reaches the near pane four ticks later
+        // at 120 * 0.85^4 = 62, well over Glass's 16
+        run(&mut sim, 5);
+        let out = sim.chunk_data(0);
+        assert_eq!(material_at(out, 8, 1, 16), GLASS);
+        assert_eq!(
+            velocity_at(out, 8, 1, 16),
+            [23, 0, 0],
+            "the pane should fly away from the blast"
+        );
+
+        // Twenty-four voxels out the front has faded to nothing
+        run(&mut sim, 35);
+        let out = sim.chunk_data(0);
+        assert_eq!(material_at(out, 28, 1, 16), GLASS);
+        assert_eq!(velocity_at(out, 28, 1, 16), [0, 0, 0]);
+    }
+
+    #[test]
+    fn test_cpu_blast_shadowed_by_stone_wall() {
+        let (mut sim, mut data) = blast_scene();
+        for z in 10..=22 {
+            for y in 1..=6 {
+                data[idx(6, y, z)] = voxel(STONE, AMBIENT_TEMP_QUANTIZED);
+            }
+        }
+        data[idx(8, 1, 16)] = voxel(GLASS, AMBIENT_TEMP_QUANTIZED);
+        sim.upload_chunk_data(0, &data);
+
+        run(&mut sim, 40);
+        let out = sim.chunk_data(0);
+        // The wall holds, and the way around it is too long for what is left
+        assert_eq!(velocity_at(out, 6, 1, 16), [0, 0, 0]);
+        assert_eq!(material_at(out, 8, 1, 16), GLASS);
+        assert_eq!(velocity_at(out, 8, 1, 16), [0, 0, 0]);
+    }
+
+    #[test]
+    fn test_cpu_blast_yield_reported_in_activity_flags() {
+        let (mut sim, data) = blast_scene();
+        sim.upload_chunk_data(0, &data);
+
+        // The Gunpowder and the floor voxel under it both react
+        run(&mut sim, 1);
+        let flags = sim.activity_flags()[0];
+        assert_eq!(flags & ACTIVITY_DIRTY, ACTIVITY_DIRTY);
+        assert_eq!(flags >> ACTIVITY_BLAST_SHIFT, 240);
+
+        // Only the tick of the detonation carries a yield
+        run(&mut sim, 1);
+        assert_eq!(sim.activity_flags()[0] >> ACTIVITY_BLAST_SHIFT, 0);
+    }
+
+    #[test]
+    fn test_cpu_blast_yield_saturates_when_a_chunk_detonates() {
+        // A whole chunk of Gunpowder and Stone in a 3D checkerboard, every
+        // pair going off at full strength
+        let mut sim = simulator_with_rules(
+            r#"[(name: "bang", input_a: "Gunpowder", input_b: "Stone", output_a: "Air",
+                 output_b: "Stone", probability: 1.0, blast: 255)]"#,
+        );
+        let mut data = empty_chunk();
+        for z in 0..CHUNK_SIZE {
+            for y in 0..CHUNK_SIZE {
+                for x in 0..CHUNK_SIZE {
+                    let mat = if (x + y + z) % 2 == 0 {
+                        GUNPOWDER
+                    } else {
+                        STONE
+                    };
+                    data[idx(x, y, z)] = voxel(mat, AMBIENT_TEMP_QUANTIZED);
+                }
+            }
+        }
+        sim.upload_chunk_data(0, &data);
+        run(&mut sim, 1);
+
+        let blast = sim.chunk_channel(0, BLAST);
+        let fronts = sim.chunk_channel(0, BLAST_FRONT);
+        let total: u64 = (0..VOXELS_PER_CHUNK as usize)
+            .filter(|&i| fronts[i] == BLAST_FRONT_SOURCE)
+            .map(|i| u64::from(blast[i]))
+            .sum();
+        assert!(
+            total > 1 << 20,
+            "most of the chunk should detonate: {total}"
+        );
+
+        let flags = sim.activity_flags()[0];
+        assert_eq!(flags & ACTIVITY_DIRTY, ACTIVITY_DIRTY);
+        assert_eq!(
+            u64::from(flags >> ACTIVITY_BLAST_SHIFT),
+            total.min(u64::from(ACTIVITY_BLAST_MAX)),
+            "the yield must not wrap"
+        );
+    }
+
+    fn positions_of(data: &[[u32; 2]], mat: u16) -> Vec<[u32; 3]> {
+        let mut found = Vec::new();
+        for z in 0..CHUNK_SIZE {
+            for y in 0..CHUNK_SIZE {
+                for x in 0..CHUNK_SIZE {
+                    if material_at(data, x, y, z) == mat {
+                        found.push([x, y, z]);
+                    }
+                }
+            }
+        }
+        found
+    }
+
+    fn flying(mat: u16, velocity: [i8; 3]) -> [u32; 2] {
+        Voxel::new(MaterialId(mat))
+            .with_temperature(AMBIENT_TEMP_QUANTIZED)
+            .with_velocity(velocity)
+            .into()
+    }
+
+    #[test]
+    fn test_cpu_ballistic_voxel_arcs_and_lands() {
+        let mut sim = simulator_with_rules("[]");
+        let mut data = empty_chunk();
+        stone_floor(&mut data);
+        // Two voxels a tick sideways
+        data[idx(2, 20, 16)] = flying(SAND, [64, 0, 0]);
+        sim.upload_chunk_data(0, &data);
+
+        run(&mut sim, 4);
+        let [[x, y, _]] = positions_of(sim.chunk_data(0), SAND)[..] else {
+            panic!("the sand should still be there, once");
+        };
+        assert!(
+            x >= 9,
+            "should cover about two voxels a tick, got to x = {x}"
+        );
+        assert!(y < 20, "gravity should bend the path down");
+
+        run(&mut sim, 25);
+        let out = sim.chunk_data(0);
+        let [[_, y, _]] = positions_of(out, SAND)[..] else {
+            panic!("the sand should still be there, once");
+        };
+        assert_eq!(y, 1, "the sand should come down on the floor");
+        let [pos] = positions_of(out, SAND)[..] else {
+            unreachable!()
+        };
+        assert_eq!(velocity_at(out, pos[0], pos[1], pos[2]), [0, 0, 0]);
+    }
+
+    #[test]
+    fn test_cpu_ballistic_impact_passes_momentum_on() {
+        let mut sim = simulator_with_rules("[]");
+        let mut data = empty_chunk();
+        stone_floor(&mut data);
+        data[idx(4, 1, 16)] = flying(IRON, [64, 0, 0]);
+        data[idx(10, 1, 16)] = voxel(SAND, AMBIENT_TEMP_QUANTIZED);
+        sim.upload_chunk_data(0, &data);
+
+        // Few enough ticks that neither reaches the chunk edge
+        run(&mut sim, 6);
+        let out = sim.chunk_data(0);
+        let [iron] = positions_of(out, IRON)[..] else {
+            panic!("one Iron voxel expected");
+        };
+        let [sand] = positions_of(out, SAND)[..] else {
+            panic!("one Sand voxel expected");
+        };
+        // The heavy Iron knocks the Sand on ahead of it and keeps going
+        assert!(
+            sand[0] > 10,
+            "the Sand should be knocked on, still at {sand:?}"
+        );
+        assert!(
+            iron[0] > 4 && iron[0] < sand[0],
+            "iron {iron:?}, sand {sand:?}"
+        );
+    }
+
+    #[test]
+    fn test_cpu_ballistic_movement_conserves_voxels() {
+        let mut sim = simulator_with_rules("[]");
+        let mut data = empty_chunk();
+        stone_floor(&mut data);
+        // A cloud of sand, water and stone fragments flying every which way,
+        // into each other, the floor and the chunk edges
+        let materials = [SAND, WATER, STONE, IRON];
+        for z in 8..24 {
+            for y in 4..20 {
+                for x in 8..24 {
+                    let h = sim_hash(x as i32, y as i32, z as i32, 0);
+                    if !h.is_multiple_of(3) {
+                        continue;
+                    }
+                    let speed = |shift: u32| ((h >> shift) & 0xFF) as i8 / 2;
+                    data[idx(x, y, z)] = flying(
+                        materials[(h >> 2) as usize % materials.len()],
+                        [speed(8), speed(16), speed(24)],
+                    );
+                }
+            }
+        }
+        sim.upload_chunk_data(0, &data);
+        let before: Vec<usize> = materials.iter().map(|&m| count(&data, m)).collect();
+
+        for _ in 0..30 {
+            run(&mut sim, 1);
+            // Sand the water scoured up counts as sand
+            let after: Vec<usize> = materials.iter().map(|&m| count_settled(&sim, m)).collect();
+            assert_eq!(after, before, "no voxel may be duplicated or lost");
+        }
+    }
+
+    #[test]
+    fn test_cpu_ballistic_voxel_falls_into_chunk_below() {
+        let materials = base_materials();
+        let rules = load_all_rules(&["[]"], &materials).unwrap();
+        let mut sim = CpuSimulator::new(compile_tables(&materials, &rules), 2);
+        // Slot 0 under slot 1; neighbor index (dz+1)*9 + (dy+1)*3 + (dx+1),
+        // less one past the center (coords.wgsl)
+        let mut descriptors = isolated_chunk_descriptors(&[0, 1]);
+        let stride = CHUNK_DESC_STRIDE as usize;
+        descriptors[1 + 15] = BYTES_PER_CHUNK;
+        descriptors[stride + 1 + 10] = 0;
+        sim.upload_chunk_descriptors(&descriptors);
+
+        let mut lower = empty_chunk();
+        stone_floor(&mut lower);
+        sim.upload_chunk_data(0, &lower);
+        let mut upper = empty_chunk();
+        upper[idx(16, 8, 16)] = flying(SAND, [16, -64, 0]);
+        sim.upload_chunk_data(1, &upper);
+
+        for _ in 0..30 {
+            sim.tick(2, &[0, 1]);
+        }
+        assert_eq!(count(sim.chunk_data(1), SAND), 0, "the sand should leave");
+        let out = sim.chunk_data(0);
+        let [[x, y, z]] = positions_of(out, SAND)[..] else {
+            panic!("the sand should land in the chunk below, once");
+        };
+        assert_eq!(y, 1, "the sand should come down on the floor");
+        assert!(x > 16, "the sand should keep drifting sideways");
+        assert_eq!(velocity_at(out, x, y, z), [0, 0, 0]);
+    }
+
+    #[test]
+    fn test_cpu_detached_slab_falls_into_chunk_below() {
+        let materials = base_materials();
+        let rules = load_all_rules(&["[]"], &materials).unwrap();
+        let mut sim = CpuSimulator::new(compile_tables(&materials, &rules), 2);
+        let mut descriptors = isolated_chunk_descriptors(&[0, 1]);
+        let stride = CHUNK_DESC_STRIDE as usize;
+        descriptors[1 + 15] = BYTES_PER_CHUNK;
+        descriptors[stride + 1 + 10] = 0;
+        sim.upload_chunk_descriptors(&descriptors);
+        sim.upload_chunk_coords(&[ChunkCoord::ZERO, ChunkCoord::Y]);
+
+        // A slab at the bottom of the upper chunk on a pillar up from the
+        // floor of the lower one
+        let stone = voxel(STONE, AMBIENT_TEMP_QUANTIZED);
+        let mut lower = empty_chunk();
+        stone_floor(&mut lower);
+        fill(&mut lower, stone, [16, 1, 16], [16, 31, 16]);
+        sim.upload_chunk_data(0, &lower);
+        let mut upper = empty_chunk();
+        fill(&mut upper, stone, [16, 0, 16], [16, 0, 16]);
+        fill(&mut upper, stone, [12, 1, 16], [20, 1, 16]);
+        sim.upload_chunk_data(1, &upper);
+        let total = count(&lower, STONE) + count(&upper, STONE);
+
+        // Blow nine voxels out of the pillar
+        sim.enqueue_command(SimCommand {
+            tool_type: TOOL_REMOVE,
+            pos_x: 16,
+            pos_y: 12,
+            pos_z: 16,
+            material_id: 0,
+            chunk_dispatch_idx: 0,
+            brush_radius: 4,
+            brush_shape: 2,
+        });
+        for _ in 0..80 {
+            sim.solve_structures(2);
+            sim.tick(2, &[0, 1]);
+        }
+
+        assert_eq!(
+            count(sim.chunk_data(1), STONE),
+            0,
+            "the slab should come down"
+        );
+        let out = sim.chunk_data(0);
+        assert_eq!(count(out, STONE), total - 9);
+        assert!(
+            (0..=24).all(|y| material_at(out, 16, y, 16) == STONE),
+            "the pillar should stand nine voxels shorter"
+        );
+        assert_eq!(
+            solve(&[(ChunkCoord::ZERO, out), (ChunkCoord::Y, sim.chunk_data(1))]),
+            StressReport::default(),
+            "what came down should stand"
+        );
+    }
+
+    /// Structural solve of the given chunks over the base materials.
+    fn solve(chunks: &[(ChunkCoord, &[[u32; 2]])]) -> StressReport {
+        let mut world = StructuralWorld::new();
+        for &(coord, data) in chunks {
+            world.insert(coord, data);
+        }
+        structural::solve(&world, &StructuralTable::from_materials(&base_materials()))
+    }
+
+    fn pressure_at(data: &[[u32; 2]], x: u32, y: u32, z: u32) -> u8 {
+        Voxel::from(data[idx(x, y, z)]).pressure()
+    }
+
+    /// Fills the box from `lo` to `hi` (inclusive) with `value`.
+    fn fill(data: &mut [[u32; 2]], value: [u32; 2], lo: [u32; 3], hi: [u32; 3]) {
+        for z in lo[2]..=hi[2] {
+            for y in lo[1]..=hi[1] {
+                for x in lo[0]..=hi[0] {
+                    data[idx(x, y, z)] = value;
+                }
+            }
+        }
+    }
+
+    /// Top of the water in the shaft at x, z = 16, or 0 if it holds none.
+    fn water_level(data: &[[u32; 2]], x: u32) -> u32 {
+        (0..CHUNK_SIZE)
+            .filter(|&y| material_at(data, x, y, 16) == WATER)
+            .max()
+            .unwrap_or(0)
+    }
+
+    #[test]
+    fn test_cpu_hydrostatic_pressure_grows_with_depth() {
+        let mut sim = simulator_with_rules("[]");
+        let mut data = empty_chunk();
+        let (stone, water) = (
+            voxel(STONE, AMBIENT_TEMP_QUANTIZED),
+            voxel(WATER, AMBIENT_TEMP_QUANTIZED),
+        );
+        // A one-voxel shaft through a stone block, water from y = 1 to 12
+        fill(&mut data, stone, [14, 0, 14], [18, 16, 18]);
+        fill(&mut data, [0, 0], [16, 1, 16], [16, 16, 16]);
+        fill(&mut data, water, [16, 1, 16], [16, 12, 16]);
+        sim.upload_chunk_data(0, &data);
+
+        run(&mut sim, 2);
+        let out = sim.chunk_data(0);
+        for y in 1..=12 {
+            assert_eq!(
+                pressure_at(out, 16, y, 16),
+                13 - y as u8,
+                "water at y = {y} carries the column above it"
+            );
+        }
+    }
+
+    #[test]
+    fn test_cpu_water_levels_equalize_in_u_tube() {
+        let mut sim = simulator_with_rules("[]");
+        let mut data = empty_chunk();
+        let (stone, water) = (
+            voxel(STONE, AMBIENT_TEMP_QUANTIZED),
+            voxel(WATER, AMBIENT_TEMP_QUANTIZED),
+        );
+        // Two shafts at x = 10 and x = 14 joined by a tube along y = 2, the
+        // left one filled to y = 17
+        fill(&mut data, stone, [8, 0, 14], [16, 22, 18]);
+        fill(&mut data, [0, 0], [10, 2, 16], [10, 22, 16]);
+        fill(&mut data, [0, 0], [14, 2, 16], [14, 22, 16]);
+        fill(&mut data, water, [10, 2, 16], [14, 2, 16]);
+        fill(&mut data, water, [10, 3, 16], [10, 17, 16]);
+        sim.upload_chunk_data(0, &data);
+        let water = count(&data, WATER);
+
+        run(&mut sim, 300);
+        let out = sim.chunk_data(0);
+        assert_eq!(count(out, WATER), water, "no water may be lost");
+        let (left, right) = (water_level(out, 10), water_level(out, 14));
+        assert!(
+            right >= 8 && left.abs_diff(right) <= 1,
+            "levels should meet near y = 9, got {left} and {right}"
+        );
+    }
+
+    #[test]
+    fn test_cpu_dam_breaks_under_tall_column_only() {
+        let mut sim = simulator_with_rules("[]");
+        let mut data = empty_chunk();
+        let (stone, water) = (
+            voxel(STONE, AMBIENT_TEMP_QUANTIZED),
+            voxel(WATER, AMBIENT_TEMP_QUANTIZED),
+        );
+        fill(&mut data, stone, [4, 0, 14], [12, 20, 18]);
+        // Two shafts, each with a Glass pane in its side at the bottom: the
+        // tall column weighs 14 against it, over Glass's 8; the short one 4
+        for (x, height) in [(6, 14), (10, 4)] {
+            fill(&mut data, [0, 0], [x, 1, 16], [x, 20, 16]);
+            fill(&mut data, water, [x, 1, 16], [x, height, 16]);
+            data[idx(x + 1, 1, 16)] = voxel(GLASS, AMBIENT_TEMP_QUANTIZED);
+        }
+        sim.upload_chunk_data(0, &data);
+
+        run(&mut sim, 20);
+        let out = sim.chunk_data(0);
+        assert_ne!(
+            material_at(out, 7, 1, 16),
+            GLASS,
+            "the tall column should break its pane"
+        );
+        assert_eq!(
+            material_at(out, 11, 1, 16),
+            GLASS,
+            "the short column should not"
+        );
+    }
+
+    #[test]
+    fn test_cpu_enclosed_liquid_builds_pressure_over_its_head() {
+        let mut sim = simulator_with_rules("[]");
+        let mut data = empty_chunk();
+        // Warm water sealed in a one-voxel shaft from y = 1 to 4: its head
+        // alone would be 4 at the bottom
+        fill(
+            &mut data,
+            voxel(STONE, AMBIENT_TEMP_QUANTIZED),
+            [14, 0, 14],
+            [18, 6, 18],
+        );
+        fill(
+            &mut data,
+            voxel(WATER, AMBIENT_TEMP_QUANTIZED + 40),
+            [16, 1, 16],
+            [16, 4, 16],
+        );
+        sim.upload_chunk_data(0, &data);
+
+        run(&mut sim, 10);
+        let out = sim.chunk_data(0);
+        // Replacing the pressure with the head each tick would leave at most
+        // one tick's thermal pressure over it
+        assert!(
+            pressure_at(out, 16, 1, 16) > 4 + THERMAL_PRESSURE_FACTOR as u8,
+            "thermal pressure should build up on top of the head"
+        );
+    }
+
+    #[test]
+    fn test_cpu_liquid_ruptures_under_its_own_head() {
+        let mut sim = simulator_with_rules("[]");
+        let mut data = empty_chunk();
+        // Putty (integrity 2) stacked 4 deep in a shaft: the bottom voxel
+        // bears more than it holds
+        fill(
+            &mut data,
+            voxel(STONE, AMBIENT_TEMP_QUANTIZED),
+            [14, 0, 14],
+            [18, 8, 18],
+        );
+        fill(&mut data, [0, 0], [16, 1, 16], [16, 8, 16]);
+        fill(
+            &mut data,
+            voxel(PUTTY, AMBIENT_TEMP_QUANTIZED),
+            [16, 1, 16],
+            [16, 4, 16],
+        );
+        sim.upload_chunk_data(0, &data);
+
+        run(&mut sim, 1);
+        let out = sim.chunk_data(0);
+        assert!(
+            count(out, PUTTY) < 4,
+            "the bottom of the column should burst"
+        );
+    }
+
+    #[test]
+    fn test_cpu_repose_angle_sets_pile_spread() {
+        // The same 6-voxel cube slumps on an inert floor: Sand (32°) spreads
+        // widest, Gravel (40°) less, Mud (70°) barely at all
+        let footprints = [SAND, GRAVEL, MUD].map(|mat| {
+            let mut sim = simulator_with_rules("[]");
+            let mut data = empty_chunk();
+            fill(
+                &mut data,
+                voxel(SEALED_METAL, AMBIENT_TEMP_QUANTIZED),
+                [0, 0, 0],
+                [31, 0, 31],
+            );
+            fill(
+                &mut data,
+                voxel(mat, AMBIENT_TEMP_QUANTIZED),
+                [13, 1, 13],
+                [18, 6, 18],
+            );
+            sim.upload_chunk_data(0, &data);
+
+            run(&mut sim, 200);
+            let out = sim.chunk_data(0);
+            assert_eq!(count(out, mat), 216, "grains are conserved");
+            (0..CHUNK_SIZE * CHUNK_SIZE)
+                .filter(|&i| material_at(out, i % CHUNK_SIZE, 1, i / CHUNK_SIZE) == mat)
+                .count()
+        });
+        assert!(
+            footprints[0] > footprints[1] && footprints[1] > footprints[2],
+            "piles should cover less floor as the angle steepens, got {footprints:?}"
+        );
+        assert!(footprints[2] < 50, "Mud should stand nearly as cast");
+    }
+
+    #[test]
+    fn test_cpu_cohesive_overhang_stands_until_disturbed() {
+        // A five-voxel ledge off a column: Wet Sand (cohesion 0.75) holds the
+        // first three out over nothing, Sand holds none
+        let ledge = |mat: u16| {
+            let mut data = empty_chunk();
+            fill(
+                &mut data,
+                voxel(SEALED_METAL, AMBIENT_TEMP_QUANTIZED),
+                [0, 0, 0],
+                [31, 0, 31],
+            );
+            fill(
+                &mut data,
+                voxel(mat, AMBIENT_TEMP_QUANTIZED),
+                [10, 1, 16],
+                [10, 4, 16],
+            );
+            fill(
+                &mut data,
+                voxel(mat, AMBIENT_TEMP_QUANTIZED),
+                [11, 4, 16],
+                [15, 4, 16],
+            );
+            data
+        };
+        let standing = |data: &[[u32; 2]], mat: u16| {
+            (11..=15)
+                .filter(|&x| material_at(data, x, 4, 16) == mat)
+                .count()
+        };
+
+        let mut sim = simulator_with_rules("[]");
+        sim.upload_chunk_data(0, &ledge(SAND));
+        run(&mut sim, 10);
+        assert_eq!(standing(sim.chunk_data(0), SAND), 0);
+
+        let mut sim = simulator_with_rules("[]");
+        sim.upload_chunk_data(0, &ledge(WET_SAND));
+        run(&mut sim, 10);
+        assert_eq!(standing(sim.chunk_data(0), WET_SAND), 3);
+
+        // Knocking the column's top out from under the ledge brings it down
+        let mut data = sim.chunk_data(0).to_vec();
+        data[idx(10, 4, 16)] = [0, 0];
+        sim.upload_chunk_data_both(0, &data);
+        run(&mut sim, 10);
+        assert_eq!(standing(sim.chunk_data(0), WET_SAND), 0);
+    }
+
+    #[test]
+    fn test_cpu_water_soaks_sand_into_wet_sand() {
+        let mut sim = base_simulator();
+        let mut data = empty_chunk();
+        fill(
+            &mut data,
+            voxel(SEALED_METAL, AMBIENT_TEMP_QUANTIZED),
+            [0, 0, 0],
+            [31, 0, 31],
+        );
+        fill(
+            &mut data,
+            voxel(SAND, AMBIENT_TEMP_QUANTIZED),
+            [12, 1, 12],
+            [19, 2, 19],
+        );
+        fill(
+            &mut data,
+            voxel(WATER, AMBIENT_TEMP_QUANTIZED),
+            [12, 3, 12],
+            [19, 3, 19],
+        );
+        sim.upload_chunk_data(0, &data);
+
+        run(&mut sim, 40);
+        let out = sim.chunk_data(0);
+        let wet = count(out, WET_SAND);
+        assert!(wet > 0, "water should wet the sand");
+        assert_eq!(count(out, SAND) + wet, 128, "wetting keeps every grain");
+        assert_eq!(count(out, WATER), 64, "and keeps every drop of water");
+    }
+
+    #[test]
+    fn test_cpu_hot_wet_sand_dries_out() {
+        let mut sim = base_simulator();
+        let mut data = empty_chunk();
+        fill(
+            &mut data,
+            voxel(SEALED_METAL, AMBIENT_TEMP_QUANTIZED),
+            [0, 0, 0],
+            [31, 0, 31],
+        );
+        fill(
+            &mut data,
+            voxel(WET_SAND, Kelvin(600.0).quantize().0),
+            [12, 1, 12],
+            [19, 1, 19],
+        );
+        sim.upload_chunk_data(0, &data);
+
+        run(&mut sim, 40);
+        let out = sim.chunk_data(0);
+        assert_eq!(count(out, SAND), 64, "hot wet sand should dry out");
+    }
+
+    /// Voxels of `mat` once all carried sediment is dropped.
+    fn count_settled(sim: &CpuSimulator, mat: u16) -> usize {
+        let sediment = sim.chunk_channel(0, SEDIMENT);
+        sim.chunk_data(0)
+            .iter()
+            .zip(&sediment)
+            .filter(|&(v, &s)| {
+                if s != 0 {
+                    s == mat as u32
+                } else {
+                    material_of(v) == mat
+                }
+            })
+            .count()
+    }
+
+    /// Voxels of sediment carried by liquid, by material.
+    fn carried(sim: &CpuSimulator, mat: u16) -> usize {
+        sim.chunk_channel(0, SEDIMENT)
+            .iter()
+            .filter(|&&s| s == mat as u32)
+            .count()
+    }
+
+    #[test]
+    fn test_cpu_falling_water_scours_and_redeposits_sand() {
+        // No rules, so the sand stays sand instead of soaking
+        let mut sim = simulator_with_rules("[]");
+        let mut data = empty_chunk();
+        fill(
+            &mut data,
+            voxel(SEALED_METAL, AMBIENT_TEMP_QUANTIZED),
+            [0, 0, 0],
+            [31, 0, 31],
+        );
+        // Glass basin, 16x16 inside, holding a two-deep sand bed
+        fill(
+            &mut data,
+            voxel(GLASS, AMBIENT_TEMP_QUANTIZED),
+            [7, 1, 7],
+            [24, 12, 24],
+        );
+        fill(&mut data, [0, 0], [8, 1, 8], [23, 12, 23]);
+        fill(
+            &mut data,
+            voxel(SAND, AMBIENT_TEMP_QUANTIZED),
+            [8, 1, 8],
+            [23, 2, 23],
+        );
+        // Enough water to fill the basin two deep, dropped from high up
+        fill(
+            &mut data,
+            voxel(WATER, AMBIENT_TEMP_QUANTIZED),
+            [12, 20, 12],
+            [19, 27, 19],
+        );
+        sim.upload_chunk_data(0, &data);
+
+        let mut most_carried = 0;
+        for _ in 0..150 {
+            run(&mut sim, 1);
+            let carried = carried(&sim, SAND);
+            most_carried = most_carried.max(carried);
+            assert_eq!(
+                count(sim.chunk_data(0), SAND) + carried,
+                512,
+                "every grain is either in the bed or in the water"
+            );
+        }
+        assert!(most_carried > 0, "the falling water should scour the bed");
+        assert_eq!(carried(&sim, SAND), 0, "still water drops its load");
+        assert_eq!(count(sim.chunk_data(0), WATER), 512);
+    }
+
+    #[test]
+    fn test_cpu_carried_sediment_sits_out_reactions() {
+        let mut sim = simulator_with_rules(
+            r#"[(name: "soak", input_a: "Water", input_b: "Sand", output_a: "Air",
+                 output_b: "Wet Sand", probability: 1.0)]"#,
+        );
+        let mut data = empty_chunk();
+        // Fast water carrying gravel, boxed in by glass beside a grain of sand
+        fill(
+            &mut data,
+            voxel(GLASS, AMBIENT_TEMP_QUANTIZED),
+            [9, 9, 9],
+            [12, 11, 11],
+        );
+        data[idx(10, 10, 10)] = flying(WATER, [FLOW_SPEED_MAX as i8, 0, 0]);
+        data[idx(11, 10, 10)] = voxel(SAND, AMBIENT_TEMP_QUANTIZED);
+        let mut aux = vec![0; WORDS_PER_CHUNK];
+        SEDIMENT.write(&mut aux, idx(10, 10, 10), GRAVEL as u32);
+        sim.upload_chunk_data_both(0, &data);
+        sim.upload_chunk_aux_both(0, &aux);
+
+        run(&mut sim, 1);
+        let out = sim.chunk_data(0);
+        assert_eq!(material_at(out, 10, 10, 10), WATER, "no soaking away");
+        assert_eq!(material_at(out, 11, 10, 10), SAND);
+        assert_eq!(carried(&sim, GRAVEL), 1, "the load stays in the water");
+    }
+
+    #[test]
+    fn test_cpu_still_water_leaves_sand_alone() {
+        let mut sim = simulator_with_rules("[]");
+        let mut data = empty_chunk();
+        fill(
+            &mut data,
+            voxel(SEALED_METAL, AMBIENT_TEMP_QUANTIZED),
+            [0, 0, 0],
+            [31, 0, 31],
+        );
+        fill(
+            &mut data,
+            voxel(SAND, AMBIENT_TEMP_QUANTIZED),
+            [0, 1, 0],
+            [31, 2, 31],
+        );
+        // Water filling the chunk to the top has nowhere to flow
+        fill(
+            &mut data,
+            voxel(WATER, AMBIENT_TEMP_QUANTIZED),
+            [0, 3, 0],
+            [31, 31, 31],
+        );
+        sim.upload_chunk_data(0, &data);
+
+        run(&mut sim, 30);
+        assert_eq!(count(sim.chunk_data(0), SAND), 2048);
+        assert_eq!(carried(&sim, SAND), 0);
+    }
+
+    #[test]
+    fn test_cpu_slack_water_deposits_its_sediment() {
+        let mut sim = simulator_with_rules("[]");
+        let mut data = empty_chunk();
+        // Water carrying gravel, at rest in a one-voxel glass pocket
+        fill(
+            &mut data,
+            voxel(GLASS, AMBIENT_TEMP_QUANTIZED),
+            [9, 9, 9],
+            [11, 11, 11],
+        );
+        data[idx(10, 10, 10)] = voxel(WATER, AMBIENT_TEMP_QUANTIZED);
+        let mut aux = vec![0; WORDS_PER_CHUNK];
+        SEDIMENT.write(&mut aux, idx(10, 10, 10), GRAVEL as u32);
+        sim.upload_chunk_data_both(0, &data);
+        sim.upload_chunk_aux_both(0, &aux);
+
+        run(&mut sim, 1);
+        assert_eq!(material_at(sim.chunk_data(0), 10, 10, 10), GRAVEL);
+        assert_eq!(carried(&sim, GRAVEL), 0);
+    }
+
+    #[test]
+    fn test_cpu_ice_absorbs_latent_heat_at_melting_point() {
+        let materials = base_materials();
+        let ice = materials.get(ICE).unwrap();
+        let melting_point = ice.phase_change_temp.quantize().0;
+        // 27 K above melting, a sixth of Ice's 160 K latent heat
+        let warm = Kelvin(300.0).quantize().0;
+
+        // On the floor, so the ice left standing stays in the chunk
+        let mut sim = base_simulator();
+        let mut data = empty_chunk();
+        for z in 8..18 {
+            for y in 0..10 {
+                for x in 8..18 {
+                    data[idx(x, y, z)] = voxel(ICE, warm);
+                }
+            }
+        }
+        sim.upload_chunk_data(0, &data);
+
+        run(&mut sim, 1);
+
+        let out = sim.chunk_data(0);
+        let melted = count(out, WATER);
+        assert_eq!(count(out, ICE) + melted, 1000);
+        assert!(
+            (100..250).contains(&melted),
+            "about a sixth should melt, got {melted}"
+        );
+        // Remaining ice sits at its melting point (plus one tick of entropy drain)
+        for z in 8..18 {
+            for x in 8..18 {
+                let temp = temp_at(out, x, 4, z);
+                assert!(
+                    (melting_point..=melting_point + 1).contains(&temp),
+                    "temperature {temp} should be pinned at {melting_point}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_cpu_steam_condenses_below_freeze_temp() {
+        let materials = base_materials();
+        let steam = materials.get(STEAM).unwrap();
+        let water = materials.get(WATER).unwrap();
+        assert!(steam.freeze_temp.0 < water.phase_change_temp.0);
+
+        // Materials present after one tick of a block of `mat` at `kelvin`
+        let after_tick = |mat: u16, kelvin: f32| {
+            let mut sim = base_simulator();
+            let mut data = empty_chunk();
+            for z in 12..16 {
+                for y in 12..16 {
+                    for x in 12..16 {
+                        data[idx(x, y, z)] = voxel(mat, Kelvin(kelvin).quantize().0);
+                    }
+                }
+            }
+            sim.upload_chunk_data(0, &data);
+            run(&mut sim, 1);
+            let mut present: Vec<u16> = sim
+                .chunk_data(0)
+                .iter()
+                .map(material_of)
+                .filter(|&m| m != 0)
+                .collect();
+            present.sort_unstable();
+            present.dedup();
+            present
+        };
+
+        assert_eq!(
+            after_tick(STEAM, 330.0),
+            vec![WATER],
+            "cold steam condenses"
+        );
+        // Between condensing and boiling neither phase changes
+        assert_eq!(after_tick(STEAM, 370.0), vec![STEAM]);
+        assert_eq!(after_tick(WATER, 370.0), vec![WATER]);
+    }
+
+    #[test]
+    fn test_cpu_charge_propagates_along_wire() {
+        let mut sim = base_simulator();
+        let mut data = empty_chunk();
+        data[idx(5, 5, 5)] = voxel(POWER_SOURCE, AMBIENT_TEMP_QUANTIZED);
+        for x in 6..10 {
+            data[idx(x, 5, 5)] = voxel(COPPER_WIRE, AMBIENT_TEMP_QUANTIZED);
+        }
+        sim.upload_chunk_data(0, &data);
+
+        run(&mut sim, 10);
+
+        let charge = sim.chunk_channel(0, CHARGE);
+        assert!(charge[idx(5, 5, 5)] > 0, "source should emit");
+        assert!(charge[idx(6, 5, 5)] > 0, "adjacent wire should be charged");
+        assert!(
+            charge[idx(7, 5, 5)] > 0,
+            "charge should propagate down the wire"
+        );
+        assert!(
+            charge[idx(6, 5, 5)] >= charge[idx(7, 5, 5)],
+            "charge should attenuate away from the source"
+        );
+        assert_eq!(charge[idx(20, 20, 20)], 0, "air carries no charge");
+    }
+
+    #[test]
+    fn test_cpu_place_command_sphere_brush() {
+        let mut sim = base_simulator();
+        sim.upload_chunk_data(0, &empty_chunk());
+        sim.enqueue_command(SimCommand {
+            tool_type: TOOL_PLACE,
+            pos_x: 16,
+            pos_y: 16,
+            pos_z: 16,
+            material_id: STONE as u32,
+            chunk_dispatch_idx: 0,
+            brush_radius: 2,
+            brush_shape: 2,
+        });
+
+        run(&mut sim, 1);
+
+        // Lattice points with dx²+dy²+dz² <= 4
+        assert_eq!(count(sim.chunk_data(0), STONE), 33);
+        assert_eq!(sim.activity_flags(), &[1]);
+    }
+
+    #[test]
+    fn test_cpu_deferred_commands_follow_their_chunk() {
+        let materials = base_materials();
+        let rules = load_all_rules(&["[]"], &materials).unwrap();
+        let mut sim = CpuSimulator::new(compile_tables(&materials, &rules), 2);
+        sim.upload_chunk_descriptors(&isolated_chunk_descriptors(&[0, 1]));
+        sim.upload_chunk_coords(&[ChunkCoord::ZERO, ChunkCoord::Y]);
+
+        // A full tick of commands for the first chunk, then a few for the
+        // second that have to wait a tick
+        let place = |x: i32, chunk_dispatch_idx: u32| SimCommand {
+            tool_type: TOOL_PLACE,
+            pos_x: x,
+            pos_y: 10,
+            pos_z: 10,
+            material_id: STONE as u32,
+            chunk_dispatch_idx,
+            brush_radius: 0,
+            brush_shape: 0,
+        };
+        for _ in 0..MAX_COMMANDS {
+            sim.enqueue_command(place(0, 0));
+        }
+        for x in 1..=5 {
+            sim.enqueue_command(place(x, 1));
+        }
+        sim.tick(2, &[0, 1]);
+        assert_eq!(count(sim.chunk_data(0), STONE), 1);
+        assert_eq!(count(sim.chunk_data(1), STONE), 0);
+
+        // The dispatch order flips before the deferred commands run
+        sim.upload_chunk_descriptors(&isolated_chunk_descriptors(&[1, 0]));
+        sim.upload_chunk_coords(&[ChunkCoord::Y, ChunkCoord::ZERO]);
+        sim.tick(2, &[1, 0]);
+        assert_eq!(count(sim.chunk_data(0), STONE), 1);
+        assert_eq!(count(sim.chunk_data(1), STONE), 5);
+        assert!((1..=5).all(|x| material_at(sim.chunk_data(1), x, 10, 10) == STONE));
+    }
+
+    #[test]
+    fn test_cpu_beam_comes_down_when_its_post_is_removed() {
+        let mut sim = base_simulator();
+        let mut data = empty_chunk();
+        for y in 0..4 {
+            data[idx(16, y, 16)] = voxel(STONE, AMBIENT_TEMP_QUANTIZED);
+        }
+        for x in 12..=20 {
+            data[idx(x, 4, 16)] = voxel(STONE, AMBIENT_TEMP_QUANTIZED);
+        }
+        sim.upload_chunk_data(0, &data);
+
+        // Standing, it holds
+        run(&mut sim, 5);
+        assert_eq!(sim.chunk_data(0), &data[..]);
+
+        // Knock a voxel out of the post: the host solves the break before
+        // the next tick, and what the post held comes down in that tick
+        sim.enqueue_command(SimCommand {
+            tool_type: TOOL_REMOVE,
+            pos_x: 16,
+            pos_y: 1,
+            pos_z: 16,
+            material_id: 0,
+            chunk_dispatch_idx: 0,
+            brush_radius: 0,
+            brush_shape: 0,
+        });
+        run(&mut sim, 1);
+        assert_eq!(count(sim.chunk_data(0), STONE), 12);
+        run(&mut sim, 1);
+        let out = sim.chunk_data(0);
+        assert!(
+            (12..=20)
+                .all(|x| material_at(out, x, 4, 16) == STONE && velocity_at(out, x, 4, 16)[1] < 0),
+            "the whole beam should start falling at once"
+        );
+
+        run(&mut sim, 10);
+        let out = sim.chunk_data(0);
+        assert_eq!(count(out, STONE), 12, "only the removed voxel is gone");
+        assert!(
+            (12..=20).all(|x| material_at(out, x, 4, 16) != STONE),
+            "nothing should be left where the beam was"
+        );
+        assert_eq!(sim.activity_flags(), &[0], "the rubble should settle");
+        assert_eq!(solve(&[(ChunkCoord::ZERO, out)]), StressReport::default());
+    }
+
+    #[test]
+    fn test_cpu_pair_candidates_follow_temperature() {
+        let mut sim = simulator_with_rules(
+            r#"[
+                (name: "Cool", input_a: "Iron", input_b: "Stone", output_a: "Copper",
+                 output_b: "Stone", probability: 1.0, max_temp: 300),
+                (name: "Hot", input_a: "Iron", input_b: "Stone", output_a: "Gold",
+                 output_b: "Stone", probability: 1.0, min_temp: 301),
+            ]"#,
+        );
+        let mut data = empty_chunk();
+        stone_floor(&mut data);
+        data[idx(4, 1, 4)] = voxel(IRON, AMBIENT_TEMP_QUANTIZED);
+        data[idx(20, 1, 20)] = voxel(IRON, 600);
+        sim.upload_chunk_data(0, &data);
+
+        run(&mut sim, 1);
+
+        let out = sim.chunk_data(0);
+        assert_eq!(material_at(out, 4, 1, 4), COPPER);
+        assert_eq!(material_at(out, 20, 1, 20), GOLD);
+    }
+
+    #[test]
+    fn test_cpu_pressure_gated_rule() {
+        let mut sim = simulator_with_rules(
+            r#"[
+                (name: "Squeeze", input_a: "Iron", input_b: "Stone", output_a: "Gold",
+                 output_b: "Stone", probability: 1.0, min_pressure: 15),
+            ]"#,
+        );
+        let mut data = empty_chunk();
+        stone_floor(&mut data);
+        data[idx(4, 1, 4)] = voxel(IRON, AMBIENT_TEMP_QUANTIZED);
+        data[idx(20, 1, 20)] = Voxel::new(MaterialId(IRON))
+            .with_temperature(AMBIENT_TEMP_QUANTIZED)
+            .with_pressure(20)
+            .into();
+        sim.upload_chunk_data(0, &data);
+
+        run(&mut sim, 1);
+
+        let out = sim.chunk_data(0);
+        assert_eq!(material_at(out, 4, 1, 4), IRON);
+        assert_eq!(material_at(out, 20, 1, 20), GOLD);
+    }
+
+    #[test]
+    fn test_cpu_temp_delta_is_curve_independent() {
+        // The same rule warms iron by the same number of Kelvin whether the
+        // compiled LUT is linear or finer around ambient
+        let rise_on = |curve: TempCurve| {
+            let materials = base_materials();
+            let rules = load_all_rules(
+                &[r#"[
+                    (name: "Warm", input_a: "Iron", input_b: "Stone", output_a: "Gold",
+                     output_b: "Stone", probability: 1.0, temp_delta: 10K),
+                ]"#],
+                &materials,
+            )
+            .unwrap();
+            let mut tables = compile_tables(&materials, &rules);
+            tables.temp_lut = curve.lut();
+            let mut sim = CpuSimulator::new(tables, 1);
+            sim.upload_chunk_descriptors(&isolated_chunk_descriptors(&[0]));
+
+            let start = curve.to_quantized(AMBIENT_TEMP_K);
+            let mut data = empty_chunk();
+            data[idx(4, 4, 4)] = voxel(IRON, start);
+            data[idx(5, 4, 4)] = voxel(STONE, start);
+            sim.upload_chunk_data(0, &data);
+            run(&mut sim, 1);
+
+            let out = sim.chunk_data(0);
+            assert_eq!(material_at(out, 4, 4, 4), GOLD);
+            curve.to_kelvin(temp_at(out, 4, 4, 4)) - curve.to_kelvin(start)
+        };
+        let linear = rise_on(TempCurve::Linear);
+        let detailed = rise_on(AMBIENT_DETAIL_CURVE);
+        assert!(linear > 5.0, "reaction should warm iron, got {linear} K");
+        assert!(
+            (linear - detailed).abs() < 0.5,
+            "{linear} K vs {detailed} K"
+        );
+    }
+
+    #[test]
+    fn test_cpu_catalyst_must_be_adjacent() {
+        let mut sim = simulator_with_rules(
+            r#"[
+                (name: "Plated", input_a: "Iron", input_b: "Stone", output_a: "Gold",
+                 output_b: "Stone", probability: 1.0, catalyst: Some("Copper")),
+            ]"#,
+        );
+        let mut data = empty_chunk();
+        stone_floor(&mut data);
+        data[idx(4, 1, 4)] = voxel(IRON, AMBIENT_TEMP_QUANTIZED);
+        data[idx(20, 1, 20)] = voxel(IRON, AMBIENT_TEMP_QUANTIZED);
+        data[idx(21, 1, 20)] = voxel(COPPER, AMBIENT_TEMP_QUANTIZED);
+        sim.upload_chunk_data(0, &data);
+
+        run(&mut sim, 1);
+
+        let out = sim.chunk_data(0);
+        assert_eq!(material_at(out, 4, 1, 4), IRON);
+        assert_eq!(material_at(out, 20, 1, 20), GOLD);
+        // The catalyst is not consumed
+        assert_eq!(material_at(out, 21, 1, 20), COPPER);
+    }
+
+    #[test]
+    fn test_cpu_overlapping_candidates_share_one_roll() {
+        let mut sim = simulator_with_rules(
+            r#"[
+                (name: "A", input_a: "Iron", input_b: "Stone", output_a: "Copper",
+                 output_b: "Stone", probability: 0.3),
+                (name: "B", input_a: "Iron", input_b: "Stone", output_a: "Gold",
+                 output_b: "Stone", probability: 0.3),
+            ]"#,
+        );
+        let mut data = empty_chunk();
+        stone_floor(&mut data);
+        for z in 0..16 {
+            for x in 0..16 {
+                data[idx(x, 1, z)] = voxel(IRON, AMBIENT_TEMP_QUANTIZED);
+            }
+        }
+        sim.upload_chunk_data(0, &data);
+
+        run(&mut sim, 1);
+
+        // Each iron sees stone once: ~30% Copper, ~30% Gold, ~40% unchanged.
+        let out = sim.chunk_data(0);
+        let (copper, gold, iron) = (count(out, COPPER), count(out, GOLD), count(out, IRON));
+        assert_eq!(copper + gold + iron, 256);
+        for (name, n) in [("copper", copper), ("gold", gold), ("iron", iron)] {
+            assert!((40..=140).contains(&n), "{name}: {n} of 256");
+        }
+    }
+
+    #[test]
+    fn test_cpu_simulation_is_deterministic() {
+        let mut data = empty_chunk();
+        stone_floor(&mut data);
+        for x in 4..28 {
+            data[idx(x, 12, 16)] = voxel(SAND, AMBIENT_TEMP_QUANTIZED);
+            data[idx(x, 13, 16)] = voxel(WOOD, AMBIENT_TEMP_QUANTIZED);
+        }
+        data[idx(16, 14, 16)] = voxel(FIRE, 1500);
+
+        let mut a = base_simulator();
+        let mut b = base_simulator();
+        a.upload_chunk_data(0, &data);
+        b.upload_chunk_data(0, &data);
+        run(&mut a, 25);
+        run(&mut b, 25);
+
+        assert_eq!(a.tick_count(), 25);
+        assert!(a.chunk_data(0) == b.chunk_data(0));
+        assert!(a.chunk_aux(0) == b.chunk_aux(0));
+    }
+
+    // ── Recipe regression scenes (docs/recipes.md) ──
+
+    #[test]
+    fn test_recipe_furnace() {
+        check_scene_file("furnace.ron");
+    }
+
+    #[test]
+    fn test_recipe_water_filter() {
+        check_scene_file("water_filter.ron");
+    }
+
+    #[test]
+    fn test_recipe_pressure_blast() {
+        check_scene_file("pressure_blast.ron");
+    }
+
+    #[test]
+    fn test_recipe_volcano() {
+        check_scene_file("volcano.ron");
+    }
+
+    #[test]
+    fn test_recipe_transmutation() {
+        check_scene_file("transmutation.ron");
+    }
+
+    #[test]
+    fn test_recipe_simple_circuit() {
+        check_scene_file("simple_circuit.ron");
+    }
+
+    #[test]
+    fn test_recipe_and_gate() {
+        check_scene_file("and_gate.ron");
+        check_scene_file("and_gate_single_input.ron");
+    }
+
+    #[test]
+    fn test_recipe_short_circuit() {
+        check_scene_file("short_circuit.ron");
+    }
+
+    #[test]
+    fn test_recipe_resistance_heater() {
+        check_scene_file("resistance_heater.ron");
+    }
+
+    #[test]
+    fn test_every_recipe_has_a_scene() {
+        let recipes: Vec<u32> = include_str!("../../../docs/recipes.md")
+            .lines()
+            .filter_map(|line| line.strip_prefix("## "))
+            .filter_map(|heading| heading.split('.').next()?.parse().ok())
+            .collect();
+        assert!(!recipes.is_empty(), "no numbered recipes found");
+
+        let covered: Vec<u32> = std::fs::read_dir(recipe_scene_dir())
+            .expect("recipe scene directory exists")
+            .filter_map(|e| e.ok().map(|e| e.path()))
+            .filter(|p| p.extension().is_some_and(|ext| ext == "ron"))
+            .filter_map(|p| load_scene(&p).recipe)
+            .collect();
+        for recipe in recipes {
+            assert!(
+                covered.contains(&recipe),
+                "docs/recipes.md §{recipe} has no scene in tests/recipes"
+            );
+        }
+    }
+
+    #[test]
+    fn test_snapshot_hash_detects_changes() {
+        let mut data = empty_chunk();
+        let before = snapshot_hash(&data);
+        data[idx(3, 4, 5)] = voxel(SAND, AMBIENT_TEMP_QUANTIZED);
+        assert_ne!(before, snapshot_hash(&data));
+    }
 }
@@ -4,14 +4,19 @@ use crate::input::InputState;
 use crate::tools::{self, ToolState};
 use crate::ui::debug::DebugPanel;
 use crate::ui::UiState;
+use alkahest_core::aux_channel;
 use alkahest_core::constants::*;
 use alkahest_render::{MaterialColor, Renderer};
 use alkahest_sim::pipeline::SimPipeline;
+use alkahest_sim::structural::{self, Launch, StructuralTable, StructuralWorld};
 use alkahest_world::World;
 use std::cell::RefCell;
 use std::rc::Rc;
 use wasm_bindgen::prelude::*;
 
+/// Bytes of aux words per chunk slot.
+const AUX_BYTES_PER_CHUNK: usize = aux_channel::WORDS_PER_CHUNK * 4;
+
 type RafClosure = Rc<RefCell<Option<Closure<dyn FnMut(f64)>>>>;
 
 /// Save/load state machine phases.
@@ -36,6 +41,22 @@ pub enum SaveState {
     LoadPending { bytes: Vec<u8> },
 }
 
+/// Structural solve phases (ARCH 10.2). When a tick breaks a structure the
+/// dispatched chunks are read back and solved on the CPU, and what gives way
+/// goes back to the sim as push commands.
+enum StructureState {
+    /// No break waiting to be solved.
+    Idle,
+    /// Chunk data copied to staging, waiting for the map.
+    Reading {
+        staging: wgpu::Buffer,
+        chunk_coords: Vec<glam::IVec3>,
+        mapped: std::sync::mpsc::Receiver<Result<(), wgpu::BufferAsyncError>>,
+    },
+    /// Solved; the launches wait for a frame the sim ticks in.
+    Solved(Vec<Launch>),
+}
+
 /// Save/load status for UI display.
 #[derive(Clone)]
 pub enum SaveStatus {
@@ -82,6 +103,10 @@ pub struct Application {
     help_open: bool,
     /// Save/load state machine.
     save_state: SaveState,
+    /// Structural solve state machine.
+    structure_state: StructureState,
+    /// Structural properties of every material, for the solve.
+    structural: StructuralTable,
     /// Save/load status for UI display.
     pub save_status: SaveStatus,
     /// Auto-save timer in milliseconds.
@@ -194,7 +219,7 @@ impl Application {
         octree.clear_dirty();
 
         // Bind sim's read pool to renderer
-        renderer.update_voxel_pool(&gpu.device, sim.get_read_pool());
+        renderer.update_voxel_pool(&gpu.device, sim.get_read_pool(), sim.get_aux_read_pool());
 
         let debug_panel = DebugPanel::new(gpu.adapter_name.clone(), gpu.backend.clone());
         let tool_state = ToolState::new();
@@ -229,6 +254,8 @@ impl Application {
             browser_state: crate::ui::browser::BrowserState::new(&material_table),
             help_open: false,
             save_state: SaveState::Idle,
+            structure_state: StructureState::Idle,
+            structural: StructuralTable::from_materials(&material_table),
             save_status: SaveStatus::None,
             auto_save_timer: 0.0,
             auto_save_interval: 300_000.0, // 5 minutes
@@ -308,16 +335,19 @@ impl Application {
         ])
         .expect("failed to parse material RON data");
 
-        let rules = alkahest_rules::loader::load_all_rules(&[
-            combustion_ron,
-            structural_ron,
-            phase_change_ron,
-            dissolution_ron,
-            displacement_ron,
-            biological_ron,
-            thermal_ron,
-            synthesis_ron,
-        ])
+        let rules = alkahest_rules::loader::load_all_rules(
+            &[
+                combustion_ron,
+                structural_ron,
+                phase_change_ron,
+                dissolution_ron,
+                displacement_ron,
+                biological_ron,
+                thermal_ron,
+                synthesis_ron,
+            ],
+            &materials,
+        )
         .expect("failed to parse rules RON data");
 
         let mut materials = materials;
@@ -336,6 +366,8 @@ impl Application {
                 mod_manifest_ron,
                 &[mod_materials_ron],
                 &[mod_rules_ron],
+                &materials,
+                &[],
             )
             .expect("failed to load example mod");
 
@@ -350,13 +382,13 @@ impl Application {
             }
 
             let mut remap = alkahest_rules::migration::IdRemap::new(materials.max_id());
-            let warnings = alkahest_rules::loader::merge_mod(
+            let report = alkahest_rules::loader::merge_mod(
                 &mut materials,
                 &mut rules,
                 &mod_result,
                 &mut remap,
             );
-            for w in &warnings {
+            for w in report.warnings() {
                 log::warn!("{w}");
             }
 
@@ -365,7 +397,7 @@ impl Application {
                 mod_result.manifest.name,
                 mod_result.materials.len(),
                 mod_result.rules.len(),
-                warnings.len(),
+                report.conflict_count(),
             );
         }
 
@@ -377,10 +409,25 @@ impl Application {
             panic!("Material validation failed with {} errors", errors.len());
         }
         if let Err(errors) = alkahest_rules::validator::validate_rules(&rules, &materials) {
-            for e in &errors {
+            let (warnings, fatal): (Vec<_>, Vec<_>) =
+                errors.into_iter().partition(|e| e.is_warning());
+            for e in &warnings {
+                log::warn!("Rule validation warning: {e}");
+            }
+            for e in &fatal {
                 log::error!("Rule validation error: {e}");
             }
-            panic!("Rule validation failed with {} errors", errors.len());
+            if !fatal.is_empty() {
+                panic!("Rule validation failed with {} errors", fatal.len());
+            }
+        }
+        for expansion in alkahest_rules::validator::report_pattern_expansions(&rules, &materials) {
+            log::info!(
+                "Pattern rule '{}' expanded to {} rules ({} pairs already covered)",
+                expansion.rule,
+                expansion.generated,
+                expansion.shadowed
+            );
         }
 
         log::info!(
@@ -562,9 +609,11 @@ impl Application {
             return;
         }
 
-        // Create bulk staging buffer
-        let staging_size =
-            chunk_coords.len() as u64 * alkahest_core::constants::BYTES_PER_CHUNK as u64;
+        // Create bulk staging buffer: every chunk's voxel data, then every
+        // chunk's aux words
+        let chunk_bytes = BYTES_PER_CHUNK as u64;
+        let aux_bytes = AUX_BYTES_PER_CHUNK as u64;
+        let staging_size = chunk_coords.len() as u64 * (chunk_bytes + aux_bytes);
         let staging = self.gpu.device.create_buffer(&wgpu::BufferDescriptor {
             label: Some("save-staging-buffer"),
             size: staging_size,
@@ -581,15 +630,22 @@ impl Application {
             });
 
         let read_pool = self.sim.get_read_pool();
+        let aux_read_pool = self.sim.get_aux_read_pool();
+        let aux_base = chunk_coords.len() as u64 * chunk_bytes;
         for (i, &(_coord, pool_slot)) in chunk_coords.iter().enumerate() {
-            let src_offset = pool_slot as u64 * alkahest_core::constants::BYTES_PER_CHUNK as u64;
-            let dst_offset = i as u64 * alkahest_core::constants::BYTES_PER_CHUNK as u64;
             encoder.copy_buffer_to_buffer(
                 read_pool,
-                src_offset,
+                pool_slot as u64 * chunk_bytes,
                 &staging,
-                dst_offset,
-                alkahest_core::constants::BYTES_PER_CHUNK as u64,
+                i as u64 * chunk_bytes,
+                chunk_bytes,
+            );
+            encoder.copy_buffer_to_buffer(
+                aux_read_pool,
+                pool_slot as u64 * aux_bytes,
+                &staging,
+                aux_base + i as u64 * aux_bytes,
+                aux_bytes,
             );
         }
 
@@ -634,19 +690,7 @@ impl Application {
                         staging.unmap();
 
                         // Build ChunkSnapshots
-                        let chunk_size = alkahest_core::constants::BYTES_PER_CHUNK as usize;
-                        let chunks: Vec<alkahest_persist::ChunkSnapshot> = chunk_coords
-                            .iter()
-                            .enumerate()
-                            .map(|(i, &(coord, _slot))| {
-                                let start = i * chunk_size;
-                                let end = start + chunk_size;
-                                alkahest_persist::ChunkSnapshot {
-                                    coord,
-                                    voxel_data: all_bytes[start..end].to_vec(),
-                                }
-                            })
-                            .collect();
+                        let chunks = snapshots_from_staging(&chunk_coords, &all_bytes);
 
                         let camera_state = self.camera_to_persist();
                         let save_data = alkahest_persist::save(
@@ -692,19 +736,7 @@ impl Application {
                         drop(data);
                         staging.unmap();
 
-                        let chunk_size = alkahest_core::constants::BYTES_PER_CHUNK as usize;
-                        let chunks: Vec<alkahest_persist::ChunkSnapshot> = chunk_coords
-                            .iter()
-                            .enumerate()
-                            .map(|(i, &(coord, _slot))| {
-                                let start = i * chunk_size;
-                                let end = start + chunk_size;
-                                alkahest_persist::ChunkSnapshot {
-                                    coord,
-                                    voxel_data: all_bytes[start..end].to_vec(),
-                                }
-                            })
-                            .collect();
+                        let chunks = snapshots_from_staging(&chunk_coords, &all_bytes);
 
                         let camera_state = self.camera_to_persist();
                         let save_data = alkahest_persist::save(
@@ -747,6 +779,112 @@ impl Application {
         }
     }
 
+    /// Process one frame's worth of the structural solve state machine.
+    fn update_structure_state(&mut self) {
+        let state = std::mem::replace(&mut self.structure_state, StructureState::Idle);
+        self.structure_state = match state {
+            StructureState::Idle if self.sim.structure_broken() => self.begin_structure_readback(),
+            StructureState::Reading {
+                staging,
+                chunk_coords,
+                mapped,
+            } => {
+                self.gpu.device.poll(wgpu::Maintain::Poll);
+                match mapped.try_recv() {
+                    Ok(Ok(())) => {
+                        let bytes = staging.slice(..).get_mapped_range().to_vec();
+                        staging.unmap();
+                        let launches = self.solve_structures(&chunk_coords, &bytes);
+                        if launches.is_empty() {
+                            StructureState::Idle
+                        } else {
+                            StructureState::Solved(launches)
+                        }
+                    }
+                    Err(std::sync::mpsc::TryRecvError::Empty) => StructureState::Reading {
+                        staging,
+                        chunk_coords,
+                        mapped,
+                    },
+                    _ => {
+                        log::warn!("Structural readback failed");
+                        StructureState::Idle
+                    }
+                }
+            }
+            state => state,
+        };
+    }
+
+    /// Copy the dispatched chunks to a staging buffer for the structural
+    /// solve, clearing the sim's break flag in the same submission.
+    fn begin_structure_readback(&mut self) -> StructureState {
+        let dispatch_list = alkahest_world::dispatch::build_dispatch_list(self.world.chunk_map());
+        let chunk_bytes = BYTES_PER_CHUNK as u64;
+        let staging = self.gpu.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("structure-staging-buffer"),
+            size: (dispatch_list.len() as u64 * chunk_bytes).max(4),
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = self
+            .gpu
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor {
+                label: Some("structure-copy-encoder"),
+            });
+        self.sim.clear_structure_broken(&mut encoder);
+        let read_pool = self.sim.get_read_pool();
+        for (i, entry) in dispatch_list.entries.iter().enumerate() {
+            encoder.copy_buffer_to_buffer(
+                read_pool,
+                entry.pool_slot as u64 * chunk_bytes,
+                &staging,
+                i as u64 * chunk_bytes,
+                chunk_bytes,
+            );
+        }
+        self.gpu.queue.submit(std::iter::once(encoder.finish()));
+
+        let (tx, mapped) = std::sync::mpsc::channel();
+        staging
+            .slice(..)
+            .map_async(wgpu::MapMode::Read, move |result| {
+                let _ = tx.send(result);
+            });
+        StructureState::Reading {
+            staging,
+            chunk_coords: dispatch_list.entries.iter().map(|e| e.coord).collect(),
+            mapped,
+        }
+    }
+
+    /// Solve the chunks read back into `bytes` (voxel data in `chunk_coords`
+    /// order) and return what gives way.
+    fn solve_structures(&self, chunk_coords: &[glam::IVec3], bytes: &[u8]) -> Vec<Launch> {
+        let chunks: Vec<Vec<[u32; 2]>> = bytes
+            .chunks_exact(BYTES_PER_CHUNK as usize)
+            .map(|chunk| {
+                chunk
+                    .chunks_exact(8)
+                    .map(|v| {
+                        [
+                            u32::from_le_bytes([v[0], v[1], v[2], v[3]]),
+                            u32::from_le_bytes([v[4], v[5], v[6], v[7]]),
+                        ]
+                    })
+                    .collect()
+            })
+            .collect();
+        let mut world = StructuralWorld::new();
+        for (&coord, data) in chunk_coords.iter().zip(&chunks) {
+            world.insert(coord, data);
+        }
+        let report = structural::solve(&world, &self.structural);
+        structural::launches(&world, &self.structural, &report)
+    }
+
     /// Process loaded save data: rebuild world from save file.
     fn process_load(&mut self, bytes: &[u8]) {
         self.save_status = SaveStatus::Loading;
@@ -769,6 +907,9 @@ impl Application {
         // Restore tick count
         self.sim.set_tick_count(save_data.header.tick_count);
 
+        // A collapse solved for the old world does not apply to this one
+        self.structure_state = StructureState::Idle;
+
         // Restore camera
         let cam = &save_data.camera;
         self.camera.yaw = cam.yaw;
@@ -795,7 +936,9 @@ impl Application {
         }
 
         // Load saved chunks
-        for (coord, voxel_data) in &save_data.chunks {
+        for snapshot in &save_data.chunks {
+            let coord = &snapshot.coord;
+            let voxel_data = &snapshot.voxel_data;
             if let Some(pool_slot) = self.world.chunk_map_mut().load_chunk(*coord) {
                 // Convert bytes to [u32; 2] slice for upload
                 let voxel_pairs: &[[u32; 2]] = bytemuck::cast_slice(voxel_data);
@@ -804,6 +947,18 @@ impl Application {
                     pool_slot,
                     voxel_pairs,
                 );
+                // The slot may hold a previous world's aux words; empty aux
+                // data means every channel is zero
+                let zeroed;
+                let aux_data = if snapshot.aux_data.is_empty() {
+                    zeroed = vec![0u32; aux_channel::WORDS_PER_CHUNK];
+                    &zeroed
+                } else {
+                    &snapshot.aux_data
+                };
+                self.sim
+                    .chunk_pool()
+                    .upload_aux_data_both(&self.gpu.queue, pool_slot, aux_data);
 
                 // Mark chunk as having non-air data
                 if let Some(chunk) = self.world.chunk_map_mut().get_mut(coord) {
@@ -954,6 +1109,7 @@ impl Application {
 
         // Process save/load state machine
         self.update_save_state();
+        self.update_structure_state();
 
         // Auto-save timer
         if self.auto_save_enabled && matches!(self.save_state, SaveState::Idle) {
@@ -990,6 +1146,7 @@ impl Application {
             browser_state,
             help_open,
             save_state,
+            structure_state,
             save_status,
             auto_save_enabled,
             rule_mismatch_warning,
@@ -1011,6 +1168,14 @@ impl Application {
 
         if let Some(flags) = sim.poll_readback(&gpu.device, dispatch_list.len() as u32) {
             world.process_activity(&flags);
+            // Chunks that set off a blast carry its yield above the dirty bit
+            for (entry, &flag) in dispatch_list.entries.iter().zip(&flags) {
+                let blast_yield = flag >> ACTIVITY_BLAST_SHIFT;
+                if blast_yield > 0 {
+                    let pos = (entry.coord.as_vec3() + 0.5) * CHUNK_SIZE as f32;
+                    audio_system.register_explosion(pos, blast_yield);
+                }
+            }
         }
 
         // Poll pick buffer readback (1–2 frame latency)
@@ -1023,6 +1188,25 @@ impl Application {
         let descriptor_data = dispatch_list.build_descriptor_data();
         let active_chunk_count = dispatch_list.len() as u32;
         let active_slots: Vec<u32> = dispatch_list.entries.iter().map(|e| e.pool_slot).collect();
+        // Commands are addressed by index into this list; the sim holds the
+        // deferred ones by coordinate
+        let chunk_coords: Vec<_> = dispatch_list.entries.iter().map(|e| e.coord).collect();
+        sim.upload_chunk_coords(&chunk_coords);
+
+        // Hand a solved collapse to the sim as pushes, against this frame's
+        // dispatch list (a paused sim would drop them)
+        if matches!(structure_state, StructureState::Solved(_)) && !sim.is_paused() {
+            if let StructureState::Solved(launches) =
+                std::mem::replace(structure_state, StructureState::Idle)
+            {
+                for launch in &launches {
+                    let coord = alkahest_core::math::world_to_chunk(launch.pos);
+                    if let Some(idx) = dispatch_list.entries.iter().position(|e| e.coord == coord) {
+                        sim.enqueue_command(launch.command(idx as u32));
+                    }
+                }
+            }
+        }
 
         // 2. Read input and update camera + handle sim controls
         {
@@ -1507,7 +1691,7 @@ impl Application {
 
         // 8. Bind the sim's read pool to the renderer (update bind group)
         // We do this every frame because the read buffer alternates after each tick.
-        renderer.update_voxel_pool(&gpu.device, sim.get_read_pool());
+        renderer.update_voxel_pool(&gpu.device, sim.get_read_pool(), sim.get_aux_read_pool());
 
         // Update renderer chunk map (in case chunks loaded/unloaded)
         let chunk_map_data = Self::build_renderer_chunk_map(world.chunk_map());
@@ -1575,3 +1759,29 @@ impl Application {
         output.present();
     }
 }
+
+/// Split a mapped save staging buffer (every chunk's voxel data, then every
+/// chunk's aux words, in `chunk_coords` order) into chunk snapshots.
+fn snapshots_from_staging(
+    chunk_coords: &[(glam::IVec3, u32)],
+    all_bytes: &[u8],
+) -> Vec<alkahest_persist::ChunkSnapshot> {
+    let chunk_size = BYTES_PER_CHUNK as usize;
+    let aux_base = chunk_coords.len() * chunk_size;
+    chunk_coords
+        .iter()
+        .enumerate()
+        .map(|(i, &(coord, _slot))| {
+            let start = i * chunk_size;
+            let aux_start = aux_base + i * AUX_BYTES_PER_CHUNK;
+            alkahest_persist::ChunkSnapshot {
+                coord,
+                voxel_data: all_bytes[start..start + chunk_size].to_vec(),
+                aux_data: all_bytes[aux_start..aux_start + AUX_BYTES_PER_CHUNK]
+                    .chunks_exact(4)
+                    .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                    .collect(),
+            }
+        })
+        .collect()
+}
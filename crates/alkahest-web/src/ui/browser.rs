@@ -118,6 +118,7 @@ pub fn show(ctx: &egui::Context, state: &mut BrowserState, current_material: u32
 mod tests {
     use super::*;
     use alkahest_core::material::{MaterialDef, MaterialTable, Phase as MatPhase};
+    use alkahest_core::types::{Kelvin, QuantizedTemp};
 
     fn test_table() -> MaterialTable {
         MaterialTable {
@@ -130,15 +131,26 @@ mod tests {
                     color: (0.0, 0.0, 0.0),
                     emission: 0.0,
                     flammability: 0.0,
-                    ignition_temp: 0.0,
+                    ignition_temp: Kelvin(0.0),
                     decay_rate: 0,
-                    decay_threshold: 0,
+                    decay_threshold: QuantizedTemp(0),
                     decay_product: 0,
                     viscosity: 0.0,
+                    repose_angle: 45.0,
+                    cohesion: 0.0,
+                    erodibility: 0.0,
                     thermal_conductivity: 0.0,
-                    phase_change_temp: 0.0,
+                    heat_capacity: 1.0,
+                    radiative_emissivity: 0.0,
+                    phase_change_temp: Kelvin(0.0),
                     phase_change_product: 0,
+                    latent_heat: 0.0,
+                    freeze_temp: Kelvin(0.0),
+                    freeze_product: 0,
                     structural_integrity: 0.0,
+                    compressive_strength: None,
+                    tensile_strength: None,
+                    blast_resistance: None,
                     opacity: None,
                     absorption_rate: 0.0,
                     electrical_conductivity: 0.0,
@@ -154,15 +166,26 @@ mod tests {
                     color: (0.5, 0.5, 0.55),
                     emission: 0.0,
                     flammability: 0.0,
-                    ignition_temp: 0.0,
+                    ignition_temp: Kelvin(0.0),
                     decay_rate: 0,
-                    decay_threshold: 0,
+                    decay_threshold: QuantizedTemp(0),
                     decay_product: 0,
                     viscosity: 0.0,
+                    repose_angle: 45.0,
+                    cohesion: 0.0,
+                    erodibility: 0.0,
                     thermal_conductivity: 0.5,
-                    phase_change_temp: 0.0,
+                    heat_capacity: 1.0,
+                    radiative_emissivity: 0.0,
+                    phase_change_temp: Kelvin(0.0),
                     phase_change_product: 0,
+                    latent_heat: 0.0,
+                    freeze_temp: Kelvin(0.0),
+                    freeze_product: 0,
                     structural_integrity: 50.0,
+                    compressive_strength: None,
+                    tensile_strength: None,
+                    blast_resistance: None,
                     opacity: None,
                     absorption_rate: 0.0,
                     electrical_conductivity: 0.0,
@@ -178,15 +201,26 @@ mod tests {
                     color: (0.76, 0.70, 0.50),
                     emission: 0.0,
                     flammability: 0.0,
-                    ignition_temp: 0.0,
+                    ignition_temp: Kelvin(0.0),
                     decay_rate: 0,
-                    decay_threshold: 0,
+                    decay_threshold: QuantizedTemp(0),
                     decay_product: 0,
                     viscosity: 0.0,
+                    repose_angle: 45.0,
+                    cohesion: 0.0,
+                    erodibility: 0.0,
                     thermal_conductivity: 0.3,
-                    phase_change_temp: 0.0,
+                    heat_capacity: 1.0,
+                    radiative_emissivity: 0.0,
+                    phase_change_temp: Kelvin(0.0),
                     phase_change_product: 0,
+                    latent_heat: 0.0,
+                    freeze_temp: Kelvin(0.0),
+                    freeze_product: 0,
                     structural_integrity: 0.0,
+                    compressive_strength: None,
+                    tensile_strength: None,
+                    blast_resistance: None,
                     opacity: None,
                     absorption_rate: 0.0,
                     electrical_conductivity: 0.0,
@@ -202,15 +236,26 @@ mod tests {
                     color: (0.2, 0.4, 0.8),
                     emission: 0.1,
                     flammability: 0.0,
-                    ignition_temp: 0.0,
+                    ignition_temp: Kelvin(0.0),
                     decay_rate: 0,
-                    decay_threshold: 0,
+                    decay_threshold: QuantizedTemp(0),
                     decay_product: 0,
                     viscosity: 0.1,
+                    repose_angle: 45.0,
+                    cohesion: 0.0,
+                    erodibility: 0.0,
                     thermal_conductivity: 0.6,
-                    phase_change_temp: 373.0,
+                    heat_capacity: 1.0,
+                    radiative_emissivity: 0.0,
+                    phase_change_temp: Kelvin(373.0),
                     phase_change_product: 7,
+                    latent_heat: 0.0,
+                    freeze_temp: Kelvin(0.0),
+                    freeze_product: 0,
                     structural_integrity: 0.0,
+                    compressive_strength: None,
+                    tensile_strength: None,
+                    blast_resistance: None,
                     opacity: Some(0.5),
                     absorption_rate: 0.15,
                     electrical_conductivity: 0.0,
@@ -1,3 +1,5 @@
+use alkahest_core::aux_channel::CHANNELS;
+use alkahest_core::math::temp_from_quantized;
 use alkahest_render::PickResult;
 
 /// Voxel hover info panel. Shows data from the GPU pick buffer when valid.
@@ -23,8 +25,8 @@ pub fn show(ctx: &egui::Context, pick: &PickResult, material_names: &[String]) {
                 pick.world_x, pick.world_y, pick.world_z
             ));
 
-            // Temperature: stored as quantized 0..4095, mapping to 0..8000 K
-            let temp_k = (pick.temperature as f32) * (8000.0 / 4095.0);
+            // Temperature: stored as quantized 0..4095 on TEMP_CURVE
+            let temp_k = temp_from_quantized(pick.temperature as u16);
             ui.label(format!("Temp: {:.0} K", temp_k));
 
             ui.label(format!("Pressure: {}", pick.pressure));
@@ -51,5 +53,12 @@ pub fn show(ctx: &egui::Context, pick: &PickResult, material_names: &[String]) {
                 }
                 ui.label(format!("Flags: {}", flags_str));
             }
+
+            // Aux channels, only where set
+            for (channel, &value) in CHANNELS.iter().zip(&pick.aux) {
+                if value != 0 {
+                    ui.label(format!("{}: {}", channel.name, value));
+                }
+            }
         });
 }
@@ -1,5 +1,6 @@
 use alkahest_core::constants::*;
-use alkahest_core::types::ChunkCoord;
+use alkahest_core::types::{ChunkCoord, MaterialId};
+use alkahest_core::voxel::Voxel;
 
 // Material IDs matching the loaded material table.
 const MAT_AIR: u16 = 0;
@@ -59,9 +60,9 @@ impl TerrainGenerator {
                     };
 
                     if material_id != MAT_AIR {
-                        let temp = AMBIENT_TEMP_QUANTIZED as u32;
-                        let low = (material_id as u32) | (temp << 16);
-                        data[idx] = [low, 0];
+                        data[idx] = Voxel::new(MaterialId(material_id))
+                            .with_temperature(AMBIENT_TEMP_QUANTIZED)
+                            .into();
                     }
                     // Air voxels are already [0, 0] from vec initialization
                 }
@@ -241,11 +242,11 @@ mod tests {
         let mut has_stone = false;
         let mut has_sand = false;
         for voxel in &bottom {
-            let mat = voxel[0] & 0xFFFF;
-            if mat == MAT_STONE as u32 {
+            let mat = Voxel::from(*voxel).material_id().0;
+            if mat == MAT_STONE {
                 has_stone = true;
             }
-            if mat == MAT_SAND as u32 {
+            if mat == MAT_SAND {
                 has_sand = true;
             }
         }
@@ -258,7 +259,7 @@ mod tests {
             for cz in 0..WORLD_CHUNKS_Z as i32 {
                 let data = gen.generate_chunk(IVec3::new(cx, 0, cz));
                 for voxel in &data {
-                    if voxel[0] & 0xFFFF == MAT_WATER as u32 {
+                    if Voxel::from(*voxel).material_id().0 == MAT_WATER {
                         has_water = true;
                         break 'outer;
                     }
@@ -274,7 +275,7 @@ mod tests {
         let coord = IVec3::new(0, (WORLD_CHUNKS_Y - 1) as i32, 0);
         let data = gen.generate_chunk(coord);
         for voxel in &data {
-            let mat = voxel[0] & 0xFFFF;
+            let mat = Voxel::from(*voxel).material_id().0;
             assert_eq!(mat, 0, "top chunk should be all air");
         }
     }
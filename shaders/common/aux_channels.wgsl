@@ -0,0 +1,134 @@
+// aux_channels.wgsl — Auxiliary per-voxel channel layout and accessors.
+// GENERATED from alkahest-core/src/aux_channel.rs; do not edit by hand. After
+// changing the channels, run `ALKAHEST_BLESS=1 cargo test -p alkahest-core`.
+// Each voxel owns AUX_WORDS_PER_VOXEL u32 words in the aux pools, starting at
+// its voxel pool index * AUX_WORDS_PER_VOXEL.
+//
+// Channels:
+//   word 0 [0:7]    charge (u8)
+//   word 0 [8:15]   oxygen_deficit (u8)
+//   word 0 [16:23]  blast (u8)
+//   word 0 [24:31]  blast_front (u8)
+//   word 1 [0:15]   sediment (u16)
+//   word 1 [16:23]  head (u8)
+//   word 1 [24:31]  column (u8)
+
+const AUX_WORDS_PER_VOXEL: u32 = 2u;
+const AUX_CHANNEL_COUNT: u32 = 7u;
+const AUX_CHARGE_WORD: u32 = 0u;
+const AUX_CHARGE_OFFSET: u32 = 0u;
+const AUX_CHARGE_BITS: u32 = 8u;
+const AUX_CHARGE_MAX: u32 = 255u;
+const AUX_OXYGEN_DEFICIT_WORD: u32 = 0u;
+const AUX_OXYGEN_DEFICIT_OFFSET: u32 = 8u;
+const AUX_OXYGEN_DEFICIT_BITS: u32 = 8u;
+const AUX_OXYGEN_DEFICIT_MAX: u32 = 255u;
+const AUX_BLAST_WORD: u32 = 0u;
+const AUX_BLAST_OFFSET: u32 = 16u;
+const AUX_BLAST_BITS: u32 = 8u;
+const AUX_BLAST_MAX: u32 = 255u;
+const AUX_BLAST_FRONT_WORD: u32 = 0u;
+const AUX_BLAST_FRONT_OFFSET: u32 = 24u;
+const AUX_BLAST_FRONT_BITS: u32 = 8u;
+const AUX_BLAST_FRONT_MAX: u32 = 255u;
+const AUX_SEDIMENT_WORD: u32 = 1u;
+const AUX_SEDIMENT_OFFSET: u32 = 0u;
+const AUX_SEDIMENT_BITS: u32 = 16u;
+const AUX_SEDIMENT_MAX: u32 = 65535u;
+const AUX_HEAD_WORD: u32 = 1u;
+const AUX_HEAD_OFFSET: u32 = 16u;
+const AUX_HEAD_BITS: u32 = 8u;
+const AUX_HEAD_MAX: u32 = 255u;
+const AUX_COLUMN_WORD: u32 = 1u;
+const AUX_COLUMN_OFFSET: u32 = 24u;
+const AUX_COLUMN_BITS: u32 = 8u;
+const AUX_COLUMN_MAX: u32 = 255u;
+
+/// Index of aux word `word` of the voxel at voxel pool index `pool_index`.
+fn aux_index(pool_index: u32, word: u32) -> u32 {
+    return pool_index * AUX_WORDS_PER_VOXEL + word;
+}
+
+fn unpack_aux_charge(w: u32) -> u32 {
+    return w & 0xFFu;
+}
+
+fn with_aux_charge(w: u32, value: u32) -> u32 {
+    return (w & 0xFFFFFF00u) | (value & 0xFFu);
+}
+
+fn unpack_aux_oxygen_deficit(w: u32) -> u32 {
+    return (w >> 8u) & 0xFFu;
+}
+
+fn with_aux_oxygen_deficit(w: u32, value: u32) -> u32 {
+    return (w & 0xFFFF00FFu) | ((value & 0xFFu) << 8u);
+}
+
+fn unpack_aux_blast(w: u32) -> u32 {
+    return (w >> 16u) & 0xFFu;
+}
+
+fn with_aux_blast(w: u32, value: u32) -> u32 {
+    return (w & 0xFF00FFFFu) | ((value & 0xFFu) << 16u);
+}
+
+fn unpack_aux_blast_front(w: u32) -> u32 {
+    return (w >> 24u) & 0xFFu;
+}
+
+fn with_aux_blast_front(w: u32, value: u32) -> u32 {
+    return (w & 0xFFFFFFu) | ((value & 0xFFu) << 24u);
+}
+
+fn unpack_aux_sediment(w: u32) -> u32 {
+    return w & 0xFFFFu;
+}
+
+fn with_aux_sediment(w: u32, value: u32) -> u32 {
+    return (w & 0xFFFF0000u) | (value & 0xFFFFu);
+}
+
+fn unpack_aux_head(w: u32) -> u32 {
+    return (w >> 16u) & 0xFFu;
+}
+
+fn with_aux_head(w: u32, value: u32) -> u32 {
+    return (w & 0xFF00FFFFu) | ((value & 0xFFu) << 16u);
+}
+
+fn unpack_aux_column(w: u32) -> u32 {
+    return (w >> 24u) & 0xFFu;
+}
+
+fn with_aux_column(w: u32, value: u32) -> u32 {
+    return (w & 0xFFFFFFu) | ((value & 0xFFu) << 24u);
+}
+
+/// Aux word holding channel number `channel`.
+fn aux_channel_word(channel: u32) -> u32 {
+    switch channel {
+        case 0u: { return AUX_CHARGE_WORD; }
+        case 1u: { return AUX_OXYGEN_DEFICIT_WORD; }
+        case 2u: { return AUX_BLAST_WORD; }
+        case 3u: { return AUX_BLAST_FRONT_WORD; }
+        case 4u: { return AUX_SEDIMENT_WORD; }
+        case 5u: { return AUX_HEAD_WORD; }
+        case 6u: { return AUX_COLUMN_WORD; }
+        default: { return 0u; }
+    }
+}
+
+/// Value of channel number `channel` in its aux word `w`.
+fn unpack_aux_channel(w: u32, channel: u32) -> u32 {
+    switch channel {
+        case 0u: { return unpack_aux_charge(w); }
+        case 1u: { return unpack_aux_oxygen_deficit(w); }
+        case 2u: { return unpack_aux_blast(w); }
+        case 3u: { return unpack_aux_blast_front(w); }
+        case 4u: { return unpack_aux_sediment(w); }
+        case 5u: { return unpack_aux_head(w); }
+        case 6u: { return unpack_aux_column(w); }
+        default: { return 0u; }
+    }
+}
@@ -107,6 +107,22 @@ fn cross_chunk_voxel(pos: vec3<i32>, chunk_idx: u32) -> vec2<u32> {
     return read_pool[idx];
 }
 
+/// Pool index of a position that may lie in a neighboring chunk, or
+/// SENTINEL_NEIGHBOR if that chunk is unloaded. Valid for either voxel pool,
+/// and for the aux pools through aux_index.
+fn cross_chunk_index(pos: vec3<i32>, chunk_idx: u32) -> u32 {
+    if in_bounds(pos) {
+        return pool_voxel_index(pos, chunk_idx);
+    }
+
+    let neighbor_dir = compute_neighbor_dir(pos);
+    let neighbor_slot_offset = chunk_descriptors[chunk_idx * CHUNK_DESC_STRIDE + 1u + neighbor_dir];
+    if neighbor_slot_offset == SENTINEL_NEIGHBOR {
+        return SENTINEL_NEIGHBOR;
+    }
+    return (neighbor_slot_offset / 8u) + voxel_index(remap_coords(pos));
+}
+
 /// Read a voxel from the write pool for the current chunk only (no cross-chunk).
 /// Used when writing to the current chunk's slot.
 fn write_pool_voxel_index(pos: vec3<i32>, chunk_idx: u32) -> u32 {
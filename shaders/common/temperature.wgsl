@@ -0,0 +1,49 @@
+// temperature.wgsl — Temperature quantization curve lookups.
+// Included by the thermal and reaction shaders via concatenation.
+// Reads the `materials` buffer declared in the including shader: the curve's
+// LUT follows the material properties (MATERIAL_PROPS_STRIDE vec4s per
+// material), one linear-scale value per quantized temperature. Identical
+// search exists in
+// alkahest-core/src/math.rs (step_temperature).
+//
+// Constants injected by build preamble:
+//   TEMP_QUANT_MAX_VALUE, MATERIAL_PROPS_STRIDE
+
+/// Linear-scale value of quantized temperature `q` (q itself on the linear curve).
+fn temp_lut(material_count: u32, q: u32) -> f32 {
+    let i = min(q, TEMP_QUANT_MAX_VALUE);
+    return materials[material_count * MATERIAL_PROPS_STRIDE + i / 4u][i % 4u];
+}
+
+/// Move `q` by `delta` on the linear scale without overshooting it.
+/// Returns q + trunc(delta), clamped to [0, TEMP_QUANT_MAX_VALUE], on the linear curve.
+fn step_temperature(material_count: u32, q: u32, delta: f32) -> u32 {
+    let start = min(q, TEMP_QUANT_MAX_VALUE);
+    let base = temp_lut(material_count, start);
+    if delta >= 0.0 {
+        // Largest step in [start, max] within delta of start
+        var lo = start;
+        var hi = TEMP_QUANT_MAX_VALUE;
+        while lo < hi {
+            let mid = (lo + hi + 1u) / 2u;
+            if temp_lut(material_count, mid) - base <= delta {
+                lo = mid;
+            } else {
+                hi = mid - 1u;
+            }
+        }
+        return lo;
+    }
+    // Smallest step in [0, start] within delta of start
+    var lo = 0u;
+    var hi = start;
+    while lo < hi {
+        let mid = (lo + hi) / 2u;
+        if temp_lut(material_count, mid) - base >= delta {
+            hi = mid;
+        } else {
+            lo = mid + 1u;
+        }
+    }
+    return lo;
+}
@@ -1,80 +1,137 @@
-// types.wgsl — Shared voxel data types and unpack functions.
-// Must produce identical results to alkahest-core/src/math.rs.
+// types.wgsl — Voxel layout constants and accessors.
+// GENERATED from alkahest-core/src/voxel.rs; do not edit by hand. After
+// changing the layout, run `ALKAHEST_BLESS=1 cargo test -p alkahest-core`.
 // VoxelData is packed as vec2<u32>: .x = low word, .y = high word.
 //
 // Bit layout:
-//   low  [0:15]   material_id (u16)
-//   low  [16:27]  temperature (12-bit quantized)
-//   low  [28:31]  velocity_x low 4 bits
-//   high [0:3]    velocity_x high 4 bits
-//   high [4:11]   velocity_y (i8)
-//   high [12:19]  velocity_z (i8)
+//   low  [0:15]   material_id (16-bit)
+//   low  [16:27]  temperature (12-bit)
+//   low  [28:31]  vel_x bits 0-3
+//   high [0:3]    vel_x bits 4-7
+//   high [4:11]   vel_y (signed 8-bit)
+//   high [12:19]  vel_z (signed 8-bit)
 //   high [20:25]  pressure (6-bit)
 //   high [26:31]  flags (6-bit)
 
+const VOXEL_MATERIAL_ID_OFFSET: u32 = 0u;
+const VOXEL_MATERIAL_ID_BITS: u32 = 16u;
+const VOXEL_TEMPERATURE_OFFSET: u32 = 16u;
+const VOXEL_TEMPERATURE_BITS: u32 = 12u;
+const VOXEL_VEL_X_OFFSET: u32 = 28u;
+const VOXEL_VEL_X_BITS: u32 = 8u;
+const VOXEL_VEL_Y_OFFSET: u32 = 36u;
+const VOXEL_VEL_Y_BITS: u32 = 8u;
+const VOXEL_VEL_Z_OFFSET: u32 = 44u;
+const VOXEL_VEL_Z_BITS: u32 = 8u;
+const VOXEL_PRESSURE_OFFSET: u32 = 52u;
+const VOXEL_PRESSURE_BITS: u32 = 6u;
+const VOXEL_FLAGS_OFFSET: u32 = 58u;
+const VOXEL_FLAGS_BITS: u32 = 6u;
+
 fn unpack_material_id(v: vec2<u32>) -> u32 {
     return v.x & 0xFFFFu;
 }
 
+fn with_material_id(v: vec2<u32>, value: u32) -> vec2<u32> {
+    let bits = value & 0xFFFFu;
+    var packed = v;
+    packed.x = (packed.x & 0xFFFF0000u) | bits;
+    return packed;
+}
+
 fn unpack_temperature(v: vec2<u32>) -> u32 {
     return (v.x >> 16u) & 0xFFFu;
 }
 
+fn with_temperature(v: vec2<u32>, value: u32) -> vec2<u32> {
+    let bits = value & 0xFFFu;
+    var packed = v;
+    packed.x = (packed.x & 0xF000FFFFu) | (bits << 16u);
+    return packed;
+}
+
 fn unpack_vel_x(v: vec2<u32>) -> i32 {
-    // vel_x straddles the u32 boundary: low 4 bits from low[28:31], high 4 from high[0:3]
-    let vx_low = (v.x >> 28u) & 0xFu;
-    let vx_high = v.y & 0xFu;
-    let vx_u8 = vx_low | (vx_high << 4u);
-    // Sign-extend from 8-bit to i32
-    return i32(vx_u8) - select(0, 256, vx_u8 >= 128u);
+    let raw = (v.x >> 28u) | ((v.y & 0xFu) << 4u);
+    return i32(raw << 24u) >> 24u;
+}
+
+fn with_vel_x(v: vec2<u32>, value: i32) -> vec2<u32> {
+    let bits = u32(value) & 0xFFu;
+    var packed = v;
+    packed.x = (packed.x & 0xFFFFFFFu) | (bits << 28u);
+    packed.y = (packed.y & 0xFFFFFFF0u) | (bits >> 4u);
+    return packed;
 }
 
 fn unpack_vel_y(v: vec2<u32>) -> i32 {
-    let vy_u8 = (v.y >> 4u) & 0xFFu;
-    return i32(vy_u8) - select(0, 256, vy_u8 >= 128u);
+    let raw = (v.y >> 4u) & 0xFFu;
+    return i32(raw << 24u) >> 24u;
+}
+
+fn with_vel_y(v: vec2<u32>, value: i32) -> vec2<u32> {
+    let bits = u32(value) & 0xFFu;
+    var packed = v;
+    packed.y = (packed.y & 0xFFFFF00Fu) | (bits << 4u);
+    return packed;
 }
 
 fn unpack_vel_z(v: vec2<u32>) -> i32 {
-    let vz_u8 = (v.y >> 12u) & 0xFFu;
-    return i32(vz_u8) - select(0, 256, vz_u8 >= 128u);
+    let raw = (v.y >> 12u) & 0xFFu;
+    return i32(raw << 24u) >> 24u;
+}
+
+fn with_vel_z(v: vec2<u32>, value: i32) -> vec2<u32> {
+    let bits = u32(value) & 0xFFu;
+    var packed = v;
+    packed.y = (packed.y & 0xFFF00FFFu) | (bits << 12u);
+    return packed;
 }
 
 fn unpack_pressure(v: vec2<u32>) -> u32 {
     return (v.y >> 20u) & 0x3Fu;
 }
 
+fn with_pressure(v: vec2<u32>, value: u32) -> vec2<u32> {
+    let bits = value & 0x3Fu;
+    var packed = v;
+    packed.y = (packed.y & 0xFC0FFFFFu) | (bits << 20u);
+    return packed;
+}
+
 fn unpack_flags(v: vec2<u32>) -> u32 {
-    return (v.y >> 26u) & 0x3Fu;
+    return (v.y >> 26u);
 }
 
-/// Pack all voxel fields into vec2<u32>. Must produce identical results to Rust pack_voxel.
+fn with_flags(v: vec2<u32>, value: u32) -> vec2<u32> {
+    let bits = value & 0x3Fu;
+    var packed = v;
+    packed.y = (packed.y & 0x3FFFFFFu) | (bits << 26u);
+    return packed;
+}
+
+/// Pack all voxel fields into vec2<u32>. Values are truncated to their
+/// field widths.
 fn pack_voxel(
-    mat_id: u32,
-    temp: u32,
-    vx: i32,
-    vy: i32,
-    vz: i32,
+    material_id: u32,
+    temperature: u32,
+    vel_x: i32,
+    vel_y: i32,
+    vel_z: i32,
     pressure: u32,
     flags: u32,
 ) -> vec2<u32> {
-    let vx_u8 = u32(vx) & 0xFFu;
-    let vy_u8 = u32(vy) & 0xFFu;
-    let vz_u8 = u32(vz) & 0xFFu;
-    let pr = pressure & 0x3Fu;
-    let fl = flags & 0x3Fu;
-
-    let low = (mat_id & 0xFFFFu) | ((temp & 0xFFFu) << 16u) | ((vx_u8 & 0x0Fu) << 28u);
-    let high = ((vx_u8 >> 4u) & 0x0Fu) | (vy_u8 << 4u) | (vz_u8 << 12u) | (pr << 20u) | (fl << 26u);
-
-    return vec2<u32>(low, high);
+    var v = vec2<u32>(0u, 0u);
+    v = with_material_id(v, material_id);
+    v = with_temperature(v, temperature);
+    v = with_vel_x(v, vel_x);
+    v = with_vel_y(v, vel_y);
+    v = with_vel_z(v, vel_z);
+    v = with_pressure(v, pressure);
+    v = with_flags(v, flags);
+    return v;
 }
 
 /// Repack a voxel with new material and temperature, preserving velocity/pressure/flags.
 fn repack_material_temp(old: vec2<u32>, new_mat: u32, new_temp: u32) -> vec2<u32> {
-    let vx = unpack_vel_x(old);
-    let vy = unpack_vel_y(old);
-    let vz = unpack_vel_z(old);
-    let pressure = unpack_pressure(old);
-    let flags = unpack_flags(old);
-    return pack_voxel(new_mat, new_temp, vx, vy, vz, pressure, flags);
+    return with_temperature(with_material_id(old, new_mat), new_temp);
 }
@@ -2,7 +2,8 @@
 // M10: Adds ambient occlusion, multi-light with shadow ray budgeting, volumetric transparency,
 //       LOD for distant chunks, procedural sky, and HDR output for tone mapping.
 // Reads: voxel_pool (storage), material_colors (storage), chunk_map (storage),
-//         octree_nodes (storage), camera + light_config uniforms, light_array (storage).
+//         octree_nodes (storage), camera + light_config uniforms, light_array (storage),
+//         aux_pool (storage, cursor voxel only).
 // Writes: output_texture (storage texture, rgba16float for HDR).
 // Workgroup size: 8x8x1 — 64 threads per workgroup, one thread per pixel.
 //
@@ -12,9 +13,11 @@
 // Outer DDA: steps through chunk-sized cells (8x4x8 grid).
 // Inner DDA: steps through 32^3 voxels within a non-empty chunk.
 
-// -- Injected constants: CHUNK_SIZE, VOXELS_PER_CHUNK, WORLD_CHUNKS_X, WORLD_CHUNKS_Y, WORLD_CHUNKS_Z, SENTINEL_NEIGHBOR --
+// -- Injected constants: CHUNK_SIZE, VOXELS_PER_CHUNK, WORLD_CHUNKS_X, WORLD_CHUNKS_Y, WORLD_CHUNKS_Z, SENTINEL_NEIGHBOR,
+//    CHUNK_DESC_STRIDE, TEMP_QUANT_MAX_K, and temp_to_kelvin() for the active TEMP_CURVE --
 // -- Injected: shaders/common/types.wgsl --
 // -- Injected: shaders/common/coords.wgsl --
+// -- Injected: shaders/common/aux_channels.wgsl --
 // -- Injected: shaders/render/sky.wgsl --
 
 struct CameraUniforms {
@@ -68,6 +71,7 @@ struct MaterialColor {
 @group(1) @binding(3) var<storage, read> chunk_map: array<u32>;
 @group(1) @binding(4) var<storage, read> octree_nodes: array<vec4<u32>>;
 @group(1) @binding(5) var<storage, read_write> pick_result: array<u32>;
+@group(1) @binding(6) var<storage, read> aux_pool: array<u32>;
 
 const MAX_RAY_STEPS: u32 = 512u;
 const MAX_SHADOW_STEPS: u32 = 128u;
@@ -172,8 +176,9 @@ fn intersect_aabb(ray_origin: vec3<f32>, ray_dir_inv: vec3<f32>, box_min: vec3<f
 
 /// Convert temperature to heatmap color: blue(cold) -> cyan -> green -> yellow -> red(hot).
 fn heatmap_color(temp: u32) -> vec3<f32> {
-    // Normalize to [0,1] range over 0-4095 quantized range
-    let t = clamp(f32(temp) / 4095.0, 0.0, 1.0);
+    // Normalize to [0,1] over 0-TEMP_QUANT_MAX_K through the sim's curve, so
+    // colors track Kelvin whichever quantization curve is active
+    let t = clamp(temp_to_kelvin(temp) / TEMP_QUANT_MAX_K, 0.0, 1.0);
     // 5-stop gradient: blue -> cyan -> green -> yellow -> red
     if t < 0.25 {
         let f = t / 0.25;
@@ -209,7 +214,8 @@ fn is_clipped(world_pos: vec3<i32>, clip_axis: u32, clip_pos: f32) -> bool {
 }
 
 /// Write voxel data to the pick buffer for hover info display.
-fn write_pick(world_pos: vec3<i32>, voxel: vec2<u32>) {
+/// Aux channel values follow the 8 voxel fields, in channel number order.
+fn write_pick(world_pos: vec3<i32>, voxel: vec2<u32>, pool_idx: u32) {
     pick_result[0] = u32(world_pos.x);
     pick_result[1] = u32(world_pos.y);
     pick_result[2] = u32(world_pos.z);
@@ -225,6 +231,10 @@ fn write_pick(world_pos: vec3<i32>, voxel: vec2<u32>) {
     let vz_u8 = u32(vz + 128) & 0xFFu;
     pick_result[6] = vx_u8 | (vy_u8 << 8u) | (vz_u8 << 16u);
     pick_result[7] = unpack_flags(voxel);
+    for (var c = 0u; c < AUX_CHANNEL_COUNT; c++) {
+        let w = aux_pool[aux_index(pool_idx, aux_channel_word(c))];
+        pick_result[8u + c] = unpack_aux_channel(w, c);
+    }
 }
 
 // ─── Inverse direction helper ──────────────────────────────────────────
@@ -783,9 +793,8 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
         let slot_offset = chunk_map[chunk_map_index(cc)];
         if slot_offset != 0xFFFFFFFFu {
             let local = world_to_local(hit.hit_voxel);
-            let vi = voxel_index(local);
-            let voxel_data = voxel_pool[(slot_offset / 8u) + vi];
-            write_pick(hit.hit_voxel, voxel_data);
+            let pool_idx = (slot_offset / 8u) + voxel_index(local);
+            write_pick(hit.hit_voxel, voxel_pool[pool_idx], pool_idx);
         }
     }
 
@@ -1,17 +1,29 @@
-// activity.wgsl — Pass 5: Activity scan (M5: multi-chunk).
+// activity.wgsl — Pass 6: Activity scan (M5: multi-chunk).
 // Compares read_pool vs write_pool per voxel to detect chunk-level changes.
 // Uses workgroup shared memory with parallel OR reduction (atomicOr).
-// Output: one u32 flag per chunk (non-zero = dirty, 0 = idle).
+// Output: one u32 flag per chunk (non-zero = dirty, 0 = idle). Bit
+// ACTIVITY_DIRTY is set when anything changed or a blast front is still
+// passing through or fading; the bits from ACTIVITY_BLAST_SHIFT up hold the
+// summed strength of the blasts set off in the chunk this tick (its yield),
+// saturating at ACTIVITY_BLAST_MAX.
+// A structural voxel that was at rest and is gone or flying, or a structural
+// fragment coming to rest, sets structure_broken, which only the host clears: it then reads the chunks
+// back and solves what else gives way (alkahest_sim::structural).
 //
 // Workgroup: 8×8×4 = 256 threads.
 // Dispatch: (CHUNK_SIZE/8, CHUNK_SIZE/8, active_chunk_count * CHUNK_SIZE/4)
 //
-// Bind group (separate from main sim, 5 bindings):
+// Bind group (separate from main sim, 8 bindings):
 //   binding 0: read_pool (storage, read)
 //   binding 1: write_pool (storage, read) — post-sim state
 //   binding 2: activity_flags (storage, read_write) — output, one u32 per chunk
 //   binding 3: uniforms (uniform)
 //   binding 4: chunk_descriptors (storage, read) — pool slot offsets
+//   binding 5: aux_write_ro (storage, read) — post-sim aux channels
+//   binding 6: materials (storage, read) — material properties
+//   binding 7: structure_broken (storage, read_write) — one u32, set on a break
+
+const PHASE_SOLID: u32 = 2u;
 
 struct ActivityUniforms {
     tick: u32,
@@ -29,8 +41,36 @@ struct ActivityUniforms {
 @group(0) @binding(2) var<storage, read_write> activity_flags: array<atomic<u32>>;
 @group(0) @binding(3) var<uniform> activity_uniforms: ActivityUniforms;
 @group(0) @binding(4) var<storage, read> chunk_descriptors: array<u32>;
+@group(0) @binding(5) var<storage, read> aux_write_ro: array<u32>;
+@group(0) @binding(6) var<storage, read> materials: array<vec4<f32>>;
+@group(0) @binding(7) var<storage, read_write> structure_broken: atomic<u32>;
+
+/// Whether the material bonds into structures that carry load: a solid with
+/// compressive or tensile strength.
+fn is_structural(mat_id: u32) -> bool {
+    let base = mat_id * MATERIAL_PROPS_STRIDE;
+    let strength = materials[base + 6u];
+    return u32(materials[base].y) == PHASE_SOLID && (strength.y > 0.0 || strength.z > 0.0);
+}
+
+fn is_flying(voxel: vec2<u32>) -> bool {
+    let speed = max(abs(unpack_vel_x(voxel)), max(abs(unpack_vel_y(voxel)), abs(unpack_vel_z(voxel))));
+    return speed >= i32(BALLISTIC_MIN_SPEED);
+}
+
+/// Whether the change from `before` to `after` can move what structures
+/// stand: a structural voxel at rest is gone or flying, or a structural
+/// fragment came to rest (possibly with nothing under it).
+fn changes_structure(before: vec2<u32>, after: vec2<u32>) -> bool {
+    let mat_id = unpack_material_id(before);
+    let broke = is_structural(mat_id) && !is_flying(before)
+        && (unpack_material_id(after) != mat_id || is_flying(after));
+    let landed = is_structural(unpack_material_id(after)) && is_flying(before) && !is_flying(after);
+    return broke || landed;
+}
 
 var<workgroup> wg_dirty: atomic<u32>;
+var<workgroup> wg_yield: atomic<u32>;
 
 @compute @workgroup_size(8, 8, 4)
 fn main(
@@ -40,6 +80,7 @@ fn main(
     // Initialize workgroup shared atomic (first thread clears it)
     if lid == 0u {
         atomicStore(&wg_dirty, 0u);
+        atomicStore(&wg_yield, 0u);
     }
     // All threads must reach this barrier (C-WGSL-4)
     workgroupBarrier();
@@ -58,7 +99,19 @@ fn main(
 
         // Any bit difference = dirty (C-SIM-8: errs toward false positives)
         if read_val.x != write_val.x || read_val.y != write_val.y {
-            atomicOr(&wg_dirty, 1u);
+            atomicOr(&wg_dirty, ACTIVITY_DIRTY);
+            if changes_structure(read_val, write_val) {
+                atomicOr(&structure_broken, 1u);
+            }
+        }
+
+        // A blast still on the move or fading keeps the chunk awake
+        let blast_word = aux_write_ro[aux_index(pool_idx, AUX_BLAST_WORD)];
+        if unpack_aux_blast(blast_word) != 0u || unpack_aux_blast_front(blast_word) != BLAST_FRONT_NONE {
+            atomicOr(&wg_dirty, ACTIVITY_DIRTY);
+        }
+        if unpack_aux_blast_front(blast_word) == BLAST_FRONT_SOURCE {
+            atomicAdd(&wg_yield, unpack_aux_blast(blast_word));
         }
     }
 
@@ -71,5 +124,24 @@ fn main(
         if dirty != 0u {
             atomicOr(&activity_flags[chunk_idx], dirty);
         }
+        let blast_yield = min(atomicLoad(&wg_yield), ACTIVITY_BLAST_MAX);
+        if blast_yield != 0u {
+            // Saturating add into the yield bits; other workgroups of the
+            // chunk update the same word
+            let low_mask = (1u << ACTIVITY_BLAST_SHIFT) - 1u;
+            var old = atomicLoad(&activity_flags[chunk_idx]);
+            loop {
+                let total = min((old >> ACTIVITY_BLAST_SHIFT) + blast_yield, ACTIVITY_BLAST_MAX);
+                let swap = atomicCompareExchangeWeak(
+                    &activity_flags[chunk_idx],
+                    old,
+                    (old & low_mask) | (total << ACTIVITY_BLAST_SHIFT),
+                );
+                if swap.exchanged {
+                    break;
+                }
+                old = swap.old_value;
+            }
+        }
     }
 }
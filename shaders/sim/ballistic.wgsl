@@ -0,0 +1,230 @@
+// ballistic.wgsl — Pass 2a: Ballistic movement along the velocity field.
+// A voxel flies when it is not air or gas and some axis of its velocity is at
+// least BALLISTIC_MIN_SPEED. VELOCITY_PER_CELL velocity units carry it one cell
+// a tick, so the fastest voxels cover about four cells a tick.
+//
+// `integrate` runs once a tick: gravity pulls every flying voxel down by
+// BALLISTIC_GRAVITY, and one too slow on every axis comes to rest (velocity
+// zeroed) and is left to ordinary movement.
+//
+// `main` then runs once per ballistic sub-pass (BALLISTIC_SUBSTEPS substeps x
+// axes y, x, z x sign -1, +1 x parity 0, 1). A sub-pass takes the flying voxels
+// whose coordinate along the axis has the given parity and whose step is due,
+// and moves them one cell along the axis with the given sign. Sources and
+// destinations have opposite parity along the axis, so a cell is written by
+// one thread at most and sub-passes never race. A voxel flying into air or gas
+// swaps with it; one flying into a voxel that is not closing on it waits; one
+// hitting something anchored (solid and not flying) bounces back with
+// BALLISTIC_RESTITUTION of its speed; one hitting a loose voxel shares momentum
+// with it by density. Impacts take BALLISTIC_FRICTION off the other axes.
+// A voxel flies on into a neighboring chunk that is dispatched this tick
+// (CHUNK_SIZE is even, so the parity split holds across the edge). At the
+// edge of one that is not, it waits for it to wake; at the edge of the loaded
+// world, it bounces as off something anchored.
+// A voxel that moves takes its cell's sediment and head (aux_write) with it.
+//
+// Reads/writes write_pool only (read_pool is unused).
+//
+// Workgroup: 8x8x4 = 256 threads.
+// Dispatch: (CHUNK_SIZE/8, CHUNK_SIZE/8, active_chunk_count * CHUNK_SIZE/4)
+
+const PHASE_GAS: u32 = 0u;
+const PHASE_SOLID: u32 = 2u;
+
+struct BallisticParams {
+    axis: u32,
+    sign: i32,
+    parity: u32,
+    substep: u32,
+    tick: u32,
+    chunk_count: u32,
+    _pad0: u32,
+    _pad1: u32,
+}
+
+@group(0) @binding(0) var<storage, read> read_pool: array<vec2<u32>>;
+@group(0) @binding(1) var<storage, read_write> write_pool: array<vec2<u32>>;
+@group(0) @binding(2) var<storage, read> materials: array<vec4<f32>>;
+@group(0) @binding(4) var<uniform> ballistic_params: BallisticParams;
+@group(0) @binding(7) var<storage, read> chunk_descriptors: array<u32>;
+
+// Aux channel pools (aux_channels.wgsl), in their own group (C-GPU-3)
+@group(1) @binding(0) var<storage, read> aux_read: array<u32>;
+@group(1) @binding(1) var<storage, read_write> aux_write: array<u32>;
+
+fn is_gas(voxel: vec2<u32>) -> bool {
+    return u32(materials[unpack_material_id(voxel) * MATERIAL_PROPS_STRIDE].y) == PHASE_GAS;
+}
+
+/// Whether `voxel` flies: not air or gas, and fast enough on some axis.
+fn is_ballistic(voxel: vec2<u32>) -> bool {
+    let speed = max(abs(unpack_vel_x(voxel)), max(abs(unpack_vel_y(voxel)), abs(unpack_vel_z(voxel))));
+    return speed >= i32(BALLISTIC_MIN_SPEED) && unpack_material_id(voxel) != 0u && !is_gas(voxel);
+}
+
+/// Zeroes a velocity too slow to fly on any axis.
+fn settle(vel: vec3<i32>) -> vec3<i32> {
+    if max(abs(vel.x), max(abs(vel.y), abs(vel.z))) < i32(BALLISTIC_MIN_SPEED) {
+        return vec3<i32>(0, 0, 0);
+    }
+    return vel;
+}
+
+/// Whether a voxel moving at `speed` along an axis steps this substep. Spreads
+/// speed / VELOCITY_PER_CELL steps a tick evenly over the substeps, the same
+/// for every voxel, so voxels flying together stay together.
+fn ballistic_step_due(speed: u32, tick: u32, substep: u32) -> bool {
+    let period = VELOCITY_PER_CELL * BALLISTIC_SUBSTEPS;
+    let phase = (tick % VELOCITY_PER_CELL) * BALLISTIC_SUBSTEPS + substep;
+    return (speed * phase) % period + speed >= period;
+}
+
+/// Velocity field value for `v`, truncated toward zero.
+fn clamp_velocity(v: f32) -> i32 {
+    return clamp(i32(v), -127, 127);
+}
+
+/// `vel` after an impact along `axis`: BALLISTIC_FRICTION off the other axes.
+fn with_friction(vel: vec3<i32>, axis: u32) -> vec3<i32> {
+    var braked = vel;
+    for (var a = 0u; a < 3u; a++) {
+        if a != axis {
+            braked[a] = clamp_velocity(f32(vel[a]) * (1.0 - BALLISTIC_FRICTION));
+        }
+    }
+    return braked;
+}
+
+/// Whether the chunk whose slot starts at `slot_offset` (bytes) is dispatched
+/// this tick, so that its write pool slot is live.
+fn slot_dispatched(slot_offset: u32) -> bool {
+    for (var i = 0u; i < ballistic_params.chunk_count; i++) {
+        if chunk_descriptors[i * CHUNK_DESC_STRIDE] == slot_offset {
+            return true;
+        }
+    }
+    return false;
+}
+
+fn with_velocity(voxel: vec2<u32>, vel: vec3<i32>) -> vec2<u32> {
+    return with_vel_z(with_vel_y(with_vel_x(voxel, vel.x), vel.y), vel.z);
+}
+
+/// Swap the sediment and head of two cells whose voxels just swapped, so they
+/// stay with the voxel that has them.
+fn carry_aux(a: u32, b: u32) {
+    let a_idx = aux_index(a, AUX_SEDIMENT_WORD);
+    let b_idx = aux_index(b, AUX_SEDIMENT_WORD);
+    let sediment_a = unpack_aux_sediment(aux_write[a_idx]);
+    let sediment_b = unpack_aux_sediment(aux_write[b_idx]);
+    aux_write[a_idx] = with_aux_sediment(aux_write[a_idx], sediment_b);
+    aux_write[b_idx] = with_aux_sediment(aux_write[b_idx], sediment_a);
+    let head_a_idx = aux_index(a, AUX_HEAD_WORD);
+    let head_b_idx = aux_index(b, AUX_HEAD_WORD);
+    let head_a = unpack_aux_head(aux_write[head_a_idx]);
+    let head_b = unpack_aux_head(aux_write[head_b_idx]);
+    aux_write[head_a_idx] = with_aux_head(aux_write[head_a_idx], head_b);
+    aux_write[head_b_idx] = with_aux_head(aux_write[head_b_idx], head_a);
+}
+
+@compute @workgroup_size(8, 8, 4)
+fn integrate(@builtin(global_invocation_id) gid: vec3<u32>) {
+    let chunk_idx = gid.z / CHUNK_SIZE;
+    let local_z = gid.z % CHUNK_SIZE;
+    let pos = vec3<i32>(i32(gid.x), i32(gid.y), i32(local_z));
+    if !in_bounds(pos) {
+        return;
+    }
+
+    let idx = write_pool_voxel_index(pos, chunk_idx);
+    let voxel = write_pool[idx];
+    if !is_ballistic(voxel) {
+        return;
+    }
+    let vel = vec3<i32>(
+        unpack_vel_x(voxel),
+        max(unpack_vel_y(voxel) - i32(BALLISTIC_GRAVITY), -127),
+        unpack_vel_z(voxel),
+    );
+    write_pool[idx] = with_velocity(voxel, settle(vel));
+}
+
+@compute @workgroup_size(8, 8, 4)
+fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
+    let chunk_idx = gid.z / CHUNK_SIZE;
+    let local_z = gid.z % CHUNK_SIZE;
+    let pos = vec3<i32>(i32(gid.x), i32(gid.y), i32(local_z));
+    if !in_bounds(pos) {
+        return;
+    }
+
+    let axis = ballistic_params.axis;
+    let sign = ballistic_params.sign;
+    if u32(pos[axis]) % 2u != ballistic_params.parity {
+        return;
+    }
+
+    let src_idx = write_pool_voxel_index(pos, chunk_idx);
+    let src_voxel = write_pool[src_idx];
+    if !is_ballistic(src_voxel) {
+        return;
+    }
+    var vel = vec3<i32>(unpack_vel_x(src_voxel), unpack_vel_y(src_voxel), unpack_vel_z(src_voxel));
+    let speed = vel[axis] * sign;
+    if speed <= 0 || !ballistic_step_due(u32(speed), ballistic_params.tick, ballistic_params.substep) {
+        return;
+    }
+
+    var dst_pos = pos;
+    dst_pos[axis] += sign;
+    let dst_idx = cross_chunk_index(dst_pos, chunk_idx);
+    // The edge of the loaded world is a wall
+    if dst_idx == SENTINEL_NEIGHBOR {
+        vel[axis] = clamp_velocity(-BALLISTIC_RESTITUTION * f32(vel[axis]));
+        write_pool[src_idx] = with_velocity(src_voxel, settle(with_friction(vel, axis)));
+        return;
+    }
+    // A neighbor left out of this dispatch has no live write slot: wait at
+    // its edge until it wakes
+    if !in_bounds(dst_pos) {
+        let neighbor_dir = compute_neighbor_dir(dst_pos);
+        if !slot_dispatched(chunk_descriptors[chunk_idx * CHUNK_DESC_STRIDE + 1u + neighbor_dir]) {
+            return;
+        }
+    }
+    let dst_voxel = write_pool[dst_idx];
+
+    // Air and gas give way
+    let dst_mat = unpack_material_id(dst_voxel);
+    if dst_mat == 0u || is_gas(dst_voxel) {
+        write_pool[dst_idx] = src_voxel;
+        write_pool[src_idx] = dst_voxel;
+        carry_aux(src_idx, dst_idx);
+        return;
+    }
+
+    // Impact along the axis, unless the voxel ahead is pulling away
+    var dst_vel = vec3<i32>(unpack_vel_x(dst_voxel), unpack_vel_y(dst_voxel), unpack_vel_z(dst_voxel));
+    if (vel[axis] - dst_vel[axis]) * sign <= 0 {
+        return;
+    }
+    let src_mass = max(materials[unpack_material_id(src_voxel) * MATERIAL_PROPS_STRIDE].x, 1.0);
+    let dst_props_0 = materials[dst_mat * MATERIAL_PROPS_STRIDE];
+    let anchored = u32(dst_props_0.y) == PHASE_SOLID && !is_ballistic(dst_voxel);
+    let va = f32(vel[axis]);
+    if anchored {
+        vel[axis] = clamp_velocity(-BALLISTIC_RESTITUTION * va);
+    } else {
+        // Momentum is shared; the two keep BALLISTIC_RESTITUTION of their
+        // closing speed apart
+        let dst_mass = max(dst_props_0.x, 1.0);
+        let vb = f32(dst_vel[axis]);
+        let total = src_mass + dst_mass;
+        let shared_vel = (src_mass * va + dst_mass * vb) / total;
+        let rebound = BALLISTIC_RESTITUTION * (va - vb) / total;
+        vel[axis] = clamp_velocity(shared_vel - rebound * dst_mass);
+        dst_vel[axis] = clamp_velocity(shared_vel + rebound * src_mass);
+        write_pool[dst_idx] = with_velocity(dst_voxel, settle(dst_vel));
+    }
+    write_pool[src_idx] = with_velocity(src_voxel, settle(with_friction(vel, axis)));
+}
@@ -0,0 +1,145 @@
+// blast.wgsl — Pass 5b: Blast front propagation, damage and fragments.
+// Reads aux_read for every cell's blast strength and front state at the start
+// of the tick, and aux_write for the fronts the reactions pass seeded this tick
+// (BLAST_FRONT_SEEDED on the detonating voxel, strength in its blast channel).
+// Reads/writes write_pool (own voxel only: a voxel the front breaks keeps its
+// material and gets the front's velocity). Writes aux_write (own voxel's blast
+// and blast_front only; other channels are left as the pre-pass copy made them).
+//
+// A front moves one voxel per tick. Each live neighbor (front LIVE or SOURCE
+// last tick) offers what got through it times BLAST_FALLOFF, rounded down; the
+// strongest offer that beats what this voxel remembers (the strength of the
+// last front to reach it, faded by BLAST_DECAY) puts the front on it. Rounding
+// down means an offer is always below what its sender remembers next tick, so
+// fronts never flow back.
+// Gas lets the front through untouched. Anything else resists with its
+// blast_resistance: a front that beats it breaks the voxel loose as a fragment
+// flying away from the blast, and only the excess gets through (the sender's
+// resistance is taken off its offer, from read_pool, where it is the voxel the
+// front broke last tick); a front that does not stops there, so whatever lies
+// behind a wall strong enough is shadowed.
+//
+// Workgroup: 8x8x4 = 256 threads.
+// Dispatch: (CHUNK_SIZE/8, CHUNK_SIZE/8, active_chunk_count * CHUNK_SIZE/4)
+
+const PHASE_GAS: u32 = 0u;
+
+// 6 face-adjacent neighbor offsets
+const FACE_OFFSETS: array<vec3<i32>, 6> = array<vec3<i32>, 6>(
+    vec3<i32>(1, 0, 0),
+    vec3<i32>(-1, 0, 0),
+    vec3<i32>(0, 1, 0),
+    vec3<i32>(0, -1, 0),
+    vec3<i32>(0, 0, 1),
+    vec3<i32>(0, 0, -1),
+);
+
+@group(0) @binding(0) var<storage, read> read_pool: array<vec2<u32>>;
+@group(0) @binding(1) var<storage, read_write> write_pool: array<vec2<u32>>;
+@group(0) @binding(2) var<storage, read> materials: array<vec4<f32>>;
+@group(0) @binding(7) var<storage, read> chunk_descriptors: array<u32>;
+
+// Aux channel pools (aux_channels.wgsl), in their own group (C-GPU-3)
+@group(1) @binding(0) var<storage, read> aux_read: array<u32>;
+@group(1) @binding(1) var<storage, read_write> aux_write: array<u32>;
+
+fn is_gas(voxel: vec2<u32>) -> bool {
+    return u32(materials[unpack_material_id(voxel) * MATERIAL_PROPS_STRIDE].y) == PHASE_GAS;
+}
+
+/// Blast resistance of `voxel`; gas has none.
+fn blast_resistance(voxel: vec2<u32>) -> f32 {
+    if is_gas(voxel) {
+        return 0.0;
+    }
+    return materials[unpack_material_id(voxel) * MATERIAL_PROPS_STRIDE + 5u].y;
+}
+
+@compute @workgroup_size(8, 8, 4)
+fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
+    let chunk_idx = gid.z / CHUNK_SIZE;
+    let local_z = gid.z % CHUNK_SIZE;
+    let pos = vec3<i32>(i32(gid.x), i32(gid.y), i32(local_z));
+
+    if !in_bounds(pos) {
+        return;
+    }
+
+    let idx = write_pool_voxel_index(pos, chunk_idx);
+    let aux_idx = aux_index(idx, AUX_BLAST_WORD);
+    let word = aux_write[aux_idx];
+
+    // A detonation this tick: the reactions pass already set its strength
+    if unpack_aux_blast_front(word) == BLAST_FRONT_SEEDED {
+        aux_write[aux_idx] = with_aux_blast_front(word, BLAST_FRONT_SOURCE);
+        return;
+    }
+
+    let remembered = max(i32(unpack_aux_blast(aux_read[aux_idx])) - i32(BLAST_DECAY), 0);
+
+    // Strongest offer from a live neighbor, and the direction of travel
+    // weighted by every offer that gets through
+    var incoming = 0u;
+    var push = vec3<i32>(0, 0, 0);
+    for (var n = 0u; n < 6u; n = n + 1u) {
+        let offset = FACE_OFFSETS[n];
+        let neighbor_idx = cross_chunk_index(pos + offset, chunk_idx);
+        if neighbor_idx == SENTINEL_NEIGHBOR {
+            continue;
+        }
+        let neighbor_word = aux_read[aux_index(neighbor_idx, AUX_BLAST_WORD)];
+        let neighbor_front = unpack_aux_blast_front(neighbor_word);
+        if neighbor_front == BLAST_FRONT_NONE {
+            continue;
+        }
+        var through = f32(unpack_aux_blast(neighbor_word));
+        if neighbor_front == BLAST_FRONT_LIVE {
+            through = max(through - blast_resistance(read_pool[neighbor_idx]), 0.0);
+        }
+        let carried = u32(through * BLAST_FALLOFF);
+        if i32(carried) <= remembered {
+            continue;
+        }
+        incoming = max(incoming, carried);
+        push = push - offset * i32(carried);
+    }
+
+    if incoming == 0u {
+        let faded = with_aux_blast(word, u32(remembered));
+        aux_write[aux_idx] = with_aux_blast_front(faded, BLAST_FRONT_NONE);
+        return;
+    }
+
+    let voxel = write_pool[idx];
+    let resistance = blast_resistance(voxel);
+    let reached = with_aux_blast(word, incoming);
+
+    // Held: the front stops here
+    if f32(incoming) <= resistance {
+        aux_write[aux_idx] = with_aux_blast_front(reached, BLAST_FRONT_NONE);
+        return;
+    }
+    aux_write[aux_idx] = with_aux_blast_front(reached, BLAST_FRONT_LIVE);
+
+    // Broken loose: a fragment flying away from the blast
+    if !is_gas(voxel) {
+        let surplus = u32(f32(incoming) - resistance);
+        let speed = i32(clamp(surplus / BLAST_FRAGMENT_SPEED_DIVISOR, 1u, 127u));
+        // Scaled so the dominant axis gets the full speed; fronts meeting
+        // head on cancel out
+        let spread = max(max(abs(push.x), abs(push.y)), abs(push.z));
+        var vel = vec3<i32>(0, 0, 0);
+        if spread > 0 {
+            vel = push * speed / spread;
+        }
+        write_pool[idx] = pack_voxel(
+            unpack_material_id(voxel),
+            unpack_temperature(voxel),
+            vel.x,
+            vel.y,
+            vel.z,
+            unpack_pressure(voxel),
+            unpack_flags(voxel),
+        );
+    }
+}
@@ -1,6 +1,6 @@
 // commands.wgsl — Pass 1: Player command application (M7: brush expansion + push tool).
 // Reads the command buffer and writes voxels into the write pool.
-// Workgroup: 64x1x1 (one thread per command, max 64 commands).
+// Workgroup: 64x1x1 (one thread per command, up to MAX_COMMANDS per tick).
 // Each command may expand into a brush volume (up to radius 16 = ~17K writes).
 //
 // Buffers: see binding layout below (8 bindings).
@@ -49,12 +49,17 @@ fn apply_place(pos: vec3<i32>, chunk_idx: u32, mat_id: u32) {
     let idx = write_pool_voxel_index(pos, chunk_idx);
     var temp = 150u;
     if mat_id > 0u {
-        let props_1 = materials[mat_id * 4u + 1u];
+        let props_1 = materials[mat_id * MATERIAL_PROPS_STRIDE + 1u];
         let decay_rate = u32(props_1.x);
         let decay_threshold = u32(props_1.y);
         if decay_rate > 0u && decay_threshold > 0u {
             temp = min(decay_threshold * 3u, 4095u);
         }
+        // Molten and gaseous materials that would freeze at ambient start hot
+        let freeze_temp_q = u32(materials[mat_id * MATERIAL_PROPS_STRIDE + 4u].z);
+        if freeze_temp_q > AMBIENT_TEMP_QUANTIZED {
+            temp = min(freeze_temp_q * 3u, 4095u);
+        }
     }
     let voxel = pack_voxel(mat_id, temp, 0, 0, 0, 0u, 0u);
     write_pool[idx] = voxel;
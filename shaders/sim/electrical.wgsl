@@ -1,7 +1,8 @@
 // electrical.wgsl — Pass 4b: Electrical charge propagation, Joule heating (M15).
 // Reads read_pool (for cross-chunk voxel material lookups).
 // Reads/writes write_pool (Joule heating temperature updates).
-// Reads charge_read, writes charge_write (double-buffered charge propagation).
+// Reads aux_read, writes aux_write (double-buffered charge propagation; other
+// aux channels in aux_write are left as the pre-pass copy made them).
 // Uses 6 face-adjacent neighbors only (not 26 like thermal).
 //
 // Workgroup: 8x8x4 = 256 threads.
@@ -42,24 +43,29 @@ struct SimCommand {
 @group(0) @binding(0) var<storage, read> read_pool: array<vec2<u32>>;
 @group(0) @binding(1) var<storage, read_write> write_pool: array<vec2<u32>>;
 @group(0) @binding(2) var<storage, read> materials: array<vec4<f32>>;
-@group(0) @binding(3) var<storage, read> charge_read: array<u32>;
-@group(0) @binding(4) var<storage, read_write> charge_write: array<u32>;
-@group(0) @binding(5) var<uniform> electrical_uniforms: ElectricalUniforms;
-@group(0) @binding(6) var<storage, read> chunk_descriptors: array<u32>;
-
-/// Map a local voxel position to charge buffer index.
-/// Charge buffer uses 1 u32 per voxel, indexed by slot * VOXELS_PER_CHUNK + voxel_index.
-/// The pool slot_offset (in bytes) / 8 gives the voxel offset, which equals the charge offset.
-fn charge_buf_index(pos: vec3<i32>, chunk_idx: u32) -> u32 {
-    let slot_offset = chunk_descriptors[chunk_idx * CHUNK_DESC_STRIDE];
-    return (slot_offset / 8u) + voxel_index(pos);
+@group(0) @binding(3) var<uniform> electrical_uniforms: ElectricalUniforms;
+@group(0) @binding(4) var<storage, read> chunk_descriptors: array<u32>;
+
+// Aux channel pools (aux_channels.wgsl), in their own group (C-GPU-3)
+@group(1) @binding(0) var<storage, read> aux_read: array<u32>;
+@group(1) @binding(1) var<storage, read_write> aux_write: array<u32>;
+
+/// Charge of the voxel at voxel pool index `pool_idx` in the aux read pool.
+fn read_charge(pool_idx: u32) -> u32 {
+    return unpack_aux_charge(aux_read[aux_index(pool_idx, AUX_CHARGE_WORD)]);
+}
+
+/// Store the charge of the voxel at `pool_idx`, keeping its other channels.
+fn write_charge(pool_idx: u32, charge: u32) {
+    let i = aux_index(pool_idx, AUX_CHARGE_WORD);
+    aux_write[i] = with_aux_charge(aux_write[i], charge);
 }
 
 /// Read charge from a neighbor position, handling cross-chunk boundaries.
 /// Returns 0 for air, out-of-bounds, or unloaded neighbor chunks.
 fn read_neighbor_charge(pos: vec3<i32>, chunk_idx: u32) -> u32 {
     if in_bounds(pos) {
-        return charge_read[charge_buf_index(pos, chunk_idx)];
+        return read_charge(write_pool_voxel_index(pos, chunk_idx));
     }
 
     // Cross-chunk neighbor lookup
@@ -71,7 +77,7 @@ fn read_neighbor_charge(pos: vec3<i32>, chunk_idx: u32) -> u32 {
     }
 
     let remapped = remap_coords(pos);
-    return charge_read[(neighbor_slot_offset / 8u) + voxel_index(remapped)];
+    return read_charge((neighbor_slot_offset / 8u) + voxel_index(remapped));
 }
 
 @compute @workgroup_size(8, 8, 4)
@@ -85,44 +91,43 @@ fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
     }
 
     let pool_idx = write_pool_voxel_index(pos, chunk_idx);
-    let ch_idx = charge_buf_index(pos, chunk_idx);
     var voxel = write_pool[pool_idx];
     let mat_id = unpack_material_id(voxel);
 
     // Air: zero charge
     if mat_id == 0u {
-        charge_write[ch_idx] = 0u;
+        write_charge(pool_idx, 0u);
         return;
     }
 
     // Read electrical properties: vec4[3] = (conductivity, resistance, activation_threshold, charge_emission)
-    let props_3 = materials[mat_id * 4u + 3u];
+    let props_3 = materials[mat_id * MATERIAL_PROPS_STRIDE + 3u];
     let conductivity = props_3.x;
     let resistance = props_3.y;
     let activation_threshold = u32(props_3.z);
     let charge_emission = u32(props_3.w);
 
-    let current_charge = charge_read[ch_idx];
+    let current_charge = read_charge(pool_idx);
 
     // Power source: constant emission
     if charge_emission > 0u {
-        charge_write[ch_idx] = charge_emission;
+        write_charge(pool_idx, charge_emission);
         return;
     }
 
     // Insulator (conductivity == 0): decay only, no propagation
     if conductivity == 0.0 {
         if current_charge > CHARGE_DECAY_RATE {
-            charge_write[ch_idx] = current_charge - CHARGE_DECAY_RATE;
+            write_charge(pool_idx, current_charge - CHARGE_DECAY_RATE);
         } else {
-            charge_write[ch_idx] = 0u;
+            write_charge(pool_idx, 0u);
         }
         return;
     }
 
     // Ground: absorb all charge (high conductivity, zero resistance, zero emission)
     if conductivity > 0.9 && resistance == 0.0 {
-        charge_write[ch_idx] = 0u;
+        write_charge(pool_idx, 0u);
         return;
     }
 
@@ -159,7 +164,7 @@ fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
         }
     }
 
-    charge_write[ch_idx] = new_charge;
+    write_charge(pool_idx, new_charge);
 
     // Joule heating: temp_increase = charge² × resistance × JOULE_HEATING_FACTOR
     if new_charge > 0u && resistance > 0.0 {
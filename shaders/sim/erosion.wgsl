@@ -0,0 +1,163 @@
+// erosion.wgsl — Pass 2b: Erosion and sediment transport.
+// Runs after movement, which gives a moving liquid its flow velocity and takes
+// each cell's sediment (aux_write) along with the voxel that moves.
+// Reads write_pool (post-movement state) for the voxel's own cell, and
+// read_pool for its face neighbors: like the reactions pass, a voxel sees the
+// flow beside it as it was at the start of the tick, since the neighbors may
+// change in this pass.
+// Writes write_pool and aux_write (own voxel and own sediment only).
+//
+// A liquid carrying sediment whose flow has slowed below SEDIMENT_SETTLE_FLOW
+// drops it: the voxel becomes the carried material, at rest. Any other liquid
+// loses FLOW_DRAG of velocity on each axis. A powder or solid with nonzero
+// erodibility beside liquid flowing at EROSION_MIN_FLOW or faster is picked up
+// with chance erodibility * speed / FLOW_SPEED_MAX by the fastest such flow: it
+// becomes that liquid, moving with it, and carries its own material as
+// sediment. Sediment in a cell that no longer holds liquid (the liquid boiled
+// or froze) settles there if the cell is open and otherwise waits.
+//
+// Workgroup: 8x8x4 = 256 threads.
+// Dispatch: (CHUNK_SIZE/8, CHUNK_SIZE/8, active_chunk_count * CHUNK_SIZE/4)
+
+const PHASE_GAS: u32 = 0u;
+const PHASE_LIQUID: u32 = 1u;
+const PHASE_SOLID: u32 = 2u;
+const PHASE_POWDER: u32 = 3u;
+
+// 6 face-adjacent neighbor offsets
+const FACE_OFFSETS: array<vec3<i32>, 6> = array<vec3<i32>, 6>(
+    vec3<i32>(1, 0, 0),
+    vec3<i32>(-1, 0, 0),
+    vec3<i32>(0, 1, 0),
+    vec3<i32>(0, -1, 0),
+    vec3<i32>(0, 0, 1),
+    vec3<i32>(0, 0, -1),
+);
+
+struct ErosionUniforms {
+    tick: u32,
+    material_count: u32,
+    _pad0: u32,
+    _pad1: u32,
+    _pad2: u32,
+    _pad3: u32,
+    _pad4: u32,
+    _pad5: u32,
+}
+
+@group(0) @binding(0) var<storage, read> read_pool: array<vec2<u32>>;
+@group(0) @binding(1) var<storage, read_write> write_pool: array<vec2<u32>>;
+@group(0) @binding(2) var<storage, read> materials: array<vec4<f32>>;
+@group(0) @binding(4) var<uniform> erosion_uniforms: ErosionUniforms;
+@group(0) @binding(7) var<storage, read> chunk_descriptors: array<u32>;
+
+// Aux channel pools (aux_channels.wgsl), in their own group (C-GPU-3)
+@group(1) @binding(0) var<storage, read> aux_read: array<u32>;
+@group(1) @binding(1) var<storage, read_write> aux_write: array<u32>;
+
+fn phase_of(voxel: vec2<u32>) -> u32 {
+    return u32(materials[unpack_material_id(voxel) * MATERIAL_PROPS_STRIDE].y);
+}
+
+fn is_liquid(voxel: vec2<u32>) -> bool {
+    return unpack_material_id(voxel) != 0u && phase_of(voxel) == PHASE_LIQUID;
+}
+
+fn is_open(voxel: vec2<u32>) -> bool {
+    return unpack_material_id(voxel) == 0u || phase_of(voxel) == PHASE_GAS;
+}
+
+/// Largest axis of a voxel's velocity: a liquid's flow speed.
+fn speed_of(voxel: vec2<u32>) -> u32 {
+    return u32(max(abs(unpack_vel_x(voxel)), max(abs(unpack_vel_y(voxel)), abs(unpack_vel_z(voxel)))));
+}
+
+/// Whether `voxel` flies (ballistic.wgsl): not air or gas, and fast enough on
+/// some axis.
+fn is_ballistic(voxel: vec2<u32>) -> bool {
+    return speed_of(voxel) >= BALLISTIC_MIN_SPEED && !is_open(voxel);
+}
+
+/// One tick of FLOW_DRAG on an axis of flow velocity.
+fn drag(v: i32) -> i32 {
+    return sign(v) * max(abs(v) - i32(FLOW_DRAG), 0);
+}
+
+@compute @workgroup_size(8, 8, 4)
+fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
+    let chunk_idx = gid.z / CHUNK_SIZE;
+    let local_z = gid.z % CHUNK_SIZE;
+    let pos = vec3<i32>(i32(gid.x), i32(gid.y), i32(local_z));
+
+    if !in_bounds(pos) {
+        return;
+    }
+
+    let idx = write_pool_voxel_index(pos, chunk_idx);
+    let voxel = write_pool[idx];
+    let mat_id = unpack_material_id(voxel);
+    let aux_idx = aux_index(idx, AUX_SEDIMENT_WORD);
+    let sediment = unpack_aux_sediment(aux_write[aux_idx]);
+    let temp = unpack_temperature(voxel);
+    let flags = unpack_flags(voxel);
+
+    if is_liquid(voxel) {
+        // A flying liquid keeps its ballistic velocity and its load
+        if is_ballistic(voxel) {
+            return;
+        }
+        if sediment != 0u && speed_of(voxel) < SEDIMENT_SETTLE_FLOW {
+            // Slack water drops what it carries
+            write_pool[idx] = pack_voxel(sediment, temp, 0, 0, 0, 0u, flags);
+            aux_write[aux_idx] = with_aux_sediment(aux_write[aux_idx], 0u);
+            return;
+        }
+        write_pool[idx] = pack_voxel(mat_id, temp, drag(unpack_vel_x(voxel)),
+            drag(unpack_vel_y(voxel)), drag(unpack_vel_z(voxel)), unpack_pressure(voxel), flags);
+        return;
+    }
+
+    if sediment != 0u {
+        // Liquid that boiled away drops its sediment in place of the gas; in
+        // anything else (ice) the sediment waits for liquid again
+        if is_open(voxel) {
+            write_pool[idx] = pack_voxel(sediment, temp, 0, 0, 0, 0u, flags);
+            aux_write[aux_idx] = with_aux_sediment(aux_write[aux_idx], 0u);
+        }
+        return;
+    }
+
+    let erodibility = materials[mat_id * MATERIAL_PROPS_STRIDE + 6u].x;
+    let phase = phase_of(voxel);
+    if erodibility <= 0.0 || (phase != PHASE_POWDER && phase != PHASE_SOLID) || is_ballistic(voxel) {
+        return;
+    }
+
+    // Fastest liquid flowing past at the start of the tick
+    var fastest = vec2<u32>(0u, 0u);
+    var fastest_speed = EROSION_MIN_FLOW - 1u;
+    for (var n = 0u; n < 6u; n++) {
+        let neighbor = cross_chunk_voxel(pos + FACE_OFFSETS[n], chunk_idx);
+        if !is_liquid(neighbor) || is_ballistic(neighbor) {
+            continue;
+        }
+        let speed = speed_of(neighbor);
+        if speed > fastest_speed {
+            fastest = neighbor;
+            fastest_speed = speed;
+        }
+    }
+    if unpack_material_id(fastest) == 0u {
+        return;
+    }
+
+    // Picked up: the voxel becomes liquid moving with the flow, carrying its
+    // material as sediment
+    let chance = erodibility * f32(fastest_speed) / f32(FLOW_SPEED_MAX);
+    let roll = hash_to_float(sim_hash(pos.x + 7, pos.y, pos.z, erosion_uniforms.tick));
+    if roll < chance {
+        write_pool[idx] = pack_voxel(unpack_material_id(fastest), temp, unpack_vel_x(fastest),
+            unpack_vel_y(fastest), unpack_vel_z(fastest), 0u, flags);
+        aux_write[aux_idx] = with_aux_sediment(aux_write[aux_idx], mat_id);
+    }
+}
@@ -1,6 +1,35 @@
-// movement.wgsl — Pass 2: Movement with checkerboard sub-passes (M5: multi-chunk).
-// Each sub-pass handles one direction with one checkerboard parity.
-// Dispatched multiple times per tick with different uniform parameters.
+// movement.wgsl — Pass 2: Movement with parity sub-passes (M5: multi-chunk).
+// Each sub-pass handles one direction with one parity, taken along the axis
+// of travel (move_params.axis), so every source cell has the parity and every
+// destination the other. Dispatched multiple times per tick with different
+// uniform parameters, each time as two entry points: `decide` marks the cells
+// that move, reading only, and `apply` moves them. No thread reads a cell
+// another one writes, so the result does not depend on thread order and
+// matches the CPU reference (cpu.rs) bit for bit.
+//
+// A voxel moved by the even sub-pass of a direction lands on an odd cell; the
+// odd sub-pass leaves it there (the landed bits in move_flags), so nothing
+// moves more than one cell per direction per tick.
+//
+// Liquid also answers to hydrostatic head (pressure.wgsl): a surface holding
+// HYDROSTATIC_PUSH_HEAD voxel weights more than its own rises straight up,
+// and the column under it follows; a pushed column does not fall back, nor
+// into a hole that liquid under more head is about to refill from the side
+// or from below; and liquid spreading into a hole gives way to higher head
+// beyond it, or to equal head when it spreads toward negative coordinates.
+//
+// Nothing slides down a diagonal while it can still fall straight down.
+//
+// Powder heeds its repose slope (materials[5].z, tan of the angle): down a
+// diagonal only into a drop at least round(slope) deep, and sideways toward a
+// drop within round(1 / slope) voxels. A cohesive powder (materials[5].w)
+// with nothing under it stays up while the same powder resting beside it is
+// within reach.
+//
+// A moving liquid gains FLOW_ACCEL of velocity along each axis it moves along
+// (up to FLOW_SPEED_MAX, short of flying), which the erosion pass reads as its
+// flow speed. Whatever moves takes its cell's sediment and head (aux_write)
+// with it.
 //
 // Workgroup: 8x8x4 = 256 threads.
 // Dispatch: (CHUNK_SIZE/8, CHUNK_SIZE/8, active_chunk_count * CHUNK_SIZE/4)
@@ -16,9 +45,9 @@ struct MovementParams {
     dir_z: i32,
     parity: u32,
     tick: u32,
+    axis: u32,
     _pad0: u32,
     _pad1: u32,
-    _pad2: u32,
 }
 
 struct SimCommand {
@@ -41,105 +70,450 @@ struct SimCommand {
 @group(0) @binding(6) var<storage, read> rule_data: array<vec4<u32>>;
 @group(0) @binding(7) var<storage, read> chunk_descriptors: array<u32>;
 
-@compute @workgroup_size(8, 8, 4)
-fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
-    // Extract chunk index and local position from batched z-dispatch
-    let chunk_idx = gid.z / CHUNK_SIZE;
-    let local_z = gid.z % CHUNK_SIZE;
-    let pos = vec3<i32>(i32(gid.x), i32(gid.y), i32(local_z));
+// Aux channel pools (aux_channels.wgsl), in their own group (C-GPU-3)
+@group(1) @binding(0) var<storage, read> aux_read: array<u32>;
+@group(1) @binding(1) var<storage, read_write> aux_write: array<u32>;
 
-    // Bounds check (C-WGSL-6: i32 for coords, u32 only for final index)
-    if !in_bounds(pos) {
-        return;
+// Per dispatched chunk, two bitsets of one bit per voxel: cells deciding to
+// move this sub-pass, then cells landed on in the even sub-pass
+@group(2) @binding(0) var<storage, read_write> move_flags: array<atomic<u32>>;
+
+const MOVE_FLAG_WORDS: u32 = CHUNK_SIZE * CHUNK_SIZE * CHUNK_SIZE / 32u;
+const MOVE_DECIDED: u32 = 0u;
+const MOVE_LANDED: u32 = 1u;
+
+fn is_liquid(voxel: vec2<u32>) -> bool {
+    let mat_id = unpack_material_id(voxel);
+    return mat_id != 0u && u32(materials[mat_id * MATERIAL_PROPS_STRIDE].y) == PHASE_LIQUID;
+}
+
+fn is_open(voxel: vec2<u32>) -> bool {
+    let mat_id = unpack_material_id(voxel);
+    return mat_id == 0u || u32(materials[mat_id * MATERIAL_PROPS_STRIDE].y) == PHASE_GAS;
+}
+
+/// Pressure a voxel of liquid adds to the column under it (pressure.wgsl).
+fn head_weight(voxel: vec2<u32>) -> i32 {
+    let density = materials[unpack_material_id(voxel) * MATERIAL_PROPS_STRIDE].x;
+    return max(i32(floor(density * STRUCTURAL_WEIGHT_PER_DENSITY + 0.5)), 1);
+}
+
+/// Whether liquid with nothing above it holds enough head to be pushed up.
+fn surface_pushed(liquid: vec2<u32>) -> bool {
+    return i32(unpack_pressure(liquid)) >= (1 + i32(HYDROSTATIC_PUSH_HEAD)) * head_weight(liquid);
+}
+
+/// Whether the liquid column through `pos` is pushed up: the liquid at `pos`
+/// holds HYDROSTATIC_PUSH_HEAD voxel weights more than the column over it
+/// (COLUMN as the last pressure pass found it) bears on it.
+fn column_pushed(pos: vec3<i32>, chunk_idx: u32) -> bool {
+    let idx = write_pool_voxel_index(pos, chunk_idx);
+    let liquid = write_pool[idx];
+    let column = i32(unpack_aux_column(aux_read[aux_index(idx, AUX_COLUMN_WORD)]));
+    return i32(unpack_pressure(liquid)) >= column + i32(HYDROSTATIC_PUSH_HEAD) * head_weight(liquid);
+}
+
+/// Whether the liquid at `pos` rises into the air above it: into a gap under
+/// a pushed column or one lighter than its own head, or at an open surface
+/// that is pushed itself.
+fn pushed_up(pos: vec3<i32>, chunk_idx: u32) -> bool {
+    let liquid = write_pool[write_pool_voxel_index(pos, chunk_idx)];
+    let over = pos + vec3<i32>(0, 2, 0);
+    if in_bounds(over) {
+        let column = write_pool[write_pool_voxel_index(over, chunk_idx)];
+        if is_liquid(column) {
+            return column_pushed(over, chunk_idx)
+                || i32(unpack_pressure(liquid))
+                    >= i32(unpack_pressure(column)) + (1 + i32(HYDROSTATIC_PUSH_HEAD)) * head_weight(liquid);
+        }
     }
+    return surface_pushed(liquid);
+}
 
-    // Checkerboard filter: only process cells matching current parity
-    let cell_parity = u32(pos.x + pos.z) % 2u;
-    if cell_parity != move_params.parity {
-        return;
+/// Whether the hole at `pos` is about to be refilled from the side by liquid
+/// under more head than `liquid` above it, so that liquid need not fall in.
+fn refilled(pos: vec3<i32>, liquid: vec2<u32>, chunk_idx: u32) -> bool {
+    let needed = i32(unpack_pressure(liquid)) + i32(HYDROSTATIC_PUSH_HEAD) * head_weight(liquid);
+    let sides = array<vec3<i32>, 4>(
+        vec3<i32>(1, 0, 0),
+        vec3<i32>(-1, 0, 0),
+        vec3<i32>(0, 0, 1),
+        vec3<i32>(0, 0, -1),
+    );
+    for (var i = 0u; i < 4u; i++) {
+        let side = pos + sides[i];
+        if !in_bounds(side) {
+            continue;
+        }
+        let voxel = write_pool[write_pool_voxel_index(side, chunk_idx)];
+        if is_liquid(voxel) && i32(unpack_pressure(voxel)) >= needed {
+            return true;
+        }
     }
+    return false;
+}
 
-    let src_idx = write_pool_voxel_index(pos, chunk_idx);
-    let src_voxel = write_pool[src_idx];
+/// Whether the hole at `pos` is about to be filled from below by liquid pushed
+/// up into it.
+fn lifted(pos: vec3<i32>, chunk_idx: u32) -> bool {
+    let below = pos - vec3<i32>(0, 1, 0);
+    return in_bounds(below) && is_liquid(write_pool[write_pool_voxel_index(below, chunk_idx)])
+        && pushed_up(below, chunk_idx);
+}
+
+/// Whether `voxel` bears what rests on it: a solid or powder that is not
+/// flying.
+fn supports(voxel: vec2<u32>) -> bool {
+    let mat_id = unpack_material_id(voxel);
+    let phase = u32(materials[mat_id * MATERIAL_PROPS_STRIDE].y);
+    return mat_id != 0u && (phase == PHASE_SOLID || phase == PHASE_POWDER) && !is_ballistic(voxel);
+}
+
+/// Voxels of drop a powder needs beside it to slide down a diagonal.
+fn repose_drop(mat_id: u32) -> i32 {
+    let slope = materials[mat_id * MATERIAL_PROPS_STRIDE + 5u].z;
+    return max(i32(floor(slope + 0.5)), 1);
+}
+
+/// Voxels across within which a powder creeps sideways toward a drop.
+fn repose_run(mat_id: u32) -> i32 {
+    let slope = materials[mat_id * MATERIAL_PROPS_STRIDE + 5u].z;
+    return max(i32(floor(1.0 / slope + 0.5)), 1);
+}
+
+/// Longest overhang, in voxels, a powder stands out over nothing.
+fn cohesion_reach(mat_id: u32) -> i32 {
+    let cohesion = materials[mat_id * MATERIAL_PROPS_STRIDE + 5u].w;
+    return i32(floor(cohesion * f32(COHESION_MAX_OVERHANG) + 0.5));
+}
+
+/// Whether the powder at `pos` may move along `dir` (down or a down
+/// diagonal): not while cohesion holds it up over nothing, and down a
+/// diagonal only into a drop at least as deep as its repose slope.
+fn powder_falls(pos: vec3<i32>, dir: vec3<i32>, mat_id: u32, chunk_idx: u32) -> bool {
+    let below = pos - vec3<i32>(0, 1, 0);
+    let reach = cohesion_reach(mat_id);
+    if reach > 0 && in_bounds(below)
+        && !supports(write_pool[write_pool_voxel_index(below, chunk_idx)])
+        && cohered(pos, mat_id, reach, chunk_idx) {
+        return false;
+    }
+    if dir.x == 0 && dir.z == 0 {
+        return true;
+    }
+    let drop = repose_drop(mat_id);
+    for (var depth = 2; depth <= drop; depth++) {
+        let cell = vec3<i32>(pos.x + dir.x, pos.y - depth, pos.z + dir.z);
+        if !in_bounds(cell) || supports(write_pool[write_pool_voxel_index(cell, chunk_idx)]) {
+            return false;
+        }
+    }
+    return true;
+}
+
+/// Whether the resting powder at `pos` creeps one voxel along the lateral
+/// `dir`: its repose run is more than one voxel, and the surface that way
+/// stays clear until it drops away within the run.
+fn powder_creeps(pos: vec3<i32>, dir: vec3<i32>, mat_id: u32, chunk_idx: u32) -> bool {
+    let run = repose_run(mat_id);
+    let below = pos - vec3<i32>(0, 1, 0);
+    if run < 2 || !in_bounds(below) || !supports(write_pool[write_pool_voxel_index(below, chunk_idx)]) {
+        return false;
+    }
+    for (var step = 1; step <= run; step++) {
+        let cell = pos + dir * step;
+        if !in_bounds(cell) || supports(write_pool[write_pool_voxel_index(cell, chunk_idx)]) {
+            return false;
+        }
+        let under = cell - vec3<i32>(0, 1, 0);
+        if !supports(write_pool[write_pool_voxel_index(under, chunk_idx)]) {
+            return true;
+        }
+    }
+    return false;
+}
+
+/// Whether the cohesive powder at `pos`, with nothing under it, is held up:
+/// a run of the same powder out to one side reaches, within `reach` voxels, a
+/// voxel resting on something. Grains hold to grains only, so powder does not
+/// stick to walls.
+fn cohered(pos: vec3<i32>, mat_id: u32, reach: i32, chunk_idx: u32) -> bool {
+    let sides = array<vec3<i32>, 4>(
+        vec3<i32>(1, 0, 0),
+        vec3<i32>(-1, 0, 0),
+        vec3<i32>(0, 0, 1),
+        vec3<i32>(0, 0, -1),
+    );
+    for (var i = 0u; i < 4u; i++) {
+        for (var step = 1; step <= reach; step++) {
+            let cell = pos + sides[i] * step;
+            let below = cell - vec3<i32>(0, 1, 0);
+            if !in_bounds(cell) || !in_bounds(below) {
+                break;
+            }
+            let voxel = write_pool[write_pool_voxel_index(cell, chunk_idx)];
+            if unpack_material_id(voxel) != mat_id || is_ballistic(voxel) {
+                break;
+            }
+            if supports(write_pool[write_pool_voxel_index(below, chunk_idx)]) {
+                return true;
+            }
+        }
+    }
+    return false;
+}
+
+/// Whether `voxel` flies (ballistic.wgsl): not air or gas, and fast enough on
+/// some axis.
+fn is_ballistic(voxel: vec2<u32>) -> bool {
+    let speed = max(abs(unpack_vel_x(voxel)), max(abs(unpack_vel_y(voxel)), abs(unpack_vel_z(voxel))));
+    let mat_id = unpack_material_id(voxel);
+    return speed >= i32(BALLISTIC_MIN_SPEED) && mat_id != 0u
+        && u32(materials[mat_id * MATERIAL_PROPS_STRIDE].y) != PHASE_GAS;
+}
+
+/// `liquid` after moving one step along `dir`: FLOW_ACCEL faster along each
+/// axis it moved, up to FLOW_SPEED_MAX.
+fn flow(liquid: vec2<u32>, dir: vec3<i32>) -> vec2<u32> {
+    let max_speed = i32(FLOW_SPEED_MAX);
+    let accel = dir * i32(FLOW_ACCEL);
+    var vx = unpack_vel_x(liquid);
+    var vy = unpack_vel_y(liquid);
+    var vz = unpack_vel_z(liquid);
+    if dir.x != 0 {
+        vx = clamp(vx + accel.x, -max_speed, max_speed);
+    }
+    if dir.y != 0 {
+        vy = clamp(vy + accel.y, -max_speed, max_speed);
+    }
+    if dir.z != 0 {
+        vz = clamp(vz + accel.z, -max_speed, max_speed);
+    }
+    return pack_voxel(unpack_material_id(liquid), unpack_temperature(liquid), vx, vy, vz,
+        unpack_pressure(liquid), unpack_flags(liquid));
+}
+
+/// Swap the sediment and head of two cells whose voxels just swapped, so they
+/// stay with the voxel that has them.
+fn carry_aux(a: u32, b: u32) {
+    let a_idx = aux_index(a, AUX_SEDIMENT_WORD);
+    let b_idx = aux_index(b, AUX_SEDIMENT_WORD);
+    let sediment_a = unpack_aux_sediment(aux_write[a_idx]);
+    let sediment_b = unpack_aux_sediment(aux_write[b_idx]);
+    aux_write[a_idx] = with_aux_sediment(aux_write[a_idx], sediment_b);
+    aux_write[b_idx] = with_aux_sediment(aux_write[b_idx], sediment_a);
+    let head_a_idx = aux_index(a, AUX_HEAD_WORD);
+    let head_b_idx = aux_index(b, AUX_HEAD_WORD);
+    let head_a = unpack_aux_head(aux_write[head_a_idx]);
+    let head_b = unpack_aux_head(aux_write[head_b_idx]);
+    aux_write[head_a_idx] = with_aux_head(aux_write[head_a_idx], head_b);
+    aux_write[head_b_idx] = with_aux_head(aux_write[head_b_idx], head_a);
+}
+
+/// Word of `move_flags` holding the bit of `bitset` (MOVE_DECIDED or
+/// MOVE_LANDED) for the cell at `pos`.
+fn move_flag_word(bitset: u32, pos: vec3<i32>, chunk_idx: u32) -> u32 {
+    return (chunk_idx * 2u + bitset) * MOVE_FLAG_WORDS + voxel_index(pos) / 32u;
+}
+
+fn move_flag_bit(pos: vec3<i32>) -> u32 {
+    return 1u << (voxel_index(pos) % 32u);
+}
+
+/// Chunk-local position of this thread's cell.
+fn source_cell(gid: vec3<u32>) -> vec3<i32> {
+    // Extract chunk-local position from batched z-dispatch
+    return vec3<i32>(i32(gid.x), i32(gid.y), i32(gid.z % CHUNK_SIZE));
+}
+
+/// Whether the cell at `pos` takes part in the sub-pass: its coordinate along
+/// the axis of travel has the sub-pass parity.
+fn processes(pos: vec3<i32>) -> bool {
+    // Bounds check (C-WGSL-6: i32 for coords, u32 only for final index)
+    return in_bounds(pos) && u32(pos[move_params.axis]) % 2u == move_params.parity;
+}
+
+/// Whether the voxel at `pos` moves one cell along the sub-pass direction.
+/// Reads only.
+fn decide_move(pos: vec3<i32>, chunk_idx: u32) -> bool {
+    let src_voxel = write_pool[write_pool_voxel_index(pos, chunk_idx)];
     let src_mat_id = unpack_material_id(src_voxel);
 
     // Air doesn't move
     if src_mat_id == 0u {
-        return;
+        return false;
     }
 
     // Look up source material properties (density-driven movement, C-DESIGN-1)
-    let src_props_0 = materials[src_mat_id * 4u];
+    let src_props_0 = materials[src_mat_id * MATERIAL_PROPS_STRIDE];
     let src_density = src_props_0.x;
     let src_phase = u32(src_props_0.y);
 
     // Solid phase doesn't move
     if src_phase == PHASE_SOLID {
-        return;
+        return false;
+    }
+
+    // Flying voxels move in the ballistic sub-passes
+    if is_ballistic(src_voxel) {
+        return false;
     }
 
     // Phase-direction filtering (C-DESIGN-1)
+    let dir = vec3<i32>(move_params.dir_x, move_params.dir_y, move_params.dir_z);
     let dir_y = move_params.dir_y;
     if dir_y < 0 {
         if src_phase != PHASE_POWDER && src_phase != PHASE_LIQUID {
-            return;
+            return false;
+        }
+        if src_phase == PHASE_POWDER && !powder_falls(pos, dir, src_mat_id, chunk_idx) {
+            return false;
+        }
+        // Nothing slides down a diagonal while it can still fall straight
+        let below = pos - vec3<i32>(0, 1, 0);
+        if (dir.x != 0 || dir.z != 0) && in_bounds(below)
+            && is_open(write_pool[write_pool_voxel_index(below, chunk_idx)]) {
+            return false;
         }
     } else if dir_y == 0 {
-        if src_phase != PHASE_LIQUID {
-            return;
-        }
-        let src_props_1 = materials[src_mat_id * 4u + 1u];
-        let viscosity = src_props_1.w;
-        if viscosity > 0.0 {
-            let h = sim_hash(pos.x, pos.y, pos.z, move_params.tick);
-            let roll = hash_to_float(h);
-            if roll < viscosity {
-                return;
+        if src_phase == PHASE_POWDER {
+            // Powder only creeps sideways toward a drop within its run
+            if !powder_creeps(pos, dir, src_mat_id, chunk_idx) {
+                return false;
+            }
+        } else if src_phase != PHASE_LIQUID {
+            return false;
+        } else {
+            let src_props_1 = materials[src_mat_id * MATERIAL_PROPS_STRIDE + 1u];
+            let viscosity = src_props_1.w;
+            if viscosity > 0.0 {
+                let h = sim_hash(pos.x, pos.y, pos.z, move_params.tick);
+                let roll = hash_to_float(h);
+                if roll < viscosity {
+                    return false;
+                }
             }
         }
+    } else if src_phase == PHASE_LIQUID {
+        // Liquid only rises straight up, pushed by the head around it
+        if move_params.dir_x != 0 || move_params.dir_z != 0 || !pushed_up(pos, chunk_idx) {
+            return false;
+        }
     } else {
         if src_phase != PHASE_GAS {
-            return;
+            return false;
         }
         if src_density <= 0.0 {
-            return;
+            return false;
         }
     }
 
     // Compute destination position (may cross chunk boundary)
-    let dir = vec3<i32>(move_params.dir_x, move_params.dir_y, move_params.dir_z);
     let dst_pos = pos + dir;
 
-    // Cross-chunk voxel read for destination
-    let dst_voxel = cross_chunk_voxel(dst_pos, chunk_idx);
-    let dst_mat_id = unpack_material_id(dst_voxel);
-
     // For cross-chunk writes, we can only write to our own chunk's write pool.
     // If destination is in another chunk, skip (neighbor chunk handles it from its side).
     if !in_bounds(dst_pos) {
-        return;
+        return false;
     }
 
-    let dst_idx = write_pool_voxel_index(dst_pos, chunk_idx);
+    // Read the destination as this tick has left it (write_pool), so a cell
+    // filled by an earlier sub-pass or the ballistic pass is not overwritten
+    let dst_voxel = write_pool[write_pool_voxel_index(dst_pos, chunk_idx)];
+    let dst_mat_id = unpack_material_id(dst_voxel);
+
+    if src_phase == PHASE_LIQUID {
+        // A liquid pushed up by the head around it does not fall back, nor
+        // into a hole that liquid under more head is about to refill
+        if dir_y < 0 && is_open(dst_voxel)
+            && (column_pushed(pos, chunk_idx) || refilled(dst_pos, src_voxel, chunk_idx)
+                || lifted(dst_pos, chunk_idx)) {
+            return false;
+        }
+        // A liquid spreading into a hole gives way to higher head beyond it,
+        // and to equal head when it spreads toward negative coordinates, so
+        // the hole is filled from one side and does not slosh back
+        let beyond = dst_pos + dir;
+        if dir_y == 0 && dst_mat_id == 0u && in_bounds(beyond) {
+            let beyond_voxel = write_pool[write_pool_voxel_index(beyond, chunk_idx)];
+            let head = unpack_pressure(src_voxel);
+            let beyond_head = unpack_pressure(beyond_voxel);
+            if is_liquid(beyond_voxel)
+                && (beyond_head > head || (beyond_head == head && dir.x + dir.z < 0)) {
+                return false;
+            }
+        }
+        // A rising liquid only moves into air
+        if dir_y > 0 && dst_mat_id != 0u {
+            return false;
+        }
+    }
 
-    // If destination is air, move there
+    // Move into air, or displace anything lighter that is not solid
     if dst_mat_id == 0u {
-        write_pool[dst_idx] = src_voxel;
-        write_pool[src_idx] = vec2<u32>(0u, 0u);
+        return true;
+    }
+    let dst_props_0 = materials[dst_mat_id * MATERIAL_PROPS_STRIDE];
+    return u32(dst_props_0.y) != PHASE_SOLID && src_density > dst_props_0.x;
+}
+
+@compute @workgroup_size(8, 8, 4)
+fn decide(@builtin(global_invocation_id) gid: vec3<u32>) {
+    let chunk_idx = gid.z / CHUNK_SIZE;
+    let pos = source_cell(gid);
+    if !processes(pos) {
+        return;
+    }
+
+    // A voxel that landed here in the even sub-pass stays for the odd one
+    let bit = move_flag_bit(pos);
+    let landed = atomicAnd(&move_flags[move_flag_word(MOVE_LANDED, pos, chunk_idx)], ~bit);
+    if (landed & bit) != 0u {
         return;
     }
 
-    // Density-driven displacement: if destination is lighter, swap
-    let dst_props_0 = materials[dst_mat_id * 4u];
-    let dst_density = dst_props_0.x;
-    let dst_phase = u32(dst_props_0.y);
+    if decide_move(pos, chunk_idx) {
+        atomicOr(&move_flags[move_flag_word(MOVE_DECIDED, pos, chunk_idx)], bit);
+    }
+}
 
-    if dst_phase == PHASE_SOLID {
+@compute @workgroup_size(8, 8, 4)
+fn apply(@builtin(global_invocation_id) gid: vec3<u32>) {
+    let chunk_idx = gid.z / CHUNK_SIZE;
+    let pos = source_cell(gid);
+    if !processes(pos) {
+        return;
+    }
+
+    let bit = move_flag_bit(pos);
+    let decided = atomicAnd(&move_flags[move_flag_word(MOVE_DECIDED, pos, chunk_idx)], ~bit);
+    if (decided & bit) == 0u {
         return;
     }
 
-    if src_density > dst_density {
-        write_pool[dst_idx] = src_voxel;
+    // Both cells belong to this thread for the sub-pass: swap the voxels,
+    // air staying behind as air
+    let dir = vec3<i32>(move_params.dir_x, move_params.dir_y, move_params.dir_z);
+    let dst_pos = pos + dir;
+    let src_idx = write_pool_voxel_index(pos, chunk_idx);
+    let dst_idx = write_pool_voxel_index(dst_pos, chunk_idx);
+    let src_voxel = write_pool[src_idx];
+    let dst_voxel = write_pool[dst_idx];
+
+    // Liquid picks up speed as it flows
+    var moved = src_voxel;
+    if is_liquid(src_voxel) {
+        moved = flow(src_voxel, dir);
+    }
+    write_pool[dst_idx] = moved;
+    if unpack_material_id(dst_voxel) == 0u {
+        write_pool[src_idx] = vec2<u32>(0u, 0u);
+    } else {
         write_pool[src_idx] = dst_voxel;
     }
+    carry_aux(src_idx, dst_idx);
+
+    if move_params.parity == 0u {
+        atomicOr(&move_flags[move_flag_word(MOVE_LANDED, dst_pos, chunk_idx)], move_flag_bit(dst_pos));
+    }
 }
@@ -0,0 +1,112 @@
+// oxygen.wgsl — Pass 3b: Oxygen exchange between gas cells.
+// Reads write_pool (post-reaction materials and temperatures; not modified here).
+// Reads aux_read for every cell's oxygen deficit at the start of the tick. The
+// reactions pass has already added whatever this voxel burned (or the exhaust it
+// turned into) to its own word in aux_write; that change is carried into the new
+// deficit on top of the exchange.
+// Writes aux_write (own voxel's oxygen deficit only; other channels are left as
+// the pre-pass copy made them).
+//
+// Gas cells (air included) exchange deficit with gas neighbors. A cell holding
+// anything else only hands deficit out (the oxygen a burning solid or liquid drew
+// from the gas around it, or spent air that something moved into) and never
+// takes any in, so walls and fuel stay clean. A debt only drains into gas that
+// still holds more oxygen than the debt, so fuel that overdrew a sealed pocket
+// leaves it spent. Unloaded neighbors are fresh air, so
+// the edge of the loaded world is an endless supply and a vented chamber refills
+// through its vent. Each pair's exchange is truncated toward zero, which makes it
+// exactly opposite on the two sides: exchange inside the world makes no oxygen.
+//
+// Workgroup: 8x8x4 = 256 threads.
+// Dispatch: (CHUNK_SIZE/8, CHUNK_SIZE/8, active_chunk_count * CHUNK_SIZE/4)
+
+const PHASE_GAS: u32 = 0u;
+
+// 6 face-adjacent neighbor offsets
+const FACE_OFFSETS: array<vec3<i32>, 6> = array<vec3<i32>, 6>(
+    vec3<i32>(1, 0, 0),
+    vec3<i32>(-1, 0, 0),
+    vec3<i32>(0, 1, 0),
+    vec3<i32>(0, -1, 0),
+    vec3<i32>(0, 0, 1),
+    vec3<i32>(0, 0, -1),
+);
+
+@group(0) @binding(0) var<storage, read> read_pool: array<vec2<u32>>;
+@group(0) @binding(1) var<storage, read_write> write_pool: array<vec2<u32>>;
+@group(0) @binding(2) var<storage, read> materials: array<vec4<f32>>;
+@group(0) @binding(7) var<storage, read> chunk_descriptors: array<u32>;
+
+// Aux channel pools (aux_channels.wgsl), in their own group (C-GPU-3)
+@group(1) @binding(0) var<storage, read> aux_read: array<u32>;
+@group(1) @binding(1) var<storage, read_write> aux_write: array<u32>;
+
+fn is_gas(voxel: vec2<u32>) -> bool {
+    return u32(materials[unpack_material_id(voxel) * MATERIAL_PROPS_STRIDE].y) == PHASE_GAS;
+}
+
+@compute @workgroup_size(8, 8, 4)
+fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
+    let chunk_idx = gid.z / CHUNK_SIZE;
+    let local_z = gid.z % CHUNK_SIZE;
+    let pos = vec3<i32>(i32(gid.x), i32(gid.y), i32(local_z));
+
+    if !in_bounds(pos) {
+        return;
+    }
+
+    let idx = write_pool_voxel_index(pos, chunk_idx);
+    let voxel = write_pool[idx];
+    let aux_idx = aux_index(idx, AUX_OXYGEN_DEFICIT_WORD);
+    let my_deficit = i32(unpack_aux_oxygen_deficit(aux_read[aux_idx]));
+    let burned = i32(unpack_aux_oxygen_deficit(aux_write[aux_idx])) - my_deficit;
+    let my_gas = is_gas(voxel);
+    let my_temp = unpack_temperature(voxel);
+
+    var flow = 0;
+    for (var n = 0u; n < 6u; n = n + 1u) {
+        let offset = FACE_OFFSETS[n];
+        let neighbor_idx = cross_chunk_index(pos + offset, chunk_idx);
+
+        // Unloaded neighbors are fresh ambient air
+        var neighbor_deficit = 0;
+        var neighbor_gas = true;
+        var neighbor_temp = AMBIENT_TEMP_QUANTIZED;
+        if neighbor_idx != SENTINEL_NEIGHBOR {
+            let neighbor = write_pool[neighbor_idx];
+            neighbor_deficit = i32(unpack_aux_oxygen_deficit(
+                aux_read[aux_index(neighbor_idx, AUX_OXYGEN_DEFICIT_WORD)]
+            ));
+            neighbor_gas = is_gas(neighbor);
+            neighbor_temp = unpack_temperature(neighbor);
+        }
+
+        var diff = neighbor_deficit - my_deficit;
+        if !my_gas && !neighbor_gas {
+            continue;
+        }
+        if !my_gas {
+            diff = min(diff, 0);
+        } else if !neighbor_gas {
+            diff = max(diff, 0);
+        }
+
+        // Draft: hot gas below a cooler cell rises and pulls air through faster
+        var rate = OXYGEN_DIFFUSION_RATE;
+        if offset.y != 0 {
+            var lower_temp = my_temp;
+            var upper_temp = neighbor_temp;
+            if offset.y < 0 {
+                lower_temp = neighbor_temp;
+                upper_temp = my_temp;
+            }
+            if lower_temp > upper_temp + CONVECTION_THRESHOLD {
+                rate = OXYGEN_DRAFT_RATE;
+            }
+        }
+        flow = flow + i32(f32(diff) * rate);
+    }
+
+    let new_deficit = clamp(my_deficit + flow + burned, 0, i32(AUX_OXYGEN_DEFICIT_MAX));
+    aux_write[aux_idx] = with_aux_oxygen_deficit(aux_write[aux_idx], u32(new_deficit));
+}
@@ -2,17 +2,33 @@
 // Reads/writes write_pool (in-place, own voxel only). Reads materials buffer.
 // Uses cross_chunk_voxel() for 6-neighbor pressure diffusion across chunk boundaries.
 //
-// Workgroup: 8x8x4 = 256 threads.
-// Dispatch: (CHUNK_SIZE/8, CHUNK_SIZE/8, active_chunk_count * CHUNK_SIZE/4)
+// Two entry points, dispatched in order:
+//   columns — Workgroup 8x8x1, one thread per (x, z) column of a chunk.
+//             Dispatch: (CHUNK_SIZE/8, CHUNK_SIZE/8, active_chunk_count)
+//             Walks the column down once, storing in COLUMN (aux_write) the
+//             weight of the liquid from each cell up to the top of its column.
+//   main    — Workgroup 8x8x4 = 256 threads.
+//             Dispatch: (CHUNK_SIZE/8, CHUNK_SIZE/8, active_chunk_count * CHUNK_SIZE/4)
 //
-// Algorithm per voxel:
+// Algorithm per voxel (main):
 //   1. Enclosure check: count non-air face neighbors (6-connected)
 //   2. Thermal pressure: enclosed gas/liquid above ambient → +1 pressure/tick
-//   3. Pressure diffusion: average with 6 face neighbors weighted by PRESSURE_DIFFUSION_RATE
-//   4. Rupture: pressure > structural_integrity → become Air with high outward velocity
+//   3. Pressure diffusion: average with 6 face neighbors weighted by
+//      PRESSURE_DIFFUSION_RATE
+//   4. Hydrostatic head: a liquid adds the weight of its column, or the head
+//      of the liquid beside and below it if that is more
+//   5. Rupture: pressure, or for a solid the load of the liquid against it,
+//      > structural_integrity → become Air with high outward velocity
+//
+// A voxel's pressure is what it has built up (steps 2 and 3) plus, for a
+// liquid, its head, which is kept apart in HEAD (aux) so that only the
+// built-up part accumulates and diffuses. Liquid is found where movement
+// left it this tick (COLUMN, which main does not change), and head as the
+// neighbors had it at the start of the tick (read_pool, aux_read).
 
 const PHASE_GAS: u32 = 0u;
 const PHASE_LIQUID: u32 = 1u;
+const PHASE_SOLID: u32 = 2u;
 
 // 6 face-adjacent neighbor offsets
 const FACE_OFFSETS: array<vec3<i32>, 6> = array<vec3<i32>, 6>(
@@ -55,6 +71,116 @@ struct SimCommand {
 @group(0) @binding(6) var<storage, read> rule_data: array<vec4<u32>>;
 @group(0) @binding(7) var<storage, read> chunk_descriptors: array<u32>;
 
+// Aux channel pools (aux_channels.wgsl), in their own group (C-GPU-3)
+@group(1) @binding(0) var<storage, read> aux_read: array<u32>;
+@group(1) @binding(1) var<storage, read_write> aux_write: array<u32>;
+
+fn is_liquid(voxel: vec2<u32>) -> bool {
+    let mat_id = unpack_material_id(voxel);
+    return mat_id != 0u && u32(materials[mat_id * MATERIAL_PROPS_STRIDE].y) == PHASE_LIQUID;
+}
+
+fn is_open(voxel: vec2<u32>) -> bool {
+    let mat_id = unpack_material_id(voxel);
+    return mat_id == 0u || u32(materials[mat_id * MATERIAL_PROPS_STRIDE].y) == PHASE_GAS;
+}
+
+/// Pressure a voxel of liquid adds to the column under it: its weight, in
+/// whole pressure units and at least one.
+fn head_weight(voxel: vec2<u32>) -> i32 {
+    let density = materials[unpack_material_id(voxel) * MATERIAL_PROPS_STRIDE].x;
+    return max(i32(floor(density * STRUCTURAL_WEIGHT_PER_DENSITY + 0.5)), 1);
+}
+
+fn aux_head(aux: u32) -> i32 {
+    return i32(unpack_aux_head(aux));
+}
+
+fn set_head(idx: u32, head: i32) {
+    let aux_idx = aux_index(idx, AUX_HEAD_WORD);
+    aux_write[aux_idx] = with_aux_head(aux_write[aux_idx], u32(head));
+}
+
+/// COLUMN of the cell at voxel pool index `idx`, as `columns` stored it.
+fn liquid_column(idx: u32) -> i32 {
+    return i32(unpack_aux_column(aux_write[aux_index(idx, AUX_COLUMN_WORD)]));
+}
+
+/// Hydrostatic head of the liquid at `pos`: the weight of its column, or the
+/// head passed on by the liquid beside and below it if that is more. Liquid
+/// at the edge of an open surface spills rather than holding head, so it
+/// only has its column.
+fn hydrostatic_pressure(pos: vec3<i32>, chunk_idx: u32, tick: u32) -> i32 {
+    let column = liquid_column(write_pool_voxel_index(pos, chunk_idx));
+    let decay = select(0, 1, tick % HYDROSTATIC_DECAY_PERIOD == 0u);
+    var head = column;
+    for (var n = 0u; n < 6u; n = n + 1u) {
+        let offset = FACE_OFFSETS[n];
+        if offset.y > 0 {
+            continue;
+        }
+        let neighbor_pos = pos + offset;
+        if offset.y == 0
+            && is_open(cross_chunk_voxel(neighbor_pos, chunk_idx))
+            && is_open(cross_chunk_voxel(neighbor_pos + vec3<i32>(0, 1, 0), chunk_idx)) {
+            return column;
+        }
+        let idx = cross_chunk_index(neighbor_pos, chunk_idx);
+        if idx == SENTINEL_NEIGHBOR {
+            continue;
+        }
+        let voxel = read_pool[idx];
+        if !is_liquid(voxel) || liquid_column(idx) == 0 {
+            continue;
+        }
+        // Head climbs the liquid below less its weight, and spreads sideways
+        // as is, fading if nothing renews it
+        let neighbor_head = aux_head(aux_read[aux_index(idx, AUX_HEAD_WORD)]);
+        if offset.y < 0 {
+            head = max(head, neighbor_head - head_weight(voxel));
+        } else {
+            head = max(head, neighbor_head - decay);
+        }
+    }
+    return min(head, i32(MAX_PRESSURE));
+}
+
+@compute @workgroup_size(8, 8, 1)
+fn columns(@builtin(global_invocation_id) gid: vec3<u32>) {
+    let chunk_idx = gid.z;
+    let x = i32(gid.x);
+    let z = i32(gid.y);
+    if x >= i32(CHUNK_SIZE) || z >= i32(CHUNK_SIZE) {
+        return;
+    }
+
+    // Liquid running on into the chunk above, at most CHUNK_SIZE voxels of it
+    var weight = 0;
+    for (var y = i32(CHUNK_SIZE); y < 2 * i32(CHUNK_SIZE); y++) {
+        let idx = cross_chunk_index(vec3<i32>(x, y, z), chunk_idx);
+        if idx == SENTINEL_NEIGHBOR {
+            break;
+        }
+        let voxel = write_pool[idx];
+        if !is_liquid(voxel) || weight >= i32(MAX_PRESSURE) {
+            break;
+        }
+        weight += head_weight(voxel);
+    }
+
+    for (var y = i32(CHUNK_SIZE) - 1; y >= 0; y--) {
+        let idx = write_pool_voxel_index(vec3<i32>(x, y, z), chunk_idx);
+        let voxel = write_pool[idx];
+        if is_liquid(voxel) {
+            weight = min(weight + head_weight(voxel), i32(MAX_PRESSURE));
+        } else {
+            weight = 0;
+        }
+        let aux_idx = aux_index(idx, AUX_COLUMN_WORD);
+        aux_write[aux_idx] = with_aux_column(aux_write[aux_idx], u32(weight));
+    }
+}
+
 @compute @workgroup_size(8, 8, 4)
 fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
     let chunk_idx = gid.z / CHUNK_SIZE;
@@ -68,24 +194,30 @@ fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
     let idx = write_pool_voxel_index(pos, chunk_idx);
     var voxel = write_pool[idx];
     let mat_id = unpack_material_id(voxel);
+    let own_head = aux_head(aux_write[aux_index(idx, AUX_HEAD_WORD)]);
 
     if mat_id == 0u {
+        if own_head != 0 {
+            set_head(idx, 0);
+        }
         return;
     }
 
     let my_temp = unpack_temperature(voxel);
-    var my_pressure = i32(unpack_pressure(voxel));
+    // The pressure the voxel has built up, without its head
+    var my_pressure = max(i32(unpack_pressure(voxel)) - own_head, 0);
 
     // Read material properties
-    let props_0 = materials[mat_id * 4u];
+    let props_0 = materials[mat_id * MATERIAL_PROPS_STRIDE];
     let phase = u32(props_0.y);
-    let props_2 = materials[mat_id * 4u + 2u];
+    let props_2 = materials[mat_id * MATERIAL_PROPS_STRIDE + 2u];
     let structural_integrity = props_2.w;
 
     // --- Enclosure check: count non-air face neighbors ---
     var non_air_count = 0u;
     var neighbor_pressure_sum = 0;
     var neighbor_count = 0;
+    var liquid_load = 0;
 
     for (var n = 0u; n < 6u; n = n + 1u) {
         let neighbor_pos = pos + FACE_OFFSETS[n];
@@ -96,8 +228,17 @@ fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
             non_air_count = non_air_count + 1u;
         }
 
-        neighbor_pressure_sum = neighbor_pressure_sum + i32(unpack_pressure(neighbor_voxel));
+        var neighbor_head = 0;
+        let neighbor_idx = cross_chunk_index(neighbor_pos, chunk_idx);
+        if neighbor_idx != SENTINEL_NEIGHBOR {
+            neighbor_head = aux_head(aux_read[aux_index(neighbor_idx, AUX_HEAD_WORD)]);
+        }
+        neighbor_pressure_sum = neighbor_pressure_sum
+            + max(i32(unpack_pressure(neighbor_voxel)) - neighbor_head, 0);
         neighbor_count = neighbor_count + 1;
+        if is_liquid(neighbor_voxel) {
+            liquid_load = max(liquid_load, i32(unpack_pressure(neighbor_voxel)));
+        }
     }
 
     let enclosed = non_air_count == 6u;
@@ -109,7 +250,7 @@ fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
     }
 
     // --- Pressure diffusion ---
-    // Average own pressure with face neighbors
+    // Average own pressure with what the face neighbors built up
     if neighbor_count > 0 {
         let avg_neighbor = f32(neighbor_pressure_sum) / f32(neighbor_count);
         let diff = avg_neighbor - f32(my_pressure);
@@ -117,25 +258,38 @@ fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
         my_pressure = clamp(my_pressure, 0, i32(MAX_PRESSURE));
     }
 
+    // --- Hydrostatic head, on top ---
+    var head = 0;
+    if phase == PHASE_LIQUID {
+        head = hydrostatic_pressure(pos, chunk_idx, pressure_uniforms.tick);
+    }
+    my_pressure = min(my_pressure + head, i32(MAX_PRESSURE));
+
     // --- Rupture check ---
-    // If pressure exceeds structural integrity, voxel ruptures → becomes Air
-    if structural_integrity > 0.0 && f32(my_pressure) > structural_integrity {
+    // If pressure, or for a solid the load of the liquid against it, exceeds
+    // structural integrity, voxel ruptures → becomes Air
+    var load = my_pressure;
+    if phase == PHASE_SOLID {
+        load = max(load, liquid_load);
+    }
+    if structural_integrity > 0.0 && f32(load) > structural_integrity {
         // Rupture: become air, keep high pressure for blast wave propagation
         let flags = unpack_flags(voxel);
         // Set outward velocity based on hash for varied blast direction
         let h = sim_hash(pos.x, pos.y, pos.z, pressure_uniforms.tick);
         let dir_idx = h % 6u;
         let blast_dir = FACE_OFFSETS[dir_idx];
-        let blast_speed = clamp(my_pressure / 8, 1, 4);
+        let blast_speed = clamp(load / 8, 1, 4);
         write_pool[idx] = pack_voxel(
             0u,                          // Air
             my_temp,
             blast_dir.x * blast_speed,
             blast_dir.y * blast_speed,
             blast_dir.z * blast_speed,
-            u32(my_pressure),
+            u32(load),
             flags,
         );
+        set_head(idx, 0);
         return;
     }
 
@@ -145,4 +299,5 @@ fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
     let vz = unpack_vel_z(voxel);
     let flags = unpack_flags(voxel);
     write_pool[idx] = pack_voxel(mat_id, my_temp, vx, vy, vz, u32(my_pressure), flags);
+    set_head(idx, head);
 }
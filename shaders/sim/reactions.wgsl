@@ -1,12 +1,23 @@
-// reactions.wgsl — Pass 3: Pairwise reactions and self-decay (M5: multi-chunk, M15: charge conditions).
+// reactions.wgsl — Pass 3: Pairwise reactions and self-decay (M5: multi-chunk, M15: charge conditions, pressure conditions, catalysts).
+// Each material pair maps to a short list of candidate rules chosen by weighted roll.
 // Reads write_pool (post-movement state) + materials + rule_lookup + rule_data.
-// Reads charge_read (@group(1)) for charge-gated reactions (M15).
-// Writes write_pool (own voxel only — no cross-voxel writes).
+// Reads charge from aux_read (@group(1)) for charge-gated reactions (M15), the
+// oxygen deficit of the voxel's cell and its face neighbors for
+// oxygen-consuming reactions, and sediment (own in aux_write, neighbors' in
+// aux_read) to keep sediment-carrying liquid out of pairwise reactions.
+// Writes write_pool (own voxel only — no cross-voxel writes), and the voxel's
+// own oxygen deficit in aux_write: a gas voxel (the flame) burns a reaction's
+// oxygen out of its own cell, anything else draws on its gas neighbors and
+// records what it burned as a debt on its own cell, and fuel that burns away
+// into gas leaves its cell full of exhaust. The oxygen pass hands debts on to
+// the surrounding gas and spreads the deficit. A detonating reaction (blast > 0)
+// also seeds a blast front on its own voxel in aux_write for the blast pass.
 //
 // Workgroup: 8x8x4 = 256 threads.
 // Dispatch: (CHUNK_SIZE/8, CHUNK_SIZE/8, active_chunk_count * CHUNK_SIZE/4)
 
 const NO_RULE: u32 = 0xFFFFFFFFu;
+const PHASE_GAS: u32 = 0u;
 
 // 6 face-adjacent neighbor offsets
 const NEIGHBOR_OFFSETS: array<vec3<i32>, 6> = array<vec3<i32>, 6>(
@@ -49,8 +60,27 @@ struct SimCommand {
 @group(0) @binding(6) var<storage, read> rule_data: array<vec4<u32>>;
 @group(0) @binding(7) var<storage, read> chunk_descriptors: array<u32>;
 
-// M15: Charge buffer for charge-gated reactions (separate bind group to stay within C-GPU-3 limit)
-@group(1) @binding(0) var<storage, read> charge_read: array<u32>;
+// Aux channel pools (aux_channels.wgsl) for charge-gated and oxygen-consuming reactions
+// (separate bind group to stay within C-GPU-3 limit)
+@group(1) @binding(0) var<storage, read> aux_read: array<u32>;
+@group(1) @binding(1) var<storage, read_write> aux_write: array<u32>;
+
+fn is_gas_material(id: u32) -> bool {
+    return u32(materials[id * MATERIAL_PROPS_STRIDE].y) == PHASE_GAS;
+}
+
+/// Oxygen left in the cell of `voxel` (at pool index `pool_idx`), or 0 if
+/// the cell holds no gas. Unloaded cells are fresh air.
+fn cell_oxygen(voxel: vec2<u32>, pool_idx: u32) -> u32 {
+    if pool_idx == SENTINEL_NEIGHBOR {
+        return AUX_OXYGEN_DEFICIT_MAX;
+    }
+    if !is_gas_material(unpack_material_id(voxel)) {
+        return 0u;
+    }
+    let deficit = unpack_aux_oxygen_deficit(aux_read[aux_index(pool_idx, AUX_OXYGEN_DEFICIT_WORD)]);
+    return AUX_OXYGEN_DEFICIT_MAX - deficit;
+}
 
 @compute @workgroup_size(8, 8, 4)
 fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
@@ -72,22 +102,20 @@ fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
 
     var my_temp = unpack_temperature(voxel);
 
-    // Read charge for this voxel (M15: same index mapping as charge_buf_index in electrical.wgsl)
-    let slot_offset = chunk_descriptors[chunk_idx * CHUNK_DESC_STRIDE];
-    let my_charge = charge_read[(slot_offset / 8u) + voxel_index(pos)];
+    // Read charge for this voxel (M15)
+    let my_charge = unpack_aux_charge(aux_read[aux_index(idx, AUX_CHARGE_WORD)]);
+    let my_pressure = unpack_pressure(voxel);
+    let my_oxygen_deficit = unpack_aux_oxygen_deficit(aux_read[aux_index(idx, AUX_OXYGEN_DEFICIT_WORD)]);
 
     // --- Self-decay ---
-    let props_1 = materials[mat_id * 4u + 1u];
+    let props_1 = materials[mat_id * MATERIAL_PROPS_STRIDE + 1u];
     let decay_rate = u32(props_1.x);
     let decay_threshold = u32(props_1.y);
     let decay_product = u32(props_1.z);
 
+    let mc = reaction_uniforms.material_count;
     if decay_rate > 0u {
-        if my_temp > decay_rate {
-            my_temp = my_temp - decay_rate;
-        } else {
-            my_temp = 0u;
-        }
+        my_temp = step_temperature(mc, my_temp, -f32(decay_rate));
 
         if my_temp < decay_threshold {
             write_pool[idx] = repack_material_temp(voxel, decay_product, my_temp);
@@ -99,67 +127,158 @@ fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
 
     // --- Upward phase change ---
     {
-        let props_2 = materials[unpack_material_id(voxel) * 4u + 2u];
+        let props_2 = materials[unpack_material_id(voxel) * MATERIAL_PROPS_STRIDE + 2u];
         let phase_change_temp_q = u32(props_2.y);
         let phase_change_product = u32(props_2.z);
         if phase_change_temp_q > 0u && my_temp >= phase_change_temp_q {
-            voxel = repack_material_temp(voxel, phase_change_product, my_temp);
+            // Heat above the transition point goes into the latent heat: the
+            // voxel stays at phase_change_temp and changes with probability
+            // excess / latent_heat, one slot past the six pair rolls
+            let latent_heat_steps = materials[unpack_material_id(voxel) * MATERIAL_PROPS_STRIDE + 4u].y;
+            var product = phase_change_product;
+            if latent_heat_steps > 0.0 {
+                let excess = temp_lut(mc, my_temp) - temp_lut(mc, phase_change_temp_q);
+                let roll = hash_to_float(sim_hash(pos.x + 6, pos.y, pos.z, reaction_uniforms.tick));
+                if roll * latent_heat_steps >= excess {
+                    product = unpack_material_id(voxel);
+                }
+                my_temp = phase_change_temp_q;
+            }
+            voxel = repack_material_temp(voxel, product, my_temp);
+        }
+    }
+
+    // --- Downward phase change (ends the voxel's turn, like self-decay) ---
+    {
+        let props_4 = materials[unpack_material_id(voxel) * MATERIAL_PROPS_STRIDE + 4u];
+        let freeze_temp_q = u32(props_4.z);
+        let freeze_product = u32(props_4.w);
+        if freeze_temp_q > 0u && my_temp < freeze_temp_q {
+            write_pool[idx] = repack_material_temp(voxel, freeze_product, my_temp);
+            return;
         }
     }
 
     // --- Pairwise reactions with cross-chunk neighbor reads ---
-    let mc = reaction_uniforms.material_count;
 
+    // Fetch all six neighbors first so catalyst and oxygen checks can see the
+    // whole neighborhood. Use cross_chunk_voxel for cross-boundary reads.
+    // Oxygen-consuming rules need the oxygen in a gas voxel's own cell, or in
+    // the best-supplied gas neighbor of anything else.
+    // Liquid carrying sediment sits out pairwise reactions on both sides of
+    // the pair, so the load is never lost with the liquid.
+    let my_gas = is_gas_material(unpack_material_id(voxel));
+    let my_sediment = unpack_aux_sediment(aux_write[aux_index(idx, AUX_SEDIMENT_WORD)]);
+    var neighbor_mats: array<u32, 6>;
+    var neighbor_carries: array<bool, 6>;
+    var oxygen_in_reach = cell_oxygen(voxel, idx);
     for (var n = 0u; n < 6u; n = n + 1u) {
         let neighbor_pos = pos + NEIGHBOR_OFFSETS[n];
-
-        // Use cross_chunk_voxel for cross-boundary reads
         let neighbor_voxel = cross_chunk_voxel(neighbor_pos, chunk_idx);
-        let neighbor_mat = unpack_material_id(neighbor_voxel);
+        let neighbor_idx = cross_chunk_index(neighbor_pos, chunk_idx);
+        neighbor_mats[n] = unpack_material_id(neighbor_voxel);
+        if !my_gas {
+            oxygen_in_reach = max(oxygen_in_reach, cell_oxygen(neighbor_voxel, neighbor_idx));
+        }
+        neighbor_carries[n] = neighbor_idx != SENTINEL_NEIGHBOR
+            && unpack_aux_sediment(aux_read[aux_index(neighbor_idx, AUX_SEDIMENT_WORD)]) != 0u;
+    }
+
+    for (var n = 0u; n < 6u; n = n + 1u) {
+        if my_sediment != 0u || neighbor_carries[n] {
+            continue;
+        }
+        let neighbor_mat = neighbor_mats[n];
 
         let lookup_idx = mat_id * mc + neighbor_mat;
-        let rule_idx = rule_lookup[lookup_idx];
+        let lookup = rule_lookup[lookup_idx];
 
-        if rule_idx == NO_RULE {
+        if lookup == NO_RULE {
             continue;
         }
 
-        let rule_0 = rule_data[rule_idx * 2u];
-        let rule_1 = rule_data[rule_idx * 2u + 1u];
+        // Candidates for this pair are contiguous: (first << RULE_COUNT_BITS) | count.
+        // One roll per neighbor; eligible candidates claim consecutive slices
+        // of the probability range in rule order.
+        let first_rule = lookup >> RULE_COUNT_BITS;
+        let rule_count = lookup & ((1u << RULE_COUNT_BITS) - 1u);
+        let roll = sim_hash(pos.x + i32(n), pos.y, pos.z, reaction_uniforms.tick);
+        var cumulative = 0u;
+        var rule_idx = NO_RULE;
+
+        for (var c = 0u; c < rule_count; c = c + 1u) {
+            let candidate = first_rule + c;
+            let cand_0 = rule_data[candidate * 2u];
+            let cand_1 = rule_data[candidate * 2u + 1u];
+
+            // rule_1.z/.w pack min/max temp (low 16 bits) with min/max pressure (high 16 bits)
+            let min_temp = cand_1.z & 0xFFFFu;
+            let max_temp = cand_1.w & 0xFFFFu;
+            if min_temp > 0u && my_temp < min_temp {
+                continue;
+            }
+            if max_temp > 0u && my_temp > max_temp {
+                continue;
+            }
 
-        let input_a_becomes = rule_0.x;
-        let probability_u32 = rule_0.w;
+            // M15: Charge condition checks (rule_0.z low bits = min_charge, rule_1.y low bits = max_charge)
+            let min_charge_rule = cand_0.z & 0xFFFFu;
+            let max_charge_rule = cand_1.y & 0xFFFFu;
+            if min_charge_rule > 0u && my_charge < min_charge_rule {
+                continue;
+            }
+            if max_charge_rule > 0u && my_charge > max_charge_rule {
+                continue;
+            }
 
-        let min_temp = rule_1.z;
-        let max_temp = rule_1.w;
+            let min_pressure_rule = cand_1.z >> 16u;
+            let max_pressure_rule = cand_1.w >> 16u;
+            if min_pressure_rule > 0u && my_pressure < min_pressure_rule {
+                continue;
+            }
+            if max_pressure_rule > 0u && my_pressure > max_pressure_rule {
+                continue;
+            }
 
-        if min_temp > 0u && my_temp < min_temp {
-            continue;
-        }
-        if max_temp > 0u && my_temp > max_temp {
-            continue;
-        }
+            // Catalyst (high 16 bits of rule_0.x) must be somewhere in the neighborhood
+            let catalyst = cand_0.x >> 16u;
+            if catalyst != NO_CATALYST {
+                var found = false;
+                for (var k = 0u; k < 6u; k = k + 1u) {
+                    found = found || neighbor_mats[k] == catalyst;
+                }
+                if !found {
+                    continue;
+                }
+            }
 
-        // M15: Charge condition checks (rule_0.z = min_charge, rule_1.y = max_charge)
-        let min_charge_rule = rule_0.z;
-        let max_charge_rule = rule_1.y;
-        if min_charge_rule > 0u && my_charge < min_charge_rule {
-            continue;
+            // Oxygen (high 16 bits of rule_0.z) must be in reach, and this
+            // voxel's cell must have room to record burning it
+            let oxygen = cand_0.z >> 16u;
+            if oxygen > 0u
+                && (oxygen_in_reach < oxygen || my_oxygen_deficit + oxygen > AUX_OXYGEN_DEFICIT_MAX) {
+                continue;
+            }
+
+            // Saturating add of this candidate's probability (rule_0.w)
+            let sum = cumulative + cand_0.w;
+            cumulative = select(sum, 0xFFFFFFFFu, sum < cumulative);
+            if cumulative == 0xFFFFFFFFu || roll <= cumulative {
+                rule_idx = candidate;
+                break;
+            }
         }
-        if max_charge_rule > 0u && my_charge > max_charge_rule {
+
+        if rule_idx == NO_RULE {
             continue;
         }
 
-        if probability_u32 < 0xFFFFFFFFu {
-            let h = sim_hash(pos.x + i32(n), pos.y, pos.z, reaction_uniforms.tick);
-            if h > probability_u32 {
-                continue;
-            }
-        }
+        let rule_0 = rule_data[rule_idx * 2u];
+        let rule_1 = rule_data[rule_idx * 2u + 1u];
+        let input_a_becomes = rule_0.x & 0xFFFFu;
 
         let temp_delta = bitcast<i32>(rule_1.x);
-        var new_temp = i32(my_temp) + temp_delta;
-        new_temp = clamp(new_temp, 0, 4095);
+        let new_temp = step_temperature(mc, my_temp, f32(temp_delta));
 
         // Apply pressure_delta from rule (rule_0.y, bitcast to i32)
         let pressure_delta = bitcast<i32>(rule_0.y);
@@ -170,7 +289,26 @@ fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
         let vy = unpack_vel_y(voxel);
         let vz = unpack_vel_z(voxel);
         let flags = unpack_flags(voxel);
-        voxel = pack_voxel(input_a_becomes, u32(new_temp), vx, vy, vz, u32(new_pressure), flags);
+        voxel = pack_voxel(input_a_becomes, new_temp, vx, vy, vz, u32(new_pressure), flags);
+
+        let oxygen = rule_0.z >> 16u;
+        if oxygen > 0u {
+            // Fuel that burns away into gas leaves its cell full of exhaust
+            var new_deficit = my_oxygen_deficit + oxygen;
+            if !my_gas && is_gas_material(input_a_becomes) {
+                new_deficit = AUX_OXYGEN_DEFICIT_MAX;
+            }
+            let aux_idx = aux_index(idx, AUX_OXYGEN_DEFICIT_WORD);
+            aux_write[aux_idx] = with_aux_oxygen_deficit(aux_write[aux_idx], new_deficit);
+        }
+
+        // Detonation (high 16 bits of rule_1.y) seeds a blast front here
+        let blast = rule_1.y >> 16u;
+        if blast > 0u {
+            let aux_idx = aux_index(idx, AUX_BLAST_WORD);
+            let seeded = with_aux_blast(aux_write[aux_idx], blast);
+            aux_write[aux_idx] = with_aux_blast_front(seeded, BLAST_FRONT_SEEDED);
+        }
         break;
     }
 
@@ -1,6 +1,10 @@
 // thermal.wgsl — Pass 4: Thermal diffusion, entropy drain, convection (M5: multi-chunk).
 // Reads/writes write_pool (in-place, own voxel only). Reads materials buffer.
 // Uses cross_chunk_voxel() for 26-neighbor diffusion across chunk boundaries.
+// Temperatures are compared and stepped on the linear scale via temperature.wgsl.
+// Radiation: where a neighbor is air or gas, an emissive voxel's ray continues
+// up to RADIATION_RADIUS voxels and exchanges radiant heat with what it sees.
+// Skipped entirely when no material is emissive (thermal_uniforms.radiates == 0).
 //
 // Workgroup: 8x8x4 = 256 threads.
 // Dispatch: (CHUNK_SIZE/8, CHUNK_SIZE/8, active_chunk_count * CHUNK_SIZE/4)
@@ -11,12 +15,12 @@ const PHASE_LIQUID: u32 = 1u;
 struct ThermalUniforms {
     tick: u32,
     material_count: u32,
+    radiates: u32,
     _pad0: u32,
     _pad1: u32,
     _pad2: u32,
     _pad3: u32,
     _pad4: u32,
-    _pad5: u32,
 }
 
 struct SimCommand {
@@ -39,6 +43,54 @@ struct SimCommand {
 @group(0) @binding(6) var<storage, read> rule_data: array<vec4<u32>>;
 @group(0) @binding(7) var<storage, read> chunk_descriptors: array<u32>;
 
+/// Radiant power at linear temperature `linear`, as (T / 1000 K)^4.
+fn radiant_power(linear: f32) -> f32 {
+    let t = linear * (TEMP_QUANT_MAX_K / f32(TEMP_QUANT_MAX_VALUE)) / 1000.0;
+    let t2 = t * t;
+    return t2 * t2;
+}
+
+/// Net radiant heat flow into the voxel at `pos` from voxels seen along `dir`,
+/// 2..=RADIATION_RADIUS steps away (step 1 is the conduction neighbor, which
+/// the caller has found to be air or gas). Air and gases let the ray through;
+/// the first liquid or solid ends it. Each voxel pair exchanges
+/// RADIATION_RATE * min(emissivity) * (power difference) / distance^2, capped
+/// at RADIATION_MAX_GAP_FRACTION of their temperature gap, so the other
+/// voxel's ray back along -dir sees the same pair with the sign flipped and
+/// the heat is conserved. A non-emissive voxel neither emits nor absorbs, so
+/// callers skip the ray when `my_emissivity` is zero.
+fn radiate_along(
+    pos: vec3<i32>,
+    dir: vec3<i32>,
+    chunk_idx: u32,
+    mc: u32,
+    my_emissivity: f32,
+    my_linear: f32,
+    my_power: f32,
+) -> f32 {
+    let unit_dist2 = f32(dot(dir, dir));
+    var flow = 0.0;
+    for (var k = 2; k <= i32(RADIATION_RADIUS); k = k + 1) {
+        let other = cross_chunk_voxel(pos + dir * k, chunk_idx);
+        let other_mat = unpack_material_id(other);
+        if other_mat == 0u {
+            continue;
+        }
+        let emissivity = min(my_emissivity, materials[other_mat * MATERIAL_PROPS_STRIDE + 5u].x);
+        if emissivity > 0.0 {
+            let other_linear = temp_lut(mc, unpack_temperature(other));
+            let exchange = RADIATION_RATE * emissivity * (radiant_power(other_linear) - my_power)
+                / (unit_dist2 * f32(k * k));
+            let cap = abs(other_linear - my_linear) * RADIATION_MAX_GAP_FRACTION;
+            flow += clamp(exchange, -cap, cap);
+        }
+        if u32(materials[other_mat * MATERIAL_PROPS_STRIDE].y) != PHASE_GAS {
+            break;
+        }
+    }
+    return flow;
+}
+
 @compute @workgroup_size(8, 8, 4)
 fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
     let chunk_idx = gid.z / CHUNK_SIZE;
@@ -57,13 +109,20 @@ fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
         return;
     }
 
-    var my_temp = unpack_temperature(voxel);
+    let mc = thermal_uniforms.material_count;
+    let my_temp = unpack_temperature(voxel);
+    let my_linear = temp_lut(mc, my_temp);
 
-    let props_2 = materials[mat_id * 4u + 2u];
+    let props_2 = materials[mat_id * MATERIAL_PROPS_STRIDE + 2u];
     let my_conductivity = props_2.x;
+    let inv_heat_capacity = materials[mat_id * MATERIAL_PROPS_STRIDE + 4u].x;
+    let my_emissivity = materials[mat_id * MATERIAL_PROPS_STRIDE + 5u].x;
+    let radiates = thermal_uniforms.radiates != 0u && my_emissivity > 0.0;
+    let my_power = radiant_power(my_linear);
 
-    // --- 26-neighbor diffusion with cross-chunk reads ---
+    // --- 26-neighbor diffusion with cross-chunk reads, radiation past air/gas ---
     var delta = 0.0;
+    var radiation = 0.0;
     for (var dz = -1; dz <= 1; dz = dz + 1) {
         for (var dy = -1; dy <= 1; dy = dy + 1) {
             for (var dx = -1; dx <= 1; dx = dx + 1) {
@@ -77,12 +136,17 @@ fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
                 let neighbor_voxel = cross_chunk_voxel(neighbor_pos, chunk_idx);
                 let neighbor_mat = unpack_material_id(neighbor_voxel);
 
+                let neighbor_phase = u32(materials[neighbor_mat * MATERIAL_PROPS_STRIDE].y);
+                if radiates && (neighbor_mat == 0u || neighbor_phase == PHASE_GAS) {
+                    radiation += radiate_along(pos, vec3<i32>(dx, dy, dz), chunk_idx, mc, my_emissivity, my_linear, my_power);
+                }
+
                 if neighbor_mat == 0u {
                     continue;
                 }
 
                 let neighbor_temp = unpack_temperature(neighbor_voxel);
-                let neighbor_props_2 = materials[neighbor_mat * 4u + 2u];
+                let neighbor_props_2 = materials[neighbor_mat * MATERIAL_PROPS_STRIDE + 2u];
                 let neighbor_conductivity = neighbor_props_2.x;
 
                 let abs_sum = abs(dx) + abs(dy) + abs(dz);
@@ -94,26 +158,31 @@ fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
                 }
 
                 let k_avg = (my_conductivity + neighbor_conductivity) * 0.5;
-                delta += weight * k_avg * (f32(neighbor_temp) - f32(my_temp));
+                delta += weight * k_avg * (temp_lut(mc, neighbor_temp) - my_linear);
             }
         }
     }
 
-    var new_temp = i32(my_temp) + i32(DIFFUSION_RATE * delta / 26.0);
-    new_temp = clamp(new_temp, 0, i32(TEMP_QUANT_MAX_VALUE));
+    // delta and radiation are heat flows; heat capacity turns them into a temperature change
+    let heat = DIFFUSION_RATE * delta / 26.0 + radiation;
+    var new_temp = step_temperature(mc, my_temp, heat * inv_heat_capacity);
 
     // --- Entropy drain ---
-    if new_temp > i32(AMBIENT_TEMP_QUANTIZED) {
-        new_temp = max(new_temp - i32(ENTROPY_DRAIN_RATE), i32(AMBIENT_TEMP_QUANTIZED));
-    } else if new_temp < i32(AMBIENT_TEMP_QUANTIZED) {
-        new_temp = min(new_temp + i32(ENTROPY_DRAIN_RATE), i32(AMBIENT_TEMP_QUANTIZED));
+    if new_temp > AMBIENT_TEMP_QUANTIZED {
+        new_temp = max(step_temperature(mc, new_temp, -f32(ENTROPY_DRAIN_RATE)), AMBIENT_TEMP_QUANTIZED);
+    } else if new_temp < AMBIENT_TEMP_QUANTIZED {
+        new_temp = min(step_temperature(mc, new_temp, f32(ENTROPY_DRAIN_RATE)), AMBIENT_TEMP_QUANTIZED);
     }
 
     // --- Convection ---
-    let props_0 = materials[mat_id * 4u];
+    let props_0 = materials[mat_id * MATERIAL_PROPS_STRIDE];
     let phase = u32(props_0.y);
     var vy = unpack_vel_y(voxel);
-    if (phase == PHASE_LIQUID || phase == PHASE_GAS) && u32(new_temp) > AMBIENT_TEMP_QUANTIZED + CONVECTION_THRESHOLD {
+    let convection_linear = temp_lut(mc, AMBIENT_TEMP_QUANTIZED) + f32(CONVECTION_THRESHOLD);
+    // A flying liquid keeps its ballistic velocity (ballistic.wgsl)
+    let speed = max(abs(unpack_vel_x(voxel)), max(abs(unpack_vel_y(voxel)), abs(unpack_vel_z(voxel))));
+    let flying = phase == PHASE_LIQUID && speed >= i32(BALLISTIC_MIN_SPEED);
+    if (phase == PHASE_LIQUID || phase == PHASE_GAS) && !flying && temp_lut(mc, new_temp) > convection_linear {
         vy = 1;
     }
 
@@ -121,5 +190,5 @@ fn main(@builtin(global_invocation_id) gid: vec3<u32>) {
     let vz = unpack_vel_z(voxel);
     let pressure = unpack_pressure(voxel);
     let flags = unpack_flags(voxel);
-    write_pool[idx] = pack_voxel(mat_id, u32(new_temp), vx, vy, vz, pressure, flags);
+    write_pool[idx] = pack_voxel(mat_id, new_temp, vx, vy, vz, pressure, flags);
 }